@@ -2,6 +2,11 @@ pub use sea_orm_migration::prelude::*;
 
 mod m20251128_171016_create_table_biz_metadata;
 mod m20251128_171200_create_table_biz_metadata_alias;
+mod m20251201_090000_normalize_biz_metadata_code_case;
+mod m20261016_090000_create_table_biz_metadata_alias_history;
+mod m20261016_100000_add_unique_index_biz_metadata_alias_live;
+mod m20261016_110000_scope_biz_metadata_code_unique_to_parent;
+mod m20261016_120000_normalize_biz_metadata_alias_text;
 
 pub struct Migrator;
 
@@ -11,6 +16,11 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20251128_171016_create_table_biz_metadata::Migration),
             Box::new(m20251128_171200_create_table_biz_metadata_alias::Migration),
+            Box::new(m20251201_090000_normalize_biz_metadata_code_case::Migration),
+            Box::new(m20261016_090000_create_table_biz_metadata_alias_history::Migration),
+            Box::new(m20261016_100000_add_unique_index_biz_metadata_alias_live::Migration),
+            Box::new(m20261016_110000_scope_biz_metadata_code_unique_to_parent::Migration),
+            Box::new(m20261016_120000_normalize_biz_metadata_alias_text::Migration),
         ]
     }
 }
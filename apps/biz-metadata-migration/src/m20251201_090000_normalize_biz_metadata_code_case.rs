@@ -0,0 +1,138 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::Statement;
+
+/// 将存量 biz_metadata.code 规范化为小写，并同步改写 `value_type` 中 `ref:<code>` 的目标编码。
+///
+/// 同一租户内未删除记录若仅大小写不同，会在规范化后违反 ux_biz_metadata_tenant_code_alive；
+/// 迁移会先检测此类冲突并整体报错（列出冲突编码），由人工处理后再重试，不做静默合并。
+/// 引用目标与编码在同一迁移事务内改写，避免留下指向旧大小写编码的悬空引用。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let collisions = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                r#"
+                SELECT tenant_id, lower(code) AS canonical_code, string_agg(code, ',' ORDER BY id) AS codes
+                FROM biz_metadata
+                WHERE deleted_at IS NULL
+                GROUP BY tenant_id, lower(code)
+                HAVING count(*) > 1
+                ORDER BY tenant_id, canonical_code;
+                "#,
+            ))
+            .await?;
+
+        if !collisions.is_empty() {
+            let details = collisions
+                .iter()
+                .map(|row| {
+                    let tenant_id: String = row.try_get("", "tenant_id")?;
+                    let canonical: String = row.try_get("", "canonical_code")?;
+                    let codes: String = row.try_get("", "codes")?;
+                    Ok(format!("{tenant_id}:{canonical} <- [{codes}]"))
+                })
+                .collect::<Result<Vec<_>, DbErr>>()?;
+            return Err(DbErr::Migration(format!(
+                "biz_metadata code case collisions, resolve manually before retrying: {}",
+                details.join("; ")
+            )));
+        }
+
+        db.execute_unprepared(
+            r#"
+            UPDATE biz_metadata
+            SET code = lower(code)
+            WHERE code <> lower(code);
+            "#,
+        )
+        .await?;
+
+        let refs = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                r#"
+                SELECT id, value_type
+                FROM biz_metadata
+                WHERE value_type LIKE '%ref:%'
+                ORDER BY id;
+                "#,
+            ))
+            .await?;
+        for row in &refs {
+            let id: i64 = row.try_get("", "id")?;
+            let value_type: String = row.try_get("", "value_type")?;
+            let rewritten = lowercase_ref_targets(&value_type);
+            if rewritten != value_type {
+                db.execute_raw(Statement::from_sql_and_values(
+                    db.get_database_backend(),
+                    "UPDATE biz_metadata SET value_type = $1 WHERE id = $2",
+                    [rewritten.into(), id.into()],
+                ))
+                .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // 原始大小写已不可恢复，回滚为空操作。
+        Ok(())
+    }
+}
+
+/// 将 `value_type` 中每个 `ref:<code>` 的目标编码转为小写，其余文本保持原样。
+///
+/// `ref:` 仅在位于开头或紧随 `|`、`<`、`:`、空白之后时视为引用前缀。
+fn lowercase_ref_targets(value_type: &str) -> String {
+    let mut out = String::with_capacity(value_type.len());
+    let mut rest = value_type;
+    while let Some(pos) = rest.find("ref:") {
+        let starts_term = rest[..pos]
+            .chars()
+            .next_back()
+            .or_else(|| out.chars().next_back())
+            .is_none_or(|c| matches!(c, '|' | '<' | ':') || c.is_whitespace());
+        let (head, tail) = rest.split_at(pos + "ref:".len());
+        out.push_str(head);
+        let end = tail
+            .find(|c: char| matches!(c, '|' | '>') || c.is_whitespace())
+            .unwrap_or(tail.len());
+        if starts_term {
+            out.push_str(&tail[..end].to_lowercase());
+            rest = &tail[end..];
+        } else {
+            rest = tail;
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::lowercase_ref_targets;
+
+    #[test]
+    fn ref_targets_are_lowercased_and_other_terms_kept() {
+        assert_eq!(
+            lowercase_ref_targets("ref:Company.Base.Amount"),
+            "ref:company.base.amount"
+        );
+        assert_eq!(
+            lowercase_ref_targets("String | ref:Company.Name"),
+            "String | ref:company.name"
+        );
+        assert_eq!(
+            lowercase_ref_targets("json<array:int|ref:Company.Tag>"),
+            "json<array:int|ref:company.tag>"
+        );
+        assert_eq!(lowercase_ref_targets("xref:Company"), "xref:Company");
+        assert_eq!(lowercase_ref_targets("decimal"), "decimal");
+    }
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Alias::new("biz_metadata_alias_history"))
+                    .comment("业务元数据别名变更历史表 (如首选别名切换)")
+                    .if_not_exists()
+                    .col(big_pk_auto("id").comment("自增主键"))
+                    .col(
+                        big_integer("metadata_id")
+                            .not_null()
+                            .comment("关联的标准元数据 ID"),
+                    )
+                    .col(
+                        string_len("action", 32)
+                            .not_null()
+                            .comment("变更动作：promote_primary"),
+                    )
+                    .col(
+                        big_integer("alias_id")
+                            .not_null()
+                            .comment("本次变更的目标别名 ID (如被提升为首选的别名)"),
+                    )
+                    .col(
+                        big_integer("previous_alias_id")
+                            .null()
+                            .comment("被替换的别名 ID (如原首选别名)，没有时为空"),
+                    )
+                    .col(
+                        timestamp_with_time_zone("created_at")
+                            .not_null()
+                            .default(Expr::current_timestamp())
+                            .comment("变更时间"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_metadata_alias_history_mid")
+                    .table(Alias::new("biz_metadata_alias_history"))
+                    .col(Alias::new("metadata_id"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(Alias::new("biz_metadata_alias_history"))
+                    .if_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+}
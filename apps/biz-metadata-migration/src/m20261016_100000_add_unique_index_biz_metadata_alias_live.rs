@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::Statement;
+
+/// 为存活别名建立 (metadata_id, alias, language) 唯一索引，支撑同步场景的幂等 find-or-create。
+///
+/// 存量数据中同一元数据下文本与语言都相同的存活别名会违反该索引；迁移先检测此类重复并整体报错
+/// （列出每组的别名 ID），由人工处理后再重试，不做静默删除。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        let duplicates = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                r#"
+                SELECT metadata_id, alias, language, string_agg(id::text, ',' ORDER BY id) AS ids
+                FROM biz_metadata_alias
+                WHERE deleted_at IS NULL
+                GROUP BY metadata_id, alias, language
+                HAVING count(*) > 1
+                ORDER BY metadata_id, alias, language;
+                "#,
+            ))
+            .await?;
+
+        if !duplicates.is_empty() {
+            let details = duplicates
+                .iter()
+                .map(|row| {
+                    let metadata_id: i64 = row.try_get("", "metadata_id")?;
+                    let alias: String = row.try_get("", "alias")?;
+                    let language: String = row.try_get("", "language")?;
+                    let ids: String = row.try_get("", "ids")?;
+                    Ok(format!("{metadata_id}:{alias}@{language} <- [{ids}]"))
+                })
+                .collect::<Result<Vec<_>, DbErr>>()?;
+            return Err(DbErr::Migration(format!(
+                "duplicate live biz_metadata_alias rows, resolve manually before retrying: {}",
+                details.join("; ")
+            )));
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE UNIQUE INDEX IF NOT EXISTS ux_biz_metadata_alias_alive
+                ON biz_metadata_alias (metadata_id, alias, language)
+                WHERE deleted_at IS NULL;
+            "#,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared("DROP INDEX IF EXISTS ux_biz_metadata_alias_alive;")
+            .await
+            .map(|_| ())
+    }
+}
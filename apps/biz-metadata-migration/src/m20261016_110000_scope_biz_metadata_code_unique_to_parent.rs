@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::Statement;
+
+/// 为按父节点划分编码唯一性（`CodeUniqueness::PerParent`）的部署放宽编码唯一索引，需显式开启。
+///
+/// 仅当环境变量 `BIZ_METADATA_CODE_UNIQUENESS=per_parent` 时，将存活记录的唯一索引从 (tenant_id, code)
+/// 收窄为同一父节点内唯一：
+/// - 子节点：`ux_biz_metadata_tenant_parent_code_alive` 约束 (tenant_id, parent_id, code)
+/// - 根节点：`ux_biz_metadata_tenant_root_code_alive` 约束 (tenant_id, code)，`parent_id` 为 NULL 的行不参与上一个索引
+///
+/// 默认（未设置或为 `global`）保留 `ux_biz_metadata_tenant_code_alive`，租户内编码唯一仍由数据库兜底。
+/// 现有数据满足原全局索引，必然满足收窄后的索引，无需清理。
+///
+/// 服务启动时按同一配置项校验实际存在的索引，迁移与服务取值不一致时拒绝启动。`per_parent` 下同编码可出现在不同父节点下：
+/// 按编码查询与 `Upsert` 导入命中共用编码时返回 `biz_metadata.code_ambiguous`，`ref:<code>` 视为依赖每个同编码节点。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 与服务端共用的编码唯一性配置项。
+const CODE_UNIQUENESS_ENV: &str = "BIZ_METADATA_CODE_UNIQUENESS";
+
+fn per_parent_opted_in() -> Result<bool, DbErr> {
+    match std::env::var(CODE_UNIQUENESS_ENV) {
+        Ok(raw) => match raw.trim().to_ascii_lowercase().as_str() {
+            "global" => Ok(false),
+            "per_parent" => Ok(true),
+            other => Err(DbErr::Migration(format!(
+                "{CODE_UNIQUENESS_ENV} must be global or per_parent, got {other}"
+            ))),
+        },
+        Err(_) => Ok(false),
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if !per_parent_opted_in()? {
+            return Ok(());
+        }
+        manager
+            .get_connection()
+            .execute_unprepared(
+                r#"
+                CREATE UNIQUE INDEX IF NOT EXISTS ux_biz_metadata_tenant_parent_code_alive
+                    ON biz_metadata (tenant_id, parent_id, code)
+                    WHERE deleted_at IS NULL AND parent_id IS NOT NULL;
+                CREATE UNIQUE INDEX IF NOT EXISTS ux_biz_metadata_tenant_root_code_alive
+                    ON biz_metadata (tenant_id, code)
+                    WHERE deleted_at IS NULL AND parent_id IS NULL;
+                DROP INDEX IF EXISTS ux_biz_metadata_tenant_code_alive;
+                "#,
+            )
+            .await
+            .map(|_| ())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        // 不同父节点下同编码的存活记录无法回到全局唯一索引，列出冲突编码并拒绝回滚。
+        let duplicates = db
+            .query_all_raw(Statement::from_string(
+                db.get_database_backend(),
+                r#"
+                SELECT tenant_id, code, string_agg(id::text, ',' ORDER BY id) AS ids
+                FROM biz_metadata
+                WHERE deleted_at IS NULL
+                GROUP BY tenant_id, code
+                HAVING count(*) > 1
+                ORDER BY tenant_id, code;
+                "#,
+            ))
+            .await?;
+        if !duplicates.is_empty() {
+            let groups = duplicates
+                .iter()
+                .map(|row| {
+                    let tenant_id: String = row.try_get("", "tenant_id")?;
+                    let code: String = row.try_get("", "code")?;
+                    let ids: String = row.try_get("", "ids")?;
+                    Ok(format!("{tenant_id}:{code} <- [{ids}]"))
+                })
+                .collect::<Result<Vec<_>, DbErr>>()?;
+            return Err(DbErr::Migration(format!(
+                "biz_metadata codes shared across parents, resolve manually before rolling back: {}",
+                groups.join("; ")
+            )));
+        }
+
+        db.execute_unprepared(
+            r#"
+            CREATE UNIQUE INDEX IF NOT EXISTS ux_biz_metadata_tenant_code_alive
+                ON biz_metadata (tenant_id, code)
+                WHERE deleted_at IS NULL;
+            DROP INDEX IF EXISTS ux_biz_metadata_tenant_parent_code_alive;
+            DROP INDEX IF EXISTS ux_biz_metadata_tenant_root_code_alive;
+            "#,
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::Statement;
+use unicode_normalization::UnicodeNormalization;
+
+/// 将存量别名文本回填为 Unicode NFKC 形式，与新写入的别名及查询条件的规范化方式一致。
+///
+/// 规范化后同一元数据下文本与语言都相同的存活别名会违反存活别名唯一索引；迁移先检测此类冲突并整体报错
+/// （列出每组的别名 ID 与原文），由人工处理后再重试，不做静默合并。数据库可能不是 UTF8 编码，
+/// 规范化在迁移进程内完成而不依赖 `normalize()`。
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        let backend = db.get_database_backend();
+
+        let rows = db
+            .query_all_raw(Statement::from_string(
+                backend,
+                r#"
+                SELECT id, metadata_id, alias, language, deleted_at IS NULL AS alive
+                FROM biz_metadata_alias
+                ORDER BY id;
+                "#,
+            ))
+            .await?;
+
+        let mut groups: BTreeMap<(i64, String, String), Vec<(i64, String)>> = BTreeMap::new();
+        let mut changed = Vec::new();
+        for row in &rows {
+            let id: i64 = row.try_get("", "id")?;
+            let metadata_id: i64 = row.try_get("", "metadata_id")?;
+            let alias: String = row.try_get("", "alias")?;
+            let language: String = row.try_get("", "language")?;
+            let alive: bool = row.try_get("", "alive")?;
+            let normalized: String = alias.nfkc().collect();
+            if alive {
+                groups
+                    .entry((metadata_id, normalized.clone(), language))
+                    .or_default()
+                    .push((id, alias.clone()));
+            }
+            if normalized != alias {
+                changed.push((id, normalized));
+            }
+        }
+
+        let collisions: Vec<_> = groups
+            .iter()
+            .filter(|(_, members)| members.len() > 1)
+            .map(|((metadata_id, alias, language), members)| {
+                let members: Vec<_> = members
+                    .iter()
+                    .map(|(id, original)| format!("{id}={original}"))
+                    .collect();
+                format!(
+                    "{metadata_id}:{alias}@{language} <- [{}]",
+                    members.join(",")
+                )
+            })
+            .collect();
+        if !collisions.is_empty() {
+            return Err(DbErr::Migration(format!(
+                "live biz_metadata_alias rows collide after NFKC normalization, resolve manually before retrying: {}",
+                collisions.join("; ")
+            )));
+        }
+
+        for (id, alias) in changed {
+            db.execute_raw(Statement::from_sql_and_values(
+                backend,
+                "UPDATE biz_metadata_alias SET alias = $1 WHERE id = $2",
+                [alias.into(), id.into()],
+            ))
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, _manager: &SchemaManager) -> Result<(), DbErr> {
+        // 规范化前的原文未保留，回滚不恢复文本。
+        Ok(())
+    }
+}
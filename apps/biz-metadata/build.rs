@@ -4,6 +4,11 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+#[path = "build_support/body_types.rs"]
+mod body_types;
+
+use body_types::parse_body_types;
+
 fn main() {
     let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").expect("manifest dir"));
     let out_dir = PathBuf::from(env::var("OUT_DIR").expect("OUT_DIR"));
@@ -33,6 +38,10 @@ fn main() {
         "cargo:rerun-if-changed={}",
         manifest_dir.join("src/interface/http/dto").display()
     );
+    println!(
+        "cargo:rerun-if-changed={}",
+        manifest_dir.join("build_support").display()
+    );
 }
 
 /// 递归收集给定目录下的所有 .rs 文件（包含子目录），用于 handler/DTO 扫描。
@@ -109,54 +118,41 @@ fn collect_routes(handler_files: &[PathBuf]) -> Vec<(String, Vec<RouteInfo>)> {
     grouped.into_iter().collect()
 }
 
-/// 解析 #[utoipa::path] 中的 body= 类型（含泛型/绝对路径），补齐自动扫描不到的 schema。
+/// 解析 #[utoipa::path] 中的请求/响应类型（含多内容类型、泛型/绝对路径），补齐自动扫描不到的 schema。
 fn collect_body_types(handler_files: &[PathBuf]) -> Vec<String> {
     let mut types = BTreeSet::new();
-    let markers = ["body"];
     for path in handler_files {
         let content = fs::read_to_string(path).expect("read handler file");
         for attr_block in content.split("#[utoipa::path").skip(1) {
-            let token_str = attr_block;
-            for marker in markers {
-                let mut search = 0;
-                while let Some(pos) = token_str[search..].find(marker) {
-                    let pos = pos + search;
-                    if token_str[..pos].ends_with("request_") {
-                        search = pos + marker.len();
-                        continue;
-                    }
-                    let rest = &token_str[pos + marker.len()..];
-                    let Some(eq_pos_rel) = rest.find('=') else {
-                        break;
-                    };
-                    let rest_after_eq = &rest[eq_pos_rel + 1..];
-                    let rest_after_eq = rest_after_eq.trim_start();
-                    let mut end = rest_after_eq.len();
-                    for (i, ch) in rest_after_eq.char_indices() {
-                        if ch == ',' || ch == ')' {
-                            end = i;
-                            break;
-                        }
-                    }
-                    let ty = rest_after_eq[..end].trim();
-                    if !ty.is_empty() {
-                        let normalized = normalize_type(ty);
-                        if !normalized.contains("::") && !normalized.contains('<') {
-                            search = pos + marker.len() + eq_pos_rel + end;
-                            continue;
-                        }
-                        types.insert(normalized);
-                    }
-                    search = pos + marker.len() + eq_pos_rel + end;
-                }
-            }
+            // 仅扫描属性本身，避免把函数体中的 `body` 标识符误判为响应类型。
+            let token_str = attr_block
+                .split_once("\n)]")
+                .map_or(attr_block, |(attr, _)| attr);
+            // 普通类型名由 DTO 扫描注册，这里只补充带路径或泛型参数的类型；
+            // Vec/Option 等容器本身不是 schema，取其元素类型。
+            types.extend(
+                parse_body_types(token_str)
+                    .iter()
+                    .map(|ty| strip_containers(ty))
+                    .filter(|ty| ty.contains("::") || ty.contains('<'))
+                    .map(str::to_string),
+            );
         }
     }
     types.into_iter().collect()
 }
 
-fn normalize_type(raw: &str) -> String {
-    raw.split_whitespace().collect::<String>()
+/// 剥离 `Vec<..>`/`Option<..>`/`Box<..>` 外层容器。
+fn strip_containers(ty: &str) -> &str {
+    let mut ty = ty;
+    while let Some(inner) = ["Vec<", "Option<", "Box<"]
+        .iter()
+        .find_map(|prefix| ty.strip_prefix(prefix))
+        .and_then(|rest| rest.strip_suffix('>'))
+    {
+        ty = inner;
+    }
+    ty
 }
 
 /// 收集 DTO 中带 ToSchema 的结构体/枚举，并合并额外的类型（如泛型响应包装）。
@@ -169,23 +165,19 @@ fn collect_schemas(dirs: &[PathBuf], extra: &[String]) -> Vec<String> {
             if let Ok(file) = syn::parse_file(&content) {
                 for item in file.items {
                     match item {
-                        syn::Item::Struct(s) => {
-                            if has_to_schema(&s.attrs) {
-                                let type_name = s.ident.to_string();
-                                if should_skip_schema(&type_name) {
-                                    continue;
-                                }
-                                set.insert(to_schema_path(&path, &type_name));
+                        syn::Item::Struct(s) if has_to_schema(&s.attrs) => {
+                            let type_name = s.ident.to_string();
+                            if should_skip_schema(&type_name) {
+                                continue;
                             }
+                            set.insert(to_schema_path(&path, &type_name));
                         }
-                        syn::Item::Enum(e) => {
-                            if has_to_schema(&e.attrs) {
-                                let type_name = e.ident.to_string();
-                                if should_skip_schema(&type_name) {
-                                    continue;
-                                }
-                                set.insert(to_schema_path(&path, &type_name));
+                        syn::Item::Enum(e) if has_to_schema(&e.attrs) => {
+                            let type_name = e.ident.to_string();
+                            if should_skip_schema(&type_name) {
+                                continue;
                             }
+                            set.insert(to_schema_path(&path, &type_name));
                         }
                         _ => {}
                     }
@@ -254,8 +246,16 @@ fn render_api_doc(
                 })
                 .collect::<Vec<_>>()
                 .join("\n");
+            // 仅导入当前分组用到的方法，避免生成未使用的 import。
+            let methods = routes
+                .iter()
+                .map(|r| r.method.as_str())
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+                .join(", ");
             format!(
-                "pub fn generated_routes_{group}(state: crate::interface::http::state::AppState) -> axum::Router<()> {{\n    use axum::routing::{{delete, get, post, put}};\n    let router: axum::Router<crate::interface::http::state::AppState> = axum::Router::new()\n{routes_str};\n    router.with_state(state)\n}}"
+                "pub fn generated_routes_{group}(state: crate::interface::http::state::AppState) -> axum::Router<()> {{\n    use axum::routing::{{{methods}}};\n    let router: axum::Router<crate::interface::http::state::AppState> = axum::Router::new()\n{routes_str};\n    router.with_state(state)\n}}"
             )
         })
         .collect::<Vec<_>>()
@@ -264,7 +264,9 @@ fn render_api_doc(
     let template = r#"
 use utoipa::OpenApi;
 use crate::interface::http::dto::response::{
-    BizMetadataAliasResponse, BizMetadataResponse, EmptyPayload, PageResultResponse, ResultResponse,
+    BizMetadataAliasResponse, BizMetadataEnumsResponse, BizMetadataResponse,
+    BizMetadataVersionResponse, CodeAvailableResponse, PageResultResponse, ResultResponse,
+    SuggestCodeResponse,
 };
 
 #[derive(OpenApi)]
@@ -281,10 +283,65 @@ use crate::interface::http::dto::response::{
         (name = "biz_metadata", description = "BizMetadata HTTP API"),
         (name = "biz_metadata_alias", description = "BizMetadata Alias HTTP API")
     ),
-    modifiers(&TrimTrailingSlash)
+    modifiers(&TrimTrailingSlash, &BearerSecurity)
 )]
 pub struct ApiDoc;
 
+/// 安全方案名称，与网关要求的 Bearer Token 对应。
+pub const BEARER_AUTH: &str = "bearer_auth";
+
+/// 无需鉴权的公开路径，生成文档时以空安全要求覆盖默认的 Bearer 要求。
+pub const PUBLIC_PATHS: &[&str] = &["/health"];
+
+/// 注册 `bearer_auth` 安全方案，并将其作为全局默认安全要求（公开路径除外）。
+pub struct BearerSecurity;
+
+impl utoipa::Modify for BearerSecurity {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        use utoipa::openapi::security::{
+            HttpAuthScheme, HttpBuilder, SecurityRequirement, SecurityScheme,
+        };
+
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .add_security_scheme(
+                BEARER_AUTH,
+                SecurityScheme::Http(
+                    HttpBuilder::new()
+                        .scheme(HttpAuthScheme::Bearer)
+                        .bearer_format("JWT")
+                        .build(),
+                ),
+            );
+        openapi.security = Some(vec![SecurityRequirement::new(
+            BEARER_AUTH,
+            Vec::<String>::new(),
+        )]);
+
+        for (path, item) in openapi.paths.paths.iter_mut() {
+            if !PUBLIC_PATHS.contains(&path.as_str()) {
+                continue;
+            }
+            for operation in [
+                &mut item.get,
+                &mut item.put,
+                &mut item.post,
+                &mut item.delete,
+                &mut item.options,
+                &mut item.head,
+                &mut item.patch,
+                &mut item.trace,
+            ]
+            .into_iter()
+            .flatten()
+            {
+                operation.security = Some(vec![SecurityRequirement::default()]);
+            }
+        }
+    }
+}
+
 pub struct TrimTrailingSlash;
 
 impl utoipa::Modify for TrimTrailingSlash {
@@ -313,7 +370,7 @@ impl utoipa::Modify for TrimTrailingSlash {
 
 /// 从属性块解析 HTTP 方法。
 fn parse_method(attr_block: &str) -> Option<String> {
-    for method in ["get", "post", "put", "delete"] {
+    for method in ["get", "post", "put", "patch", "delete"] {
         if attr_block.contains(&format!("{method},")) || attr_block.contains(&format!("{method}\n"))
         {
             return Some(method.to_string());
@@ -0,0 +1,127 @@
+//! `#[utoipa::path]` 属性块中的请求/响应类型解析，供 build.rs 与解析器测试共用。
+
+/// 以 `marker = Type` 形式声明类型的属性名。
+const TYPE_MARKERS: [&str; 3] = ["request_body", "body", "content"];
+
+/// 解析单个属性块中声明的全部请求/响应类型（按出现顺序去重）。
+///
+/// 支持：
+/// - `body = T`、`request_body = T`、`content = T`；
+/// - `content((T = "application/json"), (U = "application/x-www-form-urlencoded"))` 等多内容类型元组；
+/// - 含逗号的泛型参数（如 `Wrapper<A, B>`）。
+pub fn parse_body_types(attr_block: &str) -> Vec<String> {
+    let mut types = Vec::new();
+    let mut push = |ty: String| {
+        if !ty.is_empty() && !types.contains(&ty) {
+            types.push(ty);
+        }
+    };
+
+    let bytes = attr_block.as_bytes();
+    let mut pos = 0;
+    while pos < attr_block.len() {
+        let Some(marker) = TYPE_MARKERS
+            .iter()
+            .find(|marker| attr_block[pos..].starts_with(**marker))
+        else {
+            pos += attr_block[pos..].chars().next().map_or(1, char::len_utf8);
+            continue;
+        };
+        let end = pos + marker.len();
+        let standalone = (pos == 0 || !is_ident_byte(bytes[pos - 1]))
+            && bytes.get(end).is_none_or(|b| !is_ident_byte(*b));
+        if !standalone {
+            pos = end;
+            continue;
+        }
+
+        let rest = attr_block[end..].trim_start();
+        if let Some(value) = rest.strip_prefix('=') {
+            push(take_type(value.trim_start()));
+        } else if let Some(tuples) = rest.strip_prefix('(') {
+            // `content(...)` / `request_body(content(...))`：逐个解析 `(Type = "mime")` 元组。
+            let inner = &tuples[..matching_paren(tuples)];
+            if *marker == "content" {
+                for entry in split_top_level(inner) {
+                    let entry = entry.trim();
+                    if let Some(tuple) = entry.strip_prefix('(') {
+                        push(take_type(tuple.trim_start()));
+                    }
+                }
+            } else {
+                parse_body_types(inner).into_iter().for_each(&mut push);
+            }
+            pos = attr_block.len() - tuples.len() + inner.len();
+            continue;
+        }
+        pos = end;
+    }
+    types
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+/// 读取一个类型表达式，直到顶层的 `,`、`)` 或 `=`（跳过泛型尖括号内的逗号）。
+fn take_type(raw: &str) -> String {
+    if raw.starts_with('"') {
+        return String::new();
+    }
+    let mut depth = 0usize;
+    let mut end = raw.len();
+    for (i, ch) in raw.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => depth = depth.saturating_sub(1),
+            ',' | ')' | '=' if depth == 0 => {
+                end = i;
+                break;
+            }
+            _ => {}
+        }
+    }
+    raw[..end].split_whitespace().collect()
+}
+
+/// 返回与已消费的 `(` 匹配的 `)` 在 `s` 中的位置；未闭合时返回 `s.len()`。
+fn matching_paren(s: &str) -> usize {
+    let mut depth = 0usize;
+    let mut in_str = false;
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '"' => in_str = !in_str,
+            '(' if !in_str => depth += 1,
+            ')' if !in_str => {
+                if depth == 0 {
+                    return i;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+    s.len()
+}
+
+/// 按顶层逗号切分（忽略括号、尖括号与字符串内的逗号）。
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut in_str = false;
+    let mut start = 0;
+    for (i, ch) in s.char_indices() {
+        match ch {
+            '"' => in_str = !in_str,
+            '(' | '<' if !in_str => depth += 1,
+            ')' | '>' if !in_str => depth = depth.saturating_sub(1),
+            ',' if !in_str && depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
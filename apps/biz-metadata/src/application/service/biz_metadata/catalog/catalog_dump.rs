@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+/// 当前导出格式的版本号，导入时拒绝其他版本。
+pub const CATALOG_SCHEMA_VERSION: u32 = 1;
+
+/// 整个目录的自描述导出，用于备份与跨环境迁移。
+///
+/// 父子关系与别名归属均以编码表示而非数值 id，目标环境的 id 可以不同。
+///
+/// ```
+/// use biz_metadata::{CATALOG_SCHEMA_VERSION, CatalogDump};
+///
+/// let dump: CatalogDump = serde_json::from_str(
+///     r#"{"schema_version":1,"exported_at":"2024-05-01T00:00:00Z","entries":[
+///         {"code":"company","name":"公司","object_type":"entity","status":"active","source":"manual"}
+///     ]}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(dump.schema_version, CATALOG_SCHEMA_VERSION);
+/// assert!(dump.entries[0].aliases.is_empty());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogDump {
+    /// 导出格式版本，见 [`CATALOG_SCHEMA_VERSION`]。
+    pub schema_version: u32,
+    /// 导出时间（RFC 3339）。
+    pub exported_at: String,
+    /// 全部存活元数据，按编码升序。
+    pub entries: Vec<CatalogEntry>,
+}
+
+/// 单条元数据及其别名。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogEntry {
+    pub code: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub object_type: String,
+    /// 父节点编码，根节点为空。
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_class: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    pub status: String,
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<CatalogAlias>,
+}
+
+/// 元数据下的单个存活别名。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CatalogAlias {
+    pub alias: String,
+    pub language: String,
+    pub source: String,
+    pub weight: i32,
+    pub is_primary: bool,
+}
@@ -0,0 +1,30 @@
+/// 导入目录时对目标环境既有数据的处理方式。
+///
+/// ```
+/// use biz_metadata::ImportMode;
+///
+/// assert_eq!(ImportMode::default(), ImportMode::Upsert);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportMode {
+    /// 先软删除目标环境全部存活元数据及其别名，再按导出内容重建，全部在单个事务内完成。
+    Replace,
+    /// 按编码匹配：已存在的记录覆盖为导出内容，不存在的新建，导出中没有的记录保持不变。
+    ///
+    /// 逐条写入而非单个事务：中途失败时已写入的条目保留，重新导入同一份内容可幂等地补齐剩余条目。
+    #[default]
+    Upsert,
+}
+
+/// 一次导入的结果统计。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CatalogImportSummary {
+    /// 新建的元数据条数。
+    pub inserted: u64,
+    /// 内容有变化而被覆盖的元数据条数。
+    pub updated: u64,
+    /// 内容一致而跳过的元数据条数。
+    pub unchanged: u64,
+    /// 新建或覆盖的别名条数。
+    pub aliases: u64,
+}
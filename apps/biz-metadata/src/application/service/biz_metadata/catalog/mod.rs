@@ -0,0 +1,5 @@
+pub mod catalog_dump;
+pub mod import_mode;
+
+pub use catalog_dump::{CATALOG_SCHEMA_VERSION, CatalogAlias, CatalogDump, CatalogEntry};
+pub use import_mode::{CatalogImportSummary, ImportMode};
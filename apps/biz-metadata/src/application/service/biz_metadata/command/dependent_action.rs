@@ -0,0 +1,19 @@
+/// 删除元数据时对仍引用它的存活节点的处理方式：以 `parent_id` 挂在其下的子节点，
+/// 以及 `value_type` 含 `ref:<其编码>` 的 feature。
+///
+/// ```
+/// use biz_metadata::DependentAction;
+///
+/// assert_eq!(DependentAction::default(), DependentAction::Restrict);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DependentAction {
+    /// 存在依赖方时拒绝删除，并在错误中列出阻塞的节点 ID。
+    #[default]
+    Restrict,
+    /// 将依赖方级联标记为 `deprecated` 后再删除。
+    Deprecate,
+    /// 将子节点的 `parent_id` 置空、从 feature 的 `value_type` 中移除该引用后再删除；
+    /// 引用是 `value_type` 唯一 term 时无法脱离，删除被拒绝。
+    Detach,
+}
@@ -0,0 +1,22 @@
+use domain_core::domain_error::DomainError;
+
+use crate::domain::biz_metadata::BizMetadata;
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+
+/// 一次子树弃用的结果。
+///
+/// ```
+/// use biz_metadata::DeprecateSubtreeReport;
+///
+/// let report = DeprecateSubtreeReport::default();
+/// assert!(report.deprecated.is_empty() && report.skipped.is_empty() && report.failed.is_empty());
+/// ```
+#[derive(Debug, Default)]
+pub struct DeprecateSubtreeReport {
+    /// 本次改为 `deprecated` 并已落库的节点，按子树顺序（根节点在首位）。
+    pub deprecated: Vec<BizMetadata>,
+    /// 已是 `deprecated` 而跳过的节点 ID。
+    pub skipped: Vec<BizMetadataId>,
+    /// 状态变更被领域规则拒绝、未写入的节点 ID 及原因。
+    pub failed: Vec<(BizMetadataId, DomainError)>,
+}
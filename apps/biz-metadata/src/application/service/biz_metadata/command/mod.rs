@@ -1,5 +1,9 @@
 pub mod create_biz_metadata_command;
+pub mod dependent_action;
+pub mod deprecate_subtree_report;
 pub mod update_biz_metadata_command;
 
 pub use create_biz_metadata_command::CreateBizMetadataCommand;
+pub use dependent_action::DependentAction;
+pub use deprecate_subtree_report::DeprecateSubtreeReport;
 pub use update_biz_metadata_command::{FieldUpdate, UpdateBizMetadataCommand};
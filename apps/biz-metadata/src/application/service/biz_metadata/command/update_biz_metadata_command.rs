@@ -4,7 +4,9 @@ use crate::domain::biz_metadata::value_object::{
 
 /// 更新命令，包含可选的增量字段。
 ///
-/// 更新必须携带 `version`，用于乐观锁校验。
+/// 更新必须携带 `version`，用于乐观锁校验。feature 必填的 `data_class`/`value_type` 不可
+/// [`FieldUpdate::Clear`]，服务在修改任何字段之前拒绝。应用后内容未变化（见 [`BizMetadata::content_eq`](crate::BizMetadata::content_eq)）时
+/// 服务跳过写入、不递增版本号，`force=true` 时仍照常写入。
 ///
 /// ```
 /// use biz_metadata::{FieldUpdate, BizMetadataId, DataClass, UpdateBizMetadataCommand, Version};
@@ -15,25 +17,29 @@ use crate::domain::biz_metadata::value_object::{
 ///     name: Some("new name".into()),
 ///     description: FieldUpdate::Clear,
 ///     parent_id: FieldUpdate::Keep,
-///     data_class: Some(DataClass::Metric),
-///     value_type: Some("int".into()),
+///     data_class: FieldUpdate::Set(DataClass::Metric),
+///     value_type: FieldUpdate::Set("int".into()),
 ///     unit: FieldUpdate::Set("CNY".into()),
 ///     status: None,
 ///     source: None,
+///     force: false,
 /// };
 /// assert!(matches!(cmd.description, FieldUpdate::Clear));
 /// ```
+#[derive(Debug, Clone)]
 pub struct UpdateBizMetadataCommand {
     pub id: BizMetadataId,
     pub version: Version,
     pub name: Option<String>,
     pub description: FieldUpdate<String>,
-    pub data_class: Option<DataClass>,
-    pub value_type: Option<String>,
+    pub data_class: FieldUpdate<DataClass>,
+    pub value_type: FieldUpdate<String>,
     pub unit: FieldUpdate<String>,
     pub parent_id: FieldUpdate<BizMetadataId>,
     pub status: Option<BizMetadataStatus>,
     pub source: Option<Source>,
+    /// 内容未变化时仍写入并递增版本号。
+    pub force: bool,
 }
 
 impl Default for UpdateBizMetadataCommand {
@@ -43,12 +49,13 @@ impl Default for UpdateBizMetadataCommand {
             version: Version::new(1).expect("default version"),
             name: None,
             description: FieldUpdate::Keep,
-            data_class: None,
-            value_type: None,
+            data_class: FieldUpdate::Keep,
+            value_type: FieldUpdate::Keep,
             unit: FieldUpdate::Keep,
             parent_id: FieldUpdate::Keep,
             status: None,
             source: None,
+            force: false,
         }
     }
 }
@@ -1,7 +1,15 @@
+pub mod catalog;
 pub mod command;
 pub mod query;
 pub mod service;
 
-pub use command::{CreateBizMetadataCommand, FieldUpdate, UpdateBizMetadataCommand};
-pub use query::BizMetadataQueryRequest;
-pub use service::BizMetadataService;
+pub use catalog::{
+    CATALOG_SCHEMA_VERSION, CatalogAlias, CatalogDump, CatalogEntry, CatalogImportSummary,
+    ImportMode,
+};
+pub use command::{
+    CreateBizMetadataCommand, DependentAction, DeprecateSubtreeReport, FieldUpdate,
+    UpdateBizMetadataCommand,
+};
+pub use query::{BizMetadataQueryRequest, BizMetadataSearchHit};
+pub use service::{BizMetadataService, DEFAULT_MAX_TREE_DEPTH};
@@ -0,0 +1,19 @@
+use crate::domain::biz_metadata::BizMetadata;
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+
+/// 首选别名命中的基础分。
+pub const SCORE_PRIMARY_ALIAS: u32 = 3000;
+/// 名称或别名与检索词完全一致的基础分。
+pub const SCORE_EXACT: u32 = 2000;
+/// 名称或别名包含检索词的基础分。
+pub const SCORE_SUBSTRING: u32 = 1000;
+
+/// 元数据与别名联合检索的单条结果。
+///
+/// `score` 越高越相关：首选别名 > 完全匹配 > 子串匹配，别名命中时再叠加 `AliasWeight`（0~100）。
+pub struct BizMetadataSearchHit {
+    pub metadata: BizMetadata,
+    /// 命中的别名；仅名称命中时为 `None`。
+    pub matched_alias: Option<BizMetadataAlias>,
+    pub score: u32,
+}
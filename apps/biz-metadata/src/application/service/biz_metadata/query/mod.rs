@@ -1,3 +1,5 @@
 pub mod biz_metadata_query_request;
+pub mod biz_metadata_search_hit;
 
 pub use biz_metadata_query_request::BizMetadataQueryRequest;
+pub use biz_metadata_search_hit::BizMetadataSearchHit;
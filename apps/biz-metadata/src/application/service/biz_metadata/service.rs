@@ -1,16 +1,43 @@
 use domain_core::domain_error::DomainError;
-use domain_core::pagination::PageResult;
+use domain_core::expression::{Expression, OrderBy, QueryOptions, contains, eq, r#in};
+use domain_core::pagination::{Page, PageResult};
 
+use crate::application::service::biz_metadata::catalog::{
+    CATALOG_SCHEMA_VERSION, CatalogAlias, CatalogDump, CatalogEntry, CatalogImportSummary,
+    ImportMode,
+};
 use crate::application::service::biz_metadata::command::{
-    CreateBizMetadataCommand, FieldUpdate, UpdateBizMetadataCommand,
+    CreateBizMetadataCommand, DependentAction, DeprecateSubtreeReport, FieldUpdate,
+    UpdateBizMetadataCommand,
+};
+use crate::application::service::biz_metadata::query::biz_metadata_search_hit::{
+    SCORE_EXACT, SCORE_PRIMARY_ALIAS, SCORE_SUBSTRING,
+};
+use crate::application::service::biz_metadata::query::{
+    BizMetadataQueryRequest, BizMetadataSearchHit,
+};
+use crate::application::service::biz_metadata_alias::DEFAULT_MAX_ALIASES_PER_METADATA;
+use crate::domain::biz_metadata::lint::type_ref_target;
+use crate::domain::biz_metadata::repository::{
+    BizMetadataRepository, CatalogReplacement, code_ambiguous, ensure_facetable,
+    ensure_known_fields, is_retryable,
 };
-use crate::application::service::biz_metadata::query::BizMetadataQueryRequest;
-use crate::domain::biz_metadata::BizMetadata;
-use crate::domain::biz_metadata::repository::BizMetadataRepository;
 use crate::domain::biz_metadata::value_object::{
-    BizMetadataId, BizMetadataName, ObjectType, TenantId, Unit, ValueType, Version,
+    BizMetadataCode, BizMetadataId, BizMetadataName, BizMetadataStatus, DataClass, ObjectType,
+    Source, TenantId, Unit, ValueType, ValueTypeRegistry, Version,
 };
-use chrono::Utc;
+use crate::domain::biz_metadata::{BizMetadata, CodePolicy, CodeUniqueness, MetadataSnapshot};
+use crate::domain::biz_metadata_alias::{
+    AliasNormalization, AliasSource, AliasText, BizMetadataAlias, BizMetadataAliasId,
+    BizMetadataAliasRepository, BizMetadataAliasSnapshot,
+};
+use crate::domain::error_code;
+use chrono::{DateTime, Utc};
+use domain_core::clock::{Clock, SystemClock};
+use domain_core::id_generator::{DatabaseSequence, IdGenerator};
+use domain_core::prelude::{Audit, TimestampPrecision};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Arc;
 
 /// 元数据的应用服务，负责协调命令与查询。
 ///
@@ -65,16 +92,150 @@ where
     R: BizMetadataRepository,
 {
     repository: R,
+    default_source: Option<Source>,
+    default_status: Option<BizMetadataStatus>,
+    code_policy: CodePolicy,
+    code_uniqueness: CodeUniqueness,
+    clock: Arc<dyn Clock>,
+    id_generator: Arc<dyn IdGenerator>,
+    value_type_registry: ValueTypeRegistry,
+    max_depth: usize,
+    timestamp_precision: TimestampPrecision,
+    strict_query: bool,
+    max_aliases_per_metadata: usize,
 }
 
 const DEFAULT_TENANT_ID: &str = "default";
 
+/// 增量同步时单次拉取的批量大小。
+const CHANGED_SINCE_BATCH_SIZE: u64 = 200;
+
+/// 按过滤条件批量删除时，单次加载候选与子节点的批量大小。
+const DELETE_BY_FILTER_BATCH_SIZE: u64 = 200;
+
+/// 搜索时分页加载命中别名、按 id 分块加载别名所属元数据的批量大小。
+const SEARCH_BATCH_SIZE: u64 = 200;
+
+/// 审计不变式时单次加载的批量大小。
+const AUDIT_BATCH_SIZE: u64 = 200;
+
+/// 导出目录时单次加载别名的批量大小。
+const CATALOG_BATCH_SIZE: u64 = 200;
+
+/// 物理清理软删除记录时单批删除的行数，避免长事务持锁。
+const PURGE_BATCH_SIZE: u64 = 500;
+
+/// 沿 `parent_id` 向上回溯的最大层数，防止脏数据成环导致死循环。
+const MAX_ANCESTOR_DEPTH: usize = 64;
+
+/// 默认允许的最大树深度（根节点深度为 0）。
+pub const DEFAULT_MAX_TREE_DEPTH: usize = 8;
+
 impl<R> BizMetadataService<R>
 where
     R: BizMetadataRepository,
 {
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            default_source: None,
+            default_status: None,
+            code_policy: CodePolicy::default(),
+            code_uniqueness: CodeUniqueness::default(),
+            clock: Arc::new(SystemClock),
+            id_generator: Arc::new(DatabaseSequence),
+            value_type_registry: ValueTypeRegistry::builtin().clone(),
+            max_depth: DEFAULT_MAX_TREE_DEPTH,
+            timestamp_precision: TimestampPrecision::default(),
+            strict_query: false,
+            max_aliases_per_metadata: DEFAULT_MAX_ALIASES_PER_METADATA,
+        }
+    }
+
+    /// 设置创建命令未指定 `source` 时使用的默认来源。
+    pub fn with_default_source(mut self, source: Source) -> Self {
+        self.default_source = Some(source);
+        self
+    }
+
+    /// 设置创建命令未指定 `status` 时使用的默认状态。
+    pub fn with_default_status(mut self, status: BizMetadataStatus) -> Self {
+        self.default_status = Some(status);
+        self
+    }
+
+    /// 设置创建时校验编码的命名策略，默认不追加约束。
+    pub fn with_code_policy(mut self, code_policy: CodePolicy) -> Self {
+        self.code_policy = code_policy;
+        self
+    }
+
+    /// 设置编码唯一性的作用范围，默认全局唯一。
+    pub fn with_code_uniqueness(mut self, code_uniqueness: CodeUniqueness) -> Self {
+        self.code_uniqueness = code_uniqueness;
+        self
+    }
+
+    /// 设置审计时间来源，默认读取系统时间；测试中可注入 `FixedClock` 断言精确时间戳。
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// 设置新建元数据的标识来源，默认 [`DatabaseSequence`] 交由数据库自增；
+    /// 注入 `SnowflakeIdGenerator` 等实现后，`create` 会在插入前预分配 id。
+    pub fn with_id_generator(mut self, id_generator: impl IdGenerator + 'static) -> Self {
+        self.id_generator = Arc::new(id_generator);
+        self
+    }
+
+    /// 设置创建、更新与导入时校验 `value_type` 所用的标量登记表，默认 [`ValueTypeRegistry::builtin`]。
+    pub fn with_value_type_registry(mut self, value_type_registry: ValueTypeRegistry) -> Self {
+        self.value_type_registry = value_type_registry;
+        self
+    }
+
+    /// 设置挂载父节点时允许的最大树深度（根节点深度为 0），默认 [`DEFAULT_MAX_TREE_DEPTH`]。
+    ///
+    /// 创建、更新与批量移动时校验，移动节点会连同其子树一并计入；导入目录按原样恢复层级，不受此限制。
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// 设置审计时间的保留精度，默认完整精度；写入聚合前统一截断，截断向下取整，不改变先后顺序。
+    pub fn with_timestamp_precision(mut self, timestamp_precision: TimestampPrecision) -> Self {
+        self.timestamp_precision = timestamp_precision;
+        self
+    }
+
+    /// 设置查询是否严格校验字段，默认关闭。
+    ///
+    /// 开启后 [`query_biz_metadata`](Self::query_biz_metadata) 先按 [`BizMetadataRepository::query_fields`]
+    /// 校验过滤与排序字段，存在未登记字段时返回 `query.field_unknown` 并列出全部未知字段；
+    /// 关闭时交由仓储宽松处理（未登记的过滤条件视为恒真、排序忽略）。
+    pub fn with_strict_query(mut self, strict_query: bool) -> Self {
+        self.strict_query = strict_query;
+        self
+    }
+
+    /// 设置合并与导入目录时单个元数据的存活别名上限，默认 [`DEFAULT_MAX_ALIASES_PER_METADATA`]；
+    /// 应与 [`BizMetadataAliasService::with_max_aliases_per_metadata`](crate::application::service::biz_metadata_alias::service::BizMetadataAliasService::with_max_aliases_per_metadata) 保持一致。
+    pub fn with_max_aliases_per_metadata(mut self, limit: usize) -> Self {
+        self.max_aliases_per_metadata = limit;
+        self
+    }
+
+    /// 按配置精度截断后的当前时间，所有审计时间均经由此处取得。
+    fn now(&self) -> DateTime<Utc> {
+        self.timestamp_precision.truncate(self.clock.now())
+    }
+
+    /// 按标量登记表校验值类型语法。
+    fn validate_value_type(&self, raw: &str) -> Result<ValueType, DomainError> {
+        let value_type = ValueType::new(raw)?;
+        self.value_type_registry.validate(&value_type)?;
+        Ok(value_type)
     }
 
     pub async fn create_biz_metadata(
@@ -82,13 +243,16 @@ where
         cmd: CreateBizMetadataCommand,
     ) -> Result<BizMetadata, DomainError> {
         let tenant_id = TenantId::new(DEFAULT_TENANT_ID)?;
+        let now = self.now();
         let object_type = cmd.object_type;
         let mut biz_metadata = match object_type {
             ObjectType::Feature => {
                 let data_class = cmd.data_class.ok_or(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_FEATURE_FIELD_REQUIRED,
                     message: "object_type=feature requires data_class".into(),
                 })?;
                 let value_type = cmd.value_type.ok_or(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_FEATURE_FIELD_REQUIRED,
                     message: "object_type=feature requires value_type".into(),
                 })?;
                 BizMetadata::new_feature(
@@ -96,130 +260,4657 @@ where
                     cmd.code,
                     cmd.name,
                     data_class,
-                    ValueType::new(value_type)?,
+                    self.validate_value_type(&value_type)?,
+                    now,
                 )?
             }
-            _ => BizMetadata::new_node(tenant_id, cmd.code, cmd.name, object_type)?,
+            _ => BizMetadata::new_node(tenant_id, cmd.code, cmd.name, object_type, now)?,
         };
-        biz_metadata.set_description(cmd.description)?;
-        biz_metadata.set_parent_id(cmd.parent_id)?;
+        self.code_policy.validate(biz_metadata.code())?;
+        self.ensure_code_unique(biz_metadata.code(), cmd.parent_id, None)
+            .await?;
+        if let Some(parent_id) = cmd.parent_id {
+            self.ensure_depth_within_limit(parent_id, &HashSet::new())
+                .await?;
+        }
+        biz_metadata.set_description(cmd.description, now)?;
+        biz_metadata.set_parent_id(cmd.parent_id, now)?;
         if object_type == ObjectType::Feature {
             let unit = cmd.unit.map(Unit::new).transpose()?;
-            biz_metadata.set_unit(unit)?;
+            biz_metadata.set_unit(unit, now)?;
         }
-        if let Some(status) = cmd.status {
-            biz_metadata.change_status(status)?;
+        if let Some(status) = cmd.status.or(self.default_status) {
+            biz_metadata.change_status(status, now)?;
         }
-        if let Some(source) = cmd.source {
-            biz_metadata.change_source(source)?;
+        if let Some(source) = cmd.source.or(self.default_source) {
+            biz_metadata.change_source(source, now)?;
+        }
+        if let Some(id) = self.id_generator.next_id() {
+            biz_metadata.assign_id(BizMetadataId::new(id))?;
         }
 
         self.repository.insert_biz_metadata(biz_metadata).await
     }
 
+    /// 按命令更新元数据。
+    ///
+    /// 加载、版本校验与提交通过 [`BizMetadataRepository::update_biz_metadata_locked_checked`] 在同一事务内完成，
+    /// 并发写入时后到者会得到明确的版本冲突错误；改挂父节点时的树深度与同级编码校验基于锁定读到的记录。应用命令后内容与锁定读到的记录一致（见
+    /// [`BizMetadata::content_eq`]）时不写库，直接返回该记录，版本号与 `updated_at` 保持不变；
+    /// `cmd.force=true` 时跳过该判断。
     pub async fn update_biz_metadata(
         &self,
         cmd: UpdateBizMetadataCommand,
     ) -> Result<BizMetadata, DomainError> {
-        let mut biz_metadata = self
+        if let FieldUpdate::Set(value_type) = &cmd.value_type {
+            self.validate_value_type(value_type)?;
+        }
+        let now = self.now();
+        self.repository
+            .update_biz_metadata_locked_checked(
+                cmd.id,
+                move |biz_metadata| {
+                    let force = cmd.force;
+                    let loaded = biz_metadata.clone();
+                    Self::apply_update(biz_metadata, cmd, now)?;
+                    if !force && biz_metadata.content_eq(&loaded) {
+                        *biz_metadata = loaded;
+                        return Ok(false);
+                    }
+                    Ok(true)
+                },
+                Box::new(|loaded, updated| Box::pin(self.ensure_move_allowed(loaded, updated))),
+            )
+            .await
+    }
+
+    /// 锁定行被挂到新的父节点时，校验 [`CodeUniqueness::PerParent`] 下的同级编码与挂载后的树深度。
+    async fn ensure_move_allowed(
+        &self,
+        loaded: BizMetadata,
+        updated: BizMetadata,
+    ) -> Result<(), DomainError> {
+        let Some(parent_id) = updated.parent_id() else {
+            return Ok(());
+        };
+        if loaded.parent_id() == Some(parent_id) {
+            return Ok(());
+        }
+        if self.code_uniqueness == CodeUniqueness::PerParent {
+            self.ensure_code_unique(updated.code(), Some(parent_id), Some(updated.id()))
+                .await?;
+        }
+        self.ensure_depth_within_limit(parent_id, &HashSet::from([updated.id()]))
+            .await
+    }
+
+    /// 不改动任何字段，仅推进 `updated_at` 与版本号，用于让按时间戳增量同步的消费方重新拉取。
+    ///
+    /// 与普通更新一样按版本号做乐观锁校验；时钟回退时返回时间线不变式错误。
+    pub async fn touch_biz_metadata(
+        &self,
+        id: BizMetadataId,
+        version: Version,
+    ) -> Result<BizMetadata, DomainError> {
+        let now = self.now();
+        self.repository
+            .update_biz_metadata_locked(id, move |biz_metadata| {
+                Self::ensure_version(biz_metadata, version)?;
+                biz_metadata.touch(now)?;
+                Ok(true)
+            })
+            .await
+    }
+
+    /// 将一批 feature 的 `value_type` 统一改为 `value_type`，返回更新后的节点（重复 id 只处理一次）。
+    ///
+    /// 写入前先加载全部节点：不存在或非 feature 的 id 汇总在同一个错误中整批拒绝，不做任何写入。
+    /// 写入通过 [`BizMetadataRepository::update_biz_metadata_many`] 在单个事务内完成，
+    /// 每条按加载时的版本号做乐观锁校验，任一冲突整批回滚。
+    pub async fn bulk_change_value_type(
+        &self,
+        ids: Vec<BizMetadataId>,
+        value_type: ValueType,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        self.value_type_registry.validate(&value_type)?;
+
+        let mut seen = HashSet::new();
+        let mut items = Vec::with_capacity(ids.len());
+        let mut missing = Vec::new();
+        let mut non_features = Vec::new();
+        for id in ids {
+            if !seen.insert(id) {
+                continue;
+            }
+            match self.repository.find_biz_metadata_by_id(id).await? {
+                None => missing.push(id.value()),
+                Some(item) if item.object_type() != ObjectType::Feature => {
+                    non_features.push(id.value())
+                }
+                Some(item) => items.push(item),
+            }
+        }
+        if !missing.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {missing:?} not found"),
+            });
+        }
+        if !non_features.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
+                message: format!("biz_metadata {non_features:?} are not features"),
+            });
+        }
+
+        let now = self.now();
+        for item in &mut items {
+            item.change_value_type(value_type.clone(), now)?;
+        }
+        self.repository.update_biz_metadata_many(items).await
+    }
+
+    /// 将 `root` 及其全部存活后代的状态改为 `deprecated`，已弃用的节点跳过。
+    ///
+    /// 子树通过 [`BizMetadataRepository::find_subtree`] 单次加载；状态变更被领域规则拒绝的节点记入
+    /// [`DeprecateSubtreeReport::failed`] 且不写入，其余节点通过
+    /// [`BizMetadataRepository::update_biz_metadata_many`] 在单个事务内提交，每条按加载时的版本号
+    /// 做乐观锁校验，任一冲突整批回滚并返回错误。`root` 不存在或已删除时返回 `biz_metadata.not_found`。
+    pub async fn deprecate_subtree(
+        &self,
+        root: BizMetadataId,
+    ) -> Result<DeprecateSubtreeReport, DomainError> {
+        let subtree = self.repository.find_subtree(root).await?;
+        if subtree.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", root.value()),
+            });
+        }
+
+        let now = self.now();
+        let mut report = DeprecateSubtreeReport::default();
+        let mut pending = Vec::with_capacity(subtree.len());
+        for mut node in subtree {
+            if node.status() == BizMetadataStatus::Deprecated {
+                report.skipped.push(node.id());
+                continue;
+            }
+            match node.change_status(BizMetadataStatus::Deprecated, now) {
+                Ok(()) => pending.push(node),
+                Err(err) => report.failed.push((node.id(), err)),
+            }
+        }
+        if !pending.is_empty() {
+            report.deprecated = self.repository.update_biz_metadata_many(pending).await?;
+        }
+        Ok(report)
+    }
+
+    /// 将一批节点整体移动到 `new_parent` 下（`None` 表示移为根节点），返回更新后的节点。
+    ///
+    /// 环检测基于全部移动完成后的状态一次性进行，而非逐条校验：`new_parent` 是任一待移动节点
+    /// 自身或其后代时整批拒绝并返回 `biz_metadata.parent_chain_invalid`。写入通过
+    /// [`BizMetadataRepository::update_biz_metadata_many`] 在单个事务内完成，任一失败均不落库。
+    pub async fn reparent_many(
+        &self,
+        ids: Vec<BizMetadataId>,
+        new_parent: Option<BizMetadataId>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut moving = Vec::with_capacity(ids.len());
+        let mut moving_ids = HashSet::new();
+        for id in ids {
+            if !moving_ids.insert(id) {
+                continue;
+            }
+            let item = self
+                .repository
+                .find_biz_metadata_by_id(id)
+                .await?
+                .ok_or_else(|| DomainError::Validation {
+                    code: error_code::BIZ_METADATA_NOT_FOUND,
+                    message: format!("biz_metadata {} not found", id.value()),
+                })?;
+            moving.push(item);
+        }
+
+        if let Some(parent_id) = new_parent {
+            self.ensure_acyclic_after_move(parent_id, &moving_ids)
+                .await?;
+            self.ensure_depth_within_limit(parent_id, &moving_ids)
+                .await?;
+            if self.code_uniqueness == CodeUniqueness::PerParent {
+                self.ensure_leaves_unique_under(parent_id, &moving).await?;
+            }
+        }
+
+        let now = self.now();
+        for item in &mut moving {
+            item.set_parent_id(new_parent, now)?;
+        }
+        self.repository.update_biz_metadata_many(moving).await
+    }
+
+    /// 校验移动不会成环：`parent_id` 落在任一待移动节点的子树（含节点自身）内即说明移动后成环。
+    ///
+    /// 每个待移动节点的子树通过 [`BizMetadataRepository::find_subtree`] 单次加载。
+    async fn ensure_acyclic_after_move(
+        &self,
+        parent_id: BizMetadataId,
+        moving_ids: &HashSet<BizMetadataId>,
+    ) -> Result<(), DomainError> {
+        if self
             .repository
-            .find_biz_metadata_by_id(cmd.id)
+            .find_biz_metadata_by_id(parent_id)
             .await?
-            .ok_or_else(|| DomainError::Validation {
-                message: format!("biz_metadata {} not found", cmd.id.value()),
-            })?;
+            .is_none()
+        {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("parent biz_metadata {} not found", parent_id.value()),
+            });
+        }
+        for moving_id in moving_ids {
+            let subtree = self.repository.find_subtree(*moving_id).await?;
+            if subtree.iter().any(|node| node.id() == parent_id) {
+                return Err(DomainError::InvariantViolation {
+                    code: error_code::BIZ_METADATA_PARENT_CHAIN_INVALID,
+                    message: format!(
+                        "moving under {} would create a cycle through {}",
+                        parent_id.value(),
+                        moving_id.value()
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
 
-        if biz_metadata.version() != cmd.version {
+    /// 校验将 `moving` 挂到 `parent_id` 下后，这些节点及其子树的深度都不超过 `max_depth`。
+    ///
+    /// `moving` 为空时只校验新节点自身；子树通过 [`BizMetadataRepository::find_subtree`] 单次加载，
+    /// 同批移动的节点不计入其他节点的子树。
+    async fn ensure_depth_within_limit(
+        &self,
+        parent_id: BizMetadataId,
+        moving: &HashSet<BizMetadataId>,
+    ) -> Result<(), DomainError> {
+        // 祖先路径含父节点自身，其长度即为挂载节点的深度。
+        let depth = self.ancestor_path(parent_id).await?.len();
+        let mut height = 0;
+        for id in moving {
+            let subtree = self.repository.find_subtree(*id).await?;
+            let deepest = subtree_depths(*id, &subtree, moving)
+                .into_values()
+                .max()
+                .unwrap_or(0);
+            height = height.max(deepest);
+        }
+        if depth + height > self.max_depth {
             return Err(DomainError::Validation {
-                message: "version not match".into(),
+                code: error_code::BIZ_METADATA_TREE_TOO_DEEP,
+                message: format!(
+                    "placing under {} would exceed max tree depth {}",
+                    parent_id.value(),
+                    self.max_depth
+                ),
             });
         }
+        Ok(())
+    }
 
-        if let Some(name) = cmd.name {
-            let name = BizMetadataName::new(name)?;
-            biz_metadata.rename(name)?;
+    /// [`CodeUniqueness::PerParent`] 下校验移动后同一父节点下的末段编码不重复，
+    /// 同时覆盖批内节点之间与批内节点和既有子节点之间的冲突。
+    async fn ensure_leaves_unique_under(
+        &self,
+        parent_id: BizMetadataId,
+        moving: &[BizMetadata],
+    ) -> Result<(), DomainError> {
+        let mut leaves: HashMap<&str, &BizMetadata> = HashMap::new();
+        let siblings = self
+            .repository
+            .find_biz_metadata_children(parent_id)
+            .await?;
+        let moving_ids: HashSet<_> = moving.iter().map(BizMetadata::id).collect();
+        for item in siblings
+            .iter()
+            .filter(|sibling| !moving_ids.contains(&sibling.id()))
+            .chain(moving)
+        {
+            let leaf = leaf_segment(item.code().as_str());
+            if let Some(existing) = leaves.insert(leaf, item) {
+                return Err(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_DUPLICATE_CODE,
+                    message: format!(
+                        "code segment {leaf} of {} collides with {} under parent {}",
+                        item.code().as_str(),
+                        existing.code().as_str(),
+                        parent_id.value()
+                    ),
+                });
+            }
         }
+        Ok(())
+    }
 
-        match cmd.description {
-            FieldUpdate::Keep => {}
-            FieldUpdate::Set(desc) => biz_metadata.set_description(Some(desc))?,
-            FieldUpdate::Clear => biz_metadata.set_description(None)?,
+    /// 按 [`CodeUniqueness`] 预检存活记录的编码唯一性，使各后端返回一致的领域错误；
+    /// 数据库唯一索引仍是最终防线。`exclude` 为更新中的记录自身。
+    async fn ensure_code_unique(
+        &self,
+        code: &BizMetadataCode,
+        parent_id: Option<BizMetadataId>,
+        exclude: Option<BizMetadataId>,
+    ) -> Result<(), DomainError> {
+        let parent_id = match (self.code_uniqueness, parent_id) {
+            (CodeUniqueness::PerParent, Some(parent_id)) => parent_id,
+            _ => {
+                if self.repository.code_exists(code.as_str()).await? {
+                    return Err(DomainError::Validation {
+                        code: error_code::BIZ_METADATA_DUPLICATE_CODE,
+                        message: format!("code {} already exists", code.as_str()),
+                    });
+                }
+                return Ok(());
+            }
+        };
+
+        let leaf = leaf_segment(code.as_str());
+        let siblings = self
+            .repository
+            .find_biz_metadata_children(parent_id)
+            .await?;
+        if let Some(sibling) = siblings.iter().find(|sibling| {
+            Some(sibling.id()) != exclude && leaf_segment(sibling.code().as_str()) == leaf
+        }) {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_DUPLICATE_CODE,
+                message: format!(
+                    "code segment {leaf} already used by sibling {} under parent {}",
+                    sibling.code().as_str(),
+                    parent_id.value()
+                ),
+            });
         }
+        Ok(())
+    }
 
-        if let Some(data_class) = cmd.data_class {
-            biz_metadata.change_data_class(data_class)?;
+    fn ensure_version(biz_metadata: &BizMetadata, expected: Version) -> Result<(), DomainError> {
+        if biz_metadata.version() != expected {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                message: format!(
+                    "biz_metadata {} version conflict: expected {}, current {}",
+                    biz_metadata.id().value(),
+                    i32::from(expected),
+                    i32::from(biz_metadata.version())
+                ),
+            });
         }
+        Ok(())
+    }
 
-        if let Some(value_type) = cmd.value_type {
-            let value_type = ValueType::new(value_type)?;
-            biz_metadata.change_value_type(value_type)?;
+    fn apply_update(
+        biz_metadata: &mut BizMetadata,
+        cmd: UpdateBizMetadataCommand,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        Self::ensure_version(biz_metadata, cmd.version)?;
+        Self::ensure_fields_match_object_type(biz_metadata, &cmd)?;
+        Self::ensure_required_fields_not_cleared(biz_metadata, &cmd)?;
+
+        if let Some(name) = cmd.name {
+            let name = BizMetadataName::new(name)?;
+            biz_metadata.rename(name, now)?;
         }
 
-        match cmd.unit {
+        match cmd.description {
             FieldUpdate::Keep => {}
-            FieldUpdate::Set(value) => biz_metadata.set_unit(Some(Unit::new(value)?))?,
-            FieldUpdate::Clear => biz_metadata.set_unit(None)?,
+            FieldUpdate::Set(desc) => biz_metadata.set_description(Some(desc), now)?,
+            FieldUpdate::Clear => biz_metadata.set_description(None, now)?,
         }
 
+        // 必填字段的 `Clear` 已在上方拒绝，此处只需区分是否设置新值。
+        let data_class = match cmd.data_class {
+            FieldUpdate::Set(value) => Some(value),
+            FieldUpdate::Keep | FieldUpdate::Clear => None,
+        };
+        let value_type = match cmd.value_type {
+            FieldUpdate::Set(value) => Some(ValueType::new(value)?),
+            FieldUpdate::Keep | FieldUpdate::Clear => None,
+        };
+        let unit = match cmd.unit {
+            FieldUpdate::Keep => None,
+            FieldUpdate::Set(value) => Some(Some(Unit::new(value)?)),
+            FieldUpdate::Clear => Some(None),
+        };
+        biz_metadata.change_feature_fields(data_class, value_type, unit, now)?;
+
         match cmd.parent_id {
             FieldUpdate::Keep => {}
-            FieldUpdate::Set(parent_id) => biz_metadata.set_parent_id(Some(parent_id))?,
-            FieldUpdate::Clear => biz_metadata.set_parent_id(None)?,
+            FieldUpdate::Set(parent_id) => biz_metadata.set_parent_id(Some(parent_id), now)?,
+            FieldUpdate::Clear => biz_metadata.set_parent_id(None, now)?,
         }
 
         if let Some(status) = cmd.status {
-            biz_metadata.change_status(status)?;
+            biz_metadata.change_status(status, now)?;
         }
 
         if let Some(source) = cmd.source {
-            biz_metadata.change_source(source)?;
+            biz_metadata.change_source(source, now)?;
+        }
+
+        Ok(())
+    }
+
+    /// 在修改任何字段之前，拒绝对非 feature 节点设置 feature 专属字段，给出明确的字段与类型。
+    fn ensure_fields_match_object_type(
+        biz_metadata: &BizMetadata,
+        cmd: &UpdateBizMetadataCommand,
+    ) -> Result<(), DomainError> {
+        let object_type = biz_metadata.object_type();
+        if object_type == ObjectType::Feature {
+            return Ok(());
+        }
+        let conflicting = [
+            ("data_class", !matches!(cmd.data_class, FieldUpdate::Keep)),
+            ("value_type", !matches!(cmd.value_type, FieldUpdate::Keep)),
+            ("unit", !matches!(cmd.unit, FieldUpdate::Keep)),
+        ]
+        .into_iter()
+        .find_map(|(field, requested)| requested.then_some(field));
+        match conflicting {
+            Some(field) => Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
+                message: format!("cannot set {field} on object_type={}", object_type.as_str()),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// 在修改任何字段之前，拒绝清空 feature 必填的 `data_class`/`value_type`，给出明确的字段名；
+    /// `description`/`unit`/`parent_id` 可空，清空不受限制。
+    fn ensure_required_fields_not_cleared(
+        biz_metadata: &BizMetadata,
+        cmd: &UpdateBizMetadataCommand,
+    ) -> Result<(), DomainError> {
+        if biz_metadata.object_type() != ObjectType::Feature {
+            return Ok(());
         }
+        let cleared = [
+            ("data_class", matches!(cmd.data_class, FieldUpdate::Clear)),
+            ("value_type", matches!(cmd.value_type, FieldUpdate::Clear)),
+        ]
+        .into_iter()
+        .find_map(|(field, cleared)| cleared.then_some(field));
+        match cleared {
+            Some(field) => Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_FEATURE_FIELD_REQUIRED,
+                message: format!("cannot clear {field}: object_type=feature requires it"),
+            }),
+            None => Ok(()),
+        }
+    }
 
-        self.repository.update_biz_metadata(biz_metadata).await
+    /// 以乐观锁重试方式更新：加载最新聚合、应用 `mutate` 后按版本提交。
+    ///
+    /// 遇到版本冲突或事务序列化失败时重新加载并重试，最多重试 `max_retries` 次；重试耗尽后返回最后一次的错误。
+    /// `mutate` 的第二个参数为本次尝试的审计时间。
+    pub async fn update_with_retry(
+        &self,
+        id: BizMetadataId,
+        mutate: impl Fn(&mut BizMetadata, DateTime<Utc>),
+        max_retries: u32,
+    ) -> Result<BizMetadata, DomainError> {
+        let mut attempt = 0;
+        loop {
+            let mut biz_metadata = self
+                .repository
+                .find_biz_metadata_by_id(id)
+                .await?
+                .ok_or_else(|| DomainError::Validation {
+                    code: error_code::BIZ_METADATA_NOT_FOUND,
+                    message: format!("biz_metadata {} not found", id.value()),
+                })?;
+            mutate(&mut biz_metadata, self.now());
+
+            match self.repository.update_biz_metadata(biz_metadata).await {
+                Err(err) if is_retryable(&err) && attempt < max_retries => attempt += 1,
+                result => return result,
+            }
+        }
     }
 
+    /// 基于版本号软删除元数据，并以同一删除时间级联软删除其存活别名。
+    ///
+    /// 删除前检查仍依赖它的存活节点：以其为 `parent_id` 的子节点，以及 `value_type` 含 `ref:<其编码>` 的 feature。
+    /// `Restrict` 时返回列出阻塞 ID 的 `InvariantViolation`；`Deprecate` 时级联弃用这些节点；
+    /// `Detach` 时置空子节点的 `parent_id`、从 feature 的 `value_type` 中移除该引用，引用是唯一 term 时无法脱离，
+    /// 同样返回 `InvariantViolation`。
+    /// 依赖方变更、元数据删除与别名级联经 [`BizMetadataRepository::delete_biz_metadata_with_aliases`]
+    /// 在同一事务内提交，任一依赖方版本冲突或别名写入失败时整体回滚。
     pub async fn delete_biz_metadata(
         &self,
         id: BizMetadataId,
         version: Version,
+        dependents: DependentAction,
     ) -> Result<(), DomainError> {
         let mut biz_metadata = self
             .repository
             .find_biz_metadata_by_id(id)
             .await?
             .ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
                 message: format!("biz_metadata {} not found", id.value()),
             })?;
 
         if biz_metadata.version() != version {
             return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
                 message: "version not match".into(),
             });
         }
 
-        biz_metadata.mark_deleted(Utc::now())?;
-        let _ = self.repository.update_biz_metadata(biz_metadata).await?;
+        let now = self.now();
+        let code = biz_metadata.code().as_str().to_string();
+        let blocking: BTreeMap<i64, BizMetadata> = self
+            .find_dependents(std::slice::from_ref(&biz_metadata))
+            .await?
+            .into_iter()
+            .map(|(dependent, _)| (dependent.id().value(), dependent))
+            .collect();
+        let mut batch = Vec::with_capacity(blocking.len() + 1);
+        match dependents {
+            DependentAction::Restrict => {
+                if !blocking.is_empty() {
+                    let ids: Vec<String> = blocking.keys().map(i64::to_string).collect();
+                    return Err(DomainError::InvariantViolation {
+                        code: error_code::BIZ_METADATA_HAS_DEPENDENTS,
+                        message: format!(
+                            "biz_metadata {} is referenced by: {}",
+                            id.value(),
+                            ids.join(", ")
+                        ),
+                    });
+                }
+            }
+            DependentAction::Deprecate => {
+                for mut dependent in blocking.into_values() {
+                    dependent.change_status(BizMetadataStatus::Deprecated, now)?;
+                    batch.push(dependent);
+                }
+            }
+            DependentAction::Detach => {
+                for mut dependent in blocking.into_values() {
+                    if dependent.parent_id() == Some(id) {
+                        dependent.set_parent_id(None, now)?;
+                    }
+                    if let Some(value_type) = dependent.value_type()
+                        && has_type_ref(value_type.as_str(), &code)
+                    {
+                        let remaining = remove_type_ref(value_type.as_str(), &code).ok_or_else(
+                            || DomainError::InvariantViolation {
+                                code: error_code::BIZ_METADATA_HAS_DEPENDENTS,
+                                message: format!(
+                                    "biz_metadata {} is referenced by: {} (sole value_type term ref:{code} cannot be detached)",
+                                    id.value(),
+                                    dependent.id().value()
+                                ),
+                            },
+                        )?;
+                        dependent.change_value_type(ValueType::new(remaining)?, now)?;
+                    }
+                    batch.push(dependent);
+                }
+            }
+        }
+
+        biz_metadata.mark_deleted(now)?;
+        batch.push(biz_metadata);
+        self.repository
+            .delete_biz_metadata_with_aliases(batch, id)
+            .await?;
         Ok(())
     }
 
-    pub async fn query_biz_metadata(
+    /// 基于版本号恢复已软删除的元数据，返回恢复后的聚合。
+    ///
+    /// `restore_aliases=true` 时经 [`BizMetadataRepository::restore_biz_metadata_with_aliases`] 在同一事务内
+    /// 一并恢复删除时间不早于元数据删除时间的别名（即级联删除的别名），此前单独删除的别名保持删除，
+    /// 删除期间 (`alias`, `language`) 已被新别名占用的别名同样保持删除；
+    /// 父节点须仍存活，`code` 已被存活记录占用时失败。
+    pub async fn restore_biz_metadata(
         &self,
-        request: BizMetadataQueryRequest,
-    ) -> Result<PageResult<BizMetadata>, DomainError> {
+        id: BizMetadataId,
+        version: Version,
+        restore_aliases: bool,
+    ) -> Result<BizMetadata, DomainError> {
+        let mut biz_metadata = self
+            .repository
+            .find_deleted_biz_metadata_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("deleted biz_metadata {} not found", id.value()),
+            })?;
+
+        if biz_metadata.version() != version {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                message: "version not match".into(),
+            });
+        }
+        if let Some(parent_id) = biz_metadata.parent_id()
+            && self
+                .repository
+                .find_biz_metadata_by_id(parent_id)
+                .await?
+                .is_none()
+        {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("parent biz_metadata {} not found", parent_id.value()),
+            });
+        }
+
+        let deleted_at = biz_metadata.delete_at();
+        biz_metadata.restore(self.now())?;
+        match deleted_at {
+            Some(deleted_at) if restore_aliases => {
+                self.repository
+                    .restore_biz_metadata_with_aliases(biz_metadata, deleted_at)
+                    .await
+            }
+            _ => self.repository.restore_biz_metadata(biz_metadata).await,
+        }
+    }
+
+    /// 将 `loser` 合并进 `survivor`，返回合并后的存活节点。
+    ///
+    /// 两者须存在、互不相同且 `object_type` 一致；`loser` 的子节点改挂到 `survivor` 下，
+    /// 存活 feature 中 `ref:<loser 编码>` 的类型引用改写为 `ref:<survivor 编码>`（该编码仍被其他存活节点共用时保持不变），
+    /// `loser` 随后软删除。
+    /// 子节点移动沿用 [`reparent_many`](Self::reparent_many) 的环、深度与 [`CodeUniqueness::PerParent`] 末段编码校验，
+    /// 任一失败时不做任何写入。
+    ///
+    /// 别名按 [`BizMetadataAliasService::reassign`](crate::application::service::biz_metadata_alias::service::BizMetadataAliasService::reassign)
+    /// 的规则改挂到 `survivor`，改挂后超过存活别名上限时返回 `biz_metadata_alias.limit_exceeded`；
+    /// 别名改挂与元数据变更通过 [`BizMetadataRepository::merge_biz_metadata`] 在同一事务内提交，任一失败时整体回滚。
+    pub async fn merge(
+        &self,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+    ) -> Result<BizMetadata, DomainError> {
+        if loser == survivor {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_MERGE_INVALID,
+                message: format!("cannot merge biz_metadata {} into itself", loser.value()),
+            });
+        }
+        let mut loser_item = self.load_alive(loser).await?;
+        let survivor_item = self.load_alive(survivor).await?;
+        if loser_item.object_type() != survivor_item.object_type() {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
+                message: format!(
+                    "cannot merge {} {} into {} {}",
+                    loser_item.object_type().as_str(),
+                    loser.value(),
+                    survivor_item.object_type().as_str(),
+                    survivor.value()
+                ),
+            });
+        }
+
+        let mut changed = self
+            .collect_all(Expression::cmp(eq("parent_id", loser.value())))
+            .await?;
+        let moving_ids: HashSet<_> = changed.iter().map(BizMetadata::id).collect();
+        if !moving_ids.is_empty() {
+            self.ensure_acyclic_after_move(survivor, &moving_ids)
+                .await?;
+            self.ensure_depth_within_limit(survivor, &moving_ids)
+                .await?;
+            if self.code_uniqueness == CodeUniqueness::PerParent {
+                self.ensure_leaves_unique_under(survivor, &changed).await?;
+            }
+        }
+
+        let now = self.now();
+        for child in &mut changed {
+            child.set_parent_id(Some(survivor), now)?;
+        }
+        let from = loser_item.code().as_str().to_string();
+        // 编码仍被其他存活节点共用时，`ref:<from>` 在合并后仍可解析到它们，不做改写。
+        let shared = self
+            .repository
+            .count_biz_metadata(Expression::cmp(eq("code", from.as_str())))
+            .await?
+            > 1;
+        let referencing = if shared {
+            Vec::new()
+        } else {
+            self.collect_all(Expression::cmp(contains(
+                "value_type",
+                format!("ref:{from}").as_str(),
+            )))
+            .await?
+        };
+        for feature in referencing {
+            if feature.id() == loser {
+                continue;
+            }
+            let Some(rewritten) = feature.value_type().and_then(|value_type| {
+                rewrite_type_ref(value_type.as_str(), &from, survivor_item.code().as_str())
+            }) else {
+                continue;
+            };
+            let index = match changed.iter().position(|item| item.id() == feature.id()) {
+                Some(index) => index,
+                None => {
+                    changed.push(feature);
+                    changed.len() - 1
+                }
+            };
+            changed[index].change_value_type(ValueType::new(rewritten)?, now)?;
+        }
+        loser_item.mark_deleted(now)?;
+        changed.push(loser_item);
+
+        let updated = self
+            .repository
+            .merge_biz_metadata(changed, loser, survivor, self.max_aliases_per_metadata, now)
+            .await?;
+        Ok(updated
+            .into_iter()
+            .find(|item| item.id() == survivor)
+            .unwrap_or(survivor_item))
+    }
+
+    /// 加载存活节点，不存在时返回 `biz_metadata.not_found`。
+    async fn load_alive(&self, id: BizMetadataId) -> Result<BizMetadata, DomainError> {
         self.repository
-            .query_biz_metadata(request.expression, request.options)
+            .find_biz_metadata_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", id.value()),
+            })
+    }
+
+    /// 按过滤表达式批量软删除匹配的未删除记录，返回删除条数。
+    ///
+    /// - 无论是否开启严格查询，均先按仓储声明的可过滤字段校验表达式，未知字段直接拒绝，
+    ///   避免拼写错误的条件被宽松模式当作恒真而删除全部记录；仓储未声明字段时视同恒真表达式
+    /// - 恒真表达式（如 `Expression::True`、空的 `Expression::and`）须显式传入 `confirm=true`
+    /// - 过滤值无法转换为字段类型时由仓储在收集候选阶段报错，不会删除任何记录
+    /// - 默认跳过仍被存活节点依赖的记录：通过 `parent_id` 挂在其下的子节点，或 `value_type` 含 `ref:<其编码>` 的 feature
+    ///   （同批一并删除的依赖方不计）；跳过的记录不计入返回条数，`force=true` 时不跳过
+    /// - 删除通过 [`BizMetadataRepository::soft_delete_biz_metadata_many`] 在单个事务内完成
+    pub async fn delete_by_filter(
+        &self,
+        expr: Expression,
+        confirm: bool,
+        force: bool,
+    ) -> Result<u64, DomainError> {
+        let fields = self.repository.query_fields();
+        if let Some(fields) = &fields {
+            ensure_known_fields(fields, &expr, &[])?;
+        }
+        if !confirm && (fields.is_none() || is_unrestricted(&expr)) {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_FILTER_UNCONFIRMED,
+                message: "delete_by_filter with an always-true filter requires confirm".into(),
+            });
+        }
+
+        let mut candidates = self.collect_all(expr).await?;
+        if !force {
+            let ids: Vec<i64> = candidates.iter().map(|item| item.id().value()).collect();
+            let links: Vec<(i64, i64)> = self
+                .find_dependents(&candidates)
+                .await?
+                .into_iter()
+                .map(|(dependent, target)| (dependent.id().value(), target.value()))
+                .collect();
+
+            // 被保留的节点会继续引用其父节点或类型引用目标，因此迭代至不动点。
+            let mut doomed: HashSet<i64> = ids.into_iter().collect();
+            loop {
+                let kept: Vec<i64> = links
+                    .iter()
+                    .filter(|(dependent, target)| {
+                        !doomed.contains(dependent) && doomed.contains(target)
+                    })
+                    .map(|(_, target)| *target)
+                    .collect();
+                if kept.is_empty() {
+                    break;
+                }
+                for target in kept {
+                    doomed.remove(&target);
+                }
+            }
+            candidates.retain(|item| doomed.contains(&item.id().value()));
+        }
+
+        if candidates.is_empty() {
+            return Ok(0);
+        }
+        self.repository
+            .soft_delete_biz_metadata_many(candidates, self.now())
             .await
     }
 
-    pub fn repository(&self) -> &R {
-        &self.repository
+    /// 物理清理 `deleted_at` 早于 `older_than` 的软删除记录，返回清理条数。
+    ///
+    /// 按批执行以避免长时间持锁；仍被存活记录（子节点、别名）引用的行会被保留，
+    /// 被清理行的已软删除别名随同一批一并物理删除。
+    /// 保留期由调用方（如定时任务）通过 `older_than` 决定。
+    pub async fn purge_deleted(&self, older_than: DateTime<Utc>) -> Result<u64, DomainError> {
+        let mut purged = 0;
+        loop {
+            let batch = self
+                .repository
+                .purge_biz_metadata_deleted_before(older_than, PURGE_BATCH_SIZE)
+                .await?;
+            purged += batch;
+            if batch < PURGE_BATCH_SIZE {
+                return Ok(purged);
+            }
+        }
     }
 
-    /// 便捷查询：按 ID 查找。
-    pub async fn find_biz_metadata_by_id(
+    /// 按名称与别名联合检索元数据，按相关度降序返回至多 `limit` 条。
+    ///
+    /// 名称包含 `text` 的元数据与别名包含 `text` 的别名（来自 `aliases`）合并后，
+    /// 按 [`BizMetadataSearchHit::score`] 排序；同一元数据仅保留得分最高的一条，同分按 ID 升序。
+    /// 先取回全部候选再排序截断，高分命中不会因 ID 较大而被先行截掉；
+    /// 别名所属元数据以单次 `In` 查询批量加载。
+    pub async fn search<A>(
         &self,
-        id: BizMetadataId,
+        aliases: &A,
+        text: &str,
+        limit: u64,
+    ) -> Result<Vec<BizMetadataSearchHit>, DomainError>
+    where
+        A: BizMetadataAliasRepository,
+    {
+        let text = text.trim();
+        if text.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        let mut best: HashMap<i64, BizMetadataSearchHit> = HashMap::new();
+        let mut offer = |hit: BizMetadataSearchHit| {
+            let id = hit.metadata.id().value();
+            if best
+                .get(&id)
+                .is_none_or(|current| hit.score > current.score)
+            {
+                best.insert(id, hit);
+            }
+        };
+
+        let by_name = self
+            .collect_all(Expression::cmp(contains("name", text)))
+            .await?;
+        for metadata in by_name {
+            let score = if metadata.name().as_str().eq_ignore_ascii_case(text) {
+                SCORE_EXACT
+            } else {
+                SCORE_SUBSTRING
+            };
+            offer(BizMetadataSearchHit {
+                metadata,
+                matched_alias: None,
+                score,
+            });
+        }
+
+        // 别名以 NFKC 形式入库，按同样形式匹配与打分。
+        let alias_text = AliasNormalization::Nfkc.apply(text);
+        let mut by_alias = Vec::new();
+        let mut offset = 0;
+        loop {
+            let options = QueryOptions::new(Some(SEARCH_BATCH_SIZE), Some(offset))
+                .with_order_by(OrderBy::asc("id"));
+            let page = aliases
+                .query_alias(
+                    Expression::cmp(contains("alias", alias_text.as_str())),
+                    options,
+                )
+                .await?;
+            let has_next = page.has_next_page();
+            by_alias.extend(
+                page.into_items()
+                    .into_iter()
+                    .filter(|alias| alias.delete_at().is_none()),
+            );
+            if !has_next {
+                break;
+            }
+            offset += SEARCH_BATCH_SIZE;
+        }
+
+        let mut owner_ids: Vec<i64> = by_alias
+            .iter()
+            .map(|alias| alias.metadata_id().value())
+            .collect();
+        owner_ids.sort_unstable();
+        owner_ids.dedup();
+        let mut owners = HashMap::with_capacity(owner_ids.len());
+        for chunk in owner_ids.chunks(SEARCH_BATCH_SIZE as usize) {
+            for metadata in self
+                .collect_all(Expression::cmp(r#in("id", chunk.to_vec())))
+                .await?
+            {
+                owners.insert(metadata.id().value(), metadata);
+            }
+        }
+        for alias in by_alias {
+            let Some(metadata) = owners.get(&alias.metadata_id().value()) else {
+                continue;
+            };
+            let score = alias_score(&alias, &alias_text);
+            offer(BizMetadataSearchHit {
+                metadata: metadata.clone(),
+                matched_alias: Some(alias),
+                score,
+            });
+        }
+
+        let mut hits: Vec<BizMetadataSearchHit> = best.into_values().collect();
+        hits.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.metadata.id().value().cmp(&b.metadata.id().value()))
+        });
+        hits.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        Ok(hits)
+    }
+
+    /// 查找仍依赖 `targets` 的存活节点，返回 `(依赖方, 被依赖方 ID)`：
+    /// 以 `parent_id` 挂在目标下的子节点，以及 `value_type` 含 `ref:<目标编码>` term 的 feature（引用自身的不计）。
+    /// 同一节点可能因多个目标或两种引用出现多次；按父节点唯一时多个目标可能共用编码，
+    /// `ref:<code>` 视为同时依赖每个同编码的目标。
+    async fn find_dependents(
+        &self,
+        targets: &[BizMetadata],
+    ) -> Result<Vec<(BizMetadata, BizMetadataId)>, DomainError> {
+        let mut links = Vec::new();
+        for chunk in targets.chunks(DELETE_BY_FILTER_BATCH_SIZE as usize) {
+            let ids: Vec<i64> = chunk.iter().map(|item| item.id().value()).collect();
+            let children = self
+                .collect_all(Expression::cmp(r#in("parent_id", ids)))
+                .await?;
+            links.extend(children.into_iter().filter_map(|child| {
+                let parent = child.parent_id()?;
+                Some((child, parent))
+            }));
+
+            // `contains` 只做粗筛，命中后按 term 精确比对，避免 `ref:a.b` 误伤 `ref:a.bc`。
+            let mut by_code: HashMap<&str, Vec<BizMetadataId>> = HashMap::new();
+            for item in chunk {
+                by_code
+                    .entry(item.code().as_str())
+                    .or_default()
+                    .push(item.id());
+            }
+            let filter = Expression::or(
+                chunk
+                    .iter()
+                    .map(|item| {
+                        Expression::cmp(contains(
+                            "value_type",
+                            format!("ref:{}", item.code().as_str()).as_str(),
+                        ))
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            for feature in self.collect_all(filter).await? {
+                let referenced: Vec<BizMetadataId> = feature
+                    .value_type()
+                    .map(|value_type| {
+                        value_type
+                            .as_str()
+                            .split('|')
+                            .filter_map(type_ref_target)
+                            .filter_map(|code| by_code.get(code))
+                            .flatten()
+                            .copied()
+                            .filter(|target| *target != feature.id())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                for target in referenced {
+                    links.push((feature.clone(), target));
+                }
+            }
+        }
+        Ok(links)
+    }
+
+    /// 按 `id` 升序分批拉取表达式匹配的全部未删除记录。
+    async fn collect_all(&self, expr: Expression) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut items = Vec::new();
+        let mut offset = 0;
+        loop {
+            let options = QueryOptions::new(Some(DELETE_BY_FILTER_BATCH_SIZE), Some(offset))
+                .with_order_by(OrderBy::asc("id"));
+            let page = self
+                .repository
+                .query_biz_metadata(expr.clone(), options)
+                .await?;
+            let has_next = page.has_next_page();
+            items.extend(page.into_items());
+            if !has_next {
+                return Ok(items);
+            }
+            offset += DELETE_BY_FILTER_BATCH_SIZE;
+        }
+    }
+
+    pub async fn query_biz_metadata(
+        &self,
+        request: BizMetadataQueryRequest,
+    ) -> Result<PageResult<BizMetadata>, DomainError> {
+        if self.strict_query
+            && let Some(fields) = self.repository.query_fields()
+        {
+            ensure_known_fields(&fields, &request.expression, &request.options.order_bys)?;
+        }
+        self.repository
+            .query_biz_metadata(request.expression, request.options)
+            .await
+    }
+
+    /// 统计满足 `expr` 的存活记录条数，只执行计数查询、不加载行数据，结果与同条件分页查询的 `total` 一致。
+    ///
+    /// 严格查询模式下同样先校验过滤字段。
+    pub async fn count_biz_metadata(&self, expr: Expression) -> Result<u64, DomainError> {
+        if self.strict_query
+            && let Some(fields) = self.repository.query_fields()
+        {
+            ensure_known_fields(&fields, &expr, &[])?;
+        }
+        self.repository.count_biz_metadata(expr).await
+    }
+
+    /// 将父子层级导出为 GraphViz DOT，节点以编码为标签，边由父节点指向子节点。
+    ///
+    /// 指定 `root` 时仅导出其子树，否则只从顶层节点（无存活父节点）出发；
+    /// 根节点深度为 0，超过 `max_depth` 的节点不输出。遍历记录已访问节点，
+    /// 数据中存在环时只输出回指边而不重复展开；不属于任何顶层子树的环成员不会导出。
+    pub async fn export_dot(
+        &self,
+        root: Option<BizMetadataId>,
+        max_depth: usize,
+    ) -> Result<String, DomainError> {
+        let items = self.collect_all(Expression::True).await?;
+        let by_id: HashMap<BizMetadataId, &BizMetadata> =
+            items.iter().map(|item| (item.id(), item)).collect();
+        let mut children: HashMap<BizMetadataId, Vec<BizMetadataId>> = HashMap::new();
+        for item in &items {
+            if let Some(parent_id) = item.parent_id().filter(|id| by_id.contains_key(id)) {
+                children.entry(parent_id).or_default().push(item.id());
+            }
+        }
+
+        let starts: Vec<BizMetadataId> = match root {
+            Some(id) => {
+                if !by_id.contains_key(&id) {
+                    return Err(DomainError::Validation {
+                        code: error_code::BIZ_METADATA_NOT_FOUND,
+                        message: format!("biz_metadata {} not found", id.value()),
+                    });
+                }
+                vec![id]
+            }
+            None => items
+                .iter()
+                .filter(|item| !item.parent_id().is_some_and(|id| by_id.contains_key(&id)))
+                .map(BizMetadata::id)
+                .collect(),
+        };
+
+        let mut nodes = Vec::new();
+        let mut edges = Vec::new();
+        let mut visited = HashSet::new();
+        for start in starts {
+            if !visited.insert(start) {
+                continue;
+            }
+            nodes.push(start);
+            let mut frontier = vec![start];
+            for _ in 0..max_depth {
+                let mut next = Vec::new();
+                for parent in frontier {
+                    for &child in children.get(&parent).into_iter().flatten() {
+                        edges.push((parent, child));
+                        if visited.insert(child) {
+                            nodes.push(child);
+                            next.push(child);
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    break;
+                }
+                frontier = next;
+            }
+        }
+
+        let mut dot = String::from("digraph biz_metadata {\n");
+        for id in &nodes {
+            dot.push_str(&format!(
+                "  n{} [label=\"{}\"];\n",
+                id.value(),
+                dot_escape(by_id[id].code().as_str())
+            ));
+        }
+        for (parent, child) in edges {
+            dot.push_str(&format!("  n{} -> n{};\n", parent.value(), child.value()));
+        }
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// 导出全部存活元数据及其存活别名，条目按编码升序。
+    ///
+    /// 父子关系与别名归属以编码表示；父节点已删除或不存在时该条目导出为根节点。
+    pub async fn export_catalog<A>(&self, aliases: &A) -> Result<CatalogDump, DomainError>
+    where
+        A: BizMetadataAliasRepository,
+    {
+        let mut items = self.collect_all(Expression::True).await?;
+        items.sort_by(|a, b| a.code().as_str().cmp(b.code().as_str()));
+        let code_by_id: HashMap<BizMetadataId, &str> = items
+            .iter()
+            .map(|item| (item.id(), item.code().as_str()))
+            .collect();
+
+        let mut aliases_by_metadata: HashMap<BizMetadataId, Vec<CatalogAlias>> = HashMap::new();
+        for alias in collect_live_aliases(aliases).await? {
+            aliases_by_metadata
+                .entry(alias.metadata_id())
+                .or_default()
+                .push(CatalogAlias {
+                    alias: alias.alias().as_str().to_string(),
+                    language: alias.language().as_str().to_string(),
+                    source: alias.source().as_str().to_string(),
+                    weight: alias.weight().value(),
+                    is_primary: alias.is_primary(),
+                });
+        }
+
+        let entries = items
+            .iter()
+            .map(|item| CatalogEntry {
+                code: item.code().as_str().to_string(),
+                name: item.name().as_str().to_string(),
+                description: item.description().map(str::to_string),
+                object_type: item.object_type().as_str().to_string(),
+                parent_code: item
+                    .parent_id()
+                    .and_then(|parent_id| code_by_id.get(&parent_id))
+                    .map(|code| code.to_string()),
+                data_class: item.data_class().map(|value| value.as_str().to_string()),
+                value_type: item.value_type().map(|value| value.as_str().to_string()),
+                unit: item.unit().map(|value| value.as_str().to_string()),
+                status: item.status().as_str().to_string(),
+                source: item.source().as_str().to_string(),
+                aliases: aliases_by_metadata.remove(&item.id()).unwrap_or_default(),
+            })
+            .collect();
+
+        Ok(CatalogDump {
+            schema_version: CATALOG_SCHEMA_VERSION,
+            exported_at: self.now().to_rfc3339(),
+            entries,
+        })
+    }
+
+    /// 导入 [`export_catalog`](Self::export_catalog) 的导出内容，按编码而非 id 解析父节点与别名归属。
+    ///
+    /// 写入前先校验格式版本、排好父节点在前的顺序，并把全部条目与别名转换为领域快照逐条校验；
+    /// 父编码无法解析、成环或任一条目非法时不做任何写入（`Replace` 模式也不会先清空现有目录）；
+    /// `Upsert` 时条目编码或父编码被现有多个存活记录共用，返回 `biz_metadata.code_ambiguous` 且不做任何写入。
+    /// `Replace` 模式经 [`BizMetadataRepository::replace_catalog`] 在单个仓储事务内清空并写入元数据与别名，
+    /// 任一步失败时原目录保持不变，此时不使用 `aliases`。`Upsert` 模式经 `aliases` 逐条写入，不在单个事务内：
+    /// 中途失败时已写入的条目保留，修正后以同一份导出重新导入即可收敛（已一致的条目计为 `unchanged`）。
+    /// `Upsert` 的别名按（元数据、文本、语言）查找或新建，已存在时覆盖来源、权重与首选标记；条目自带的不同别名数
+    /// 或写入后的存活别名数超过上限时返回 `biz_metadata_alias.limit_exceeded`，前者在写入前校验。
+    pub async fn import_catalog<A>(
+        &self,
+        aliases: &A,
+        dump: CatalogDump,
+        mode: ImportMode,
+    ) -> Result<CatalogImportSummary, DomainError>
+    where
+        A: BizMetadataAliasRepository + Sync,
+    {
+        if dump.schema_version != CATALOG_SCHEMA_VERSION {
+            return Err(DomainError::Validation {
+                code: error_code::CATALOG_SCHEMA_UNSUPPORTED,
+                message: format!(
+                    "catalog schema_version {} is not supported, expected {CATALOG_SCHEMA_VERSION}",
+                    dump.schema_version
+                ),
+            });
+        }
+
+        // `Replace` 由仓储整体清空，只有 `Upsert` 需要现有目录来解析父编码与比对内容。
+        let existing = match mode {
+            ImportMode::Replace => Vec::new(),
+            ImportMode::Upsert => self.collect_all(Expression::True).await?,
+        };
+        // 按父节点唯一时现有目录可能有同编码的存活记录，导出内容涉及这些编码时无法按编码匹配，写入前拒绝。
+        let mut shared_codes: HashMap<&str, Vec<BizMetadataId>> = HashMap::new();
+        for item in &existing {
+            shared_codes
+                .entry(item.code().as_str())
+                .or_default()
+                .push(item.id());
+        }
+        for code in dump
+            .entries
+            .iter()
+            .flat_map(|entry| std::iter::once(&entry.code).chain(entry.parent_code.as_ref()))
+        {
+            if let Some(ids) = shared_codes.get(code.as_str()).filter(|ids| ids.len() > 1) {
+                return Err(code_ambiguous(code, ids));
+            }
+        }
+        let mut ids_by_code: HashMap<String, BizMetadataId> = existing
+            .iter()
+            .map(|item| (item.code().as_str().to_string(), item.id()))
+            .collect();
+        let entries = order_parents_first(dump.entries, &ids_by_code)?;
+        for value_type in entries
+            .iter()
+            .filter_map(|entry| entry.value_type.as_deref())
+        {
+            self.validate_value_type(value_type)?;
+        }
+
+        // 先把全部条目转换为快照并逐条校验（含别名），任一条目非法时不做任何写入。
+        let now = self.now();
+        let tenant_id = TenantId::new(DEFAULT_TENANT_ID)?;
+        let mut prepared = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let snapshot = MetadataSnapshot {
+                tenant_id: tenant_id.clone(),
+                version: Version::new(1)?,
+                id: BizMetadataId::new(0),
+                code: entry.code,
+                name: entry.name,
+                description: entry.description,
+                object_type: ObjectType::try_from(entry.object_type.as_str())?,
+                parent_id: None,
+                data_class: entry
+                    .data_class
+                    .as_deref()
+                    .map(DataClass::try_from)
+                    .transpose()?,
+                value_type: entry.value_type,
+                unit: entry.unit.map(Unit::new).transpose()?,
+                status: BizMetadataStatus::try_from(entry.status.as_str())?,
+                source: Source::try_from(entry.source.as_str())?,
+                audit: Audit::new(now),
+            };
+            let checked = BizMetadata::from_snapshot(snapshot.clone())?;
+            self.code_policy.validate(checked.code())?;
+
+            let mut alias_keys = HashSet::new();
+            let mut alias_snapshots = Vec::with_capacity(entry.aliases.len());
+            for alias in entry.aliases {
+                let alias_snapshot = BizMetadataAliasSnapshot {
+                    id: BizMetadataAliasId::new(0),
+                    metadata_id: BizMetadataId::new(0),
+                    alias: AliasText::new(alias.alias)?.into_inner(),
+                    source: AliasSource::new(&alias.source)?,
+                    weight: alias.weight,
+                    is_primary: alias.is_primary,
+                    language: alias.language,
+                    audit: Audit::new(now),
+                };
+                BizMetadataAlias::from_snapshot(alias_snapshot.clone())?;
+                alias_keys.insert((
+                    alias_snapshot.alias.clone(),
+                    alias_snapshot.language.clone(),
+                ));
+                alias_snapshots.push(alias_snapshot);
+            }
+            if alias_keys.len() > self.max_aliases_per_metadata {
+                return Err(DomainError::Validation {
+                    code: error_code::ALIAS_LIMIT_EXCEEDED,
+                    message: format!(
+                        "catalog entry {} has {} distinct aliases, exceeding the limit of {}",
+                        snapshot.code,
+                        alias_keys.len(),
+                        self.max_aliases_per_metadata
+                    ),
+                });
+            }
+            prepared.push((entry.parent_code, snapshot, alias_snapshots));
+        }
+
+        let mut summary = CatalogImportSummary::default();
+        if mode == ImportMode::Replace {
+            let mut replacements = Vec::with_capacity(prepared.len());
+            for (parent_code, snapshot, alias_snapshots) in prepared {
+                let mut metadata = BizMetadata::from_snapshot(snapshot)?;
+                if let Some(id) = self.id_generator.next_id() {
+                    metadata.assign_id(BizMetadataId::new(id))?;
+                }
+                summary.inserted += 1;
+                summary.aliases += alias_snapshots.len() as u64;
+                // 同一条目内重复的（文本、语言）以最后一次出现为准，与 upsert 逐条覆盖的结果一致。
+                let mut entry_aliases: Vec<BizMetadataAlias> =
+                    Vec::with_capacity(alias_snapshots.len());
+                for alias_snapshot in alias_snapshots {
+                    let alias = BizMetadataAlias::from_snapshot(alias_snapshot)?;
+                    entry_aliases.retain(|kept| {
+                        kept.alias().as_str() != alias.alias().as_str()
+                            || kept.language().as_str() != alias.language().as_str()
+                    });
+                    entry_aliases.push(alias);
+                }
+                replacements.push(CatalogReplacement {
+                    parent_code,
+                    metadata,
+                    aliases: entry_aliases,
+                });
+            }
+            self.repository.replace_catalog(replacements, now).await?;
+            return Ok(summary);
+        }
+
+        let mut current_by_code: HashMap<String, BizMetadata> = existing
+            .into_iter()
+            .map(|item| (item.code().as_str().to_string(), item))
+            .collect();
+        for (parent_code, mut snapshot, alias_snapshots) in prepared {
+            snapshot.parent_id =
+                match &parent_code {
+                    Some(code) => Some(ids_by_code.get(code).copied().ok_or_else(|| {
+                        DomainError::Validation {
+                            code: error_code::CATALOG_PARENT_UNRESOLVED,
+                            message: format!("parent {code} of {} is not imported", snapshot.code),
+                        }
+                    })?),
+                    None => None,
+                };
+            let current = current_by_code.remove(&snapshot.code);
+            if let Some(current) = &current {
+                let current = current.to_snapshot();
+                snapshot.id = current.id;
+                snapshot.version = current.version;
+                snapshot.audit = current.audit;
+            }
+            let mut desired = BizMetadata::from_snapshot(snapshot)?;
+
+            let stored = match current {
+                Some(current) if desired.content_eq(&current) => {
+                    summary.unchanged += 1;
+                    current
+                }
+                Some(_) => {
+                    desired.touch(now)?;
+                    summary.updated += 1;
+                    self.repository.update_biz_metadata(desired).await?
+                }
+                None => {
+                    if let Some(id) = self.id_generator.next_id() {
+                        desired.assign_id(BizMetadataId::new(id))?;
+                    }
+                    summary.inserted += 1;
+                    self.repository.insert_biz_metadata(desired).await?
+                }
+            };
+            ids_by_code.insert(stored.code().as_str().to_string(), stored.id());
+
+            for mut alias_snapshot in alias_snapshots {
+                alias_snapshot.metadata_id = stored.id();
+                let (source, weight, is_primary) = (
+                    alias_snapshot.source,
+                    alias_snapshot.weight,
+                    alias_snapshot.is_primary,
+                );
+                let candidate = BizMetadataAlias::from_snapshot(alias_snapshot)?;
+                let mut found = aliases
+                    .find_or_insert_alias(candidate, self.max_aliases_per_metadata)
+                    .await?;
+                if found.source() != source
+                    || found.weight().value() != weight
+                    || found.is_primary() != is_primary
+                {
+                    found.change_source(source, now)?;
+                    found.change_weight(weight, now)?;
+                    found.set_primary(is_primary, now)?;
+                    aliases.update_alias(found).await?;
+                }
+                summary.aliases += 1;
+            }
+        }
+        Ok(summary)
+    }
+
+    /// 计算节点的生效状态：任一祖先为 `deprecated` 时视为 `deprecated`，否则取节点自身状态。
+    ///
+    /// 回溯至多 [`MAX_ANCESTOR_DEPTH`] 层，超限或检测到环时返回 `InvariantViolation`；
+    /// 已删除或不存在的父节点视为链路终点。
+    pub async fn effective_status(
+        &self,
+        id: BizMetadataId,
+    ) -> Result<BizMetadataStatus, DomainError> {
+        let biz_metadata = self
+            .repository
+            .find_biz_metadata_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", id.value()),
+            })?;
+
+        if biz_metadata.status() == BizMetadataStatus::Deprecated {
+            return Ok(BizMetadataStatus::Deprecated);
+        }
+
+        let mut visited = vec![biz_metadata.id()];
+        let mut next = biz_metadata.parent_id();
+        while let Some(parent_id) = next {
+            if visited.contains(&parent_id) || visited.len() > MAX_ANCESTOR_DEPTH {
+                return Err(DomainError::InvariantViolation {
+                    code: error_code::BIZ_METADATA_PARENT_CHAIN_INVALID,
+                    message: format!(
+                        "biz_metadata {} parent chain is cyclic or too deep",
+                        id.value()
+                    ),
+                });
+            }
+            visited.push(parent_id);
+
+            let Some(parent) = self.repository.find_biz_metadata_by_id(parent_id).await? else {
+                break;
+            };
+            if parent.status() == BizMetadataStatus::Deprecated {
+                return Ok(BizMetadataStatus::Deprecated);
+            }
+            next = parent.parent_id();
+        }
+
+        Ok(biz_metadata.status())
+    }
+
+    /// 返回从根节点到 `id` 的祖先路径（根在前、节点自身在末尾），供面包屑展示。
+    ///
+    /// 沿 `parent_id` 回溯至多 [`MAX_ANCESTOR_DEPTH`] 层，超限或检测到环时返回 `InvariantViolation`；
+    /// 父节点缺失（已删除）时路径从最近的存活祖先开始。
+    pub async fn ancestor_path(&self, id: BizMetadataId) -> Result<Vec<BizMetadata>, DomainError> {
+        let biz_metadata = self
+            .repository
+            .find_biz_metadata_by_id(id)
+            .await?
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", id.value()),
+            })?;
+
+        let mut next = biz_metadata.parent_id();
+        let mut path = vec![biz_metadata];
+        while let Some(parent_id) = next {
+            if path.iter().any(|node| node.id() == parent_id) || path.len() > MAX_ANCESTOR_DEPTH {
+                return Err(DomainError::InvariantViolation {
+                    code: error_code::BIZ_METADATA_PARENT_CHAIN_INVALID,
+                    message: format!(
+                        "biz_metadata {} parent chain is cyclic or too deep",
+                        id.value()
+                    ),
+                });
+            }
+            let Some(parent) = self.repository.find_biz_metadata_by_id(parent_id).await? else {
+                break;
+            };
+            next = parent.parent_id();
+            path.push(parent);
+        }
+        path.reverse();
+        Ok(path)
+    }
+
+    /// 返回 `root` 下各层存活后代中的 feature（中间的非 feature 节点照常下探但不返回），按 ID 升序，供构建实体的 schema 视图。
+    ///
+    /// 通过 [`BizMetadataRepository::find_subtree_within_depth`] 单次加载 [`Self::with_max_depth`] 配置层数以内的子树；
+    /// `root` 不存在时返回 `biz_metadata.not_found`。
+    pub async fn descendant_features(
+        &self,
+        root: BizMetadataId,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let subtree = self
+            .repository
+            .find_subtree_within_depth(root, self.max_depth)
+            .await?;
+        if subtree.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", root.value()),
+            });
+        }
+        Ok(subtree
+            .into_iter()
+            .filter(|node| node.id() != root && node.object_type() == ObjectType::Feature)
+            .collect())
+    }
+
+    /// 拉取 `since`（含）之后变更的全部记录，按 `updated_at`、`id` 升序返回。
+    ///
+    /// `include_deleted=true` 时同时返回在此之后被软删除的记录（`is_deleted()` 为真），供下游剔除缓存。
+    pub async fn changed_since(
+        &self,
+        since: DateTime<Utc>,
+        include_deleted: bool,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut changed = Vec::new();
+        let mut offset = 0;
+        loop {
+            let options = QueryOptions::new(Some(CHANGED_SINCE_BATCH_SIZE), Some(offset))
+                .with_order_by(OrderBy::asc("updated_at"))
+                .with_order_by(OrderBy::asc("id"));
+            let page = self
+                .repository
+                .query_biz_metadata_changed_since(since, include_deleted, options)
+                .await?;
+            let has_next = page.has_next_page();
+            changed.extend(page.into_items());
+            if !has_next {
+                return Ok(changed);
+            }
+            offset += CHANGED_SINCE_BATCH_SIZE;
+        }
+    }
+
+    /// 遍历全部存活记录检查聚合不变式，按 ID 升序返回违反规则的记录及其全部违反项，供修复工具使用。
+    pub async fn audit_invariants(
+        &self,
+    ) -> Result<Vec<(BizMetadataId, Vec<DomainError>)>, DomainError> {
+        let mut offending = Vec::new();
+        let mut offset = 0;
+        loop {
+            let options = QueryOptions::new(Some(AUDIT_BATCH_SIZE), Some(offset))
+                .with_order_by(OrderBy::asc("id"));
+            let page = self
+                .repository
+                .query_biz_metadata(Expression::True, options)
+                .await?;
+            let has_next = page.has_next_page();
+            for item in page.into_items() {
+                if let Err(errors) = item.check_invariants() {
+                    offending.push((item.id(), errors));
+                }
+            }
+            if !has_next {
+                return Ok(offending);
+            }
+            offset += AUDIT_BATCH_SIZE;
+        }
+    }
+
+    pub fn repository(&self) -> &R {
+        &self.repository
+    }
+
+    /// 便捷查询：按 ID 查找。
+    pub async fn find_biz_metadata_by_id(
+        &self,
+        id: BizMetadataId,
     ) -> Result<Option<BizMetadata>, DomainError> {
         self.repository.find_biz_metadata_by_id(id).await
     }
+
+    /// 按 ID 查找，软删除的记录同样返回（可通过 [`BizMetadata::is_deleted`] 区分），从未存在时返回 `None`。
+    pub async fn find_by_id_including_deleted(
+        &self,
+        id: BizMetadataId,
+    ) -> Result<Option<BizMetadata>, DomainError> {
+        if let Some(found) = self.repository.find_biz_metadata_by_id(id).await? {
+            return Ok(Some(found));
+        }
+        self.repository.find_deleted_biz_metadata_by_id(id).await
+    }
+
+    /// 返回 `biz_metadata` 的存活父节点，根节点或父节点已软删除时返回 `None`，供详情展开父节点使用。
+    pub async fn find_parent(
+        &self,
+        biz_metadata: &BizMetadata,
+    ) -> Result<Option<BizMetadata>, DomainError> {
+        match biz_metadata.parent_id() {
+            Some(parent_id) => self.repository.find_biz_metadata_by_id(parent_id).await,
+            None => Ok(None),
+        }
+    }
+
+    /// 便捷查询：按编码查找，编码按小写规范化后匹配。
+    pub async fn find_biz_metadata_by_code(
+        &self,
+        code: &str,
+    ) -> Result<Option<BizMetadata>, DomainError> {
+        self.repository
+            .find_biz_metadata_by_code(&code.to_lowercase())
+            .await
+    }
+
+    /// 列出存活记录在分面字段上出现过的取值，按取值升序，供筛选下拉使用。
+    ///
+    /// 字段须为 `object_type`、`data_class`、`status` 或 `source`，否则返回 `query.field_not_facetable`。
+    pub async fn distinct_values(&self, field: &str) -> Result<Vec<String>, DomainError> {
+        Ok(self
+            .distinct_value_counts(field)
+            .await?
+            .into_iter()
+            .map(|(value, _)| value)
+            .collect())
+    }
+
+    /// 与 [`distinct_values`](Self::distinct_values) 相同，但附带每个取值的记录数。
+    pub async fn distinct_value_counts(
+        &self,
+        field: &str,
+    ) -> Result<Vec<(String, u64)>, DomainError> {
+        ensure_facetable(field)?;
+        self.repository.count_biz_metadata_values(field).await
+    }
+
+    /// 只读取存活记录的当前版本号，供客户端在读-改-写前获取乐观锁版本。
+    pub async fn find_version(&self, id: BizMetadataId) -> Result<Option<Version>, DomainError> {
+        self.repository.find_version(id).await
+    }
+
+    /// 判断编码能否用于在 `parent_id` 下新建：格式与 [`CodePolicy`] 校验同创建流程，
+    /// 占用判断只看同租户的存活记录，仅被已软删除记录使用的编码视为可用。
+    ///
+    /// 唯一性规则与创建相同：[`CodeUniqueness::PerParent`] 且给出 `parent_id` 时只与该父节点下存活子节点的末段比较，
+    /// 否则按完整编码全局判断。编码为空或不满足策略时返回校验错误，而非 `false`。
+    pub async fn code_available(
+        &self,
+        code: &str,
+        parent_id: Option<BizMetadataId>,
+    ) -> Result<bool, DomainError> {
+        let code = BizMetadataCode::new(code)?;
+        self.code_policy.validate(&code)?;
+        match self.ensure_code_unique(&code, parent_id, None).await {
+            Ok(()) => Ok(true),
+            Err(err) if err.code() == error_code::BIZ_METADATA_DUPLICATE_CODE => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// 计算别名命中的相关度：首选别名 > 完全匹配 > 子串匹配，再叠加别名权重。
+fn alias_score(alias: &BizMetadataAlias, text: &str) -> u32 {
+    let base = if alias.is_primary() {
+        SCORE_PRIMARY_ALIAS
+    } else if alias.alias().as_str().eq_ignore_ascii_case(text) {
+        SCORE_EXACT
+    } else {
+        SCORE_SUBSTRING
+    };
+    base + u32::try_from(alias.weight().value()).unwrap_or(0)
+}
+
+/// 判断表达式是否恒真（不限制任何记录）。
+fn is_unrestricted(expr: &Expression) -> bool {
+    match expr {
+        Expression::True => true,
+        Expression::And(list) => list.iter().all(is_unrestricted),
+        Expression::Or(list) => list.iter().any(is_unrestricted),
+        Expression::Not(inner) => matches!(**inner, Expression::False),
+        Expression::Comparison(_) | Expression::False => false,
+    }
+}
+
+/// 分页加载全部存活别名。
+async fn collect_live_aliases<A>(aliases: &A) -> Result<Vec<BizMetadataAlias>, DomainError>
+where
+    A: BizMetadataAliasRepository,
+{
+    let mut items = Vec::new();
+    let mut offset = 0;
+    loop {
+        let options = QueryOptions::new(Some(CATALOG_BATCH_SIZE), Some(offset))
+            .with_order_by(OrderBy::asc("id"));
+        let page = aliases.query_alias(Expression::True, options).await?;
+        let has_next = page.has_next_page();
+        items.extend(
+            page.into_items()
+                .into_iter()
+                .filter(|alias| alias.delete_at().is_none()),
+        );
+        if !has_next {
+            return Ok(items);
+        }
+        offset += CATALOG_BATCH_SIZE;
+    }
+}
+
+/// 按父节点在前的顺序排列导入条目，同层保持导出中的相对顺序。
+///
+/// 父编码须出现在导出中，或（仅 upsert 时）已存在于目标环境的 `known` 中；
+/// 编码重复、父编码无法解析或父子关系成环时返回错误。
+fn order_parents_first(
+    entries: Vec<CatalogEntry>,
+    known: &HashMap<String, BizMetadataId>,
+) -> Result<Vec<CatalogEntry>, DomainError> {
+    let mut in_dump = HashSet::new();
+    for entry in &entries {
+        if !in_dump.insert(entry.code.clone()) {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_DUPLICATE_CODE,
+                message: format!("code {} appears more than once in catalog", entry.code),
+            });
+        }
+    }
+
+    let mut placed: HashSet<String> = HashSet::new();
+    let mut ordered = Vec::with_capacity(entries.len());
+    let mut pending = entries;
+    while !pending.is_empty() {
+        let (ready, blocked): (Vec<_>, Vec<_>) =
+            pending
+                .into_iter()
+                .partition(|entry| match &entry.parent_code {
+                    None => true,
+                    Some(parent) if in_dump.contains(parent) => placed.contains(parent),
+                    Some(parent) => known.contains_key(parent),
+                });
+        if ready.is_empty() {
+            let codes: Vec<&str> = blocked.iter().map(|entry| entry.code.as_str()).collect();
+            return Err(DomainError::Validation {
+                code: error_code::CATALOG_PARENT_UNRESOLVED,
+                message: format!(
+                    "parents of {} are missing or form a cycle",
+                    codes.join(", ")
+                ),
+            });
+        }
+        placed.extend(ready.iter().map(|entry| entry.code.clone()));
+        ordered.extend(ready);
+        pending = blocked;
+    }
+    Ok(ordered)
+}
+
+/// 按 `parent_id` 计算子树内各节点相对 `root` 的深度（`root` 为 0），`skip` 中的节点（`root` 除外）及其下方不计入。
+fn subtree_depths(
+    root: BizMetadataId,
+    subtree: &[BizMetadata],
+    skip: &HashSet<BizMetadataId>,
+) -> HashMap<BizMetadataId, usize> {
+    let mut children: HashMap<BizMetadataId, Vec<BizMetadataId>> = HashMap::new();
+    for node in subtree {
+        if let Some(parent_id) = node.parent_id() {
+            children.entry(parent_id).or_default().push(node.id());
+        }
+    }
+    let mut depths = HashMap::from([(root, 0)]);
+    let mut frontier = vec![root];
+    let mut depth = 0;
+    while !frontier.is_empty() {
+        depth += 1;
+        let mut next = Vec::new();
+        for id in frontier {
+            for child in children.get(&id).into_iter().flatten() {
+                if !skip.contains(child) && !depths.contains_key(child) {
+                    depths.insert(*child, depth);
+                    next.push(*child);
+                }
+            }
+        }
+        frontier = next;
+    }
+    depths
+}
+
+/// 将 `value_type` 中恰为 `ref:<from>` 的 term 改写为 `ref:<to>`，没有命中时返回 `None`。
+fn rewrite_type_ref(value_type: &str, from: &str, to: &str) -> Option<String> {
+    let mut hit = false;
+    let terms: Vec<String> = value_type
+        .split('|')
+        .map(|term| match term.trim().strip_prefix("ref:") {
+            Some(target) if target == from => {
+                hit = true;
+                format!("ref:{to}")
+            }
+            _ => term.trim().to_string(),
+        })
+        .collect();
+    hit.then(|| terms.join(" | "))
+}
+
+/// `value_type` 是否含恰为 `ref:<code>` 的 term。
+fn has_type_ref(value_type: &str, code: &str) -> bool {
+    value_type
+        .split('|')
+        .filter_map(type_ref_target)
+        .any(|target| target == code)
+}
+
+/// 去掉 `value_type` 中恰为 `ref:<code>` 的 term，没有剩余 term 时返回 `None`。
+fn remove_type_ref(value_type: &str, code: &str) -> Option<String> {
+    let terms: Vec<&str> = value_type
+        .split('|')
+        .map(str::trim)
+        .filter(|term| type_ref_target(term) != Some(code))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" | "))
+}
+
+/// 取编码最后一段，如 `company.finance.revenue` 的 `revenue`。
+fn leaf_segment(code: &str) -> &str {
+    code.rsplit('.').next().unwrap_or(code)
+}
+
+/// 转义 DOT 双引号字符串中的特殊字符。
+fn dot_escape(raw: &str) -> String {
+    raw.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::repository::is_version_conflict;
+    use crate::domain::biz_metadata::value_object::{BizMetadataStatus, DataClass};
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_alias_repository::InMemoryBizMetadataAliasRepository;
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository;
+    use domain_core::audit::Audit;
+    use domain_core::clock::FixedClock;
+    use domain_core::id_generator::SnowflakeIdGenerator;
+
+    use chrono::{Duration, TimeZone};
+    use domain_core::expression::Expression;
+    use domain_core::repository::Repository;
+    use std::future::Ready;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// 在前 `conflicts` 次更新前模拟并发写入，使提交的版本过期。
+    struct ConflictingRepository {
+        inner: InMemoryBizMetadataRepository,
+        conflicts: AtomicU32,
+    }
+
+    impl Repository<BizMetadata> for ConflictingRepository {
+        type InsertFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+        type UpdateFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+        type DeleteFuture<'a> = Ready<Result<(), DomainError>>;
+        type FindByIdFuture<'a> = Ready<Result<Option<BizMetadata>, DomainError>>;
+        type QueryFuture<'a> = Ready<Result<PageResult<BizMetadata>, DomainError>>;
+
+        fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
+            self.inner.insert(aggregate)
+        }
+
+        fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+            let pending = self.conflicts.load(Ordering::SeqCst);
+            if pending > 0 {
+                self.conflicts.store(pending - 1, Ordering::SeqCst);
+                let current = self.inner.find_by_id(aggregate.id()).into_inner();
+                if let Ok(Some(current)) = current {
+                    let _ = self.inner.update(current).into_inner();
+                }
+            }
+            self.inner.update(aggregate)
+        }
+
+        fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+            self.inner.delete(id)
+        }
+
+        fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+            self.inner.find_by_id(id)
+        }
+
+        fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+            self.inner.query(expr, options)
+        }
+    }
+
+    impl BizMetadataRepository for ConflictingRepository {}
+
+    /// 服务层预读返回 `stale` 中的旧快照，锁定更新仍读取真实记录，模拟预读与加锁之间的并发写入。
+    struct StaleReadRepository {
+        inner: InMemoryBizMetadataRepository,
+        stale: BizMetadata,
+    }
+
+    impl Repository<BizMetadata> for StaleReadRepository {
+        type InsertFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+        type UpdateFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+        type DeleteFuture<'a> = Ready<Result<(), DomainError>>;
+        type FindByIdFuture<'a> = Ready<Result<Option<BizMetadata>, DomainError>>;
+        type QueryFuture<'a> = Ready<Result<PageResult<BizMetadata>, DomainError>>;
+
+        fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
+            self.inner.insert(aggregate)
+        }
+
+        fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+            self.inner.update(aggregate)
+        }
+
+        fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+            self.inner.delete(id)
+        }
+
+        fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+            self.inner.find_by_id(id)
+        }
+
+        fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+            self.inner.query(expr, options)
+        }
+    }
+
+    impl BizMetadataRepository for StaleReadRepository {
+        fn find_biz_metadata_by_id(&self, _id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+            std::future::ready(Ok(Some(self.stale.clone())))
+        }
+    }
+
+    async fn conflicting_service(
+        conflicts: u32,
+    ) -> (BizMetadataService<ConflictingRepository>, BizMetadataId) {
+        let service = BizMetadataService::new(ConflictingRepository {
+            inner: InMemoryBizMetadataRepository::new(),
+            conflicts: AtomicU32::new(0),
+        });
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        service
+            .repository()
+            .conflicts
+            .store(conflicts, Ordering::SeqCst);
+        (service, id)
+    }
+
+    async fn add_node<R: BizMetadataRepository>(
+        service: &BizMetadataService<R>,
+        code: &str,
+        parent_id: Option<BizMetadataId>,
+        status: BizMetadataStatus,
+    ) -> BizMetadataId {
+        service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: code.into(),
+                name: code.into(),
+                description: None,
+                object_type: ObjectType::Entity,
+                parent_id,
+                data_class: None,
+                value_type: None,
+                unit: None,
+                status: Some(status),
+                source: None,
+            })
+            .await
+            .unwrap()
+            .id()
+    }
+
+    #[tokio::test]
+    async fn pre_assigned_ids_are_preserved_and_default_auto_increments() {
+        let auto = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let first = add_node(&auto, "company", None, BizMetadataStatus::Active).await;
+        let second = add_node(&auto, "product", None, BizMetadataStatus::Active).await;
+        assert_eq!((first.value(), second.value()), (1, 2));
+
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_id_generator(SnowflakeIdGenerator::new(3).unwrap());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        assert_eq!(SnowflakeIdGenerator::worker_of(id.value()), 3);
+        let stored = service
+            .repository()
+            .find_biz_metadata_by_id(id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(stored.id(), id);
+        let next = add_node(&service, "product", None, BizMetadataStatus::Active).await;
+        assert!(next.value() > id.value());
+
+        let mut duplicate = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            "order",
+            "order",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        duplicate.assign_id(id).unwrap();
+        let err = service
+            .repository()
+            .insert_biz_metadata(duplicate)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            DomainError::Validation { code, .. } if code == error_code::BIZ_METADATA_ID_CONFLICT
+        ));
+    }
+
+    #[tokio::test]
+    async fn effective_status_inherits_deprecated_ancestor() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Deprecated).await;
+        let mid = add_node(
+            &service,
+            "company.base",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let leaf = add_node(
+            &service,
+            "company.base.name",
+            Some(mid),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let status = service.effective_status(leaf).await.unwrap();
+        assert_eq!(status, BizMetadataStatus::Deprecated);
+    }
+
+    #[tokio::test]
+    async fn ancestor_path_lists_root_first_and_ends_with_node() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let finance = add_node(
+            &service,
+            "company_finance",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let revenue = add_node(
+            &service,
+            "company_finance_revenue",
+            Some(finance),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let path = service.ancestor_path(revenue).await.unwrap();
+        let ids: Vec<_> = path.iter().map(|node| node.id()).collect();
+        assert_eq!(ids, [root, finance, revenue]);
+
+        let path = service.ancestor_path(root).await.unwrap();
+        assert_eq!(path.len(), 1);
+        assert_eq!(path[0].id(), root);
+
+        let err = service
+            .ancestor_path(BizMetadataId::new(999))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_NOT_FOUND);
+    }
+
+    async fn add_feature(
+        service: &BizMetadataService<InMemoryBizMetadataRepository>,
+        code: &str,
+        parent_id: BizMetadataId,
+    ) -> BizMetadataId {
+        service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: code.into(),
+                name: code.into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: Some(parent_id),
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal".into()),
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap()
+            .id()
+    }
+
+    #[tokio::test]
+    async fn descendant_features_walk_through_intermediate_nodes() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let finance = add_node(
+            &service,
+            "company_finance",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let headcount = add_feature(&service, "company_headcount", company).await;
+        let revenue = add_feature(&service, "company_finance_revenue", finance).await;
+        let other = add_node(&service, "other", None, BizMetadataStatus::Active).await;
+        add_feature(&service, "other_size", other).await;
+
+        let features = service.descendant_features(company).await.unwrap();
+        let ids: Vec<_> = features.iter().map(|node| node.id()).collect();
+        assert_eq!(ids, [headcount, revenue]);
+        assert!(
+            service
+                .descendant_features(revenue)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let err = service
+            .descendant_features(BizMetadataId::new(999))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_NOT_FOUND);
+
+        // 只保留配置层数以内的节点。
+        let service = service.with_max_depth(1);
+        let ids: Vec<_> = service
+            .descendant_features(company)
+            .await
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+        assert_eq!(ids, [headcount]);
+    }
+
+    #[tokio::test]
+    async fn merge_moves_children_aliases_and_type_refs_then_deletes_loser() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        let revenue = add_feature(&service, "corp.revenue", corp).await;
+        let total = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "company.total".into(),
+                name: "total".into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: Some(company),
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal | ref:corp".into()),
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap()
+            .id();
+        let now = Utc::now();
+        let mut alias = BizMetadataAlias::new(corp, "公司", now).unwrap();
+        alias.set_primary(true, now).unwrap();
+        let alias = aliases.insert_alias(alias).await.unwrap();
+
+        let merged = service.merge(corp, company).await.unwrap();
+        assert_eq!(merged.id(), company);
+
+        assert!(
+            service
+                .find_biz_metadata_by_id(corp)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let revenue = service
+            .find_biz_metadata_by_id(revenue)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(revenue.parent_id(), Some(company));
+        let total = service
+            .find_biz_metadata_by_id(total)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            total.value_type().map(ValueType::as_str),
+            Some("decimal | ref:company")
+        );
+        let moved = aliases.find_alias_by_id(alias.id()).await.unwrap().unwrap();
+        assert_eq!(moved.metadata_id(), company);
+        assert!(moved.is_primary());
+    }
+
+    #[tokio::test]
+    async fn failed_alias_reassignment_rolls_back_the_merge() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        let revenue = add_feature(&service, "corp.revenue", corp).await;
+        let now = Utc::now();
+        let moving = aliases
+            .insert_alias(BizMetadataAlias::new(corp, "集团", now).unwrap())
+            .await
+            .unwrap();
+        // 审计时间晚于合并时间，改挂第二个别名时失败。
+        aliases
+            .insert_alias(BizMetadataAlias::new(corp, "总公司", now + Duration::hours(1)).unwrap())
+            .await
+            .unwrap();
+
+        let err = service.merge(corp, company).await.unwrap_err();
+        assert_eq!(err.code(), domain_core::error_code::AUDIT_TIMELINE_INVALID);
+
+        assert!(
+            service
+                .find_biz_metadata_by_id(corp)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let revenue = service
+            .find_biz_metadata_by_id(revenue)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(revenue.parent_id(), Some(corp));
+        let unchanged = aliases
+            .find_alias_by_id(moving.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unchanged.metadata_id(), corp);
+    }
+
+    #[tokio::test]
+    async fn merge_rejects_alias_overflow_without_writing() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        )
+        .with_max_aliases_per_metadata(1);
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        let now = Utc::now();
+        aliases
+            .insert_alias(BizMetadataAlias::new(company, "公司", now).unwrap())
+            .await
+            .unwrap();
+        let moving = aliases
+            .insert_alias(BizMetadataAlias::new(corp, "集团", now).unwrap())
+            .await
+            .unwrap();
+
+        let err = service.merge(corp, company).await.unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        assert!(
+            service
+                .find_biz_metadata_by_id(corp)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let unchanged = aliases
+            .find_alias_by_id(moving.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unchanged.metadata_id(), corp);
+    }
+
+    #[tokio::test]
+    async fn merge_rejects_self_type_mismatch_and_leaf_conflicts_without_writing() {
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent),
+        )
+        .with_code_uniqueness(CodeUniqueness::PerParent);
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        let kept = add_feature(&service, "company.revenue", company).await;
+        let clash = add_feature(&service, "corp.revenue", corp).await;
+
+        let err = service.merge(company, company).await.unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_MERGE_INVALID);
+        let err = service.merge(kept, corp).await.unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH);
+        let err = service.merge(corp, company).await.unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_DUPLICATE_CODE);
+
+        assert!(
+            service
+                .find_biz_metadata_by_id(corp)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        let clash = service
+            .find_biz_metadata_by_id(clash)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(clash.parent_id(), Some(corp));
+    }
+
+    #[tokio::test]
+    async fn strict_query_lists_unknown_fields_while_lenient_ignores_them() {
+        let lenient = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        add_node(&lenient, "company", None, BizMetadataStatus::Active).await;
+        let request = || {
+            BizMetadataQueryRequest::new(
+                Expression::and(vec![
+                    Expression::cmp(eq("code", "company")),
+                    Expression::cmp(eq("owner", "ops")),
+                ]),
+                QueryOptions::default().with_order_by(OrderBy::asc("popularity")),
+            )
+        };
+        let ordered_by_unknown = BizMetadataQueryRequest::new(
+            Expression::cmp(eq("code", "company")),
+            QueryOptions::default().with_order_by(OrderBy::asc("popularity")),
+        );
+        let page = lenient
+            .query_biz_metadata(ordered_by_unknown)
+            .await
+            .unwrap();
+        assert_eq!(page.items().len(), 1);
+        assert!(lenient.query_biz_metadata(request()).await.is_ok());
+
+        let strict = lenient.with_strict_query(true);
+        let Err(err) = strict.query_biz_metadata(request()).await else {
+            panic!("strict query must reject unknown fields");
+        };
+        assert_eq!(err.code(), error_code::QUERY_FIELD_UNKNOWN);
+        assert!(err.message().contains("owner, popularity"));
+        let known = BizMetadataQueryRequest::new(
+            Expression::cmp(eq("code", "company")),
+            QueryOptions::default().with_order_by(OrderBy::asc("name")),
+        );
+        assert!(strict.query_biz_metadata(known).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn count_matches_query_total_and_skips_deleted() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        add_node(&service, "product", None, BizMetadataStatus::Active).await;
+        add_node(&service, "legacy", None, BizMetadataStatus::Deprecated).await;
+        let gone = add_node(&service, "gone", None, BizMetadataStatus::Active).await;
+        add_feature(&service, "company.revenue", company).await;
+        service
+            .delete_biz_metadata(gone, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+
+        let filter = || {
+            Expression::and(vec![
+                Expression::cmp(eq("object_type", "entity")),
+                Expression::cmp(eq("status", "active")),
+            ])
+        };
+        let page = service
+            .query_biz_metadata(BizMetadataQueryRequest::new(
+                filter(),
+                QueryOptions::default(),
+            ))
+            .await
+            .unwrap();
+        let count = service.count_biz_metadata(filter()).await.unwrap();
+        assert_eq!(count, page.total_count());
+        assert_eq!(count, 2);
+        assert_eq!(
+            service.count_biz_metadata(Expression::True).await.unwrap(),
+            4
+        );
+    }
+
+    #[test]
+    fn rewrite_type_ref_only_replaces_exact_targets() {
+        assert_eq!(
+            rewrite_type_ref("string|ref:corp", "corp", "company").as_deref(),
+            Some("string | ref:company")
+        );
+        assert_eq!(
+            rewrite_type_ref("ref:corp.revenue", "corp", "company"),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn find_subtree_returns_root_and_all_levels() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let finance = add_node(
+            &service,
+            "company_finance",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let revenue = add_feature(&service, "company_finance_revenue", finance).await;
+        add_node(&service, "other", None, BizMetadataStatus::Active).await;
+
+        let subtree = service.repository().find_subtree(company).await.unwrap();
+        let ids: Vec<_> = subtree.iter().map(|node| node.id()).collect();
+        assert_eq!(ids, [company, finance, revenue]);
+        let leaf = service.repository().find_subtree(revenue).await.unwrap();
+        assert_eq!(leaf.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn deprecate_subtree_deprecates_root_and_descendants() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let finance = add_node(
+            &service,
+            "company_finance",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let revenue = add_feature(&service, "company_finance_revenue", finance).await;
+        let other = add_node(&service, "other", None, BizMetadataStatus::Active).await;
+
+        let report = service.deprecate_subtree(company).await.unwrap();
+        let ids: Vec<_> = report.deprecated.iter().map(|node| node.id()).collect();
+        assert_eq!(ids, [company, finance, revenue]);
+        assert!(report.skipped.is_empty() && report.failed.is_empty());
+        for id in [company, finance, revenue] {
+            let node = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+            assert_eq!(node.status(), BizMetadataStatus::Deprecated);
+        }
+        let untouched = service
+            .find_biz_metadata_by_id(other)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(untouched.status(), BizMetadataStatus::Active);
+
+        let again = service.deprecate_subtree(company).await.unwrap();
+        assert!(again.deprecated.is_empty());
+        assert_eq!(again.skipped, [company, finance, revenue]);
+        let err = service
+            .deprecate_subtree(BizMetadataId::new(999))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn effective_status_keeps_own_status_under_active_chain() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let leaf = add_node(
+            &service,
+            "company.base",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let status = service.effective_status(leaf).await.unwrap();
+        assert_eq!(status, BizMetadataStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn effective_status_rejects_cyclic_chain() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let first = add_node(&service, "a", None, BizMetadataStatus::Active).await;
+        let second = add_node(&service, "b", Some(first), BizMetadataStatus::Active).await;
+        let mut looped = service
+            .find_biz_metadata_by_id(first)
+            .await
+            .unwrap()
+            .unwrap();
+        looped.set_parent_id(Some(second), Utc::now()).unwrap();
+        service
+            .repository()
+            .update_biz_metadata(looped)
+            .await
+            .unwrap();
+
+        let err = service.effective_status(first).await.unwrap_err();
+        assert!(matches!(err, DomainError::InvariantViolation { .. }));
+    }
+
+    #[tokio::test]
+    async fn reparent_many_moves_batch_under_new_parent() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let old_parent = add_node(&service, "old", None, BizMetadataStatus::Active).await;
+        let new_parent = add_node(&service, "new", None, BizMetadataStatus::Active).await;
+        let first = add_node(
+            &service,
+            "old_a",
+            Some(old_parent),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let second = add_node(
+            &service,
+            "old_b",
+            Some(old_parent),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let moved = service
+            .reparent_many(vec![first, second, first], Some(new_parent))
+            .await
+            .unwrap();
+        assert_eq!(moved.len(), 2);
+        assert!(
+            moved
+                .iter()
+                .all(|item| item.parent_id() == Some(new_parent))
+        );
+        assert!(moved.iter().all(|item| i32::from(item.version()) == 2));
+
+        let roots = service.reparent_many(vec![first], None).await.unwrap();
+        assert_eq!(roots[0].parent_id(), None);
+    }
+
+    #[tokio::test]
+    async fn max_depth_counts_the_moved_subtree() {
+        let service =
+            BizMetadataService::new(InMemoryBizMetadataRepository::new()).with_max_depth(2);
+        let root = add_node(&service, "root", None, BizMetadataStatus::Active).await;
+        let mid = add_node(&service, "mid", Some(root), BizMetadataStatus::Active).await;
+        let leaf = add_node(&service, "leaf", Some(mid), BizMetadataStatus::Active).await;
+        let err = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "too_deep".into(),
+                name: "too_deep".into(),
+                description: None,
+                object_type: ObjectType::Entity,
+                parent_id: Some(leaf),
+                data_class: None,
+                value_type: None,
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_TREE_TOO_DEEP);
+
+        // 两层子树挂到根下：深度 1 与 2，未超限。
+        let branch = add_node(&service, "branch", None, BizMetadataStatus::Active).await;
+        let twig = add_node(&service, "twig", Some(branch), BizMetadataStatus::Active).await;
+        let moved = service
+            .reparent_many(vec![branch], Some(root))
+            .await
+            .unwrap();
+        assert_eq!(moved[0].parent_id(), Some(root));
+
+        // 再挂到 mid 下时 twig 深度为 3，整批拒绝。
+        let err = service
+            .reparent_many(vec![branch], Some(mid))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_TREE_TOO_DEEP);
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: branch,
+                version: Version::new(2).unwrap(),
+                parent_id: FieldUpdate::Set(mid),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_TREE_TOO_DEEP);
+
+        // 同批移动的子节点不计入其他节点的子树：twig 与 branch 都挂到根下。
+        let moved = service
+            .reparent_many(vec![twig, branch], Some(root))
+            .await
+            .unwrap();
+        assert_eq!(moved.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn reparent_many_rejects_cycle_atomically() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "root", None, BizMetadataStatus::Active).await;
+        let child = add_node(&service, "child", Some(root), BizMetadataStatus::Active).await;
+        let grandchild = add_node(
+            &service,
+            "grandchild",
+            Some(child),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let other = add_node(&service, "other", None, BizMetadataStatus::Active).await;
+
+        let err = service
+            .reparent_many(vec![other, root], Some(grandchild))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_PARENT_CHAIN_INVALID);
+
+        let err = service
+            .reparent_many(vec![child], Some(child))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_PARENT_CHAIN_INVALID);
+
+        for id in [root, other] {
+            let item = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+            assert_eq!(item.parent_id(), None);
+            assert_eq!(i32::from(item.version()), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn changed_since_returns_rows_after_watermark() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let before = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let after = add_node(&service, "person", None, BizMetadataStatus::Active).await;
+        let removed = add_node(&service, "event", None, BizMetadataStatus::Active).await;
+
+        let watermark = Utc::now() + Duration::seconds(60);
+        for id in [after, removed] {
+            let mut item = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+            let mut snapshot = item.to_snapshot();
+            snapshot.audit =
+                Audit::reconstruct(item.created_at(), watermark + Duration::seconds(1), None)
+                    .unwrap();
+            item = BizMetadata::from_snapshot(snapshot).unwrap();
+            if id == removed {
+                item.mark_deleted(watermark + Duration::seconds(2)).unwrap();
+            }
+            service
+                .repository()
+                .update_biz_metadata(item)
+                .await
+                .unwrap();
+        }
+
+        let changed = service.changed_since(watermark, true).await.unwrap();
+        let ids: Vec<_> = changed.iter().map(BizMetadata::id).collect();
+        assert_eq!(ids, vec![after, removed]);
+        assert!(!ids.contains(&before));
+        assert!(changed[1].is_deleted());
+
+        let alive_only = service.changed_since(watermark, false).await.unwrap();
+        assert_eq!(alive_only.len(), 1);
+        assert_eq!(alive_only[0].id(), after);
+    }
+
+    #[tokio::test]
+    async fn update_with_retry_recovers_from_conflict() {
+        let (service, id) = conflicting_service(1).await;
+        let rename = |item: &mut BizMetadata, now| {
+            item.rename(BizMetadataName::new("Company").unwrap(), now)
+                .unwrap()
+        };
+
+        let updated = service.update_with_retry(id, rename, 2).await.unwrap();
+        assert_eq!(updated.name().as_str(), "Company");
+        assert_eq!(updated.version(), Version::new(3).unwrap());
+    }
+
+    #[tokio::test]
+    async fn update_with_retry_returns_conflict_when_exhausted() {
+        let (service, id) = conflicting_service(3).await;
+        let rename = |item: &mut BizMetadata, now| {
+            item.rename(BizMetadataName::new("Company").unwrap(), now)
+                .unwrap()
+        };
+
+        let err = service.update_with_retry(id, rename, 2).await.unwrap_err();
+        assert!(is_version_conflict(&err));
+        assert_eq!(service.repository().conflicts.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn default_stream_all_pages_by_id_cursor_across_batches() {
+        use futures_util::TryStreamExt;
+
+        let (service, first) = conflicting_service(0).await;
+        for index in 0..250 {
+            add_node(
+                &service,
+                &format!("node_{index}"),
+                None,
+                BizMetadataStatus::Active,
+            )
+            .await;
+        }
+        let repository = service.repository();
+
+        let streamed: Vec<_> = repository
+            .stream_all_biz_metadata(Expression::True)
+            .try_collect()
+            .await
+            .unwrap();
+        let vector_backed: Vec<_> = repository
+            .inner
+            .stream_all_biz_metadata(Expression::True)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(streamed.len(), 251);
+        assert_eq!(streamed, vector_backed);
+        assert_eq!(streamed[0].id(), first);
+    }
+
+    #[tokio::test]
+    async fn create_uses_service_default_source() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_default_source(Source::ApiSync)
+            .with_default_status(BizMetadataStatus::Deprecated);
+        let create = |code: &str, source| CreateBizMetadataCommand {
+            code: code.into(),
+            name: code.into(),
+            description: None,
+            object_type: ObjectType::Entity,
+            parent_id: None,
+            data_class: None,
+            value_type: None,
+            unit: None,
+            status: None,
+            source,
+        };
+
+        let defaulted = service
+            .create_biz_metadata(create("company", None))
+            .await
+            .unwrap();
+        assert_eq!(defaulted.source(), Source::ApiSync);
+        assert_eq!(defaulted.status(), BizMetadataStatus::Deprecated);
+
+        let explicit = service
+            .create_biz_metadata(create("person", Some(Source::Manual)))
+            .await
+            .unwrap();
+        assert_eq!(explicit.source(), Source::Manual);
+    }
+
+    #[tokio::test]
+    async fn delete_by_filter_skips_referenced_parents_unless_forced() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let legacy = add_node(&service, "legacy", None, BizMetadataStatus::Deprecated).await;
+        add_node(
+            &service,
+            "legacy.a",
+            Some(legacy),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let kept = add_node(
+            &service,
+            "legacy.b",
+            Some(legacy),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        add_node(&service, "company", Some(kept), BizMetadataStatus::Active).await;
+
+        let scope = Expression::cmp(domain_core::expression::contains("code", "legacy"));
+        let deleted = service
+            .delete_by_filter(scope.clone(), false, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert!(
+            service
+                .find_biz_metadata_by_id(legacy)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            service
+                .find_biz_metadata_by_id(kept)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        let deleted = service.delete_by_filter(scope, false, true).await.unwrap();
+        assert_eq!(deleted, 2);
+        let remaining = service
+            .query_biz_metadata(BizMetadataQueryRequest::new(
+                Expression::True,
+                QueryOptions::default(),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(remaining.total_count(), 1);
+        assert_eq!(remaining.items()[0].code().as_str(), "company");
+    }
+
+    #[tokio::test]
+    async fn delete_by_filter_skips_targets_of_live_type_refs() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let referenced = add_node(&service, "legacy_amount", None, BizMetadataStatus::Active).await;
+        let dropped = add_node(&service, "legacy_unit", None, BizMetadataStatus::Active).await;
+        let total = add_typed_feature(
+            &service,
+            "company.total",
+            company,
+            "decimal | ref:legacy_amount",
+        )
+        .await;
+
+        let scope = Expression::cmp(domain_core::expression::contains("code", "legacy"));
+        let deleted = service
+            .delete_by_filter(scope.clone(), false, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+        assert!(
+            service
+                .find_biz_metadata_by_id(dropped)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            service
+                .find_biz_metadata_by_id(referenced)
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // 引用方同批删除时不再阻塞目标。
+        let with_referrer = Expression::or(vec![
+            scope.clone(),
+            Expression::cmp(eq("id", total.value())),
+        ]);
+        let deleted = service
+            .delete_by_filter(with_referrer, false, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 2);
+        assert!(
+            service
+                .find_biz_metadata_by_id(referenced)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn delete_by_filter_requires_confirm_for_unrestricted_filter() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        add_node(&service, "company", None, BizMetadataStatus::Active).await;
+
+        for expr in [Expression::True, Expression::and(Vec::new())] {
+            let err = service
+                .delete_by_filter(expr, false, false)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), error_code::BIZ_METADATA_FILTER_UNCONFIRMED);
+        }
+
+        let deleted = service
+            .delete_by_filter(Expression::True, true, false)
+            .await
+            .unwrap();
+        assert_eq!(deleted, 1);
+    }
+
+    #[tokio::test]
+    async fn delete_by_filter_rejects_unknown_fields_even_when_lenient() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        add_node(&service, "company", None, BizMetadataStatus::Active).await;
+
+        for confirm in [false, true] {
+            let err = service
+                .delete_by_filter(
+                    Expression::cmp(domain_core::expression::eq("stauts", "active")),
+                    confirm,
+                    true,
+                )
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), error_code::QUERY_FIELD_UNKNOWN);
+        }
+        let remaining = service.count_biz_metadata(Expression::True).await.unwrap();
+        assert_eq!(remaining, 1);
+    }
+
+    #[tokio::test]
+    async fn delete_is_blocked_by_live_children() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let child = add_node(
+            &service,
+            "company.finance",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let err = service
+            .delete_biz_metadata(root, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_HAS_DEPENDENTS);
+        assert!(err.message().contains(&child.value().to_string()));
+        assert!(
+            service
+                .find_biz_metadata_by_id(root)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn forced_delete_deprecates_or_detaches_children() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let child = add_node(
+            &service,
+            "company.finance",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        service
+            .delete_biz_metadata(root, Version::new(1).unwrap(), DependentAction::Deprecate)
+            .await
+            .unwrap();
+        assert!(
+            service
+                .find_biz_metadata_by_id(root)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let deprecated = service
+            .find_biz_metadata_by_id(child)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(deprecated.status(), BizMetadataStatus::Deprecated);
+        assert_eq!(deprecated.parent_id(), Some(root));
+
+        let grandchild = add_node(
+            &service,
+            "company.finance.revenue",
+            Some(child),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        service
+            .delete_biz_metadata(child, Version::new(2).unwrap(), DependentAction::Detach)
+            .await
+            .unwrap();
+        let detached = service
+            .find_biz_metadata_by_id(grandchild)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(detached.parent_id(), None);
+        assert_eq!(detached.status(), BizMetadataStatus::Active);
+    }
+
+    async fn add_typed_feature(
+        service: &BizMetadataService<InMemoryBizMetadataRepository>,
+        code: &str,
+        parent_id: BizMetadataId,
+        value_type: &str,
+    ) -> BizMetadataId {
+        service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: code.into(),
+                name: code.into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: Some(parent_id),
+                data_class: Some(DataClass::Metric),
+                value_type: Some(value_type.into()),
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap()
+            .id()
+    }
+
+    #[tokio::test]
+    async fn delete_treats_type_refs_as_dependents() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let amount = add_node(&service, "amount", None, BizMetadataStatus::Active).await;
+        add_node(&service, "amounts", None, BizMetadataStatus::Active).await;
+        let total =
+            add_typed_feature(&service, "company.total", company, "decimal | ref:amount").await;
+        let only = add_typed_feature(&service, "company.only", company, "ref:amount").await;
+        let similar = add_typed_feature(&service, "company.similar", company, "ref:amounts").await;
+
+        let err = service
+            .delete_biz_metadata(amount, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_HAS_DEPENDENTS);
+        assert!(
+            err.message()
+                .contains(&format!("{}, {}", total.value(), only.value()))
+        );
+        assert!(!err.message().contains(&similar.value().to_string()));
+
+        // 唯一 term 的引用无法脱离，整体拒绝且不做任何写入。
+        let err = service
+            .delete_biz_metadata(amount, Version::new(1).unwrap(), DependentAction::Detach)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_HAS_DEPENDENTS);
+        assert!(err.message().contains(&only.value().to_string()));
+        let unchanged = service
+            .find_biz_metadata_by_id(total)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            unchanged.value_type().map(ValueType::as_str),
+            Some("decimal | ref:amount")
+        );
+
+        service
+            .delete_biz_metadata(amount, Version::new(1).unwrap(), DependentAction::Deprecate)
+            .await
+            .unwrap();
+        assert!(
+            service
+                .find_biz_metadata_by_id(amount)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        for feature in [total, only] {
+            let feature = service
+                .find_biz_metadata_by_id(feature)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(feature.status(), BizMetadataStatus::Deprecated);
+        }
+        let similar = service
+            .find_biz_metadata_by_id(similar)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(similar.status(), BizMetadataStatus::Active);
+
+        let unit = add_node(&service, "unit", None, BizMetadataStatus::Active).await;
+        let priced =
+            add_typed_feature(&service, "company.priced", company, "ref:unit | decimal").await;
+        service
+            .delete_biz_metadata(unit, Version::new(1).unwrap(), DependentAction::Detach)
+            .await
+            .unwrap();
+        let detached = service
+            .find_biz_metadata_by_id(priced)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            detached.value_type().map(ValueType::as_str),
+            Some("decimal")
+        );
+        assert_eq!(detached.parent_id(), Some(company));
+    }
+
+    #[tokio::test]
+    async fn purge_deleted_respects_cutoff_and_live_children() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let old = add_node(&service, "old", None, BizMetadataStatus::Active).await;
+        let recent = add_node(&service, "recent", None, BizMetadataStatus::Active).await;
+        let parent = add_node(&service, "parent", None, BizMetadataStatus::Active).await;
+        add_node(
+            &service,
+            "parent.child",
+            Some(parent),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let now = Utc::now();
+        for (id, deleted_at) in [(old, now), (recent, now + Duration::days(2)), (parent, now)] {
+            let mut item = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+            item.mark_deleted(deleted_at).unwrap();
+            service
+                .repository()
+                .update_biz_metadata(item)
+                .await
+                .unwrap();
+        }
+
+        let purged = service
+            .purge_deleted(now + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+
+        let remaining = service
+            .changed_since(now - Duration::days(1), true)
+            .await
+            .unwrap();
+        let codes: Vec<_> = remaining.iter().map(|m| m.code().as_str()).collect();
+        assert!(!codes.contains(&"old"));
+        assert!(codes.contains(&"recent"));
+        assert!(codes.contains(&"parent"));
+    }
+
+    #[tokio::test]
+    async fn purge_deleted_removes_aliases_of_purged_rows() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let old = add_node(&service, "old", None, BizMetadataStatus::Active).await;
+        let aliased = add_node(&service, "aliased", None, BizMetadataStatus::Active).await;
+        let now = Utc::now();
+        let cascaded = aliases
+            .insert_alias(BizMetadataAlias::new(old, "旧节点", now).unwrap())
+            .await
+            .unwrap();
+        let live = aliases
+            .insert_alias(BizMetadataAlias::new(aliased, "存活", now).unwrap())
+            .await
+            .unwrap();
+        service
+            .delete_biz_metadata(old, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        let mut item = service
+            .find_biz_metadata_by_id(aliased)
+            .await
+            .unwrap()
+            .unwrap();
+        item.mark_deleted(now).unwrap();
+        service
+            .repository()
+            .update_biz_metadata(item)
+            .await
+            .unwrap();
+
+        let purged = service
+            .purge_deleted(Utc::now() + Duration::days(1))
+            .await
+            .unwrap();
+        assert_eq!(purged, 1);
+        assert!(
+            aliases
+                .find_alias_by_id(cascaded.id())
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let listed = aliases
+            .query_alias(
+                Expression::cmp(eq("metadata_id", old.value())),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(listed.items().is_empty());
+        // 仍有存活别名的行及其别名保持不变。
+        assert!(aliases.find_alias_by_id(live.id()).await.unwrap().is_some());
+        let remaining = service
+            .changed_since(now - Duration::days(1), true)
+            .await
+            .unwrap();
+        let codes: Vec<_> = remaining.iter().map(|m| m.code().as_str()).collect();
+        assert_eq!(codes, vec!["aliased"]);
+    }
+
+    async fn sample_catalog() -> CatalogDump {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let revenue = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "company.revenue".into(),
+                name: "营业收入".into(),
+                description: Some("年度营收".into()),
+                object_type: ObjectType::Feature,
+                parent_id: Some(company),
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal".into()),
+                unit: Some("CNY".into()),
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+        let now = Utc::now();
+        let mut alias = BizMetadataAlias::new(revenue.id(), "营收", now).unwrap();
+        alias.set_primary(true, now).unwrap();
+        alias.change_weight(80, now).unwrap();
+        aliases.insert_alias(alias).await.unwrap();
+        service.export_catalog(&aliases).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn catalog_round_trips_into_fresh_repository() {
+        let dump = sample_catalog().await;
+        assert_eq!(dump.schema_version, CATALOG_SCHEMA_VERSION);
+        let codes: Vec<_> = dump
+            .entries
+            .iter()
+            .map(|entry| entry.code.as_str())
+            .collect();
+        assert_eq!(codes, vec!["company", "company.revenue"]);
+        let json = serde_json::to_string(&dump).unwrap();
+        // 子节点排在父节点之前，验证导入会自行排序。
+        let mut restored: CatalogDump = serde_json::from_str(&json).unwrap();
+        restored.entries.reverse();
+
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        // 先占用 id，使目标环境的 id 与源环境不同。
+        add_node(&service, "unrelated", None, BizMetadataStatus::Active).await;
+        let summary = service
+            .import_catalog(&aliases, restored.clone(), ImportMode::Replace)
+            .await
+            .unwrap();
+        assert_eq!((summary.inserted, summary.aliases), (2, 1));
+
+        let revenue = service
+            .find_biz_metadata_by_code("company.revenue")
+            .await
+            .unwrap()
+            .unwrap();
+        let company = service
+            .find_biz_metadata_by_code("company")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(revenue.parent_id(), Some(company.id()));
+        assert_ne!(company.id(), BizMetadataId::new(1));
+        assert!(
+            service
+                .find_biz_metadata_by_code("unrelated")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        let exported = service.export_catalog(&aliases).await.unwrap();
+        assert_eq!(exported.entries, dump.entries);
+
+        let again = service
+            .import_catalog(&aliases, restored, ImportMode::Upsert)
+            .await
+            .unwrap();
+        assert_eq!((again.inserted, again.updated, again.unchanged), (0, 0, 2));
+        assert_eq!(
+            service.export_catalog(&aliases).await.unwrap().entries,
+            dump.entries
+        );
+    }
+
+    #[tokio::test]
+    async fn replace_import_with_invalid_entry_keeps_existing_catalog() {
+        let mut dump = sample_catalog().await;
+        dump.entries[1].status = "bogus".into();
+
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        add_node(&service, "unrelated", None, BizMetadataStatus::Active).await;
+        let err = service
+            .import_catalog(&aliases, dump, ImportMode::Replace)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }));
+        assert!(
+            service
+                .find_biz_metadata_by_code("unrelated")
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            service
+                .find_biz_metadata_by_code("company")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn replace_import_failure_mid_write_keeps_existing_catalog() {
+        let dump = sample_catalog().await;
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let unrelated = add_node(&service, "unrelated", None, BizMetadataStatus::Active).await;
+        aliases
+            .insert_alias(BizMetadataAlias::new(unrelated, "无关", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        // 审计时间晚于导入时间，清空别名时失败，模拟整体替换目录时写到一半出错。
+        aliases
+            .insert_alias(
+                BizMetadataAlias::new(unrelated, "未来", Utc::now() + Duration::hours(1)).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let err = service
+            .import_catalog(&aliases, dump, ImportMode::Replace)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), domain_core::error_code::AUDIT_TIMELINE_INVALID);
+
+        let kept = service
+            .find_biz_metadata_by_code("unrelated")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(kept.id(), unrelated);
+        for code in ["company", "company.revenue"] {
+            assert!(
+                service
+                    .find_biz_metadata_by_code(code)
+                    .await
+                    .unwrap()
+                    .is_none()
+            );
+        }
+        let live: Vec<String> = collect_live_aliases(&aliases)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|alias| alias.alias().as_str().to_string())
+            .collect();
+        assert_eq!(live, vec!["无关", "未来"]);
+    }
+
+    #[tokio::test]
+    async fn upsert_import_failure_keeps_written_entries_and_reruns_cleanly() {
+        let mut dump = sample_catalog().await;
+        let mut extra = dump.entries[1].aliases[0].clone();
+        extra.alias = "营业收入".into();
+        extra.is_primary = false;
+        dump.entries[1].aliases.push(extra);
+
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        )
+        .with_max_aliases_per_metadata(2);
+        // 目标环境已有的别名使第二个条目写入别名时超限，此时第一个条目已写入。
+        let stale = add_node(&service, "company.revenue", None, BizMetadataStatus::Active).await;
+        let blocking = aliases
+            .insert_alias(BizMetadataAlias::new(stale, "收入", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let err = service
+            .import_catalog(&aliases, dump.clone(), ImportMode::Upsert)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        let company = service
+            .find_biz_metadata_by_code("company")
+            .await
+            .unwrap()
+            .unwrap();
+        let revenue = service
+            .find_biz_metadata_by_code("company.revenue")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(revenue.parent_id(), Some(company.id()));
+
+        // 移除冲突别名后重新导入同一份内容，已写入的条目计为未变化。
+        aliases.delete(blocking.id()).await.unwrap();
+        let summary = service
+            .import_catalog(&aliases, dump, ImportMode::Upsert)
+            .await
+            .unwrap();
+        assert_eq!(
+            (summary.inserted, summary.updated, summary.unchanged),
+            (0, 0, 2)
+        );
+        assert_eq!(aliases.count_live_aliases(revenue.id()).await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn catalog_import_enforces_alias_limit() {
+        let mut dump = sample_catalog().await;
+        let mut extra = dump.entries[1].aliases[0].clone();
+        extra.alias = "营业收入".into();
+        extra.is_primary = false;
+        dump.entries[1].aliases.push(extra);
+
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        )
+        .with_max_aliases_per_metadata(1);
+        add_node(&service, "unrelated", None, BizMetadataStatus::Active).await;
+        let err = service
+            .import_catalog(&aliases, dump.clone(), ImportMode::Replace)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        assert!(
+            service
+                .find_biz_metadata_by_code("unrelated")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // 条目自身未超限，但与目标环境已有的存活别名合计超限。
+        dump.entries[1].aliases.pop();
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let revenue = add_feature(&service, "company.revenue", company).await;
+        aliases
+            .insert_alias(BizMetadataAlias::new(revenue, "收入", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let err = service
+            .import_catalog(&aliases, dump, ImportMode::Upsert)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        assert_eq!(aliases.count_live_aliases(revenue).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn catalog_import_rejects_unresolved_parents_before_writing() {
+        let mut dump = sample_catalog().await;
+        dump.entries[0].parent_code = Some("company.revenue".into());
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+
+        let err = service
+            .import_catalog(&aliases, dump.clone(), ImportMode::Upsert)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::CATALOG_PARENT_UNRESOLVED);
+        assert!(
+            service
+                .export_catalog(&aliases)
+                .await
+                .unwrap()
+                .entries
+                .is_empty()
+        );
+
+        dump.schema_version = CATALOG_SCHEMA_VERSION + 1;
+        let err = service
+            .import_catalog(&aliases, dump, ImportMode::Upsert)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::CATALOG_SCHEMA_UNSUPPORTED);
+    }
+
+    #[tokio::test]
+    async fn search_ranks_primary_alias_above_name_substring() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let by_name = add_node(&service, "revenue_note", None, BizMetadataStatus::Active).await;
+        let by_alias = add_node(&service, "company.revenue", None, BizMetadataStatus::Active).await;
+
+        let now = Utc::now();
+        let mut alias = BizMetadataAlias::new(by_alias, "营收 revenue", now).unwrap();
+        alias.set_primary(true, now).unwrap();
+        alias.change_weight(90, now).unwrap();
+        aliases.insert_alias(alias).await.unwrap();
+        // 同一元数据的低分命中应被去重。
+        aliases
+            .insert_alias(BizMetadataAlias::new(by_alias, "revenue total", now).unwrap())
+            .await
+            .unwrap();
+
+        let hits = service.search(&aliases, "revenue", 10).await.unwrap();
+        let ids: Vec<_> = hits.iter().map(|hit| hit.metadata.id()).collect();
+        assert_eq!(ids, vec![by_alias, by_name]);
+        assert_eq!(hits[0].score, SCORE_PRIMARY_ALIAS + 90);
+        assert_eq!(
+            hits[0].matched_alias.as_ref().unwrap().alias().as_str(),
+            "营收 revenue"
+        );
+        assert!(hits[1].matched_alias.is_none());
+        assert_eq!(hits[1].score, SCORE_SUBSTRING);
+    }
+
+    #[tokio::test]
+    async fn search_ranks_before_truncating_to_limit() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        for code in ["revenue_a", "revenue_b"] {
+            add_node(&service, code, None, BizMetadataStatus::Active).await;
+        }
+        let exact = add_node(&service, "revenue", None, BizMetadataStatus::Active).await;
+
+        let hits = service.search(&aliases, "revenue", 1).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].metadata.id(), exact);
+        assert_eq!(hits[0].score, SCORE_EXACT);
+    }
+
+    #[tokio::test]
+    async fn delete_cascades_to_aliases_and_restore_brings_them_back() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let id = add_node(&service, "company.income", None, BizMetadataStatus::Active).await;
+        let now = Utc::now();
+        aliases
+            .insert_alias(BizMetadataAlias::new(id, "营收", now).unwrap())
+            .await
+            .unwrap();
+        let mut retired = aliases
+            .insert_alias(BizMetadataAlias::new(id, "营收旧称", now).unwrap())
+            .await
+            .unwrap();
+        retired.mark_deleted(now).unwrap();
+        aliases.update_alias(retired).await.unwrap();
+
+        service
+            .delete_biz_metadata(id, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        assert!(
+            service
+                .search(&aliases, "营收", 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let listed = aliases
+            .query_alias(
+                Expression::cmp(eq("metadata_id", id.value())),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(
+            listed
+                .items()
+                .iter()
+                .all(|alias| alias.delete_at().is_some())
+        );
+
+        let restored = service
+            .restore_biz_metadata(id, Version::new(2).unwrap(), true)
+            .await
+            .unwrap();
+        assert!(!restored.is_deleted());
+        let hits = service.search(&aliases, "营收", 10).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(
+            hits[0].matched_alias.as_ref().unwrap().alias().as_str(),
+            "营收"
+        );
+        // 级联之前单独删除的别名保持删除。
+        let listed = aliases
+            .query_alias(
+                Expression::cmp(eq("metadata_id", id.value())),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        let alive: Vec<_> = listed
+            .items()
+            .iter()
+            .filter(|alias| alias.delete_at().is_none())
+            .map(|alias| alias.alias().as_str())
+            .collect();
+        assert_eq!(alive, vec!["营收"]);
+    }
+
+    #[tokio::test]
+    async fn failed_alias_cascade_rolls_back_the_delete() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let id = add_node(&service, "company.income", None, BizMetadataStatus::Active).await;
+        // 更新时间晚于删除时间的别名无法软删除，模拟级联写入失败。
+        aliases
+            .insert_alias(
+                BizMetadataAlias::new(id, "营收", Utc::now() + chrono::Duration::hours(1)).unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let err = service
+            .delete_biz_metadata(id, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), domain_core::error_code::AUDIT_TIMELINE_INVALID);
+        let kept = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+        assert_eq!(i32::from(kept.version()), 1);
+        assert_eq!(aliases.count_live_aliases(id).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn restore_skips_aliases_reused_while_deleted() {
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_aliases(aliases.clone()),
+        );
+        let id = add_node(&service, "company.income", None, BizMetadataStatus::Active).await;
+        let now = Utc::now();
+        let cascaded = aliases
+            .insert_alias(BizMetadataAlias::new(id, "营收", now).unwrap())
+            .await
+            .unwrap();
+        service
+            .delete_biz_metadata(id, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        // 删除期间以相同文本与语言新建了存活别名。
+        let reused = aliases
+            .insert_alias(BizMetadataAlias::new(id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+
+        let restored = service
+            .restore_biz_metadata(id, Version::new(2).unwrap(), true)
+            .await
+            .unwrap();
+        assert!(!restored.is_deleted());
+        let cascaded = aliases
+            .find_alias_by_id(cascaded.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(cascaded.delete_at().is_some());
+        let reused = aliases
+            .find_alias_by_id(reused.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(reused.delete_at().is_none());
+        assert_eq!(aliases.count_live_aliases(id).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn create_enforces_code_policy() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_code_policy(CodePolicy::default().with_allowed_roots(["company"]));
+
+        let err = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "person.name".into(),
+                name: "name".into(),
+                description: None,
+                object_type: ObjectType::Entity,
+                parent_id: None,
+                data_class: None,
+                value_type: None,
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::CODE_POLICY_ROOT_NOT_ALLOWED);
+
+        add_node(&service, "company.name", None, BizMetadataStatus::Active).await;
+    }
+
+    #[tokio::test]
+    async fn create_rejects_live_duplicate_code_but_reuses_deleted() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let first = add_node(&service, "company.name", None, BizMetadataStatus::Active).await;
+
+        let duplicate = || CreateBizMetadataCommand {
+            code: "company.name".into(),
+            name: "name".into(),
+            description: None,
+            object_type: ObjectType::Entity,
+            parent_id: None,
+            data_class: None,
+            value_type: None,
+            unit: None,
+            status: None,
+            source: None,
+        };
+        let err = service.create_biz_metadata(duplicate()).await.unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }));
+        assert_eq!(err.code(), error_code::BIZ_METADATA_DUPLICATE_CODE);
+
+        service
+            .delete_biz_metadata(first, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        let reused = service.create_biz_metadata(duplicate()).await.unwrap();
+        assert_ne!(reused.id(), first);
+    }
+
+    #[tokio::test]
+    async fn per_parent_uniqueness_scopes_leaf_to_siblings() {
+        let create = |code: &str, parent_id| CreateBizMetadataCommand {
+            code: code.into(),
+            name: code.into(),
+            description: None,
+            object_type: ObjectType::Entity,
+            parent_id: Some(parent_id),
+            data_class: None,
+            value_type: None,
+            unit: None,
+            status: None,
+            source: None,
+        };
+
+        // 全局模式只比较完整编码，同一父节点下可出现同名末段。
+        let global = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&global, "company", None, BizMetadataStatus::Active).await;
+        add_node(
+            &global,
+            "company.revenue",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        assert!(
+            global
+                .create_biz_metadata(create("company.finance.revenue", company))
+                .await
+                .is_ok()
+        );
+
+        let scoped = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent),
+        )
+        .with_code_uniqueness(CodeUniqueness::PerParent);
+        let company = add_node(&scoped, "company", None, BizMetadataStatus::Active).await;
+        let person = add_node(&scoped, "person", None, BizMetadataStatus::Active).await;
+        add_node(
+            &scoped,
+            "company.revenue",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let other = scoped
+            .create_biz_metadata(create("person.revenue", person))
+            .await
+            .unwrap();
+        // 完整编码相同、父节点不同时同样允许，per_parent 迁移后唯一索引只在同一父节点内生效。
+        add_node(&scoped, "total", Some(company), BizMetadataStatus::Active).await;
+        add_node(&scoped, "total", Some(person), BizMetadataStatus::Active).await;
+
+        let err = scoped
+            .create_biz_metadata(create("company.finance.revenue", company))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_DUPLICATE_CODE);
+
+        // 改挂父节点时同样按新父节点的子节点检查。
+        let err = scoped
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: other.id(),
+                version: other.version(),
+                parent_id: FieldUpdate::Set(company),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_DUPLICATE_CODE);
+    }
+
+    #[tokio::test]
+    async fn export_dot_renders_nodes_and_edges() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let root = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let child = add_node(
+            &service,
+            "company.name",
+            Some(root),
+            BizMetadataStatus::Active,
+        )
+        .await;
+
+        let dot = service.export_dot(None, 8).await.unwrap();
+        assert_eq!(
+            dot,
+            format!(
+                "digraph biz_metadata {{\n  n{r} [label=\"company\"];\n  n{c} [label=\"company.name\"];\n  n{r} -> n{c};\n}}\n",
+                r = root.value(),
+                c = child.value()
+            )
+        );
+
+        for root in [Some(root), None] {
+            let only_root = service.export_dot(root, 0).await.unwrap();
+            assert!(only_root.contains("\"company\""));
+            assert!(!only_root.contains("company.name"));
+            assert!(!only_root.contains("->"));
+        }
+    }
+
+    #[tokio::test]
+    async fn operations_report_stable_error_codes() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+
+        let stale = UpdateBizMetadataCommand {
+            id,
+            version: Version::new(2).unwrap(),
+            name: Some("Company".into()),
+            description: FieldUpdate::Keep,
+            data_class: FieldUpdate::Keep,
+            value_type: FieldUpdate::Keep,
+            unit: FieldUpdate::Keep,
+            parent_id: FieldUpdate::Keep,
+            status: None,
+            source: None,
+            force: false,
+        };
+        let err = service.update_biz_metadata(stale).await.unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_VERSION_CONFLICT);
+
+        let err = service
+            .delete_biz_metadata(
+                BizMetadataId::new(404),
+                Version::new(1).unwrap(),
+                DependentAction::Restrict,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_NOT_FOUND);
+
+        let err = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "company.revenue".into(),
+                name: "revenue".into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: Some(id),
+                data_class: Some(crate::domain::biz_metadata::value_object::DataClass::Metric),
+                value_type: Some("  ".into()),
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::VALUE_TYPE_INVALID);
+    }
+
+    #[tokio::test]
+    async fn audit_invariants_reports_every_violation_of_legacy_rows() {
+        let repo = InMemoryBizMetadataRepository::new();
+        let node = |code: &str| {
+            BizMetadata::new_node(
+                TenantId::new("default").unwrap(),
+                code,
+                code,
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap()
+        };
+        let valid = node("company");
+        let mut snapshot = node("legacy").to_snapshot();
+        snapshot.data_class = Some(DataClass::Identifier);
+        snapshot.unit = Some(Unit::new("CNY").unwrap());
+        let legacy = BizMetadata::rehydrate(snapshot.clone()).unwrap();
+        assert!(BizMetadata::from_snapshot(snapshot).is_err());
+
+        repo.insert_biz_metadata(valid).await.unwrap();
+        let legacy = repo.insert_biz_metadata(legacy).await.unwrap();
+        let service = BizMetadataService::new(repo);
+
+        let report = service.audit_invariants().await.unwrap();
+        assert_eq!(report.len(), 1);
+        let (id, errors) = &report[0];
+        assert_eq!(*id, legacy.id());
+        let codes: Vec<_> = errors.iter().map(DomainError::code).collect();
+        assert_eq!(
+            codes,
+            vec![
+                error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
+                error_code::UNIT_NOT_ALLOWED,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn no_op_check_uses_locked_row_not_pre_read() {
+        let inner = InMemoryBizMetadataRepository::new();
+        let stored = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "公司",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let stored = inner.insert(stored).into_inner().unwrap();
+        let mut stale = stored.clone();
+        stale
+            .rename(BizMetadataName::new("Company").unwrap(), Utc::now())
+            .unwrap();
+        let service = BizMetadataService::new(StaleReadRepository { inner, stale });
+
+        // 预读看到的名称与命令一致，但锁定读到的当前记录不同，必须写入。
+        let updated = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: stored.id(),
+                version: stored.version(),
+                name: Some("Company".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.name().as_str(), "Company");
+        assert_eq!(i32::from(updated.version()), 2);
+    }
+
+    #[tokio::test]
+    async fn move_checks_use_locked_row_not_pre_read() {
+        let inner =
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent);
+        let node = |code: &str| {
+            BizMetadata::new_node(
+                TenantId::new("default").unwrap(),
+                code,
+                code,
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap()
+        };
+        let team = inner.insert(node("team")).into_inner().unwrap();
+        let mut member = node("team.lead");
+        member.set_parent_id(Some(team.id()), Utc::now()).unwrap();
+        inner.insert(member).into_inner().unwrap();
+        let lead = inner.insert(node("lead")).into_inner().unwrap();
+        let mut stale = lead.clone();
+        stale.set_parent_id(Some(team.id()), Utc::now()).unwrap();
+        let service = BizMetadataService::new(StaleReadRepository { inner, stale })
+            .with_code_uniqueness(CodeUniqueness::PerParent);
+
+        // 预读看到节点已在 team 下，但锁定读到的记录仍为根节点，挂到 team 下须校验同级末段。
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: lead.id(),
+                version: lead.version(),
+                parent_id: FieldUpdate::Set(team.id()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_DUPLICATE_CODE);
+    }
+
+    #[tokio::test]
+    async fn no_op_update_keeps_version_unless_forced() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let created = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+
+        // 字段取值与当前一致，视为无变化。
+        let same = UpdateBizMetadataCommand {
+            id,
+            version: created.version(),
+            name: Some(created.name().as_str().to_string()),
+            status: Some(BizMetadataStatus::Active),
+            ..Default::default()
+        };
+        let unchanged = service.update_biz_metadata(same.clone()).await.unwrap();
+        assert_eq!(unchanged, created);
+        let empty = UpdateBizMetadataCommand {
+            id,
+            version: created.version(),
+            ..Default::default()
+        };
+        assert_eq!(service.update_biz_metadata(empty).await.unwrap(), created);
+
+        let forced = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                force: true,
+                ..same
+            })
+            .await
+            .unwrap();
+        assert_eq!(i32::from(forced.version()), 2);
+
+        let renamed = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id,
+                version: forced.version(),
+                name: Some("公司".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(i32::from(renamed.version()), 3);
+        assert_eq!(renamed.name().as_str(), "公司");
+
+        // 无变化的更新同样校验版本号。
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id,
+                version: created.version(),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_VERSION_CONFLICT);
+    }
+
+    #[tokio::test]
+    async fn value_type_must_use_registered_scalars() {
+        let feature = |code: &str| CreateBizMetadataCommand {
+            code: code.into(),
+            name: code.into(),
+            description: None,
+            object_type: ObjectType::Feature,
+            parent_id: None,
+            data_class: Some(DataClass::Attribute),
+            value_type: Some("uuid".into()),
+            unit: None,
+            status: None,
+            source: None,
+        };
+        let default = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let err = default
+            .create_biz_metadata(feature("company_id"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::VALUE_TYPE_INVALID);
+
+        let custom = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_value_type_registry(ValueTypeRegistry::builtin().clone().register("uuid"));
+        let created = custom
+            .create_biz_metadata(feature("company_id"))
+            .await
+            .unwrap();
+        assert_eq!(created.value_type().map(|v| v.as_str()), Some("uuid"));
+
+        let err = custom
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                value_type: FieldUpdate::Set("guid".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::VALUE_TYPE_INVALID);
+    }
+
+    #[tokio::test]
+    async fn feature_fields_on_non_feature_node_are_rejected_up_front() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id,
+                version: Version::new(1).unwrap(),
+                name: Some("公司".into()),
+                data_class: FieldUpdate::Set(DataClass::Attribute),
+                value_type: FieldUpdate::Set("string".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH);
+        assert_eq!(err.message(), "cannot set data_class on object_type=entity");
+
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id,
+                version: Version::new(1).unwrap(),
+                unit: FieldUpdate::Set("CNY".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.message(), "cannot set unit on object_type=entity");
+        let unchanged = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+        assert_eq!(unchanged.name().as_str(), "company");
+        assert_eq!(i32::from(unchanged.version()), 1);
+    }
+
+    #[tokio::test]
+    async fn feature_fields_are_validated_as_a_final_combination() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let created = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "revenue".into(),
+                name: "营收".into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: None,
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal".into()),
+                unit: Some("CNY".into()),
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+
+        // 只改分类而保留单位，最终组合非法，整体拒绝。
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                data_class: FieldUpdate::Set(DataClass::Text),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::UNIT_NOT_ALLOWED);
+
+        // 同时清空单位时最终组合合法，不因中间状态失败。
+        let updated = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                data_class: FieldUpdate::Set(DataClass::Text),
+                value_type: FieldUpdate::Set("string".into()),
+                unit: FieldUpdate::Clear,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.data_class(), Some(DataClass::Text));
+        assert!(updated.unit().is_none());
+    }
+
+    #[tokio::test]
+    async fn clearing_required_feature_fields_is_rejected_up_front() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let created = service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "revenue".into(),
+                name: "营收".into(),
+                description: Some("月度营收".into()),
+                object_type: ObjectType::Feature,
+                parent_id: None,
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal".into()),
+                unit: Some("CNY".into()),
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+
+        let err = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                name: Some("收入".into()),
+                value_type: FieldUpdate::Clear,
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_FEATURE_FIELD_REQUIRED);
+        assert_eq!(
+            err.message(),
+            "cannot clear value_type: object_type=feature requires it"
+        );
+        let unchanged = service
+            .find_biz_metadata_by_id(created.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unchanged.name().as_str(), "营收");
+
+        let updated = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                description: FieldUpdate::Clear,
+                unit: FieldUpdate::Clear,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert!(updated.description().is_none());
+        assert!(updated.unit().is_none());
+        assert_eq!(updated.data_class(), Some(DataClass::Metric));
+    }
+
+    #[tokio::test]
+    async fn distinct_values_lists_present_facets_only() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        add_node(&service, "product", None, BizMetadataStatus::Active).await;
+        let deleted = add_node(&service, "region", None, BizMetadataStatus::Deprecated).await;
+        service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: "revenue".into(),
+                name: "营收".into(),
+                description: None,
+                object_type: ObjectType::Feature,
+                parent_id: None,
+                data_class: Some(DataClass::Metric),
+                value_type: Some("decimal".into()),
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+        // 仅被已删除记录使用的取值不计入。
+        service
+            .delete_biz_metadata(deleted, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            service.distinct_values("object_type").await.unwrap(),
+            ["entity", "feature"]
+        );
+        assert_eq!(
+            service.distinct_value_counts("object_type").await.unwrap(),
+            [("entity".to_string(), 2), ("feature".to_string(), 1)]
+        );
+        assert_eq!(service.distinct_values("status").await.unwrap(), ["active"]);
+        assert_eq!(
+            service.distinct_values("data_class").await.unwrap(),
+            ["metric"]
+        );
+
+        let err = service.distinct_values("code").await.unwrap_err();
+        assert_eq!(err.code(), error_code::QUERY_FIELD_NOT_FACETABLE);
+    }
+
+    #[tokio::test]
+    async fn code_available_ignores_soft_deleted_rows() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let deleted = add_node(&service, "product", None, BizMetadataStatus::Active).await;
+        service
+            .delete_biz_metadata(deleted, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+
+        assert!(!service.code_available("company", None).await.unwrap());
+        assert!(!service.code_available("COMPANY", None).await.unwrap());
+        assert!(service.code_available("product", None).await.unwrap());
+        assert!(service.code_available("region", None).await.unwrap());
+
+        let err = service.code_available("   ", None).await.unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }));
+    }
+
+    #[tokio::test]
+    async fn code_available_follows_per_parent_uniqueness() {
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent),
+        )
+        .with_code_uniqueness(CodeUniqueness::PerParent);
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        add_feature(&service, "company.revenue", company).await;
+
+        // 同一父节点下末段重复即不可用，换到其他父节点则可用。
+        assert!(
+            !service
+                .code_available("company.revenue", Some(company))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !service
+                .code_available("group.revenue", Some(company))
+                .await
+                .unwrap()
+        );
+        assert!(
+            service
+                .code_available("corp.revenue", Some(corp))
+                .await
+                .unwrap()
+        );
+        // 未给出父节点时按完整编码全局判断。
+        assert!(
+            !service
+                .code_available("company.revenue", None)
+                .await
+                .unwrap()
+        );
+        assert!(service.code_available("corp.revenue", None).await.unwrap());
+
+        // 全局唯一时忽略父节点。
+        let global = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&global, "company", None, BizMetadataStatus::Active).await;
+        add_feature(&global, "company.revenue", company).await;
+        assert!(
+            global
+                .code_available("group.revenue", Some(company))
+                .await
+                .unwrap()
+        );
+        assert!(
+            !global
+                .code_available("company.revenue", Some(company))
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn shared_codes_are_ambiguous_for_lookups_refs_and_imports() {
+        let service = BizMetadataService::new(
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent),
+        )
+        .with_code_uniqueness(CodeUniqueness::PerParent);
+        let aliases = InMemoryBizMetadataAliasRepository::new();
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let corp = add_node(&service, "corp", None, BizMetadataStatus::Active).await;
+        let first = add_feature(&service, "shared.amount", company).await;
+        let second = add_feature(&service, "shared.amount", corp).await;
+        let total = add_typed_feature(
+            &service,
+            "company.total",
+            company,
+            "decimal | ref:shared.amount",
+        )
+        .await;
+        let kept = add_feature(&service, "corp.kept", corp).await;
+
+        // 按编码查找无法在两条记录间取舍。
+        let err = service
+            .find_biz_metadata_by_code("shared.amount")
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_CODE_AMBIGUOUS);
+        assert!(!service.code_available("shared.amount", None).await.unwrap());
+
+        // `ref:shared.amount` 同时依赖两个同编码节点，删除任一都被拒绝。
+        for id in [first, second] {
+            let err = service
+                .delete_biz_metadata(id, Version::new(1).unwrap(), DependentAction::Restrict)
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), error_code::BIZ_METADATA_HAS_DEPENDENTS);
+        }
+
+        // 导入按编码匹配，命中共用编码时在写入前拒绝。
+        let mut dump = service.export_catalog(&aliases).await.unwrap();
+        dump.entries.retain(|entry| entry.code == "shared.amount");
+        dump.entries.truncate(1);
+        let err = service
+            .import_catalog(&aliases, dump, ImportMode::Upsert)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_CODE_AMBIGUOUS);
+
+        // 合并掉其中一个后引用仍可解析到另一个，类型引用保持不变。
+        service.merge(second, kept).await.unwrap();
+        let total = service
+            .find_biz_metadata_by_id(total)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            total.value_type().map(ValueType::as_str),
+            Some("decimal | ref:shared.amount")
+        );
+        let found = service
+            .find_biz_metadata_by_code("shared.amount")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id(), first);
+    }
+
+    #[tokio::test]
+    async fn touch_bumps_updated_at_and_version_with_optimistic_lock() {
+        let t0 = Utc::now() - Duration::days(1);
+        let clock = Arc::new(FixedClock::new(t0));
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_clock(Arc::clone(&clock));
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let created = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+
+        clock.advance(Duration::seconds(5));
+        let touched = service
+            .touch_biz_metadata(id, created.version())
+            .await
+            .unwrap();
+        assert_eq!(touched.updated_at(), t0 + Duration::seconds(5));
+        assert_eq!(touched.version(), created.version().next().unwrap());
+        assert_eq!(touched.name().as_str(), created.name().as_str());
+
+        let stale = service
+            .touch_biz_metadata(id, created.version())
+            .await
+            .unwrap_err();
+        assert!(is_version_conflict(&stale));
+
+        clock.set(t0);
+        let backwards = service
+            .touch_biz_metadata(id, touched.version())
+            .await
+            .unwrap_err();
+        assert!(matches!(backwards, DomainError::InvariantViolation { .. }));
+    }
+
+    #[tokio::test]
+    async fn audit_timestamps_come_from_injected_clock() {
+        let t0 = Utc::now() - Duration::days(1);
+        let clock = Arc::new(FixedClock::new(t0));
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_clock(Arc::clone(&clock));
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let created = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+        assert_eq!(created.created_at(), t0);
+        assert_eq!(created.updated_at(), t0);
+
+        clock.advance(Duration::seconds(5));
+        let updated = service
+            .update_biz_metadata(UpdateBizMetadataCommand {
+                id,
+                version: created.version(),
+                name: Some("Company".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.created_at(), t0);
+        assert_eq!(updated.updated_at(), t0 + Duration::seconds(5));
+
+        clock.advance(Duration::seconds(5));
+        service
+            .delete_biz_metadata(id, updated.version(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        let deleted = service
+            .repository()
+            .query_biz_metadata_changed_since(t0, true, QueryOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            deleted.items()[0].delete_at(),
+            Some(t0 + Duration::seconds(10))
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_change_value_type_rejects_non_features_up_front() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let feature = |code: &str| CreateBizMetadataCommand {
+            code: code.into(),
+            name: code.into(),
+            description: None,
+            object_type: ObjectType::Feature,
+            parent_id: None,
+            data_class: Some(DataClass::Attribute),
+            value_type: Some("int".into()),
+            unit: None,
+            status: None,
+            source: None,
+        };
+        let first = service
+            .create_biz_metadata(feature("order_no"))
+            .await
+            .unwrap();
+        let second = service
+            .create_biz_metadata(feature("sku_no"))
+            .await
+            .unwrap();
+        let entity = add_node(&service, "order", None, BizMetadataStatus::Active).await;
+        let widened = ValueType::new("int|string").unwrap();
+
+        let err = service
+            .bulk_change_value_type(vec![first.id(), entity], widened.clone())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH);
+        assert!(err.message().contains(&entity.value().to_string()));
+        let unchanged = service
+            .find_biz_metadata_by_id(first.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(unchanged.value_type().map(|v| v.as_str()), Some("int"));
+        assert_eq!(unchanged.version(), first.version());
+
+        let updated = service
+            .bulk_change_value_type(vec![first.id(), second.id(), first.id()], widened)
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+        for item in updated {
+            assert_eq!(item.value_type().map(|v| v.as_str()), Some("int|string"));
+            assert_eq!(i32::from(item.version()), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn find_including_deleted_tells_deleted_from_missing() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        service
+            .delete_biz_metadata(id, Version::new(1).unwrap(), DependentAction::Restrict)
+            .await
+            .unwrap();
+
+        let live = add_node(&service, "bank", None, BizMetadataStatus::Active).await;
+        let found = service
+            .find_by_id_including_deleted(live)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(!found.is_deleted());
+
+        assert!(service.find_biz_metadata_by_id(id).await.unwrap().is_none());
+        let deleted = service
+            .find_by_id_including_deleted(id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(deleted.is_deleted());
+        assert!(
+            service
+                .find_by_id_including_deleted(BizMetadataId::new(999))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn find_version_tracks_touches_and_hides_missing_or_deleted() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let touched = service
+            .touch_biz_metadata(id, Version::new(1).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            service.find_version(id).await.unwrap(),
+            Some(touched.version())
+        );
+        assert_eq!(i32::from(touched.version()), 2);
+        assert_eq!(
+            service.find_version(BizMetadataId::new(999)).await.unwrap(),
+            None
+        );
+
+        service
+            .delete_biz_metadata(id, touched.version(), DependentAction::Restrict)
+            .await
+            .unwrap();
+        assert_eq!(service.find_version(id).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn find_parent_returns_live_parent_only() {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        let company = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let finance = add_node(
+            &service,
+            "company.finance",
+            Some(company),
+            BizMetadataStatus::Active,
+        )
+        .await;
+        let load = |id| {
+            let service = &service;
+            async move { service.find_biz_metadata_by_id(id).await.unwrap().unwrap() }
+        };
+
+        let root = load(company).await;
+        assert!(service.find_parent(&root).await.unwrap().is_none());
+        let child = load(finance).await;
+        let parent = service.find_parent(&child).await.unwrap().unwrap();
+        assert_eq!(parent.id(), company);
+        assert_eq!(parent.code().as_str(), "company");
+
+        service
+            .delete_biz_metadata(company, root.version(), DependentAction::Detach)
+            .await
+            .unwrap();
+        // 分离后子节点成为根节点；直接以旧快照查询时父节点已软删除，同样不展开。
+        assert!(service.find_parent(&child).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn timestamp_precision_truncates_without_breaking_ordering() {
+        let t0 = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+        let clock = Arc::new(FixedClock::new(t0));
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new())
+            .with_clock(Arc::clone(&clock))
+            .with_timestamp_precision(TimestampPrecision::Millis);
+        let id = add_node(&service, "company", None, BizMetadataStatus::Active).await;
+        let created = service.find_biz_metadata_by_id(id).await.unwrap().unwrap();
+        let t0_millis = Utc.timestamp_opt(1_700_000_000, 123_000_000).unwrap();
+        assert_eq!(created.created_at(), t0_millis);
+        assert_eq!(created.updated_at(), t0_millis);
+
+        // 同一毫秒内的更新截断后与创建时间相等，仍满足不回退。
+        clock.advance(Duration::microseconds(400));
+        let same_millis = service
+            .touch_biz_metadata(id, created.version())
+            .await
+            .unwrap();
+        assert_eq!(same_millis.updated_at(), t0_millis);
+
+        clock.advance(Duration::milliseconds(2));
+        let later = service
+            .touch_biz_metadata(id, same_millis.version())
+            .await
+            .unwrap();
+        assert_eq!(
+            later.updated_at(),
+            Utc.timestamp_opt(1_700_000_000, 125_000_000).unwrap()
+        );
+        assert!(later.updated_at() >= later.created_at());
+        assert_eq!(later.created_at(), t0_millis);
+    }
 }
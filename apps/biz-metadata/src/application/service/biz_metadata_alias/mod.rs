@@ -3,5 +3,5 @@ pub mod query;
 pub mod service;
 
 pub use command::{AliasFieldUpdate, CreateBizMetadataAliasCommand, UpdateBizMetadataAliasCommand};
-pub use query::BizMetadataAliasQueryRequest;
-pub use service::BizMetadataAliasService;
+pub use query::{AliasResolution, BizMetadataAliasQueryRequest, LanguageScope};
+pub use service::{BizMetadataAliasService, DEFAULT_MAX_ALIASES_PER_METADATA};
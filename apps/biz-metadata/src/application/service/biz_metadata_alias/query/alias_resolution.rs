@@ -0,0 +1,11 @@
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+use crate::domain::biz_metadata_alias::value_object::LanguageCode;
+
+/// 按语言偏好解析术语的结果，见 `BizMetadataAliasService::resolve`。
+#[derive(Debug, Clone)]
+pub struct AliasResolution {
+    /// 实际命中的语言；未指定偏好（搜索全部语言）时为 `None`。
+    pub language: Option<LanguageCode>,
+    /// 该语言下文本与术语相同的存活别名，按 ID 升序，至少一条。
+    pub aliases: Vec<BizMetadataAlias>,
+}
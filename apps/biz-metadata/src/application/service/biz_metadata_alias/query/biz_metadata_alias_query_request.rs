@@ -1,19 +1,58 @@
+use domain_core::domain_error::DomainError;
 use domain_core::expression::{Expression, QueryOptions};
 
-/// 别名查询请求，封装筛选表达式与分页排序。
+use crate::domain::biz_metadata_alias::value_object::LanguageCode;
+
+/// 别名查询请求，封装筛选表达式、语言范围与分页排序。
 ///
 /// # Examples
 /// ```
-/// use biz_metadata::BizMetadataAliasQueryRequest;
+/// use biz_metadata::{BizMetadataAliasQueryRequest, LanguageScope};
 /// use domain_core::expression::{Expression, QueryOptions};
 ///
 /// let req = BizMetadataAliasQueryRequest {
 ///     expression: Expression::True,
+///     language: LanguageScope::Default,
 ///     options: QueryOptions::default(),
 /// };
 /// assert!(matches!(req.expression, Expression::True));
 /// ```
 pub struct BizMetadataAliasQueryRequest {
     pub expression: Expression,
+    pub language: LanguageScope,
     pub options: QueryOptions,
 }
+
+/// 别名查询的语言范围。
+///
+/// # Examples
+/// ```
+/// use biz_metadata::{LanguageCode, LanguageScope};
+///
+/// assert_eq!(LanguageScope::parse("ALL").unwrap(), LanguageScope::All);
+/// assert_eq!(
+///     LanguageScope::parse("en-US").unwrap(),
+///     LanguageScope::Only(LanguageCode::new("en-US").unwrap())
+/// );
+/// assert!(LanguageScope::parse("").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum LanguageScope {
+    /// 使用服务配置的默认语言，未配置时不过滤。
+    #[default]
+    Default,
+    /// 不按语言过滤，对应查询参数 `language=all`。
+    All,
+    /// 仅返回指定语言的别名。
+    Only(LanguageCode),
+}
+
+impl LanguageScope {
+    /// 解析查询参数：`all`（不区分大小写）表示全部语言，其余按语言编码校验。
+    pub fn parse(raw: &str) -> Result<Self, DomainError> {
+        if raw.eq_ignore_ascii_case("all") {
+            return Ok(Self::All);
+        }
+        LanguageCode::new(raw).map(Self::Only)
+    }
+}
@@ -1,3 +1,5 @@
+pub mod alias_resolution;
 pub mod biz_metadata_alias_query_request;
 
-pub use biz_metadata_alias_query_request::BizMetadataAliasQueryRequest;
+pub use alias_resolution::AliasResolution;
+pub use biz_metadata_alias_query_request::{BizMetadataAliasQueryRequest, LanguageScope};
@@ -1,12 +1,35 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use domain_core::audit::TimestampPrecision;
+use domain_core::clock::{Clock, SystemClock};
 use domain_core::domain_error::DomainError;
+use domain_core::expression::{Comparison, Expression, FilterValue, OrderBy, QueryOptions, eq};
 use domain_core::pagination::PageResult;
 
 use crate::application::service::biz_metadata_alias::command::{
     AliasFieldUpdate, CreateBizMetadataAliasCommand, UpdateBizMetadataAliasCommand,
 };
-use crate::application::service::biz_metadata_alias::query::BizMetadataAliasQueryRequest;
-use crate::domain::biz_metadata_alias::value_object::{AliasText, BizMetadataAliasId};
-use crate::domain::biz_metadata_alias::{BizMetadataAlias, BizMetadataAliasRepository};
+use crate::application::service::biz_metadata_alias::query::{
+    AliasResolution, BizMetadataAliasQueryRequest, LanguageScope,
+};
+use crate::domain::biz_metadata::repository::ensure_known_fields;
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::biz_metadata_alias::repository::{
+    alias_limit_exceeded, collect_aliases_of, collect_aliases_where, reassign_aliases,
+};
+use crate::domain::biz_metadata_alias::value_object::{
+    AliasNormalization, AliasText, AliasWeight, BizMetadataAliasId, LanguageCode,
+};
+use crate::domain::biz_metadata_alias::{
+    AliasPrimaryChange, BizMetadataAlias, BizMetadataAliasRepository, PrimaryWeightPolicy,
+};
+use crate::domain::error_code;
+
+/// 单个元数据默认允许的存活别名上限。
+pub const DEFAULT_MAX_ALIASES_PER_METADATA: usize = 50;
 
 /// 元数据别名的应用服务，协调命令与查询。
 pub struct BizMetadataAliasService<R>
@@ -14,6 +37,12 @@ where
     R: BizMetadataAliasRepository,
 {
     repository: R,
+    clock: Arc<dyn Clock>,
+    timestamp_precision: TimestampPrecision,
+    default_language: Option<LanguageCode>,
+    strict_query: bool,
+    primary_weight_policy: PrimaryWeightPolicy,
+    max_aliases_per_metadata: usize,
 }
 
 impl<R> BizMetadataAliasService<R>
@@ -22,31 +51,154 @@ where
 {
     /// 构造服务。
     pub fn new(repository: R) -> Self {
-        Self { repository }
+        Self {
+            repository,
+            clock: Arc::new(SystemClock),
+            timestamp_precision: TimestampPrecision::default(),
+            default_language: None,
+            strict_query: false,
+            primary_weight_policy: PrimaryWeightPolicy::default(),
+            max_aliases_per_metadata: DEFAULT_MAX_ALIASES_PER_METADATA,
+        }
     }
 
-    /// 创建别名。
+    /// 设置审计时间来源，默认读取系统时间。
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// 设置审计时间的保留精度，默认完整精度。
+    pub fn with_timestamp_precision(mut self, timestamp_precision: TimestampPrecision) -> Self {
+        self.timestamp_precision = timestamp_precision;
+        self
+    }
+
+    /// 设置查询是否严格校验字段，默认关闭；开启后未登记的过滤与排序字段返回 `query.field_unknown`。
+    pub fn with_strict_query(mut self, strict_query: bool) -> Self {
+        self.strict_query = strict_query;
+        self
+    }
+
+    /// 设置首选别名的权重策略，默认不检查；创建、更新、批量调权与提升首选时生效。
+    pub fn with_primary_weight_policy(mut self, policy: PrimaryWeightPolicy) -> Self {
+        self.primary_weight_policy = policy;
+        self
+    }
+
+    /// 设置单个元数据的存活别名上限，默认 [`DEFAULT_MAX_ALIASES_PER_METADATA`]；已软删除的别名不计入。
+    pub fn with_max_aliases_per_metadata(mut self, limit: usize) -> Self {
+        self.max_aliases_per_metadata = limit;
+        self
+    }
+
+    /// 按配置精度截断后的当前时间。
+    fn now(&self) -> DateTime<Utc> {
+        self.timestamp_precision.truncate(self.clock.now())
+    }
+
+    /// 设置查询的默认语言：请求未指定语言时仅返回该语言的别名，`language=all` 可绕过。
+    pub fn with_default_language(mut self, language: LanguageCode) -> Self {
+        self.default_language = Some(language);
+        self
+    }
+
+    /// 创建别名；元数据的存活别名数将超过上限时返回 `biz_metadata_alias.limit_exceeded`。
     pub async fn create_alias(
         &self,
         cmd: CreateBizMetadataAliasCommand,
     ) -> Result<BizMetadataAlias, DomainError> {
-        let mut alias = BizMetadataAlias::new(cmd.metadata_id, cmd.alias)?;
+        let alias = self.build_alias(cmd, self.now())?;
+        let mut inserted = self
+            .repository
+            .insert_alias_many(vec![alias], self.max_aliases_per_metadata)
+            .await?;
+        inserted.pop().ok_or_else(|| DomainError::Persistence {
+            code: error_code::PERSISTENCE_ROW_MISSING,
+            message: "biz_metadata_alias not returned after insert".into(),
+        })
+    }
+
+    /// 批量创建别名，按输入顺序返回，任一失败时整体不写入。
+    ///
+    /// 上限按每个元数据插入后的存活别名总数校验，而非逐条校验；计数与插入由仓储在同一事务内完成。
+    pub async fn create_many(
+        &self,
+        cmds: Vec<CreateBizMetadataAliasCommand>,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        let now = self.now();
+        let aliases = cmds
+            .into_iter()
+            .map(|cmd| self.build_alias(cmd, now))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.repository
+            .insert_alias_many(aliases, self.max_aliases_per_metadata)
+            .await
+    }
+
+    /// 校验 `metadata_id` 再新增 `adding` 个别名后不超过存活别名上限。
+    async fn ensure_alias_capacity(
+        &self,
+        metadata_id: BizMetadataId,
+        adding: u64,
+    ) -> Result<(), DomainError> {
+        let total = self.repository.count_live_aliases(metadata_id).await? + adding;
+        if total > self.max_aliases_per_metadata as u64 {
+            return Err(alias_limit_exceeded(
+                metadata_id,
+                total,
+                self.max_aliases_per_metadata,
+            ));
+        }
+        Ok(())
+    }
+
+    /// 由创建命令构造别名并应用首选权重策略。
+    fn build_alias(
+        &self,
+        cmd: CreateBizMetadataAliasCommand,
+        now: DateTime<Utc>,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        let mut alias = BizMetadataAlias::new(cmd.metadata_id, cmd.alias, now)?;
         if let Some(src) = cmd.source {
-            alias.change_source(src)?;
+            alias.change_source(src, now)?;
         }
         if let Some(weight) = cmd.weight {
-            alias.change_weight(weight.value())?;
+            alias.change_weight(weight.value(), now)?;
         }
         if let Some(is_primary) = cmd.is_primary {
-            alias.set_primary(is_primary)?;
+            alias.set_primary(is_primary, now)?;
         }
         if let Some(lang) = cmd.language {
-            alias.change_language(lang)?;
+            alias.change_language(lang, now)?;
         }
-        self.repository.insert_alias(alias).await
+        self.primary_weight_policy.apply(&mut alias, now)?;
+        Ok(alias)
+    }
+
+    /// 返回 `metadata_id` 下文本与语言都相同的存活别名，不存在时创建，供同步任务幂等地重复提交同义词。
+    ///
+    /// 查找与创建由仓储在单个事务内完成，并依赖存活别名唯一索引，并发调用不会产生重复别名；
+    /// 需要创建且存活别名数将超过上限时返回 `biz_metadata_alias.limit_exceeded`。
+    pub async fn find_or_create(
+        &self,
+        metadata_id: BizMetadataId,
+        alias: impl Into<String>,
+        language: LanguageCode,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        let now = self.now();
+        let mut candidate = BizMetadataAlias::new(metadata_id, alias, now)?;
+        candidate.change_language(language, now)?;
+        self.repository
+            .find_or_insert_alias(candidate, self.max_aliases_per_metadata)
+            .await
     }
 
     /// 更新别名。
+    ///
+    /// 所属元数据、文本或语言发生变化时，先检查同一元数据下是否已有文本与语言相同的其他存活别名，
+    /// 存在时返回 `biz_metadata_alias.duplicate`；存活别名唯一索引仍是并发下的最终防线。
+    /// 改挂到其他元数据时，目标的存活别名数将超过上限则返回 `biz_metadata_alias.limit_exceeded`。
     pub async fn update_alias(
         &self,
         cmd: UpdateBizMetadataAliasCommand,
@@ -56,39 +208,269 @@ where
             .find_alias_by_id(cmd.id)
             .await?
             .ok_or_else(|| DomainError::Validation {
+                code: error_code::ALIAS_NOT_FOUND,
                 message: format!("biz_metadata_alias {} not found", cmd.id.value()),
             })?;
 
+        let original_key = alias_key(&alias);
+        let now = self.now();
         if let Some(metadata_id) = cmd.metadata_id {
-            alias.change_metadata_id(metadata_id)?;
+            alias.change_metadata_id(metadata_id, now)?;
         }
 
         match cmd.alias {
             AliasFieldUpdate::Keep => {}
-            AliasFieldUpdate::Set(value) => alias.update_alias(AliasText::new(value)?)?,
+            AliasFieldUpdate::Set(value) => alias.update_alias(AliasText::new(value)?, now)?,
             AliasFieldUpdate::Clear => {
                 return Err(DomainError::Validation {
+                    code: error_code::ALIAS_REQUIRED,
                     message: "alias cannot be cleared".into(),
                 });
             }
         }
 
         if let Some(src) = cmd.source {
-            alias.change_source(src)?;
+            alias.change_source(src, now)?;
         }
         if let Some(weight) = cmd.weight {
-            alias.change_weight(weight.value())?;
+            alias.change_weight(weight.value(), now)?;
         }
         if let Some(is_primary) = cmd.is_primary {
-            alias.set_primary(is_primary)?;
+            alias.set_primary(is_primary, now)?;
         }
         if let Some(lang) = cmd.language {
-            alias.change_language(lang)?;
+            alias.change_language(lang, now)?;
         }
+        self.primary_weight_policy.apply(&mut alias, now)?;
 
+        if alias.delete_at().is_none() && alias_key(&alias) != original_key {
+            self.ensure_no_live_duplicate(&alias).await?;
+        }
+        if alias.delete_at().is_none() && alias.metadata_id() != original_key.0 {
+            self.ensure_alias_capacity(alias.metadata_id(), 1).await?;
+        }
         self.repository.update_alias(alias).await
     }
 
+    /// 校验同一元数据下没有与 `alias` 文本、语言都相同的其他存活别名。
+    async fn ensure_no_live_duplicate(&self, alias: &BizMetadataAlias) -> Result<(), DomainError> {
+        let filter = Expression::and(vec![
+            Expression::cmp(eq("metadata_id", alias.metadata_id().value())),
+            Expression::cmp(eq("alias", alias.alias().as_str())),
+            Expression::cmp(eq("language", alias.language().as_str())),
+        ]);
+        let conflicts = collect_aliases_where(&self.repository, filter, |other| {
+            other.delete_at().is_none() && other.id() != alias.id()
+        })
+        .await?;
+        match conflicts.first() {
+            Some(existing) => Err(DomainError::Validation {
+                code: error_code::ALIAS_DUPLICATE,
+                message: format!(
+                    "alias {} ({}) already exists as biz_metadata_alias {} of biz_metadata {}",
+                    alias.alias().as_str(),
+                    alias.language().as_str(),
+                    existing.id().value(),
+                    alias.metadata_id().value()
+                ),
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// 批量设置别名权重，返回更新后的别名；同一 id 出现多次时以最后一次为准。
+    ///
+    /// 写入前先校验全部权重并加载全部别名：越界权重或不存在（含已删除）的 id 汇总在同一个错误中整批拒绝，
+    /// 不做任何写入。写入通过 [`BizMetadataAliasRepository::update_alias_many`] 在单个事务内完成。
+    pub async fn bulk_set_weights(
+        &self,
+        updates: Vec<(BizMetadataAliasId, i32)>,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        let out_of_range: Vec<_> = updates
+            .iter()
+            .filter(|(_, weight)| AliasWeight::new(*weight).is_err())
+            .map(|(id, weight)| (id.value(), *weight))
+            .collect();
+        if !out_of_range.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::ALIAS_WEIGHT_INVALID,
+                message: format!("weights must be between 0 and 100, got {out_of_range:?}"),
+            });
+        }
+
+        let mut positions = HashMap::new();
+        let mut items: Vec<BizMetadataAlias> = Vec::with_capacity(updates.len());
+        let mut missing = Vec::new();
+        let now = self.now();
+        for (id, weight) in updates {
+            let index = match positions.get(&id) {
+                Some(&index) => index,
+                None => match self.repository.find_alias_by_id(id).await? {
+                    Some(alias) if alias.delete_at().is_none() => {
+                        positions.insert(id, items.len());
+                        items.push(alias);
+                        items.len() - 1
+                    }
+                    _ => {
+                        missing.push(id.value());
+                        continue;
+                    }
+                },
+            };
+            items[index].change_weight(weight, now)?;
+        }
+        if !missing.is_empty() {
+            return Err(DomainError::Validation {
+                code: error_code::ALIAS_NOT_FOUND,
+                message: format!("biz_metadata_alias {missing:?} not found"),
+            });
+        }
+        for item in &mut items {
+            self.primary_weight_policy.apply(item, now)?;
+        }
+        self.repository.update_alias_many(items).await
+    }
+
+    /// 将 `from_metadata_id` 下的全部存活别名迁移到 `to_metadata_id`，供合并重复元数据时使用，返回迁移后的别名。
+    ///
+    /// 与目标下已有别名（或先迁移的别名）文本与语言都相同的别名视为重复，直接软删除；
+    /// 目标已有首选别名时迁入的别名一律取消首选，否则保留 ID 最小的一个首选。
+    /// 迁移后目标的存活别名数超过上限时返回 `biz_metadata_alias.limit_exceeded`，不做任何变更。
+    /// 全部变更通过 [`BizMetadataAliasRepository::update_alias_many`] 在单个事务内完成；两个 ID 相同时不做任何变更。
+    pub async fn reassign(
+        &self,
+        from_metadata_id: BizMetadataId,
+        to_metadata_id: BizMetadataId,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        reassign_aliases(
+            &self.repository,
+            from_metadata_id,
+            to_metadata_id,
+            self.now(),
+            self.max_aliases_per_metadata,
+        )
+        .await
+    }
+
+    /// 将 `alias_id` 提升为 `metadata_id` 的首选别名：降级当前首选、提升目标并记录切换历史，
+    /// 由仓储在单个事务内完成。目标已是首选时不做任何变更。
+    pub async fn promote_primary(
+        &self,
+        metadata_id: BizMetadataId,
+        alias_id: BizMetadataAliasId,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        let mut target = self
+            .repository
+            .find_alias_by_id(alias_id)
+            .await?
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::ALIAS_NOT_FOUND,
+                message: format!("biz_metadata_alias {} not found", alias_id.value()),
+            })?;
+        if target.metadata_id() != metadata_id {
+            return Err(DomainError::Validation {
+                code: error_code::ALIAS_METADATA_MISMATCH,
+                message: format!(
+                    "biz_metadata_alias {} belongs to biz_metadata {}, not {}",
+                    alias_id.value(),
+                    target.metadata_id().value(),
+                    metadata_id.value()
+                ),
+            });
+        }
+        if target.is_primary() {
+            return Ok(target);
+        }
+
+        let now = self.now();
+        let current = self
+            .repository
+            .query_alias(
+                Expression::and(vec![
+                    Expression::cmp(eq("metadata_id", metadata_id.value())),
+                    Expression::cmp(eq("is_primary", true)),
+                ]),
+                QueryOptions::default().with_order_by(OrderBy::asc("id")),
+            )
+            .await?
+            .into_items();
+        let mut demoted = Vec::with_capacity(current.len());
+        for mut alias in current {
+            alias.set_primary(false, now)?;
+            demoted.push(alias);
+        }
+        target.set_primary(true, now)?;
+        self.primary_weight_policy.apply(&mut target, now)?;
+
+        let change = AliasPrimaryChange {
+            metadata_id,
+            promoted: alias_id,
+            demoted: demoted.first().map(BizMetadataAlias::id),
+            changed_at: now,
+        };
+        self.repository
+            .swap_primary_alias(demoted, target, change)
+            .await
+    }
+
+    /// 返回 `metadata_id` 的有效首选别名，仅读取不改写数据；没有存活别名时返回 `None`。
+    ///
+    /// 依次取：显式首选别名；否则权重最高者；权重相同时取 ID 最小者。多个显式首选（脏数据）时同样取 ID 最小者。
+    pub async fn effective_primary(
+        &self,
+        metadata_id: BizMetadataId,
+    ) -> Result<Option<BizMetadataAlias>, DomainError> {
+        let alive = collect_aliases_of(&self.repository, metadata_id, |alias| {
+            alias.delete_at().is_none()
+        })
+        .await?;
+        Ok(alive.into_iter().min_by_key(|alias| {
+            (
+                !alias.is_primary(),
+                Reverse(alias.weight().value()),
+                alias.id().value(),
+            )
+        }))
+    }
+
+    /// 按语言偏好解析术语：依次在 `preferred_languages` 中查找文本与 `term` 相同的存活别名，
+    /// 返回第一个有命中的语言及其别名；全部语言都未命中时返回 `None`。
+    ///
+    /// `preferred_languages` 为空时不限语言，返回全部命中且 `language` 为 `None`。
+    pub async fn resolve(
+        &self,
+        term: &str,
+        preferred_languages: Vec<LanguageCode>,
+    ) -> Result<Option<AliasResolution>, DomainError> {
+        let term = AliasText::new(term.trim())?;
+        let matches = collect_aliases_where(
+            &self.repository,
+            Expression::cmp(eq("alias", term.as_str())),
+            |alias| alias.delete_at().is_none(),
+        )
+        .await?;
+        if matches.is_empty() {
+            return Ok(None);
+        }
+        if preferred_languages.is_empty() {
+            return Ok(Some(AliasResolution {
+                language: None,
+                aliases: matches,
+            }));
+        }
+        Ok(preferred_languages.into_iter().find_map(|language| {
+            let aliases: Vec<_> = matches
+                .iter()
+                .filter(|alias| *alias.language() == language)
+                .cloned()
+                .collect();
+            (!aliases.is_empty()).then_some(AliasResolution {
+                language: Some(language),
+                aliases,
+            })
+        }))
+    }
+
     /// 删除别名。
     pub async fn delete_alias(&self, id: BizMetadataAliasId) -> Result<(), DomainError> {
         self.repository.delete_alias(id).await
@@ -103,12 +485,32 @@ where
     }
 
     /// 分页查询别名。
+    ///
+    /// 针对 `alias` 字段的字符串取值先按 NFKC 规范化，与入库文本的形式一致。
     pub async fn query_alias(
         &self,
         request: BizMetadataAliasQueryRequest,
     ) -> Result<PageResult<BizMetadataAlias>, DomainError> {
+        if self.strict_query
+            && let Some(fields) = self.repository.query_fields()
+        {
+            ensure_known_fields(&fields, &request.expression, &request.options.order_bys)?;
+        }
+        let language = match request.language {
+            LanguageScope::Default => self.default_language.clone(),
+            LanguageScope::All => None,
+            LanguageScope::Only(language) => Some(language),
+        };
+        let expression = normalize_alias_filter(request.expression);
+        let expression = match language {
+            Some(language) => Expression::and(vec![
+                expression,
+                Expression::cmp(eq("language", language.as_str())),
+            ]),
+            None => expression,
+        };
         self.repository
-            .query_alias(request.expression, request.options)
+            .query_alias(expression, request.options)
             .await
     }
 
@@ -117,3 +519,670 @@ where
         &self.repository
     }
 }
+
+/// 将表达式中作用于 `alias` 字段的字符串取值按 NFKC 规范化，其余条件原样保留。
+fn normalize_alias_filter(expr: Expression) -> Expression {
+    let normalize = |value: FilterValue| match value {
+        FilterValue::String(raw) => FilterValue::String(AliasNormalization::Nfkc.apply(&raw)),
+        other => other,
+    };
+    match expr {
+        Expression::Comparison(comparison) if comparison.field() == "alias" => {
+            Expression::Comparison(match comparison {
+                Comparison::Eq { field, value } => Comparison::Eq {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Ne { field, value } => Comparison::Ne {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Gt { field, value } => Comparison::Gt {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Ge { field, value } => Comparison::Ge {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Lt { field, value } => Comparison::Lt {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Le { field, value } => Comparison::Le {
+                    field,
+                    value: normalize(value),
+                },
+                Comparison::Between { field, start, end } => Comparison::Between {
+                    field,
+                    start: normalize(start),
+                    end: normalize(end),
+                },
+                Comparison::In { field, values } => Comparison::In {
+                    field,
+                    values: values.into_iter().map(normalize).collect(),
+                },
+                Comparison::Contains { field, value } => Comparison::Contains {
+                    field,
+                    value: normalize(value),
+                },
+            })
+        }
+        Expression::And(exprs) => {
+            Expression::And(exprs.into_iter().map(normalize_alias_filter).collect())
+        }
+        Expression::Or(exprs) => {
+            Expression::Or(exprs.into_iter().map(normalize_alias_filter).collect())
+        }
+        Expression::Not(inner) => Expression::Not(Box::new(normalize_alias_filter(*inner))),
+        other => other,
+    }
+}
+
+/// 别名的唯一性键：(所属元数据, 文本, 语言)。
+fn alias_key(alias: &BizMetadataAlias) -> (BizMetadataId, String, String) {
+    (
+        alias.metadata_id(),
+        alias.alias().as_str().to_string(),
+        alias.language().as_str().to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_alias_repository::InMemoryBizMetadataAliasRepository;
+    use chrono::Utc;
+
+    async fn alias(
+        service: &BizMetadataAliasService<InMemoryBizMetadataAliasRepository>,
+        metadata_id: i64,
+        text: &str,
+        is_primary: bool,
+    ) -> BizMetadataAlias {
+        let mut alias =
+            BizMetadataAlias::new(BizMetadataId::new(metadata_id), text, Utc::now()).unwrap();
+        alias.set_primary(is_primary, Utc::now()).unwrap();
+        service.repository().insert_alias(alias).await.unwrap()
+    }
+
+    fn create_cmd(metadata_id: i64, text: &str) -> CreateBizMetadataAliasCommand {
+        CreateBizMetadataAliasCommand {
+            metadata_id: BizMetadataId::new(metadata_id),
+            alias: text.into(),
+            source: None,
+            weight: None,
+            is_primary: None,
+            language: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn create_rejects_aliases_past_the_limit_ignoring_deleted_ones() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_max_aliases_per_metadata(2);
+        let mut deleted = alias(&service, 1, "营收", false).await;
+        deleted.mark_deleted(Utc::now()).unwrap();
+        service.repository().update_alias(deleted).await.unwrap();
+        alias(&service, 2, "收入", false).await;
+
+        service
+            .create_alias(create_cmd(1, "营业收入"))
+            .await
+            .unwrap();
+        service.create_alias(create_cmd(1, "销售额")).await.unwrap();
+        let err = service
+            .create_alias(create_cmd(1, "总收入"))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        service.create_alias(create_cmd(2, "进账")).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn create_many_checks_the_post_insert_total_per_metadata() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_max_aliases_per_metadata(2);
+        alias(&service, 1, "营收", false).await;
+
+        let err = service
+            .create_many(vec![create_cmd(1, "营业收入"), create_cmd(1, "销售额")])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        let live = collect_aliases_of(service.repository(), BizMetadataId::new(1), |_| true)
+            .await
+            .unwrap();
+        assert_eq!(live.len(), 1);
+
+        let created = service
+            .create_many(vec![
+                create_cmd(1, "营业收入"),
+                create_cmd(2, "收入"),
+                create_cmd(2, "进账"),
+            ])
+            .await
+            .unwrap();
+        let texts: Vec<_> = created.iter().map(|a| a.alias().as_str()).collect();
+        assert_eq!(texts, ["营业收入", "收入", "进账"]);
+    }
+
+    #[tokio::test]
+    async fn update_rejects_collision_with_another_live_alias() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        alias(&service, 1, "营收", false).await;
+        let other = alias(&service, 1, "营业收入", false).await;
+
+        let err = service
+            .update_alias(UpdateBizMetadataAliasCommand {
+                id: other.id(),
+                alias: AliasFieldUpdate::Set("营收".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_DUPLICATE);
+        let unchanged = service.find_by_id(other.id()).await.unwrap().unwrap();
+        assert_eq!(unchanged.alias().as_str(), "营业收入");
+    }
+
+    #[tokio::test]
+    async fn update_allows_text_freed_by_deleted_or_other_metadata() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let mut deleted = alias(&service, 1, "营收", false).await;
+        deleted.mark_deleted(Utc::now()).unwrap();
+        service.repository().update_alias(deleted).await.unwrap();
+        alias(&service, 2, "收入", false).await;
+        let target = alias(&service, 1, "营业收入", false).await;
+
+        let renamed = service
+            .update_alias(UpdateBizMetadataAliasCommand {
+                id: target.id(),
+                alias: AliasFieldUpdate::Set("营收".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(renamed.alias().as_str(), "营收");
+        let renamed = service
+            .update_alias(UpdateBizMetadataAliasCommand {
+                id: target.id(),
+                alias: AliasFieldUpdate::Set("收入".into()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        assert_eq!(renamed.alias().as_str(), "收入");
+    }
+
+    #[tokio::test]
+    async fn promote_primary_swaps_and_records_history() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let old = alias(&service, 1, "营收", true).await;
+        let better = alias(&service, 1, "营业收入", false).await;
+        let metadata_id = BizMetadataId::new(1);
+
+        let promoted = service
+            .promote_primary(metadata_id, better.id())
+            .await
+            .unwrap();
+        assert!(promoted.is_primary());
+        let old = service.find_by_id(old.id()).await.unwrap().unwrap();
+        assert!(!old.is_primary());
+
+        let history = service.repository().primary_history();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].promoted, better.id());
+        assert_eq!(history[0].demoted, Some(old.id()));
+
+        // 再次提升同一别名为空操作，不追加历史。
+        service
+            .promote_primary(metadata_id, better.id())
+            .await
+            .unwrap();
+        assert_eq!(service.repository().primary_history().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn primary_weight_policy_rejects_or_raises_low_weight_primaries() {
+        let floor = AliasWeight::new(60).unwrap();
+        let strict = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_primary_weight_policy(PrimaryWeightPolicy::Require(floor));
+        let err = strict
+            .create_alias(CreateBizMetadataAliasCommand {
+                metadata_id: BizMetadataId::new(1),
+                alias: "营收".into(),
+                source: None,
+                weight: None,
+                is_primary: Some(true),
+                language: None,
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_PRIMARY_WEIGHT_TOO_LOW);
+        let created = strict
+            .create_alias(CreateBizMetadataAliasCommand {
+                metadata_id: BizMetadataId::new(1),
+                alias: "营收".into(),
+                source: None,
+                weight: Some(floor),
+                is_primary: Some(true),
+                language: None,
+            })
+            .await
+            .unwrap();
+        let err = strict
+            .bulk_set_weights(vec![(created.id(), 10)])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_PRIMARY_WEIGHT_TOO_LOW);
+
+        let raising = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_primary_weight_policy(PrimaryWeightPolicy::Raise(floor));
+        let candidate = alias(&raising, 1, "营业收入", false).await;
+        assert_eq!(candidate.weight().value(), 0);
+        let promoted = raising
+            .promote_primary(BizMetadataId::new(1), candidate.id())
+            .await
+            .unwrap();
+        assert_eq!(promoted.weight().value(), 60);
+    }
+
+    #[tokio::test]
+    async fn promote_primary_rejects_alias_of_other_metadata() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let primary = alias(&service, 1, "营收", true).await;
+        let foreign = alias(&service, 2, "净利润", false).await;
+
+        let err = service
+            .promote_primary(BizMetadataId::new(1), foreign.id())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_METADATA_MISMATCH);
+
+        let primary = service.find_by_id(primary.id()).await.unwrap().unwrap();
+        assert!(primary.is_primary());
+        assert!(service.repository().primary_history().is_empty());
+    }
+
+    #[tokio::test]
+    async fn effective_primary_falls_back_by_weight_then_id() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let metadata_id = BizMetadataId::new(1);
+        assert!(
+            service
+                .effective_primary(metadata_id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // 权重相同：取 ID 最小者。
+        let first = alias(&service, 1, "营收", false).await;
+        alias(&service, 1, "营业收入", false).await;
+        let picked = service.effective_primary(metadata_id).await.unwrap();
+        assert_eq!(picked.unwrap().id(), first.id());
+
+        // 权重更高者优先，已删除的别名不参与。
+        let mut heavy = alias(&service, 1, "收入", false).await;
+        heavy.change_weight(90, Utc::now()).unwrap();
+        let heavy = service.repository().update_alias(heavy).await.unwrap();
+        let mut heavier = alias(&service, 1, "总收入", false).await;
+        heavier.change_weight(99, Utc::now()).unwrap();
+        heavier.mark_deleted(Utc::now()).unwrap();
+        service.repository().update_alias(heavier).await.unwrap();
+        let picked = service.effective_primary(metadata_id).await.unwrap();
+        assert_eq!(picked.unwrap().id(), heavy.id());
+
+        // 显式首选优先于权重。
+        let primary = alias(&service, 1, "营收额", true).await;
+        alias(&service, 2, "净利润", true).await;
+        let picked = service.effective_primary(metadata_id).await.unwrap();
+        assert_eq!(picked.unwrap().id(), primary.id());
+    }
+
+    #[tokio::test]
+    async fn find_or_create_returns_existing_alias() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let existing = alias(&service, 1, "营收", false).await;
+
+        let found = service
+            .find_or_create(BizMetadataId::new(1), "营收", existing.language().clone())
+            .await
+            .unwrap();
+        assert_eq!(found.id(), existing.id());
+        let all = service
+            .repository()
+            .query_alias(Expression::True, QueryOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(all.into_items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn find_or_create_creates_missing_alias_once() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let existing = alias(&service, 1, "营收", false).await;
+        let english = LanguageCode::new("en").unwrap();
+
+        let created = service
+            .find_or_create(BizMetadataId::new(1), "营收", english.clone())
+            .await
+            .unwrap();
+        assert_ne!(created.id(), existing.id());
+        assert_eq!(created.language(), &english);
+
+        let again = service
+            .find_or_create(BizMetadataId::new(1), "营收", english)
+            .await
+            .unwrap();
+        assert_eq!(again.id(), created.id());
+    }
+
+    #[tokio::test]
+    async fn find_or_create_enforces_the_limit_only_when_creating() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_max_aliases_per_metadata(1);
+        let existing = alias(&service, 1, "营收", false).await;
+        let zh = existing.language().clone();
+
+        let found = service
+            .find_or_create(BizMetadataId::new(1), "营收", zh.clone())
+            .await
+            .unwrap();
+        assert_eq!(found.id(), existing.id());
+
+        let err = service
+            .find_or_create(BizMetadataId::new(1), "收入", zh)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        assert_eq!(
+            service
+                .repository()
+                .count_live_aliases(BizMetadataId::new(1))
+                .await
+                .unwrap(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn update_rejects_moving_into_a_full_metadata() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_max_aliases_per_metadata(1);
+        alias(&service, 2, "收入", false).await;
+        let moving = alias(&service, 1, "营收", false).await;
+
+        let err = service
+            .update_alias(UpdateBizMetadataAliasCommand {
+                id: moving.id(),
+                metadata_id: Some(BizMetadataId::new(2)),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        let unchanged = service.find_by_id(moving.id()).await.unwrap().unwrap();
+        assert_eq!(unchanged.metadata_id(), BizMetadataId::new(1));
+    }
+
+    #[tokio::test]
+    async fn alias_filter_values_are_normalized_like_stored_text() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        alias(&service, 1, "GMV", false).await;
+        alias(&service, 1, "营收", false).await;
+
+        let request = |expression| BizMetadataAliasQueryRequest {
+            expression,
+            language: LanguageScope::All,
+            options: QueryOptions::default(),
+        };
+        for expression in [
+            Expression::cmp(eq("alias", "ＧＭＶ")),
+            Expression::negate(Expression::negate(Expression::cmp(
+                domain_core::expression::contains("alias", "ＭＶ"),
+            ))),
+            Expression::or(vec![Expression::cmp(domain_core::expression::r#in(
+                "alias",
+                vec!["ＧＭＶ"],
+            ))]),
+        ] {
+            let page = service.query_alias(request(expression)).await.unwrap();
+            let texts: Vec<_> = page
+                .into_items()
+                .iter()
+                .map(|alias| alias.alias().as_str().to_string())
+                .collect();
+            assert_eq!(texts, ["GMV"]);
+        }
+    }
+
+    #[tokio::test]
+    async fn default_language_scopes_queries_unless_all_requested() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_default_language(LanguageCode::new("en-US").unwrap());
+        alias(&service, 1, "营收", true).await;
+        let mut english = alias(&service, 1, "revenue", false).await;
+        english
+            .change_language(LanguageCode::new("en-US").unwrap(), Utc::now())
+            .unwrap();
+        service.repository().update_alias(english).await.unwrap();
+
+        let request = |language| BizMetadataAliasQueryRequest {
+            expression: Expression::True,
+            language,
+            options: QueryOptions::default().with_order_by(OrderBy::asc("id")),
+        };
+        let texts = |page: PageResult<BizMetadataAlias>| {
+            page.into_items()
+                .iter()
+                .map(|alias| alias.alias().as_str().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let scoped = service
+            .query_alias(request(LanguageScope::Default))
+            .await
+            .unwrap();
+        assert_eq!(texts(scoped), ["revenue"]);
+
+        let all = service
+            .query_alias(request(LanguageScope::All))
+            .await
+            .unwrap();
+        assert_eq!(texts(all), ["营收", "revenue"]);
+
+        let only = service
+            .query_alias(request(LanguageScope::Only(
+                LanguageCode::new("zh-CN").unwrap(),
+            )))
+            .await
+            .unwrap();
+        assert_eq!(texts(only), ["营收"]);
+    }
+
+    #[tokio::test]
+    async fn resolve_falls_back_through_preferred_languages() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let english = LanguageCode::new("en-US").unwrap();
+        let chinese = LanguageCode::new("zh-CN").unwrap();
+        let revenue_zh = alias(&service, 1, "revenue", false).await;
+        let mut revenue_en = alias(&service, 2, "revenue", false).await;
+        revenue_en
+            .change_language(english.clone(), Utc::now())
+            .unwrap();
+        service.repository().update_alias(revenue_en).await.unwrap();
+        let gmv = alias(&service, 3, "GMV", false).await;
+        let preferences = vec![english.clone(), chinese.clone()];
+
+        let direct = service
+            .resolve("revenue", preferences.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(direct.language, Some(english));
+        assert_eq!(direct.aliases.len(), 1);
+        assert_eq!(direct.aliases[0].metadata_id().value(), 2);
+
+        let fallback = service
+            .resolve(" GMV ", preferences.clone())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fallback.language, Some(chinese));
+        assert_eq!(fallback.aliases[0].id(), gmv.id());
+
+        let any = service.resolve("revenue", vec![]).await.unwrap().unwrap();
+        assert_eq!(any.language, None);
+        assert_eq!(any.aliases.len(), 2);
+        assert_eq!(any.aliases[0].id(), revenue_zh.id());
+
+        assert!(
+            service
+                .resolve("profit", preferences)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            service
+                .resolve("GMV", vec![LanguageCode::new("ja-JP").unwrap()])
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn bulk_set_weights_applies_valid_batch() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let revenue = alias(&service, 1, "营收", false).await;
+        let gmv = alias(&service, 2, "GMV", false).await;
+
+        let updated = service
+            .bulk_set_weights(vec![(revenue.id(), 80), (gmv.id(), 0), (revenue.id(), 90)])
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+
+        let revenue = service.find_by_id(revenue.id()).await.unwrap().unwrap();
+        let gmv = service.find_by_id(gmv.id()).await.unwrap().unwrap();
+        assert_eq!(revenue.weight().value(), 90);
+        assert_eq!(gmv.weight().value(), 0);
+    }
+
+    #[tokio::test]
+    async fn bulk_set_weights_rejects_whole_batch_on_out_of_range_weight() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let revenue = alias(&service, 1, "营收", false).await;
+        let gmv = alias(&service, 2, "GMV", false).await;
+        let before = revenue.weight().value();
+
+        let err = service
+            .bulk_set_weights(vec![(revenue.id(), 80), (gmv.id(), 101)])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_WEIGHT_INVALID);
+        let revenue = service.find_by_id(revenue.id()).await.unwrap().unwrap();
+        assert_eq!(revenue.weight().value(), before);
+
+        let err = service
+            .bulk_set_weights(vec![
+                (revenue.id(), 80),
+                (BizMetadataAliasId::new(9999), 10),
+            ])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_NOT_FOUND);
+        let revenue = service.find_by_id(revenue.id()).await.unwrap().unwrap();
+        assert_eq!(revenue.weight().value(), before);
+    }
+
+    #[tokio::test]
+    async fn reassign_moves_aliases_dropping_duplicates_and_extra_primaries() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        let (from, to) = (BizMetadataId::new(1), BizMetadataId::new(2));
+        let survivor_primary = alias(&service, 2, "营收", true).await;
+        let duplicate = alias(&service, 1, "营收", false).await;
+        let loser_primary = alias(&service, 1, "营业收入", true).await;
+        let plain = alias(&service, 1, "收入", false).await;
+        let unrelated = alias(&service, 3, "利润", false).await;
+
+        let moved = service.reassign(from, to).await.unwrap();
+        let moved_ids: Vec<_> = moved.iter().map(BizMetadataAlias::id).collect();
+        assert_eq!(moved_ids, [loser_primary.id(), plain.id()]);
+        assert!(moved.iter().all(|alias| alias.metadata_id() == to));
+
+        let duplicate = service.find_by_id(duplicate.id()).await.unwrap().unwrap();
+        assert!(duplicate.delete_at().is_some());
+        assert_eq!(duplicate.metadata_id(), from);
+
+        let survivors = collect_aliases_of(service.repository(), to, |alias| {
+            alias.delete_at().is_none()
+        })
+        .await
+        .unwrap();
+        assert_eq!(survivors.len(), 3);
+        let primaries: Vec<_> = survivors
+            .iter()
+            .filter(|alias| alias.is_primary())
+            .map(BizMetadataAlias::id)
+            .collect();
+        assert_eq!(primaries, [survivor_primary.id()]);
+
+        let left = collect_aliases_of(service.repository(), from, |alias| {
+            alias.delete_at().is_none()
+        })
+        .await
+        .unwrap();
+        assert!(left.is_empty());
+        let unrelated = service.find_by_id(unrelated.id()).await.unwrap().unwrap();
+        assert_eq!(unrelated.metadata_id(), BizMetadataId::new(3));
+    }
+
+    #[tokio::test]
+    async fn reassign_keeps_first_moved_primary_when_survivor_has_none() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new());
+        alias(&service, 2, "收入", false).await;
+        let first = alias(&service, 1, "营收", true).await;
+
+        let moved = service
+            .reassign(BizMetadataId::new(1), BizMetadataId::new(2))
+            .await
+            .unwrap();
+        assert_eq!(moved.len(), 1);
+        assert!(moved[0].is_primary());
+        assert_eq!(moved[0].id(), first.id());
+    }
+
+    #[tokio::test]
+    async fn reassign_rejects_moves_past_the_limit_counting_duplicates_once() {
+        let service = BizMetadataAliasService::new(InMemoryBizMetadataAliasRepository::new())
+            .with_max_aliases_per_metadata(2);
+        alias(&service, 2, "营收", false).await;
+        alias(&service, 1, "营收", false).await;
+        alias(&service, 1, "收入", false).await;
+        let extra = alias(&service, 1, "进账", false).await;
+
+        let err = service
+            .reassign(BizMetadataId::new(1), BizMetadataId::new(2))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+        let count = |id| {
+            service
+                .repository()
+                .count_live_aliases(BizMetadataId::new(id))
+        };
+        assert_eq!((count(1).await.unwrap(), count(2).await.unwrap()), (3, 1));
+
+        service.delete_alias(extra.id()).await.unwrap();
+        let moved = service
+            .reassign(BizMetadataId::new(1), BizMetadataId::new(2))
+            .await
+            .unwrap();
+        assert_eq!(moved.len(), 1);
+        assert_eq!(count(2).await.unwrap(), 2);
+    }
+}
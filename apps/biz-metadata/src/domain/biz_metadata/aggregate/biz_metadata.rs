@@ -2,17 +2,21 @@ use crate::domain::biz_metadata::value_object::{
     BizMetadataCode, BizMetadataId, BizMetadataName, BizMetadataStatus, DataClass, ObjectType,
     Source, TenantId, Unit, ValueType, Version,
 };
+use crate::domain::error_code;
 use chrono::{DateTime, Utc};
 use domain_core::prelude::{AggregateRoot, Audit, DomainError, Entity, validate_non_empty};
 use domain_core::value_object::ValueObject;
 
+/// `data_class=identifier` 允许的 value_type，与 DB 约束 `ck_biz_metadata_identifier_rules` 保持一致。
+const IDENTIFIER_VALUE_TYPES: &[&str] = &["string", "int", "int|string"];
+
 /// 元数据聚合根，表示系统中的一个元数据定义实体。
 ///
 /// 该聚合对齐 `biz_metadata` 表（规范 v1.0），并包含以下关键不变式：
 /// - `object_type != feature` 时，`data_class/value_type/unit` 必须为空
 /// - `object_type == feature` 时，`data_class/value_type` 必须非空
 /// - `unit` 仅允许在 `data_class=metric` 下填写
-/// - `data_class=identifier` 时，`unit` 必须为空（value_type 的严格集合由门禁工具与 DB CHECK 共同保障）
+/// - `data_class=identifier` 时，`unit` 必须为空，`value_type` 仅允许 `string/int/int|string`
 ///
 /// # 示例
 /// ```
@@ -25,6 +29,7 @@ use domain_core::value_object::ValueObject;
 ///     "公司中文名",
 ///     DataClass::Attribute,
 ///     ValueType::new("string")?,
+///     chrono::Utc::now(),
 /// )?;
 /// assert!(feature.data_class().is_some());
 /// # Ok::<(), domain_core::domain_error::DomainError>(())
@@ -73,13 +78,14 @@ impl BizMetadata {
         code: impl Into<String>,
         name: impl Into<String>,
         object_type: ObjectType,
+        now: DateTime<Utc>,
     ) -> Result<Self, DomainError> {
         if object_type == ObjectType::Feature {
             return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
                 message: "use new_feature() to create object_type=feature".into(),
             });
         }
-        let now = Utc::now();
         Self::from_snapshot(MetadataSnapshot {
             tenant_id,
             version: Version::new(1)?,
@@ -105,8 +111,8 @@ impl BizMetadata {
         name: impl Into<String>,
         data_class: DataClass,
         value_type: ValueType,
+        now: DateTime<Utc>,
     ) -> Result<Self, DomainError> {
-        let now = Utc::now();
         Self::from_snapshot(MetadataSnapshot {
             tenant_id,
             version: Version::new(1)?,
@@ -127,6 +133,18 @@ impl BizMetadata {
 
     /// 按字段与审计信息重建元数据聚合，保留时间戳与可选删除标记。
     pub fn from_snapshot(snapshot: MetadataSnapshot) -> Result<Self, DomainError> {
+        let aggregate = Self::rehydrate(snapshot)?;
+        aggregate
+            .check_invariants()
+            .map_err(|mut errors| errors.swap_remove(0))?;
+        Ok(aggregate)
+    }
+
+    /// 按持久化状态重建聚合，仅校验各字段格式，不校验跨字段的作用域不变式。
+    ///
+    /// 供持久化层加载历史数据：早于现行规则导入的行（如非 feature 带 `unit`）仍可读出，
+    /// 再由 [`check_invariants`](Self::check_invariants) 检出；后续变更仍按现行规则校验。
+    pub fn rehydrate(snapshot: MetadataSnapshot) -> Result<Self, DomainError> {
         let MetadataSnapshot {
             tenant_id,
             version,
@@ -167,8 +185,6 @@ impl BizMetadata {
             unit.validate()?;
         }
 
-        Self::validate_scope(object_type, data_class, value_type.as_ref(), unit.as_ref())?;
-
         Ok(Self {
             tenant_id,
             version,
@@ -187,16 +203,88 @@ impl BizMetadata {
         })
     }
 
+    /// 一次性检查全部作用域不变式（feature 字段、identifier 规则、unit 作用域），返回所有违反项。
+    ///
+    /// ```
+    /// use biz_metadata::{BizMetadata, ObjectType, TenantId, Unit};
+    ///
+    /// let node = BizMetadata::new_node(
+    ///     TenantId::new("default").unwrap(),
+    ///     "company",
+    ///     "公司",
+    ///     ObjectType::Entity,
+    ///     chrono::Utc::now(),
+    /// )
+    /// .unwrap();
+    /// assert!(node.check_invariants().is_ok());
+    ///
+    /// let mut snapshot = node.to_snapshot();
+    /// snapshot.unit = Some(Unit::new("CNY").unwrap());
+    /// let legacy = BizMetadata::rehydrate(snapshot).unwrap();
+    /// assert_eq!(legacy.check_invariants().unwrap_err().len(), 2);
+    /// ```
+    pub fn check_invariants(&self) -> Result<(), Vec<DomainError>> {
+        let errors = Self::scope_violations(
+            self.object_type,
+            self.data_class,
+            self.value_type.as_ref(),
+            self.unit.as_ref(),
+        );
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// 导出聚合的完整状态快照，与 [`from_snapshot`](Self::from_snapshot) 互逆。
+    pub fn to_snapshot(&self) -> MetadataSnapshot {
+        MetadataSnapshot {
+            tenant_id: self.tenant_id.clone(),
+            version: self.version,
+            id: self.id,
+            code: self.code.as_str().to_string(),
+            name: self.name.as_str().to_string(),
+            description: self.description.clone(),
+            object_type: self.object_type,
+            parent_id: self.parent_id,
+            data_class: self.data_class,
+            value_type: self.value_type.as_ref().map(|v| v.as_str().to_string()),
+            unit: self.unit.clone(),
+            status: self.status,
+            source: self.source,
+            audit: self.audit.clone(),
+        }
+    }
+
     fn validate_scope(
         object_type: ObjectType,
         data_class: Option<DataClass>,
         value_type: Option<&ValueType>,
         unit: Option<&Unit>,
     ) -> Result<(), DomainError> {
+        match Self::scope_violations(object_type, data_class, value_type, unit)
+            .into_iter()
+            .next()
+        {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+
+    /// 按规则顺序收集全部作用域违反项，[`validate_scope`](Self::validate_scope) 取其首个。
+    fn scope_violations(
+        object_type: ObjectType,
+        data_class: Option<DataClass>,
+        value_type: Option<&ValueType>,
+        unit: Option<&Unit>,
+    ) -> Vec<DomainError> {
+        let mut errors = Vec::new();
         match object_type {
             ObjectType::Feature => {
                 if data_class.is_none() || value_type.is_none() {
-                    return Err(DomainError::Validation {
+                    errors.push(DomainError::Validation {
+                        code: error_code::BIZ_METADATA_FEATURE_FIELD_REQUIRED,
                         message: "object_type=feature requires non-empty data_class and value_type"
                             .into(),
                     });
@@ -204,7 +292,8 @@ impl BizMetadata {
             }
             _ => {
                 if data_class.is_some() || value_type.is_some() || unit.is_some() {
-                    return Err(DomainError::Validation {
+                    errors.push(DomainError::Validation {
+                        code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
                         message: "object_type!=feature must keep data_class/value_type/unit empty"
                             .into(),
                     });
@@ -212,19 +301,29 @@ impl BizMetadata {
             }
         }
 
+        // identifier 的 unit 必须为空也由此覆盖（identifier 不是 metric）。
         if unit.is_some() && data_class != Some(DataClass::Metric) {
-            return Err(DomainError::Validation {
+            errors.push(DomainError::Validation {
+                code: error_code::UNIT_NOT_ALLOWED,
                 message: format!("unit not allowed when data_class is {data_class:?}"),
             });
         }
 
-        if data_class == Some(DataClass::Identifier) && unit.is_some() {
-            return Err(DomainError::Validation {
-                message: "identifier unit must be empty".into(),
+        if data_class == Some(DataClass::Identifier)
+            && let Some(value_type) = value_type
+            && !IDENTIFIER_VALUE_TYPES.contains(&value_type.as_str())
+        {
+            errors.push(DomainError::Validation {
+                code: error_code::VALUE_TYPE_INVALID,
+                message: format!(
+                    "identifier value_type must be one of {}, got {}",
+                    IDENTIFIER_VALUE_TYPES.join("/"),
+                    value_type.as_str()
+                ),
             });
         }
 
-        Ok(())
+        errors
     }
 
     pub fn tenant_id(&self) -> &TenantId {
@@ -239,6 +338,27 @@ impl BizMetadata {
         self.id
     }
 
+    /// 是否已持有持久化标识；新建聚合在插入前为 0。
+    pub fn has_id(&self) -> bool {
+        self.id.value() != 0
+    }
+
+    /// 插入前预分配标识，仅允许对尚未持有标识的新聚合设置正整数 id。
+    pub fn assign_id(&mut self, id: BizMetadataId) -> Result<(), DomainError> {
+        if self.has_id() || id.value() <= 0 {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_ID_CONFLICT,
+                message: format!(
+                    "cannot assign id {} to biz_metadata with id {}",
+                    id.value(),
+                    self.id.value()
+                ),
+            });
+        }
+        self.id = id;
+        Ok(())
+    }
+
     pub fn code(&self) -> &BizMetadataCode {
         &self.code
     }
@@ -299,29 +419,97 @@ impl BizMetadata {
         self.audit.is_deleted()
     }
 
-    pub fn rename(&mut self, name: BizMetadataName) -> Result<(), DomainError> {
+    /// 不改动字段，仅将 `updated_at` 推进到 `now`；`now` 早于当前 `updated_at` 时拒绝。
+    pub fn touch(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.bump_updated_at(now)
+    }
+
+    /// 比较业务内容是否一致：忽略审计时间戳与乐观锁版本，但仍比较 id 与是否已删除。
+    ///
+    /// 仅作比较辅助，`==` 仍逐字段比较。
+    ///
+    /// ```
+    /// use biz_metadata::{BizMetadata, ObjectType, TenantId};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let now = Utc::now();
+    /// let build = |at| {
+    ///     BizMetadata::new_node(TenantId::new("default").unwrap(), "company", "公司", ObjectType::Entity, at)
+    ///         .unwrap()
+    /// };
+    /// let (earlier, later) = (build(now), build(now + Duration::seconds(1)));
+    /// assert!(earlier.content_eq(&later));
+    /// assert_ne!(earlier, later);
+    /// ```
+    pub fn content_eq(&self, other: &Self) -> bool {
+        // 解构保证新增字段时此处必须显式决定是否参与比较。
+        let Self {
+            tenant_id,
+            version: _,
+            id,
+            code,
+            name,
+            description,
+            object_type,
+            parent_id,
+            data_class,
+            value_type,
+            unit,
+            status,
+            source,
+            audit,
+        } = self;
+        *tenant_id == other.tenant_id
+            && *id == other.id
+            && *code == other.code
+            && *name == other.name
+            && *description == other.description
+            && *object_type == other.object_type
+            && *parent_id == other.parent_id
+            && *data_class == other.data_class
+            && *value_type == other.value_type
+            && *unit == other.unit
+            && *status == other.status
+            && *source == other.source
+            && audit.is_deleted() == other.audit.is_deleted()
+    }
+
+    pub fn rename(&mut self, name: BizMetadataName, now: DateTime<Utc>) -> Result<(), DomainError> {
         name.validate()?;
         self.name = name;
-        self.bump_updated_at(Utc::now())?;
+        self.bump_updated_at(now)?;
         Ok(())
     }
 
-    pub fn set_description(&mut self, description: Option<String>) -> Result<(), DomainError> {
+    pub fn set_description(
+        &mut self,
+        description: Option<String>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         if let Some(desc) = description.as_ref() {
             validate_non_empty(desc, "description")?;
         }
         self.description = description;
-        self.bump_updated_at(Utc::now())
+        self.bump_updated_at(now)
     }
 
-    pub fn set_parent_id(&mut self, parent_id: Option<BizMetadataId>) -> Result<(), DomainError> {
+    pub fn set_parent_id(
+        &mut self,
+        parent_id: Option<BizMetadataId>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         self.parent_id = parent_id;
-        self.bump_updated_at(Utc::now())
+        self.bump_updated_at(now)
     }
 
-    pub fn change_data_class(&mut self, data_class: DataClass) -> Result<(), DomainError> {
+    pub fn change_data_class(
+        &mut self,
+        data_class: DataClass,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         if self.object_type != ObjectType::Feature {
             return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
                 message: "non-feature node cannot set data_class".into(),
             });
         }
@@ -333,13 +521,18 @@ impl BizMetadata {
             self.unit.as_ref(),
         )?;
         self.data_class = Some(data_class);
-        self.bump_updated_at(Utc::now())?;
+        self.bump_updated_at(now)?;
         Ok(())
     }
 
-    pub fn change_value_type(&mut self, value_type: ValueType) -> Result<(), DomainError> {
+    pub fn change_value_type(
+        &mut self,
+        value_type: ValueType,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         if self.object_type != ObjectType::Feature {
             return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
                 message: "non-feature node cannot set value_type".into(),
             });
         }
@@ -351,13 +544,79 @@ impl BizMetadata {
             self.unit.as_ref(),
         )?;
         self.value_type = Some(value_type);
-        self.bump_updated_at(Utc::now())?;
+        self.bump_updated_at(now)?;
         Ok(())
     }
 
-    pub fn set_unit(&mut self, unit: Option<Unit>) -> Result<(), DomainError> {
+    /// 一次性修改 feature 专属字段，`None` 表示保持原值，`unit` 为 `Some(None)` 时清空。
+    ///
+    /// 按修改后的最终组合校验作用域，避免逐项修改时中间状态被误判（如 metric 改为 text 的同时清空 unit）。
+    ///
+    /// ```
+    /// use biz_metadata::{BizMetadata, DataClass, TenantId, Unit, ValueType};
+    ///
+    /// let now = chrono::Utc::now();
+    /// let mut revenue = BizMetadata::new_feature(
+    ///     TenantId::new("default").unwrap(),
+    ///     "revenue",
+    ///     "营收",
+    ///     DataClass::Metric,
+    ///     ValueType::new("decimal").unwrap(),
+    ///     now,
+    /// )
+    /// .unwrap();
+    /// revenue.set_unit(Some(Unit::new("CNY").unwrap()), now).unwrap();
+    ///
+    /// revenue
+    ///     .change_feature_fields(Some(DataClass::Text), Some(ValueType::new("string").unwrap()), Some(None), now)
+    ///     .unwrap();
+    /// assert_eq!(revenue.data_class(), Some(DataClass::Text));
+    /// assert!(revenue.unit().is_none());
+    /// ```
+    pub fn change_feature_fields(
+        &mut self,
+        data_class: Option<DataClass>,
+        value_type: Option<ValueType>,
+        unit: Option<Option<Unit>>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if data_class.is_none() && value_type.is_none() && unit.is_none() {
+            return Ok(());
+        }
+        if self.object_type != ObjectType::Feature {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
+                message: "non-feature node cannot set data_class/value_type/unit".into(),
+            });
+        }
+        if let Some(data_class) = data_class {
+            data_class.validate()?;
+        }
+        if let Some(value_type) = &value_type {
+            value_type.validate()?;
+        }
+        if let Some(Some(unit)) = &unit {
+            unit.validate()?;
+        }
+        let data_class = data_class.or(self.data_class);
+        let value_type = value_type.or_else(|| self.value_type.clone());
+        let unit = unit.unwrap_or_else(|| self.unit.clone());
+        Self::validate_scope(
+            self.object_type,
+            data_class,
+            value_type.as_ref(),
+            unit.as_ref(),
+        )?;
+        self.data_class = data_class;
+        self.value_type = value_type;
+        self.unit = unit;
+        self.bump_updated_at(now)
+    }
+
+    pub fn set_unit(&mut self, unit: Option<Unit>, now: DateTime<Utc>) -> Result<(), DomainError> {
         if self.object_type != ObjectType::Feature {
             return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_OBJECT_TYPE_MISMATCH,
                 message: "non-feature node cannot set unit".into(),
             });
         }
@@ -371,26 +630,34 @@ impl BizMetadata {
             unit.as_ref(),
         )?;
         self.unit = unit;
-        self.bump_updated_at(Utc::now())
+        self.bump_updated_at(now)
     }
 
-    pub fn change_status(&mut self, status: BizMetadataStatus) -> Result<(), DomainError> {
+    pub fn change_status(
+        &mut self,
+        status: BizMetadataStatus,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         status.validate()?;
         self.status = status;
-        self.bump_updated_at(Utc::now())?;
+        self.bump_updated_at(now)?;
         Ok(())
     }
 
-    pub fn change_source(&mut self, source: Source) -> Result<(), DomainError> {
+    pub fn change_source(&mut self, source: Source, now: DateTime<Utc>) -> Result<(), DomainError> {
         source.validate()?;
         self.source = source;
-        self.bump_updated_at(Utc::now())
+        self.bump_updated_at(now)
     }
 
     pub fn mark_deleted(&mut self, delete_at: DateTime<Utc>) -> Result<(), DomainError> {
         self.audit.mark_deleted(delete_at)
     }
 
+    pub fn restore(&mut self, restored_at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.audit.restore(restored_at)
+    }
+
     fn bump_updated_at(&mut self, updated_at: DateTime<Utc>) -> Result<(), DomainError> {
         self.audit.bump_updated(updated_at)
     }
@@ -419,6 +686,7 @@ mod tests {
             "name",
             DataClass::Attribute,
             ValueType::new("string").unwrap(),
+            Utc::now(),
         )
         .expect("valid biz_metadata");
 
@@ -437,6 +705,7 @@ mod tests {
             "name",
             DataClass::Attribute,
             ValueType::new("string").unwrap(),
+            Utc::now(),
         )
         .unwrap_err();
 
@@ -451,10 +720,108 @@ mod tests {
             "name",
             DataClass::Attribute,
             ValueType::new("string").unwrap(),
+            Utc::now(),
         )
         .unwrap();
 
         let earlier = biz_metadata.created_at() - Duration::seconds(1);
         assert!(biz_metadata.mark_deleted(earlier).is_err());
     }
+
+    #[test]
+    fn touch_advances_updated_at_only_forward() {
+        let created_at = Utc::now();
+        let mut biz_metadata = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "公司",
+            ObjectType::Entity,
+            created_at,
+        )
+        .unwrap();
+
+        let later = created_at + Duration::seconds(5);
+        biz_metadata.touch(later).unwrap();
+        assert_eq!(biz_metadata.updated_at(), later);
+        assert_eq!(biz_metadata.name().as_str(), "公司");
+
+        let err = biz_metadata
+            .touch(later - Duration::seconds(1))
+            .unwrap_err();
+        assert!(matches!(err, DomainError::InvariantViolation { .. }));
+        assert_eq!(biz_metadata.updated_at(), later);
+    }
+
+    #[test]
+    fn accepts_identifier_with_allowed_value_type() {
+        let identifier = BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            "company.id.company_id",
+            "公司ID",
+            DataClass::Identifier,
+            ValueType::new("int|string").unwrap(),
+            Utc::now(),
+        );
+        assert!(identifier.is_ok());
+    }
+
+    #[test]
+    fn rejects_identifier_with_disallowed_value_type() {
+        let err = BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            "company.id.company_id",
+            "公司ID",
+            DataClass::Identifier,
+            ValueType::new("float").unwrap(),
+            Utc::now(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }));
+
+        let mut feature = BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            "company.id.company_id",
+            "公司ID",
+            DataClass::Identifier,
+            ValueType::new("string").unwrap(),
+            Utc::now(),
+        )
+        .unwrap();
+        assert!(
+            feature
+                .change_value_type(ValueType::new("float").unwrap(), Utc::now())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn content_eq_ignores_audit_timestamps_and_version() {
+        let now = Utc::now();
+        let original = BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            "company.base.amount",
+            "金额",
+            DataClass::Metric,
+            ValueType::new("decimal").unwrap(),
+            now,
+        )
+        .unwrap();
+
+        let mut snapshot = original.to_snapshot();
+        snapshot.version = Version::new(3).unwrap();
+        snapshot.audit = Audit::new(now + Duration::seconds(30));
+        let restamped = BizMetadata::from_snapshot(snapshot).unwrap();
+        assert!(original.content_eq(&restamped));
+        assert_ne!(original, restamped);
+
+        let mut renamed = original.clone();
+        renamed
+            .rename(BizMetadataName::new("总金额").unwrap(), now)
+            .unwrap();
+        assert!(!original.content_eq(&renamed));
+
+        let mut deleted = original.clone();
+        deleted.mark_deleted(now).unwrap();
+        assert!(!original.content_eq(&deleted));
+    }
 }
@@ -0,0 +1,114 @@
+//! 元数据编码（code）相关的辅助能力。
+//!
+//! 编码格式遵循 `^[a-z][a-z0-9_]*(\.[a-z][a-z0-9_]*)*$`，由点号分隔的若干段组成。
+
+use super::value_object::BizMetadataCode;
+
+/// 单段编码允许的最大长度，避免生成过长的 code。
+const MAX_SEGMENT_LEN: usize = 48;
+
+/// 根据显示名称生成建议编码，并以父编码加点号作为前缀。
+///
+/// 名称中的 ASCII 字母数字会被转为小写保留，其余字符视为分隔符并折叠为单个 `_`；
+/// 若结果为空（例如纯中文名称），回退为基于名称哈希的 `n_xxxxxxxx` 段，保证结果满足编码格式。
+/// 父编码去除首尾空白后经 [`BizMetadataCode::new`] 规范化为小写，其各段格式由调用方校验。
+///
+/// ```
+/// use biz_metadata::code::suggest_from_name;
+///
+/// assert_eq!(suggest_from_name(Some("company.finance"), "Revenue YoY"), "company.finance.revenue_yoy");
+/// assert_eq!(suggest_from_name(Some(" Company "), "ROE"), "company.roe");
+/// assert!(suggest_from_name(None, "营业收入").starts_with("n_"));
+/// ```
+pub fn suggest_from_name(parent_code: Option<&str>, name: &str) -> String {
+    let segment = normalize_segment(name).unwrap_or_else(|| hashed_segment(name));
+    match parent_code.and_then(|parent| BizMetadataCode::new(parent.trim()).ok()) {
+        Some(parent) => format!("{}.{segment}", parent.as_str()),
+        None => segment,
+    }
+}
+
+/// 判断单段编码是否满足 `[a-z][a-z0-9_]*`。
+pub fn is_valid_segment(segment: &str) -> bool {
+    let mut chars = segment.chars();
+    chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+}
+
+fn normalize_segment(name: &str) -> Option<String> {
+    let mut segment = String::with_capacity(name.len());
+    for ch in name.chars() {
+        if ch.is_ascii_alphanumeric() {
+            segment.push(ch.to_ascii_lowercase());
+        } else if !segment.is_empty() && !segment.ends_with('_') {
+            segment.push('_');
+        }
+    }
+    let mut segment = segment.trim_end_matches('_').to_string();
+    if segment.is_empty() {
+        return None;
+    }
+    if !segment.starts_with(|c: char| c.is_ascii_lowercase()) {
+        segment.insert_str(0, "n_");
+    }
+    segment.truncate(MAX_SEGMENT_LEN);
+    let segment = segment.trim_end_matches('_').to_string();
+    is_valid_segment(&segment).then_some(segment)
+}
+
+/// 以 FNV-1a 计算稳定哈希，保证同一名称多次生成的建议一致。
+fn hashed_segment(name: &str) -> String {
+    let hash = name.trim().bytes().fold(0x811c_9dc5_u32, |acc, byte| {
+        (acc ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("n_{hash:08x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn suggests_from_ascii_name() {
+        assert_eq!(suggest_from_name(None, "Company"), "company");
+        assert_eq!(
+            suggest_from_name(Some("company.finance"), "  Net Profit (TTM) "),
+            "company.finance.net_profit_ttm"
+        );
+        assert_eq!(
+            suggest_from_name(Some(""), "2024 Revenue"),
+            "n_2024_revenue"
+        );
+    }
+
+    #[test]
+    fn lowercases_parent_code() {
+        assert_eq!(
+            suggest_from_name(Some("  Company.Finance "), "Revenue"),
+            "company.finance.revenue"
+        );
+        assert_eq!(suggest_from_name(Some("   "), "Revenue"), "revenue");
+    }
+
+    #[test]
+    fn falls_back_to_hash_for_non_ascii_name() {
+        let code = suggest_from_name(Some("company"), "营业收入");
+        assert!(code.starts_with("company.n_"));
+        assert_eq!(code, suggest_from_name(Some("company"), "营业收入"));
+        assert_ne!(code, suggest_from_name(Some("company"), "净利润"));
+        assert!(BizMetadataCode::new(code).is_ok());
+    }
+
+    #[test]
+    fn keeps_ascii_part_of_mixed_name() {
+        assert_eq!(suggest_from_name(None, "公司ROE指标"), "roe");
+    }
+
+    #[test]
+    fn every_segment_is_valid() {
+        for name in ["", "___", "营收", "A-B-C", "9", "x".repeat(100).as_str()] {
+            let code = suggest_from_name(Some("company"), name);
+            assert!(code.split('.').all(is_valid_segment), "invalid code {code}");
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! 租户级编码命名策略，在基础格式 `^[a-z][a-z0-9_]*(\.[a-z][a-z0-9_]*)*$` 之外追加约束。
+
+use domain_core::domain_error::DomainError;
+
+use crate::domain::biz_metadata::value_object::BizMetadataCode;
+use crate::domain::error_code;
+
+/// 编码命名策略：最大层级、根段白名单与禁用段。
+///
+/// 默认策略不追加任何约束，接受所有满足基础格式的编码。
+///
+/// ```
+/// use biz_metadata::{BizMetadataCode, CodePolicy};
+///
+/// let policy = CodePolicy::default()
+///     .with_max_depth(3)
+///     .with_allowed_roots(["company"])
+///     .with_forbidden_segments(["tmp"]);
+/// assert!(policy.validate(&BizMetadataCode::new("company.finance").unwrap()).is_ok());
+/// let err = policy.validate(&BizMetadataCode::new("person.name").unwrap()).unwrap_err();
+/// assert_eq!(err.code(), biz_metadata::error_code::CODE_POLICY_ROOT_NOT_ALLOWED);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodePolicy {
+    max_depth: Option<usize>,
+    allowed_roots: Vec<String>,
+    forbidden_segments: Vec<String>,
+}
+
+impl CodePolicy {
+    /// 限制编码的最大段数（点号分隔）。
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// 要求首段属于给定白名单；为空时不限制。
+    pub fn with_allowed_roots<I, S>(mut self, roots: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_roots = roots.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 禁止任意位置出现给定的段。
+    pub fn with_forbidden_segments<I, S>(mut self, segments: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forbidden_segments = segments.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 校验编码，违反时返回携带具体规则错误码的 `Validation`。
+    pub fn validate(&self, code: &BizMetadataCode) -> Result<(), DomainError> {
+        let segments: Vec<&str> = code.as_str().split('.').collect();
+
+        if let Some(max_depth) = self.max_depth
+            && segments.len() > max_depth
+        {
+            return Err(DomainError::Validation {
+                code: error_code::CODE_POLICY_MAX_DEPTH_EXCEEDED,
+                message: format!(
+                    "code {} has {} segments, at most {max_depth} allowed",
+                    code.as_str(),
+                    segments.len()
+                ),
+            });
+        }
+
+        if !self.allowed_roots.is_empty()
+            && !self.allowed_roots.iter().any(|root| root == segments[0])
+        {
+            return Err(DomainError::Validation {
+                code: error_code::CODE_POLICY_ROOT_NOT_ALLOWED,
+                message: format!(
+                    "code {} must start with one of: {}",
+                    code.as_str(),
+                    self.allowed_roots.join(", ")
+                ),
+            });
+        }
+
+        if let Some(segment) = segments
+            .iter()
+            .find(|segment| self.forbidden_segments.iter().any(|f| f == *segment))
+        {
+            return Err(DomainError::Validation {
+                code: error_code::CODE_POLICY_SEGMENT_FORBIDDEN,
+                message: format!(
+                    "code {} contains forbidden segment {segment}",
+                    code.as_str()
+                ),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// 编码唯一性的作用范围。
+///
+/// 默认 `Global`：完整编码在租户内唯一，数据库唯一索引 `ux_biz_metadata_tenant_code_alive` 兜底，服务层预检只为返回一致的领域错误。
+/// [`CodeUniqueness::PerParent`] 要求同一父节点下的子节点末段编码互不相同，不再做全局预检，
+/// 同一编码可出现在不同父节点下，此时按编码查找返回 `biz_metadata.code_ambiguous`；部署时须以
+/// `BIZ_METADATA_CODE_UNIQUENESS=per_parent` 运行迁移将唯一索引收窄到父节点内，服务启动时校验索引与配置一致。
+///
+/// ```
+/// use biz_metadata::CodeUniqueness;
+///
+/// assert_eq!(CodeUniqueness::default(), CodeUniqueness::Global);
+/// assert_eq!(CodeUniqueness::parse(" Per_Parent "), Some(CodeUniqueness::PerParent));
+/// assert_eq!(CodeUniqueness::parse("sibling"), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CodeUniqueness {
+    /// 完整编码在租户内唯一。
+    #[default]
+    Global,
+    /// 末段编码在同一父节点的子节点间唯一；根节点仍按完整编码检查。
+    PerParent,
+}
+
+impl CodeUniqueness {
+    /// 解析 `global` / `per_parent`（忽略大小写与首尾空白），与迁移读取的配置取值一致。
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "global" => Some(Self::Global),
+            "per_parent" => Some(Self::PerParent),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn code(raw: &str) -> BizMetadataCode {
+        BizMetadataCode::new(raw).unwrap()
+    }
+
+    #[test]
+    fn default_policy_accepts_any_code() {
+        let policy = CodePolicy::default();
+        for raw in ["a", "company.finance.revenue", "x.y.z.w.v"] {
+            assert!(policy.validate(&code(raw)).is_ok());
+        }
+    }
+
+    #[test]
+    fn reports_the_violated_rule() {
+        let policy = CodePolicy::default()
+            .with_max_depth(2)
+            .with_allowed_roots(["company"])
+            .with_forbidden_segments(["test"]);
+
+        let cases = [
+            (
+                "company.finance.revenue",
+                error_code::CODE_POLICY_MAX_DEPTH_EXCEEDED,
+            ),
+            ("person.name", error_code::CODE_POLICY_ROOT_NOT_ALLOWED),
+            ("company.test", error_code::CODE_POLICY_SEGMENT_FORBIDDEN),
+        ];
+        for (raw, expected) in cases {
+            let err = policy.validate(&code(raw)).unwrap_err();
+            assert_eq!(err.code(), expected, "{raw}");
+        }
+        assert!(policy.validate(&code("company.finance")).is_ok());
+    }
+}
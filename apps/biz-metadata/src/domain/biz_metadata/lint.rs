@@ -0,0 +1,373 @@
+//! 目录级完整性检查，对应 `tools/biz-metadata-linter` 中跨行的 TypeRef 与层级规则，
+//! 供发布前以库函数方式调用。
+
+use std::collections::{HashMap, HashSet};
+
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{Expression, OrderBy, QueryOptions};
+use domain_core::pagination::Page;
+
+use super::BizMetadata;
+use super::code::is_valid_segment;
+use super::repository::BizMetadataRepository;
+use super::value_object::{BizMetadataId, BizMetadataStatus};
+
+/// TypeRef 最大展开深度，与 linter 的 `--max-ref-depth` 默认值一致。
+pub const MAX_TYPE_REF_DEPTH: usize = 5;
+
+/// 分页加载目录时的批大小。
+const LINT_BATCH_SIZE: u64 = 200;
+
+/// 检查结果的严重级别。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// 需要修正后才能发布。
+    Error,
+    /// 建议修正，不阻断发布。
+    Warning,
+}
+
+/// 检查规则，`as_str` 与 linter 的 `rule_id` 保持一致。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintRule {
+    /// `ref:<code>` 的目标不存在。
+    TypeRefNotFound,
+    /// TypeRef 展开成环。
+    TypeRefCycle,
+    /// TypeRef 展开超过 [`MAX_TYPE_REF_DEPTH`]。
+    TypeRefTooDeep,
+    /// TypeRef 目标不是 active 状态（如已废弃）。
+    TypeRefTargetNotActive,
+    /// `parent_id` 指向不存在或已删除的节点。
+    HierarchyParentMissing,
+    /// 父节点链成环。
+    HierarchyCycle,
+}
+
+impl LintRule {
+    /// 规则标识。
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::TypeRefNotFound => "TYPE_REF_NOT_FOUND",
+            Self::TypeRefCycle => "TYPE_REF_CYCLE",
+            Self::TypeRefTooDeep => "TYPE_REF_TOO_DEEP",
+            Self::TypeRefTargetNotActive => "TYPE_REF_TARGET_NOT_ACTIVE",
+            Self::HierarchyParentMissing => "HIERARCHY_PARENT_MISSING",
+            Self::HierarchyCycle => "HIERARCHY_CYCLE",
+        }
+    }
+
+    /// 规则的默认严重级别。
+    pub fn severity(&self) -> LintSeverity {
+        match self {
+            Self::TypeRefTargetNotActive => LintSeverity::Warning,
+            _ => LintSeverity::Error,
+        }
+    }
+}
+
+/// 单条检查结果。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintFinding {
+    /// 命中的规则。
+    pub rule: LintRule,
+    /// 严重级别。
+    pub severity: LintSeverity,
+    /// 出问题的元数据编码。
+    pub code: String,
+    /// 可读说明。
+    pub message: String,
+}
+
+impl LintFinding {
+    fn new(rule: LintRule, code: &str, message: String) -> Self {
+        Self {
+            rule,
+            severity: rule.severity(),
+            code: code.to_string(),
+            message,
+        }
+    }
+}
+
+/// 加载仓储中全部存活记录并执行目录级检查，结果按编码与规则排序。
+///
+/// ```
+/// use biz_metadata::{
+///     BizMetadata, BizMetadataRepository, InMemoryBizMetadataRepository, LintRule, ObjectType,
+///     TenantId, lint_catalog,
+/// };
+///
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// rt.block_on(async {
+/// let repo = InMemoryBizMetadataRepository::new();
+/// let tenant = TenantId::new("default").unwrap();
+/// let mut child =
+///     BizMetadata::new_node(tenant, "company.base", "基础", ObjectType::Entity, chrono::Utc::now())
+///         .unwrap();
+/// child
+///     .set_parent_id(Some(biz_metadata::BizMetadataId::new(404)), chrono::Utc::now())
+///     .unwrap();
+/// repo.insert_biz_metadata(child).await.unwrap();
+///
+/// let findings = lint_catalog(&repo).await.unwrap();
+/// assert_eq!(findings[0].rule, LintRule::HierarchyParentMissing);
+/// assert_eq!(findings[0].code, "company.base");
+/// });
+/// ```
+pub async fn lint_catalog(
+    repo: &impl BizMetadataRepository,
+) -> Result<Vec<LintFinding>, DomainError> {
+    let mut items = Vec::new();
+    let mut offset = 0;
+    loop {
+        let options = QueryOptions::new(Some(LINT_BATCH_SIZE), Some(offset))
+            .with_order_by(OrderBy::asc("id"));
+        let page = repo.query_biz_metadata(Expression::True, options).await?;
+        let has_next = page.has_next_page();
+        items.extend(page.into_items());
+        if !has_next {
+            break;
+        }
+        offset += LINT_BATCH_SIZE;
+    }
+
+    let mut findings = lint_items(&items);
+    findings.sort_by(|a, b| {
+        (a.code.as_str(), a.rule.as_str()).cmp(&(b.code.as_str(), b.rule.as_str()))
+    });
+    Ok(findings)
+}
+
+fn lint_items(items: &[BizMetadata]) -> Vec<LintFinding> {
+    let by_id: HashMap<BizMetadataId, &BizMetadata> =
+        items.iter().map(|item| (item.id(), item)).collect();
+    let by_code: HashMap<(&str, &str), &BizMetadata> = items
+        .iter()
+        .map(|item| ((item.tenant_id().as_str(), item.code().as_str()), item))
+        .collect();
+
+    let mut findings = Vec::new();
+    for item in items {
+        check_parent(item, &by_id, &mut findings);
+        let Some(value_type) = item.value_type() else {
+            continue;
+        };
+        for target in value_type.as_str().split('|').filter_map(type_ref_target) {
+            if let Some(finding) = check_type_ref(item, target, &by_code) {
+                findings.push(finding);
+            }
+        }
+    }
+    findings
+}
+
+/// 解析 `ref:<code>` 形式的单个 term。
+pub(crate) fn type_ref_target(term: &str) -> Option<&str> {
+    term.trim()
+        .strip_prefix("ref:")
+        .filter(|code| code.split('.').all(is_valid_segment))
+}
+
+fn check_parent(
+    item: &BizMetadata,
+    by_id: &HashMap<BizMetadataId, &BizMetadata>,
+    findings: &mut Vec<LintFinding>,
+) {
+    let Some(parent_id) = item.parent_id() else {
+        return;
+    };
+    if !by_id.contains_key(&parent_id) {
+        findings.push(LintFinding::new(
+            LintRule::HierarchyParentMissing,
+            item.code().as_str(),
+            format!("parent {} does not exist", parent_id.value()),
+        ));
+        return;
+    }
+
+    let mut seen = HashSet::from([item.id()]);
+    let mut current = Some(parent_id);
+    while let Some(id) = current {
+        if id == item.id() {
+            findings.push(LintFinding::new(
+                LintRule::HierarchyCycle,
+                item.code().as_str(),
+                "parent chain loops back to itself".to_string(),
+            ));
+            return;
+        }
+        if !seen.insert(id) {
+            // 环在更上层，由环上的成员各自报告。
+            return;
+        }
+        current = by_id.get(&id).and_then(|parent| parent.parent_id());
+    }
+}
+
+fn check_type_ref(
+    item: &BizMetadata,
+    target: &str,
+    by_code: &HashMap<(&str, &str), &BizMetadata>,
+) -> Option<LintFinding> {
+    let tenant = item.tenant_id().as_str();
+    let code = item.code().as_str();
+    let mut chain = vec![code];
+    let mut current = target;
+    loop {
+        if chain.contains(&current) {
+            chain.push(current);
+            return Some(LintFinding::new(
+                LintRule::TypeRefCycle,
+                code,
+                format!("type_ref cycle detected: {}", chain.join(" -> ")),
+            ));
+        }
+        chain.push(current);
+        if chain.len() - 1 > MAX_TYPE_REF_DEPTH {
+            return Some(LintFinding::new(
+                LintRule::TypeRefTooDeep,
+                code,
+                format!(
+                    "type_ref depth exceeded (>{MAX_TYPE_REF_DEPTH}): {}",
+                    chain.join(" -> ")
+                ),
+            ));
+        }
+
+        let Some(row) = by_code.get(&(tenant, current)) else {
+            return Some(LintFinding::new(
+                LintRule::TypeRefNotFound,
+                code,
+                format!("type_ref target not found: {current}"),
+            ));
+        };
+        if row.status() != BizMetadataStatus::Active {
+            return Some(LintFinding::new(
+                LintRule::TypeRefTargetNotActive,
+                code,
+                format!("type_ref target {current} is {}", row.status().as_str()),
+            ));
+        }
+        match row
+            .value_type()
+            .and_then(|value_type| type_ref_target(value_type.as_str()))
+        {
+            Some(next) => current = next,
+            None => return None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::value_object::{DataClass, TenantId, ValueType};
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository;
+    use chrono::Utc;
+
+    fn feature(code: &str, value_type: &str) -> BizMetadata {
+        BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            code,
+            code,
+            DataClass::Attribute,
+            ValueType::new(value_type).unwrap(),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    async fn catalog(items: Vec<BizMetadata>) -> Vec<LintFinding> {
+        let repo = InMemoryBizMetadataRepository::new();
+        for item in items {
+            repo.insert_biz_metadata(item).await.unwrap();
+        }
+        lint_catalog(&repo).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn clean_catalog_has_no_findings() {
+        let findings = catalog(vec![
+            feature("company.base.amount", "decimal"),
+            feature("company.base.total", "ref:company.base.amount"),
+        ])
+        .await;
+        assert!(findings.is_empty(), "{findings:?}");
+    }
+
+    #[tokio::test]
+    async fn reports_dangling_ref_and_cycle() {
+        let findings = catalog(vec![
+            feature("company.base.a", "ref:company.base.b"),
+            feature("company.base.b", "ref:company.base.a"),
+            feature("company.base.c", "string | ref:company.base.missing"),
+        ])
+        .await;
+
+        let summary: Vec<_> = findings
+            .iter()
+            .map(|finding| (finding.code.as_str(), finding.rule, finding.severity))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "company.base.a",
+                    LintRule::TypeRefCycle,
+                    LintSeverity::Error
+                ),
+                (
+                    "company.base.b",
+                    LintRule::TypeRefCycle,
+                    LintSeverity::Error
+                ),
+                (
+                    "company.base.c",
+                    LintRule::TypeRefNotFound,
+                    LintSeverity::Error
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_deprecated_target_and_orphan_parent() {
+        let mut deprecated = feature("company.base.old", "string");
+        deprecated
+            .change_status(BizMetadataStatus::Deprecated, Utc::now())
+            .unwrap();
+        let mut orphan = feature("company.base.orphan", "ref:company.base.old");
+        orphan
+            .set_parent_id(Some(BizMetadataId::new(9_999)), Utc::now())
+            .unwrap();
+
+        let findings = catalog(vec![deprecated, orphan]).await;
+        let rules: Vec<_> = findings.iter().map(|finding| finding.rule).collect();
+        assert_eq!(
+            rules,
+            vec![
+                LintRule::HierarchyParentMissing,
+                LintRule::TypeRefTargetNotActive,
+            ]
+        );
+        assert_eq!(findings[1].severity, LintSeverity::Warning);
+    }
+
+    #[test]
+    fn deep_ref_chain_is_reported() {
+        let items: Vec<_> = (0..=MAX_TYPE_REF_DEPTH + 1)
+            .map(|i| feature(&format!("chain.n{i}"), &format!("ref:chain.n{}", i + 1)))
+            .collect();
+        let findings = lint_items(&items[..1]);
+        assert_eq!(findings[0].rule, LintRule::TypeRefNotFound);
+
+        let findings = lint_items(&items);
+        assert!(
+            findings
+                .iter()
+                .any(|finding| finding.code == "chain.n0"
+                    && finding.rule == LintRule::TypeRefTooDeep)
+        );
+    }
+}
@@ -1,4 +1,8 @@
 pub mod aggregate;
+pub mod code;
+pub mod code_policy;
+pub mod lint;
 pub mod repository;
 pub mod value_object;
 pub use aggregate::{BizMetadata, MetadataSnapshot};
+pub use code_policy::{CodePolicy, CodeUniqueness};
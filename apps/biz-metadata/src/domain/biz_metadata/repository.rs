@@ -1,6 +1,154 @@
 use super::BizMetadata;
-use super::value_object::BizMetadataId;
-use domain_core::prelude::{Expression, QueryOptions, Repository};
+use super::value_object::{BizMetadataId, Version};
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+use crate::domain::error_code;
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{eq, ge, gt};
+use domain_core::pagination::Page;
+use domain_core::prelude::{Expression, OrderBy, QueryOptions, Repository};
+use futures_util::future::BoxFuture;
+use futures_util::stream::{self, Stream, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+
+/// 乐观锁更新未命中（记录不存在或版本不一致）时，仓储返回的 `Validation` 错误信息。
+pub const VERSION_CONFLICT_MESSAGE: &str = "biz_metadata not found or version mismatch";
+
+/// 分页加载子节点时的批量大小。
+const CHILDREN_BATCH_SIZE: u64 = 200;
+
+/// 可统计取值分布（分面）的字段白名单，均为枚举型字段。
+pub const FACET_FIELDS: &[&str] = &["object_type", "data_class", "status", "source"];
+
+/// 多个存活记录共用 `code` 时按编码定位失败的错误，列出已知的同编码记录 id。
+pub fn code_ambiguous(code: &str, ids: &[BizMetadataId]) -> DomainError {
+    let ids: Vec<String> = ids.iter().map(|id| id.value().to_string()).collect();
+    DomainError::Validation {
+        code: error_code::BIZ_METADATA_CODE_AMBIGUOUS,
+        message: format!(
+            "code {code} is shared by live biz_metadata [{}], look them up by id",
+            ids.join(", ")
+        ),
+    }
+}
+
+/// 校验字段可用于分面统计，否则返回 `query.field_not_facetable`。
+pub fn ensure_facetable(field: &str) -> Result<(), DomainError> {
+    if FACET_FIELDS.contains(&field) {
+        return Ok(());
+    }
+    Err(DomainError::Validation {
+        code: error_code::QUERY_FIELD_NOT_FACETABLE,
+        message: format!(
+            "field {field} is not facetable, expected one of: {}",
+            FACET_FIELDS.join(", ")
+        ),
+    })
+}
+
+/// 校验过滤表达式与排序引用的字段都在 `allowed` 内，否则返回 `query.field_unknown` 并列出全部未知字段。
+pub fn ensure_known_fields(
+    allowed: &[&str],
+    expr: &Expression,
+    order_bys: &[OrderBy],
+) -> Result<(), DomainError> {
+    let mut unknown: Vec<&str> = Vec::new();
+    for field in expr
+        .fields()
+        .into_iter()
+        .chain(order_bys.iter().map(|order| order.field.as_str()))
+    {
+        if !allowed.contains(&field) && !unknown.contains(&field) {
+            unknown.push(field);
+        }
+    }
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    Err(DomainError::Validation {
+        code: error_code::QUERY_FIELD_UNKNOWN,
+        message: format!(
+            "unknown query field: {}, expected one of: {}",
+            unknown.join(", "),
+            allowed.join(", ")
+        ),
+    })
+}
+
+/// 读取聚合在分面字段上的取值，字段为空或不在白名单内时返回 `None`。
+fn facet_value(item: &BizMetadata, field: &str) -> Option<&'static str> {
+    match field {
+        "object_type" => Some(item.object_type().as_str()),
+        "data_class" => item.data_class().map(|v| v.as_str()),
+        "status" => Some(item.status().as_str()),
+        "source" => Some(item.source().as_str()),
+        _ => None,
+    }
+}
+
+/// 判断错误是否为乐观锁版本冲突。
+pub fn is_version_conflict(err: &DomainError) -> bool {
+    err.code() == error_code::BIZ_METADATA_VERSION_CONFLICT
+}
+
+/// 判断错误是否可通过重新读取并重放整个操作解决：乐观锁版本冲突或事务序列化失败。
+///
+/// ```
+/// use biz_metadata::is_retryable;
+/// use biz_metadata::error_code;
+/// use domain_core::domain_error::DomainError;
+///
+/// let err = DomainError::Persistence {
+///     code: error_code::PERSISTENCE_SERIALIZATION_FAILURE,
+///     message: "could not serialize access due to concurrent update".into(),
+/// };
+/// assert!(is_retryable(&err));
+/// assert!(!is_retryable(&err.with_code(error_code::PERSISTENCE_TIMEOUT)));
+/// ```
+pub fn is_retryable(err: &DomainError) -> bool {
+    is_version_conflict(err) || err.code() == error_code::PERSISTENCE_SERIALIZATION_FAILURE
+}
+
+/// [`BizMetadataRepository::update_biz_metadata_locked_checked`] 在提交前执行的校验，
+/// 入参依次为加载时与改动后的记录。
+pub type LockedCheck<'a> =
+    Box<dyn FnOnce(BizMetadata, BizMetadata) -> BoxFuture<'a, Result<(), DomainError>> + Send + 'a>;
+
+/// 目录整体替换时写入的单个条目，见 [`BizMetadataRepository::replace_catalog`]。
+#[derive(Debug, Clone)]
+pub struct CatalogReplacement {
+    /// 父节点编码，须为同批中排在前面的条目。
+    pub parent_code: Option<String>,
+    /// 待插入的元数据，`parent_id` 由仓储按 `parent_code` 回填。
+    pub metadata: BizMetadata,
+    /// 该条目的别名，`metadata_id` 由仓储在元数据插入后回填。
+    pub aliases: Vec<BizMetadataAlias>,
+}
+
+impl CatalogReplacement {
+    /// 按已插入条目的 `code -> id` 回填 `parent_id`，父编码尚未插入时返回 `catalog.parent_unresolved`。
+    pub(crate) fn resolve_parent(
+        &mut self,
+        ids_by_code: &HashMap<String, BizMetadataId>,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let Some(code) = &self.parent_code else {
+            return Ok(());
+        };
+        let parent_id = ids_by_code
+            .get(code)
+            .copied()
+            .ok_or_else(|| DomainError::Validation {
+                code: error_code::CATALOG_PARENT_UNRESOLVED,
+                message: format!(
+                    "parent {code} of {} is not imported",
+                    self.metadata.code().as_str()
+                ),
+            })?;
+        self.metadata.set_parent_id(Some(parent_id), now)
+    }
+}
 
 pub trait BizMetadataRepository: Repository<BizMetadata> {
     fn insert_biz_metadata(&self, biz_metadata: BizMetadata) -> Self::InsertFuture<'_> {
@@ -22,4 +170,424 @@ pub trait BizMetadataRepository: Repository<BizMetadata> {
     fn query_biz_metadata(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
         self.query(expr, options)
     }
+
+    /// 过滤与排序可引用的字段名，供服务层严格模式校验；默认返回 `None`，表示不做校验。
+    ///
+    /// `query` 本身对未登记字段宽松处理：过滤条件视为恒真、排序忽略。
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// 读取 `id` 对应的记录、应用 `mutate` 后按版本提交，返回更新后的聚合。
+    ///
+    /// `mutate` 返回 `false` 表示内容未变化：不写库，直接返回 `mutate` 处理后的记录（调用方负责将其还原为加载时的状态）。
+    /// 记录不存在时返回 `biz_metadata.not_found`。等价于不做额外校验的
+    /// [`update_biz_metadata_locked_checked`](Self::update_biz_metadata_locked_checked)。
+    fn update_biz_metadata_locked<F>(
+        &self,
+        id: BizMetadataId,
+        mutate: F,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_
+    where
+        F: FnOnce(&mut BizMetadata) -> Result<bool, DomainError> + Send + 'static,
+    {
+        self.update_biz_metadata_locked_checked(
+            id,
+            mutate,
+            Box::new(|_, _| Box::pin(std::future::ready(Ok(())))),
+        )
+    }
+
+    /// 同 [`update_biz_metadata_locked`](Self::update_biz_metadata_locked)，`mutate` 有改动时在提交前以
+    /// （加载时、改动后）的记录调用 `check`，返回错误则放弃提交。
+    ///
+    /// 默认实现先读后写且不加锁；持久化实现应在同一事务内以 `SELECT ... FOR UPDATE` 锁定目标行，
+    /// 并在持有行锁期间执行 `check`，使依赖当前记录的校验（如挂载深度、同级编码）基于锁定后的记录。
+    fn update_biz_metadata_locked_checked<'a, F>(
+        &'a self,
+        id: BizMetadataId,
+        mutate: F,
+        check: LockedCheck<'a>,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + 'a
+    where
+        F: FnOnce(&mut BizMetadata) -> Result<bool, DomainError> + Send + 'static,
+    {
+        let find = self.find_by_id(id);
+        async move {
+            let mut biz_metadata = find.await?.ok_or_else(|| DomainError::Validation {
+                code: error_code::BIZ_METADATA_NOT_FOUND,
+                message: format!("biz_metadata {} not found", id.value()),
+            })?;
+            let loaded = biz_metadata.clone();
+            if !mutate(&mut biz_metadata)? {
+                return Ok(biz_metadata);
+            }
+            check(loaded, biz_metadata.clone()).await?;
+            self.update(biz_metadata).await
+        }
+    }
+
+    /// 按编码查找元数据，未命中返回 `Ok(None)`。
+    ///
+    /// 按父节点唯一时不同父节点下可能存在同编码的存活记录，此时无法按编码定位，
+    /// 返回 `biz_metadata.code_ambiguous`，调用方应改用 id 查询。
+    fn find_biz_metadata_by_code(
+        &self,
+        code: &str,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        let query = self.query(
+            Expression::cmp(eq("code", code)),
+            QueryOptions::new(Some(2), None).with_order_by(OrderBy::asc("id")),
+        );
+        let code = code.to_string();
+        async move {
+            let mut matched = query.await?.into_items().into_iter();
+            let found = matched.next();
+            if let (Some(first), Some(second)) = (&found, matched.next()) {
+                return Err(code_ambiguous(&code, &[first.id(), second.id()]));
+            }
+            Ok(found)
+        }
+    }
+
+    /// 返回 `id` 对应存活记录的当前版本号，不存在或已删除时返回 `None`。
+    ///
+    /// 默认实现加载完整聚合，持久化实现应只查询版本列。
+    fn find_version(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<Version>, DomainError>> + Send + '_ {
+        let found = self.find_biz_metadata_by_id(id);
+        async move { Ok(found.await?.map(|item| item.version())) }
+    }
+
+    /// 判断是否存在使用该编码的未删除记录；已软删除记录的编码可被复用。
+    fn code_exists(
+        &self,
+        code: &str,
+    ) -> impl Future<Output = Result<bool, DomainError>> + Send + '_ {
+        let query = self.query(
+            Expression::cmp(eq("code", code)),
+            QueryOptions::new(Some(1), None),
+        );
+        async move { Ok(!query.await?.into_items().is_empty()) }
+    }
+
+    /// 返回以 `parent_id` 为父节点的全部存活子节点，供同级编码唯一性检查使用。
+    fn find_biz_metadata_children(
+        &self,
+        parent_id: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        async move {
+            let mut children = Vec::new();
+            let mut offset = 0;
+            loop {
+                let options = QueryOptions::new(Some(CHILDREN_BATCH_SIZE), Some(offset))
+                    .with_order_by(OrderBy::asc("id"));
+                let page = self
+                    .query(Expression::cmp(eq("parent_id", parent_id.value())), options)
+                    .await?;
+                let has_next = page.has_next_page();
+                children.extend(page.into_items());
+                if !has_next {
+                    return Ok(children);
+                }
+                offset += CHILDREN_BATCH_SIZE;
+            }
+        }
+    }
+
+    /// 返回 `root` 及其全部存活后代，`root` 在首位、其余按 ID 升序；`root` 不存在或已删除时返回空列表。
+    ///
+    /// 成环的脏数据中每个节点只返回一次。默认实现逐层调用 [`Self::find_biz_metadata_children`]，
+    /// 持久化实现应以递归 CTE 单次查询重写该方法。
+    fn find_subtree(
+        &self,
+        root: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        self.find_subtree_within_depth(root, usize::MAX)
+    }
+
+    /// 与 [`Self::find_subtree`] 相同，但只下探到距 `root` `max_depth` 层以内（`root` 为第 0 层）。
+    ///
+    /// 持久化实现应在递归 CTE 内按层数截断，避免加载超出范围的深层节点。
+    fn find_subtree_within_depth(
+        &self,
+        root: BizMetadataId,
+        max_depth: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let find_root = self.find_by_id(root);
+        async move {
+            let Some(root) = find_root.await? else {
+                return Ok(Vec::new());
+            };
+            let mut visited = HashSet::from([root.id()]);
+            let mut descendants = Vec::new();
+            let mut frontier = vec![root.id()];
+            for _ in 0..max_depth {
+                if frontier.is_empty() {
+                    break;
+                }
+                let mut next = Vec::new();
+                for parent_id in frontier {
+                    for child in self.find_biz_metadata_children(parent_id).await? {
+                        if visited.insert(child.id()) {
+                            next.push(child.id());
+                            descendants.push(child);
+                        }
+                    }
+                }
+                frontier = next;
+            }
+            descendants.sort_by_key(|item| item.id().value());
+            descendants.insert(0, root);
+            Ok(descendants)
+        }
+    }
+
+    /// 查询 `since` 之后发生变更的记录，供下游增量同步使用。
+    ///
+    /// `include_deleted=true` 时需同时返回在 `since` 之后被软删除的记录，便于下游剔除缓存；
+    /// 默认实现基于通用查询，仅能覆盖未删除记录，持久化实现应重写该方法。
+    fn query_biz_metadata_changed_since(
+        &self,
+        since: DateTime<Utc>,
+        include_deleted: bool,
+        options: QueryOptions,
+    ) -> Self::QueryFuture<'_> {
+        let _ = include_deleted;
+        self.query(Expression::cmp(ge("updated_at", since)), options)
+    }
+
+    /// 批量软删除给定聚合（以 `deleted_at` 标记），返回受影响行数。
+    ///
+    /// 每条记录按 `version` 做乐观锁校验，任一冲突时整体失败；
+    /// 默认实现逐条调用 `update`，无法保证原子性，持久化实现应在单个事务内重写该方法。
+    fn soft_delete_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        async move {
+            let mut affected = 0;
+            for mut item in items {
+                item.mark_deleted(deleted_at)?;
+                self.update(item).await?;
+                affected += 1;
+            }
+            Ok(affected)
+        }
+    }
+
+    /// 批量提交已修改的聚合，返回更新后的聚合（顺序与入参一致）。
+    ///
+    /// 每条记录按 `version` 做乐观锁校验，任一冲突时整体失败；
+    /// 默认实现逐条调用 `update`，无法保证原子性，持久化实现应在单个事务内重写该方法。
+    fn update_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        async move {
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                updated.push(self.update(item).await?);
+            }
+            Ok(updated)
+        }
+    }
+
+    /// 统计满足 `expr` 的存活记录条数，不加载行数据。
+    ///
+    /// 租户与软删除范围与 `query` 一致；默认实现读取单条分页查询的 `total`，持久化实现应以 `COUNT` 重写该方法。
+    fn count_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        async move {
+            let page = self
+                .query(expr, QueryOptions::new(Some(1), Some(0)))
+                .await?;
+            Ok(page.total_count())
+        }
+    }
+
+    /// 按 `id` 升序逐条流式返回满足 `expr` 的存活记录，不分页也不一次性加载全部行，供备份与重建索引任务使用。
+    ///
+    /// 默认实现以 `id` 为游标分批调用 `query`（每批取上一批末尾之后的记录），避免偏移分页在大表上的平方级开销；
+    /// 持久化实现应以数据库游标重写。读取失败时流以该错误结束。
+    fn stream_all_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Stream<Item = Result<BizMetadata, DomainError>> + Send + '_ {
+        stream::unfold(Some(None), move |cursor: Option<Option<i64>>| {
+            let expr = expr.clone();
+            async move {
+                let after = cursor?;
+                let filter = match after {
+                    Some(id) => Expression::and(vec![expr, Expression::cmp(gt("id", id))]),
+                    None => expr,
+                };
+                let options = QueryOptions::new(Some(CHILDREN_BATCH_SIZE), Some(0))
+                    .with_order_by(OrderBy::asc("id"));
+                match self.query(filter, options).await {
+                    Ok(page) => {
+                        let items = page.into_items();
+                        let next = (items.len() as u64 == CHILDREN_BATCH_SIZE)
+                            .then(|| items.last().map(|item| item.id().value()));
+                        Some((items.into_iter().map(Ok).collect::<Vec<_>>(), next))
+                    }
+                    Err(err) => Some((vec![Err(err)], None)),
+                }
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// 统计存活记录在分面字段上的各取值及其数量，按取值升序，空值不计入。
+    ///
+    /// 字段须在 [`FACET_FIELDS`] 内；默认实现分批加载全部记录后在内存中计数，
+    /// 持久化实现应以 `GROUP BY` 重写该方法。
+    fn count_biz_metadata_values(
+        &self,
+        field: &str,
+    ) -> impl Future<Output = Result<Vec<(String, u64)>, DomainError>> + Send + '_ {
+        let field = field.to_string();
+        async move {
+            ensure_facetable(&field)?;
+            let mut counts = std::collections::BTreeMap::<String, u64>::new();
+            let mut offset = 0;
+            loop {
+                let options = QueryOptions::new(Some(CHILDREN_BATCH_SIZE), Some(offset))
+                    .with_order_by(OrderBy::asc("id"));
+                let page = self.query(Expression::True, options).await?;
+                let has_next = page.has_next_page();
+                for item in page.into_items() {
+                    if let Some(value) = facet_value(&item, &field) {
+                        *counts.entry(value.to_string()).or_default() += 1;
+                    }
+                }
+                if !has_next {
+                    return Ok(counts.into_iter().collect());
+                }
+                offset += CHILDREN_BATCH_SIZE;
+            }
+        }
+    }
+
+    /// 按 ID 查找已软删除的记录，不存在或未删除时返回 `None`。
+    ///
+    /// 默认实现不支持读取已删除行，返回 `Persistence` 错误。
+    fn find_deleted_biz_metadata_by_id(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        let _ = id;
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "reading deleted rows is not supported by this repository".into(),
+        }))
+    }
+
+    /// 提交已撤销删除的聚合：按 `version` 乐观锁匹配仍处于删除状态的行，返回恢复后的聚合。
+    ///
+    /// 恢复后与存活记录的 `code` 冲突时失败；默认实现不支持恢复，返回 `Persistence` 错误。
+    fn restore_biz_metadata(
+        &self,
+        biz_metadata: BizMetadata,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
+        let _ = biz_metadata;
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "restore is not supported by this repository".into(),
+        }))
+    }
+
+    /// 同 [`update_biz_metadata_many`](Self::update_biz_metadata_many) 提交 `items`（须含已标记删除的 `deleted`），
+    /// 并在同一事务内软删除 `deleted` 的全部存活别名，别名删除时间与写入的元数据删除时间一致，返回更新后的聚合。
+    ///
+    /// 任一步失败时整体回滚，不会留下“元数据已删除而别名仍存活”的中间态；
+    /// 默认实现不支持跨表写入，返回 `Persistence` 错误。
+    fn delete_biz_metadata_with_aliases(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let _ = (items, deleted);
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "cascading delete is not supported by this repository".into(),
+        }))
+    }
+
+    /// 合并提交：在同一事务内将 `loser` 的存活别名改挂到 `survivor`，并同
+    /// [`update_biz_metadata_many`](Self::update_biz_metadata_many) 提交 `items`（须含已标记删除的 `loser`），返回更新后的聚合。
+    ///
+    /// 别名在事务内加锁后重新读取，按 [`plan_alias_reassignment`](crate::domain::biz_metadata_alias::repository::plan_alias_reassignment)
+    /// 的规则去重、降级首选，改挂后 `survivor` 的存活别名数超过 `max_live` 时返回 `biz_metadata_alias.limit_exceeded`；
+    /// `now` 为别名变更的审计时间。任一步失败时整体回滚；默认实现不支持跨表写入，返回 `Persistence` 错误。
+    fn merge_biz_metadata(
+        &self,
+        items: Vec<BizMetadata>,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+        max_live: usize,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let _ = (items, loser, survivor, max_live, now);
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "merge is not supported by this repository".into(),
+        }))
+    }
+
+    /// 同 [`restore_biz_metadata`](Self::restore_biz_metadata)，并在同一事务内按
+    /// [`BizMetadataAliasRepository::restore_by_metadata_id`](crate::domain::biz_metadata_alias::BizMetadataAliasRepository::restore_by_metadata_id) 的规则恢复删除时间不早于 `deleted_at` 的别名，
+    /// 恢复时间取聚合的 `updated_at`。
+    ///
+    /// 任一步失败时整体回滚；默认实现不支持跨表写入，返回 `Persistence` 错误。
+    fn restore_biz_metadata_with_aliases(
+        &self,
+        biz_metadata: BizMetadata,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
+        let _ = (biz_metadata, deleted_at);
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "cascading restore is not supported by this repository".into(),
+        }))
+    }
+
+    /// 整体替换目录：软删除当前租户全部存活元数据及其存活别名，再按顺序插入 `entries` 与各自的别名，
+    /// 返回插入后的元数据（顺序与入参一致）。
+    ///
+    /// 两张表在单个事务内写入，任一步失败时整体回滚，原目录保持不变；
+    /// 默认实现不支持跨表写入，返回 `Persistence` 错误。
+    fn replace_catalog(
+        &self,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let _ = (entries, now);
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "catalog replacement is not supported by this repository".into(),
+        }))
+    }
+
+    /// 物理删除 `deleted_at` 早于 `cutoff` 且不再被存活记录引用的行，单次至多 `limit` 行，返回删除行数。
+    ///
+    /// 仍被存活子节点（`parent_id`）或存活别名（`metadata_id`）引用的行必须保留；
+    /// 被删除行的已软删除别名在同一事务内一并物理删除，不留下孤儿别名。
+    /// 默认实现不支持物理删除，返回 `Persistence` 错误。
+    fn purge_biz_metadata_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let _ = (cutoff, limit);
+        std::future::ready(Err(DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: "purge is not supported by this repository".into(),
+        }))
+    }
 }
@@ -6,8 +6,17 @@ pub struct BizMetadataCode(String);
 
 impl BizMetadataCode {
     /// 根据字符串创建编码，并校验非空。
+    ///
+    /// 编码统一规范化为小写，保证租户内唯一性不区分大小写。
+    ///
+    /// ```
+    /// use biz_metadata::BizMetadataCode;
+    ///
+    /// let code = BizMetadataCode::new("Company.Revenue").unwrap();
+    /// assert_eq!(code.as_str(), "company.revenue");
+    /// ```
     pub fn new(code: impl Into<String>) -> Result<Self, DomainError> {
-        let code = code.into();
+        let code = code.into().to_lowercase();
         validate_non_empty(&code, "biz_metadata code")?;
         Ok(Self(code))
     }
@@ -34,3 +43,16 @@ impl From<BizMetadataCode> for String {
         value.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalizes_to_lowercase() {
+        let upper = BizMetadataCode::new("COMPANY.X").unwrap();
+        let lower = BizMetadataCode::new("company.x").unwrap();
+        assert_eq!(upper, lower);
+        assert_eq!(upper.as_str(), "company.x");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 元数据生命周期状态。
@@ -10,6 +11,9 @@ pub enum BizMetadataStatus {
 }
 
 impl BizMetadataStatus {
+    /// 全部取值，按声明顺序。
+    pub const ALL: [Self; 2] = [Self::Active, Self::Deprecated];
+
     /// 从字符串创建状态值，大小写不敏感。
     pub fn new(raw: impl AsRef<str>) -> Result<Self, DomainError> {
         Self::try_from(raw.as_ref())
@@ -32,6 +36,7 @@ impl TryFrom<&str> for BizMetadataStatus {
             "active" => Ok(BizMetadataStatus::Active),
             "deprecated" => Ok(BizMetadataStatus::Deprecated),
             other => Err(DomainError::Validation {
+                code: error_code::STATUS_INVALID,
                 message: format!("invalid status: {other}"),
             }),
         }
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 元数据的数据分类，描述值的语义类型。
@@ -18,6 +19,16 @@ pub enum DataClass {
 }
 
 impl DataClass {
+    /// 全部取值，按声明顺序。
+    pub const ALL: [Self; 6] = [
+        Self::Attribute,
+        Self::Metric,
+        Self::Text,
+        Self::Object,
+        Self::Array,
+        Self::Identifier,
+    ];
+
     /// 从字符串创建数据分类，大小写不敏感。
     pub fn new(raw: impl AsRef<str>) -> Result<Self, DomainError> {
         Self::try_from(raw.as_ref())
@@ -48,6 +59,7 @@ impl TryFrom<&str> for DataClass {
             "array" => Ok(DataClass::Array),
             "identifier" => Ok(DataClass::Identifier),
             other => Err(DomainError::Validation {
+                code: error_code::DATA_CLASS_INVALID,
                 message: format!("invalid data_class: {other}"),
             }),
         }
@@ -9,7 +9,9 @@ mod object_type;
 mod source;
 mod tenant_id;
 mod unit;
+mod unit_registry;
 mod value_type;
+mod value_type_registry;
 mod version;
 
 pub use biz_metadata_code::BizMetadataCode;
@@ -21,5 +23,7 @@ pub use object_type::ObjectType;
 pub use source::Source;
 pub use tenant_id::TenantId;
 pub use unit::Unit;
+pub use unit_registry::UnitRegistry;
 pub use value_type::ValueType;
+pub use value_type_registry::ValueTypeRegistry;
 pub use version::Version;
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 语义字典节点的对象类型（五类核心对象）。
@@ -27,6 +28,15 @@ pub enum ObjectType {
 }
 
 impl ObjectType {
+    /// 全部取值，按声明顺序。
+    pub const ALL: [Self; 5] = [
+        Self::Entity,
+        Self::Event,
+        Self::Relation,
+        Self::Document,
+        Self::Feature,
+    ];
+
     /// 从字符串创建对象类型，大小写不敏感。
     pub fn new(raw: impl AsRef<str>) -> Result<Self, DomainError> {
         Self::try_from(raw.as_ref())
@@ -55,6 +65,7 @@ impl TryFrom<&str> for ObjectType {
             "document" => Ok(ObjectType::Document),
             "feature" => Ok(ObjectType::Feature),
             other => Err(DomainError::Validation {
+                code: error_code::OBJECT_TYPE_INVALID,
                 message: format!("invalid object_type: {other}"),
             }),
         }
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 元数据来源。
@@ -23,6 +24,9 @@ pub enum Source {
 }
 
 impl Source {
+    /// 全部取值，按声明顺序。
+    pub const ALL: [Self; 3] = [Self::Manual, Self::AutoMine, Self::ApiSync];
+
     /// 从字符串创建来源，大小写不敏感。
     pub fn new(raw: impl AsRef<str>) -> Result<Self, DomainError> {
         Self::try_from(raw.as_ref())
@@ -47,6 +51,7 @@ impl TryFrom<&str> for Source {
             "auto_mine" => Ok(Source::AutoMine),
             "api_sync" => Ok(Source::ApiSync),
             other => Err(DomainError::Validation {
+                code: error_code::SOURCE_INVALID,
                 message: format!("invalid source: {other}"),
             }),
         }
@@ -1,36 +1,99 @@
 use domain_core::prelude::{DomainError, ValueObject, validate_non_empty};
 
-/// 计量单位值对象，用于限制空白字符串。
+use super::UnitRegistry;
+
+/// 计量单位值对象，用于限制空白字符串，并按 [`UnitRegistry`] 归一等价写法。
+///
+/// 未登记的单位同样被接受，但 [`Unit::is_known`] 返回 `false`，便于门禁工具提示。
+///
+/// ```
+/// use biz_metadata::Unit;
+///
+/// assert_eq!(Unit::new("percent")?.as_str(), "%");
+/// assert!(!Unit::new("furlong")?.is_known());
+/// # Ok::<(), domain_core::domain_error::DomainError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct Unit(String);
+pub struct Unit {
+    value: String,
+    known: bool,
+}
 
 impl Unit {
-    /// 创建新的单位描述。
+    /// 创建新的单位描述，按内置登记表归一。
     pub fn new(unit: impl Into<String>) -> Result<Self, DomainError> {
+        Self::with_registry(unit, UnitRegistry::builtin())
+    }
+
+    /// 使用指定登记表创建单位描述。
+    pub fn with_registry(
+        unit: impl Into<String>,
+        registry: &UnitRegistry,
+    ) -> Result<Self, DomainError> {
         let unit = unit.into();
         validate_non_empty(&unit, "unit")?;
-        Ok(Self(unit))
+        Ok(match registry.canonicalize(&unit) {
+            Some(canonical) => Self {
+                value: canonical.to_string(),
+                known: true,
+            },
+            None => Self {
+                value: unit.trim().to_string(),
+                known: false,
+            },
+        })
     }
 
     /// 以 `&str` 形式读取单位。
     pub fn as_str(&self) -> &str {
-        &self.0
+        &self.value
+    }
+
+    /// 是否为登记表中的已知单位。
+    pub fn is_known(&self) -> bool {
+        self.known
     }
 
     /// 消费自身并返回内部字符串。
     pub fn into_inner(self) -> String {
-        self.0
+        self.value
     }
 }
 
 impl ValueObject for Unit {
     fn validate(&self) -> Result<(), DomainError> {
-        validate_non_empty(&self.0, "unit")
+        validate_non_empty(&self.value, "unit")
     }
 }
 
 impl From<Unit> for String {
     fn from(value: Unit) -> Self {
-        value.0
+        value.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonicalizes_known_aliases() {
+        assert_eq!(Unit::new("percent").unwrap().as_str(), "%");
+        assert_eq!(Unit::new(" PCT ").unwrap().as_str(), "%");
+        assert_eq!(Unit::new("rmb").unwrap().as_str(), "CNY");
+        assert_eq!(Unit::new("usd").unwrap().as_str(), "USD");
+        assert!(Unit::new("%").unwrap().is_known());
+    }
+
+    #[test]
+    fn accepts_unknown_unit_but_flags_it() {
+        let unit = Unit::new("furlong").unwrap();
+        assert_eq!(unit.as_str(), "furlong");
+        assert!(!unit.is_known());
+    }
+
+    #[test]
+    fn rejects_blank_unit() {
+        assert!(Unit::new("  ").is_err());
     }
 }
@@ -0,0 +1,78 @@
+use std::sync::OnceLock;
+
+/// 计量单位登记表：维护规范符号及其别名，用于把等价写法归一到同一单位。
+///
+/// 内置表覆盖常见金融单位（币种代码、百分比、倍数、量级），可基于 [`UnitRegistry::builtin`]
+/// 克隆后通过 [`UnitRegistry::register`] 扩展。
+///
+/// ```
+/// use biz_metadata::UnitRegistry;
+///
+/// let registry = UnitRegistry::builtin().clone().register("t", ["ton", "吨"]);
+/// assert_eq!(registry.canonicalize("Percent"), Some("%"));
+/// assert_eq!(registry.canonicalize("吨"), Some("t"));
+/// assert_eq!(registry.canonicalize("furlong"), None);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct UnitRegistry {
+    entries: Vec<(String, Vec<String>)>,
+}
+
+const BUILTIN_UNITS: &[(&str, &[&str])] = &[
+    ("%", &["percent", "pct", "percentage", "百分比"]),
+    ("‰", &["permille", "per mille", "千分比"]),
+    ("bp", &["bps", "basis point", "basis points", "基点"]),
+    ("x", &["times", "multiple", "倍"]),
+    ("CNY", &["rmb", "yuan", "元", "人民币", "¥"]),
+    ("USD", &["us$", "$", "dollar", "美元"]),
+    ("HKD", &["hk$", "港元", "港币"]),
+    ("EUR", &["€", "euro", "欧元"]),
+    ("JPY", &["円", "日元"]),
+    ("GBP", &["£", "英镑"]),
+    ("万元", &["10k cny"]),
+    ("亿元", &["100m cny"]),
+    ("shares", &["share", "股"]),
+    ("day", &["days", "天"]),
+];
+
+impl UnitRegistry {
+    /// 创建空登记表。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 内置的全局登记表。
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<UnitRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            BUILTIN_UNITS
+                .iter()
+                .fold(Self::new(), |registry, (canonical, aliases)| {
+                    registry.register(*canonical, aliases.iter().copied())
+                })
+        })
+    }
+
+    /// 登记一个规范单位及其别名（别名匹配大小写不敏感）。
+    pub fn register<I, S>(mut self, canonical: impl Into<String>, aliases: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let aliases = aliases.into_iter().map(Into::into).collect();
+        self.entries.push((canonical.into(), aliases));
+        self
+    }
+
+    /// 返回输入对应的规范符号，未登记时返回 `None`。
+    pub fn canonicalize(&self, raw: &str) -> Option<&str> {
+        let raw = raw.trim();
+        self.entries
+            .iter()
+            .find(|(canonical, aliases)| {
+                canonical.eq_ignore_ascii_case(raw)
+                    || aliases.iter().any(|alias| alias.eq_ignore_ascii_case(raw))
+            })
+            .map(|(canonical, _)| canonical.as_str())
+    }
+}
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject, validate_non_empty};
 
 /// 单个允许的值类型（如 "int"、"decimal"、"string"）。
@@ -8,7 +9,8 @@ impl ValueType {
     /// 创建新的值类型并校验非空。
     pub fn new(value_type: impl Into<String>) -> Result<Self, DomainError> {
         let value_type = value_type.into();
-        validate_non_empty(&value_type, "value type")?;
+        validate_non_empty(&value_type, "value type")
+            .map_err(|err| err.with_code(error_code::VALUE_TYPE_INVALID))?;
         Ok(Self(value_type))
     }
 
@@ -26,6 +28,7 @@ impl ValueType {
 impl ValueObject for ValueType {
     fn validate(&self) -> Result<(), DomainError> {
         validate_non_empty(&self.0, "value type")
+            .map_err(|err| err.with_code(error_code::VALUE_TYPE_INVALID))
     }
 }
 
@@ -0,0 +1,150 @@
+use std::collections::BTreeSet;
+use std::sync::OnceLock;
+
+use domain_core::prelude::DomainError;
+
+use super::ValueType;
+use crate::domain::biz_metadata::code::is_valid_segment;
+use crate::domain::error_code;
+
+/// 内置标量，与规范 A.5.1 及 `tools/biz-metadata-linter` 的 `SCALAR_TYPES` 一致。
+const BUILTIN_SCALARS: &[&str] = &["string", "int", "decimal", "boolean", "date", "datetime"];
+
+/// 值类型标量登记表：列出部署允许的标量名称，供 [`ValueType`] 语法校验使用。
+///
+/// 语法本身固定：标量、`a | b` 联合、`ref:<code>`、`json<object:S>` 与 `json<array:T>`；
+/// 登记表只决定哪些名称算作标量，新增标量无需改动解析逻辑。
+///
+/// ```
+/// use biz_metadata::{ValueType, ValueTypeRegistry};
+///
+/// let registry = ValueTypeRegistry::builtin().clone().register("money");
+/// assert!(registry.validate(&ValueType::new("money | ref:company.base.amount")?).is_ok());
+/// assert!(ValueTypeRegistry::builtin().validate(&ValueType::new("money")?).is_err());
+/// # Ok::<(), domain_core::domain_error::DomainError>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ValueTypeRegistry {
+    scalars: BTreeSet<String>,
+}
+
+impl ValueTypeRegistry {
+    /// 创建空登记表。
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 内置的全局登记表。
+    pub fn builtin() -> &'static Self {
+        static BUILTIN: OnceLock<ValueTypeRegistry> = OnceLock::new();
+        BUILTIN.get_or_init(|| {
+            BUILTIN_SCALARS
+                .iter()
+                .fold(Self::new(), |registry, scalar| registry.register(*scalar))
+        })
+    }
+
+    /// 登记一个标量名称。
+    pub fn register(mut self, scalar: impl Into<String>) -> Self {
+        self.scalars.insert(scalar.into());
+        self
+    }
+
+    /// 名称是否为已登记的标量。
+    pub fn contains(&self, scalar: &str) -> bool {
+        self.scalars.contains(scalar)
+    }
+
+    /// 已登记的标量，按名称升序。
+    pub fn scalars(&self) -> impl Iterator<Item = &str> {
+        self.scalars.iter().map(String::as_str)
+    }
+
+    /// 按语法校验值类型，出现未登记的标量或格式错误时返回 `value_type.invalid`。
+    pub fn validate(&self, value_type: &ValueType) -> Result<(), DomainError> {
+        let raw = value_type.as_str().trim();
+        let checked = match raw
+            .strip_prefix("json<")
+            .and_then(|rest| rest.strip_suffix('>'))
+        {
+            Some(inner) => match (inner.strip_prefix("object:"), inner.strip_prefix("array:")) {
+                (Some(schema), _) if is_code(schema) => Ok(()),
+                (_, Some("object")) => Ok(()),
+                (_, Some(element)) => self.check_union(element),
+                _ => Err(inner),
+            },
+            None => self.check_union(raw),
+        };
+        checked.map_err(|term| DomainError::Validation {
+            code: error_code::VALUE_TYPE_INVALID,
+            message: format!("value_type {raw} is invalid near '{term}'"),
+        })
+    }
+
+    /// 校验 `|` 分隔的各项，返回首个非法项。
+    fn check_union<'a>(&self, raw: &'a str) -> Result<(), &'a str> {
+        raw.split('|').map(str::trim).try_for_each(|term| {
+            let valid = match term.strip_prefix("ref:") {
+                Some(code) => is_code(code),
+                None => self.contains(term),
+            };
+            if valid { Ok(()) } else { Err(term) }
+        })
+    }
+}
+
+fn is_code(raw: &str) -> bool {
+    raw.split('.').all(is_valid_segment)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid(registry: &ValueTypeRegistry, raw: &str) -> bool {
+        registry.validate(&ValueType::new(raw).unwrap()).is_ok()
+    }
+
+    #[test]
+    fn builtin_registry_accepts_documented_grammar() {
+        let registry = ValueTypeRegistry::builtin();
+        assert_eq!(registry.scalars().collect::<Vec<_>>(), {
+            let mut scalars = BUILTIN_SCALARS.to_vec();
+            scalars.sort_unstable();
+            scalars
+        });
+        for raw in [
+            "decimal",
+            "int | string",
+            "ref:company.base.amount",
+            "string|ref:company.base.name",
+            "json<object:company.base>",
+            "json<array:object>",
+            "json<array:int|string>",
+        ] {
+            assert!(valid(registry, raw), "{raw}");
+        }
+        for raw in [
+            "float",
+            "int |",
+            "ref:Company",
+            "json<object:>",
+            "json<map:string>",
+        ] {
+            assert!(!valid(registry, raw), "{raw}");
+        }
+    }
+
+    #[test]
+    fn registered_custom_scalar_validates_and_unregistered_fails() {
+        let registry = ValueTypeRegistry::builtin().clone().register("uuid");
+        assert!(valid(&registry, "uuid"));
+        assert!(valid(&registry, "json<array:uuid>"));
+
+        let err = ValueTypeRegistry::builtin()
+            .validate(&ValueType::new("uuid").unwrap())
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::VALUE_TYPE_INVALID);
+        assert!(err.message().contains("'uuid'"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 版本号（乐观锁）。
@@ -20,6 +21,7 @@ impl Version {
     pub fn new(value: i32) -> Result<Self, DomainError> {
         if value <= 0 {
             return Err(DomainError::Validation {
+                code: error_code::VERSION_INVALID,
                 message: "version must be a positive integer".into(),
             });
         }
@@ -41,6 +43,7 @@ impl ValueObject for Version {
     fn validate(&self) -> Result<(), DomainError> {
         if self.0 <= 0 {
             return Err(DomainError::Validation {
+                code: error_code::VERSION_INVALID,
                 message: "version must be a positive integer".into(),
             });
         }
@@ -4,7 +4,7 @@ use domain_core::value_object::ValueObject;
 
 use crate::domain::biz_metadata::value_object::BizMetadataId;
 use crate::domain::biz_metadata_alias::value_object::{
-    AliasSource, AliasText, AliasWeight, BizMetadataAliasId, LanguageCode,
+    AliasNormalization, AliasSource, AliasText, AliasWeight, BizMetadataAliasId, LanguageCode,
 };
 
 /// 元数据别名聚合，描述一个标准元数据的自然语言同义词。
@@ -15,8 +15,9 @@ use crate::domain::biz_metadata_alias::value_object::{
 /// use biz_metadata::{BizMetadataAlias, BizMetadataId};
 ///
 /// # fn main() -> Result<(), domain_core::domain_error::DomainError> {
-/// let mut alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收")?;
-/// alias.change_weight(80)?;
+/// let now = chrono::Utc::now();
+/// let mut alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收", now)?;
+/// alias.change_weight(80, now)?;
 /// assert_eq!(alias.alias().as_str(), "营收");
 /// assert_eq!(alias.weight().value(), 80);
 /// # Ok(()) }
@@ -55,13 +56,16 @@ pub struct BizMetadataAliasSnapshot {
 }
 
 impl BizMetadataAlias {
-    /// 创建新的元数据别名，使用默认来源、权重与语言。
-    pub fn new(metadata_id: BizMetadataId, alias: impl Into<String>) -> Result<Self, DomainError> {
-        let now = Utc::now();
+    /// 创建新的元数据别名，文本按 NFKC 规范化，使用默认来源、权重与语言。
+    pub fn new(
+        metadata_id: BizMetadataId,
+        alias: impl Into<String>,
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
         Self::from_snapshot(BizMetadataAliasSnapshot {
             id: BizMetadataAliasId::new(0),
             metadata_id,
-            alias: alias.into(),
+            alias: AliasText::new(alias)?.into_inner(),
             source: AliasSource::Manual,
             weight: 0,
             is_primary: false,
@@ -71,6 +75,9 @@ impl BizMetadataAlias {
     }
 
     /// 按照持久化快照重建聚合。
+    ///
+    /// 文本原样保留（[`AliasNormalization::Verbatim`]），使加载结果与库中取值及唯一索引一致；
+    /// 新建别名应经 [`Self::new`] 或先以 [`AliasText::new`] 规范化。
     pub fn from_snapshot(snapshot: BizMetadataAliasSnapshot) -> Result<Self, DomainError> {
         let BizMetadataAliasSnapshot {
             id,
@@ -83,7 +90,7 @@ impl BizMetadataAlias {
             audit,
         } = snapshot;
 
-        let alias = AliasText::new(alias)?;
+        let alias = AliasText::with_normalization(alias, AliasNormalization::Verbatim)?;
         let weight = AliasWeight::new(weight)?;
         let language = LanguageCode::new(language)?;
 
@@ -99,6 +106,20 @@ impl BizMetadataAlias {
         })
     }
 
+    /// 导出持久化快照，便于仓储按新标识重建聚合。
+    pub fn to_snapshot(&self) -> BizMetadataAliasSnapshot {
+        BizMetadataAliasSnapshot {
+            id: self.id,
+            metadata_id: self.metadata_id,
+            alias: self.alias.as_str().to_string(),
+            source: self.source,
+            weight: self.weight.value(),
+            is_primary: self.is_primary,
+            language: self.language.as_str().to_string(),
+            audit: self.audit.clone(),
+        }
+    }
+
     /// 返回别名标识。
     pub fn id(&self) -> BizMetadataAliasId {
         self.id
@@ -155,41 +176,57 @@ impl BizMetadataAlias {
     }
 
     /// 调整关联的元数据 ID。
-    pub fn change_metadata_id(&mut self, metadata_id: BizMetadataId) -> Result<(), DomainError> {
+    pub fn change_metadata_id(
+        &mut self,
+        metadata_id: BizMetadataId,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         self.metadata_id = metadata_id;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
     /// 更新别名文本。
-    pub fn update_alias(&mut self, alias: AliasText) -> Result<(), DomainError> {
+    pub fn update_alias(
+        &mut self,
+        alias: AliasText,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         alias.validate()?;
         self.alias = alias;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
     /// 调整别名来源。
-    pub fn change_source(&mut self, source: AliasSource) -> Result<(), DomainError> {
+    pub fn change_source(
+        &mut self,
+        source: AliasSource,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         self.source = source;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
     /// 调整匹配权重。
-    pub fn change_weight(&mut self, weight: i32) -> Result<(), DomainError> {
+    pub fn change_weight(&mut self, weight: i32, now: DateTime<Utc>) -> Result<(), DomainError> {
         self.weight = AliasWeight::new(weight)?;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
-    /// 切换首选标记。
-    pub fn set_primary(&mut self, is_primary: bool) -> Result<(), DomainError> {
+    /// 切换首选标记，不调整权重；首选与权重的一致性由 [`PrimaryWeightPolicy`](crate::domain::biz_metadata_alias::PrimaryWeightPolicy) 按需约束。
+    pub fn set_primary(&mut self, is_primary: bool, now: DateTime<Utc>) -> Result<(), DomainError> {
         self.is_primary = is_primary;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
     /// 修改语言编码。
-    pub fn change_language(&mut self, language: LanguageCode) -> Result<(), DomainError> {
+    pub fn change_language(
+        &mut self,
+        language: LanguageCode,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
         language.validate()?;
         self.language = language;
-        self.bump_updated(Utc::now())
+        self.bump_updated(now)
     }
 
     /// 软删除别名。
@@ -197,6 +234,48 @@ impl BizMetadataAlias {
         self.audit.mark_deleted(delete_at)
     }
 
+    /// 撤销软删除。
+    pub fn restore(&mut self, restored_at: DateTime<Utc>) -> Result<(), DomainError> {
+        self.audit.restore(restored_at)
+    }
+
+    /// 比较业务内容是否一致：忽略审计时间戳，但仍比较 id 与是否已删除。
+    ///
+    /// 仅作比较辅助，`==` 仍逐字段比较。
+    ///
+    /// ```
+    /// use biz_metadata::{BizMetadataAlias, BizMetadataId};
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let now = Utc::now();
+    /// let earlier = BizMetadataAlias::new(BizMetadataId::new(1), "营收", now).unwrap();
+    /// let later =
+    ///     BizMetadataAlias::new(BizMetadataId::new(1), "营收", now + Duration::seconds(1)).unwrap();
+    /// assert!(earlier.content_eq(&later));
+    /// assert_ne!(earlier, later);
+    /// ```
+    pub fn content_eq(&self, other: &Self) -> bool {
+        // 解构保证新增字段时此处必须显式决定是否参与比较。
+        let Self {
+            id,
+            metadata_id,
+            alias,
+            source,
+            weight,
+            is_primary,
+            language,
+            audit,
+        } = self;
+        *id == other.id
+            && *metadata_id == other.metadata_id
+            && *alias == other.alias
+            && *source == other.source
+            && *weight == other.weight
+            && *is_primary == other.is_primary
+            && *language == other.language
+            && audit.is_deleted() == other.audit.is_deleted()
+    }
+
     fn bump_updated(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
         self.audit.bump_updated(now)
     }
@@ -215,10 +294,11 @@ impl AggregateRoot for BizMetadataAlias {}
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::Duration;
 
     #[test]
     fn create_alias_defaults() {
-        let alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收").unwrap();
+        let alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收", Utc::now()).unwrap();
         assert_eq!(alias.source(), AliasSource::Manual);
         assert_eq!(alias.weight().value(), 0);
         assert_eq!(alias.language().as_str(), "zh-CN");
@@ -227,10 +307,21 @@ mod tests {
 
     #[test]
     fn rejects_blank_alias() {
-        let result = BizMetadataAlias::new(BizMetadataId::new(1), "   ");
+        let result = BizMetadataAlias::new(BizMetadataId::new(1), "   ", Utc::now());
         assert!(result.is_err());
     }
 
+    #[test]
+    fn new_normalizes_but_rehydration_keeps_stored_text() {
+        let created = BizMetadataAlias::new(BizMetadataId::new(1), "ＧＭＶ", Utc::now()).unwrap();
+        assert_eq!(created.alias().as_str(), "GMV");
+
+        let mut snapshot = created.to_snapshot();
+        snapshot.alias = "ＧＭＶ".into();
+        let loaded = BizMetadataAlias::from_snapshot(snapshot).unwrap();
+        assert_eq!(loaded.alias().as_str(), "ＧＭＶ");
+    }
+
     #[test]
     fn prevents_invalid_weight() {
         let result = BizMetadataAlias::from_snapshot(BizMetadataAliasSnapshot {
@@ -245,4 +336,18 @@ mod tests {
         });
         assert!(result.is_err());
     }
+
+    #[test]
+    fn content_eq_ignores_audit_timestamps() {
+        let now = Utc::now();
+        let earlier = BizMetadataAlias::new(BizMetadataId::new(1), "营收", now).unwrap();
+        let mut later =
+            BizMetadataAlias::new(BizMetadataId::new(1), "营收", now - Duration::hours(1)).unwrap();
+        later.set_primary(false, now).unwrap();
+        assert!(earlier.content_eq(&later));
+        assert_ne!(earlier, later);
+
+        later.change_weight(10, now).unwrap();
+        assert!(!earlier.content_eq(&later));
+    }
 }
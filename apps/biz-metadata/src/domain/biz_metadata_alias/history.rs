@@ -0,0 +1,22 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::biz_metadata_alias::value_object::BizMetadataAliasId;
+
+/// 首选别名切换的历史记录，对应 `biz_metadata_alias_history` 表中 `action=promote_primary` 的一行。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasPrimaryChange {
+    /// 所属元数据。
+    pub metadata_id: BizMetadataId,
+    /// 被提升为首选的别名。
+    pub promoted: BizMetadataAliasId,
+    /// 被降级的原首选别名；切换前没有首选时为空。
+    pub demoted: Option<BizMetadataAliasId>,
+    /// 切换时间。
+    pub changed_at: DateTime<Utc>,
+}
+
+impl AliasPrimaryChange {
+    /// 历史表中的动作标识。
+    pub const ACTION: &'static str = "promote_primary";
+}
@@ -1,7 +1,13 @@
 pub mod aggregate;
+pub mod history;
+pub mod primary_weight;
 pub mod repository;
 pub mod value_object;
 
 pub use aggregate::{BizMetadataAlias, BizMetadataAliasSnapshot};
+pub use history::AliasPrimaryChange;
+pub use primary_weight::PrimaryWeightPolicy;
 pub use repository::BizMetadataAliasRepository;
-pub use value_object::{AliasSource, AliasText, AliasWeight, BizMetadataAliasId, LanguageCode};
+pub use value_object::{
+    AliasNormalization, AliasSource, AliasText, AliasWeight, BizMetadataAliasId, LanguageCode,
+};
@@ -0,0 +1,144 @@
+//! 首选别名的权重策略：保证首选别名按权重排序时不落后于普通别名。
+
+use chrono::{DateTime, Utc};
+use domain_core::prelude::DomainError;
+
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+use crate::domain::biz_metadata_alias::value_object::AliasWeight;
+use crate::domain::error_code;
+
+/// 首选别名与权重的一致性策略，默认不做检查。
+///
+/// 权重为 0 的首选别名会排在高权重的普通别名之后，多半是误操作；开启策略后，
+/// 首选别名的权重低于下限时按 [`PrimaryWeightPolicy::Require`] 拒绝，或按
+/// [`PrimaryWeightPolicy::Raise`] 自动提升到下限。非首选别名不受影响。
+///
+/// ```
+/// use biz_metadata::{AliasWeight, BizMetadataAlias, BizMetadataId, PrimaryWeightPolicy};
+///
+/// let now = chrono::Utc::now();
+/// let mut alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收", now).unwrap();
+/// alias.set_primary(true, now).unwrap();
+///
+/// let floor = AliasWeight::new(80).unwrap();
+/// assert!(PrimaryWeightPolicy::Require(floor).apply(&mut alias, now).is_err());
+/// PrimaryWeightPolicy::Raise(floor).apply(&mut alias, now).unwrap();
+/// assert_eq!(alias.weight().value(), 80);
+/// assert_eq!(PrimaryWeightPolicy::parse("raise:80"), Some(PrimaryWeightPolicy::Raise(floor)));
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrimaryWeightPolicy {
+    /// 不检查首选别名的权重。
+    #[default]
+    Unchecked,
+    /// 首选别名的权重低于下限时返回 `biz_metadata_alias.primary_weight_too_low`。
+    Require(AliasWeight),
+    /// 首选别名的权重低于下限时提升到下限。
+    Raise(AliasWeight),
+}
+
+impl PrimaryWeightPolicy {
+    /// 解析 `off`、`require:<权重>` 或 `raise:<权重>`，非法输入返回 `None`。
+    pub fn parse(raw: &str) -> Option<Self> {
+        let raw = raw.trim().to_ascii_lowercase();
+        if raw == "off" {
+            return Some(Self::Unchecked);
+        }
+        let (mode, weight) = raw.split_once(':')?;
+        let floor = AliasWeight::new(weight.trim().parse().ok()?).ok()?;
+        match mode.trim() {
+            "require" => Some(Self::Require(floor)),
+            "raise" => Some(Self::Raise(floor)),
+            _ => None,
+        }
+    }
+
+    /// 对 `alias` 执行策略；仅首选且权重低于下限的别名会被拒绝或提升。
+    pub fn apply(
+        &self,
+        alias: &mut BizMetadataAlias,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let floor = match self {
+            Self::Unchecked => return Ok(()),
+            Self::Require(floor) | Self::Raise(floor) => *floor,
+        };
+        if !alias.is_primary() || alias.weight().value() >= floor.value() {
+            return Ok(());
+        }
+        match self {
+            Self::Raise(_) => alias.change_weight(floor.value(), now),
+            _ => Err(DomainError::Validation {
+                code: error_code::ALIAS_PRIMARY_WEIGHT_TOO_LOW,
+                message: format!(
+                    "primary alias {} has weight {}, below the required {}",
+                    alias.alias().as_str(),
+                    alias.weight().value(),
+                    floor.value()
+                ),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::value_object::BizMetadataId;
+
+    fn alias(now: DateTime<Utc>, weight: i32, is_primary: bool) -> BizMetadataAlias {
+        let mut alias = BizMetadataAlias::new(BizMetadataId::new(1), "营收", now).unwrap();
+        alias.change_weight(weight, now).unwrap();
+        alias.set_primary(is_primary, now).unwrap();
+        alias
+    }
+
+    #[test]
+    fn require_rejects_low_weight_primary_only() {
+        let policy = PrimaryWeightPolicy::Require(AliasWeight::new(50).unwrap());
+        let now = Utc::now();
+
+        let err = policy.apply(&mut alias(now, 10, true), now).unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_PRIMARY_WEIGHT_TOO_LOW);
+        assert!(policy.apply(&mut alias(now, 50, true), now).is_ok());
+        assert!(policy.apply(&mut alias(now, 0, false), now).is_ok());
+        assert!(
+            PrimaryWeightPolicy::default()
+                .apply(&mut alias(now, 0, true), now)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn raise_lifts_low_weight_primary_to_the_floor() {
+        let policy = PrimaryWeightPolicy::Raise(AliasWeight::new(50).unwrap());
+        let now = Utc::now();
+
+        let mut low = alias(now, 10, true);
+        policy.apply(&mut low, now).unwrap();
+        assert_eq!(low.weight().value(), 50);
+
+        let mut high = alias(now, 90, true);
+        policy.apply(&mut high, now).unwrap();
+        assert_eq!(high.weight().value(), 90);
+
+        let mut plain = alias(now, 0, false);
+        policy.apply(&mut plain, now).unwrap();
+        assert_eq!(plain.weight().value(), 0);
+    }
+
+    #[test]
+    fn parse_rejects_unknown_modes_and_out_of_range_weights() {
+        assert_eq!(
+            PrimaryWeightPolicy::parse(" OFF "),
+            Some(PrimaryWeightPolicy::Unchecked)
+        );
+        assert_eq!(
+            PrimaryWeightPolicy::parse("require:60"),
+            Some(PrimaryWeightPolicy::Require(AliasWeight::new(60).unwrap()))
+        );
+        assert_eq!(PrimaryWeightPolicy::parse("raise:101"), None);
+        assert_eq!(PrimaryWeightPolicy::parse("bump:10"), None);
+        assert_eq!(PrimaryWeightPolicy::parse("raise"), None);
+    }
+}
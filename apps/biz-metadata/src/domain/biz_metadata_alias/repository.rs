@@ -1,7 +1,165 @@
-use domain_core::prelude::{Expression, QueryOptions, Repository};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::eq;
+use domain_core::pagination::Page;
+use domain_core::prelude::{Expression, OrderBy, QueryOptions, Repository};
 
 use super::BizMetadataAlias;
+use super::history::AliasPrimaryChange;
 use super::value_object::BizMetadataAliasId;
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::error_code;
+
+/// 默认实现按元数据加载别名时的分页大小。
+const METADATA_ALIASES_BATCH_SIZE: u64 = 200;
+
+/// 分页加载 `metadata_id` 下满足 `keep` 的全部别名（含已删除行）。
+pub(crate) async fn collect_aliases_of<R>(
+    repository: &R,
+    metadata_id: BizMetadataId,
+    keep: impl Fn(&BizMetadataAlias) -> bool,
+) -> Result<Vec<BizMetadataAlias>, DomainError>
+where
+    R: BizMetadataAliasRepository + ?Sized,
+{
+    collect_aliases_where(
+        repository,
+        Expression::cmp(eq("metadata_id", metadata_id.value())),
+        keep,
+    )
+    .await
+}
+
+/// 按 ID 升序分页加载匹配 `filter` 且满足 `keep` 的全部别名（含已删除行）。
+pub(crate) async fn collect_aliases_where<R>(
+    repository: &R,
+    filter: Expression,
+    keep: impl Fn(&BizMetadataAlias) -> bool,
+) -> Result<Vec<BizMetadataAlias>, DomainError>
+where
+    R: BizMetadataAliasRepository + ?Sized,
+{
+    let mut collected = Vec::new();
+    let mut offset = 0;
+    loop {
+        let options = QueryOptions::new(Some(METADATA_ALIASES_BATCH_SIZE), Some(offset))
+            .with_order_by(OrderBy::asc("id"));
+        let page = repository.query(filter.clone(), options).await?;
+        let has_next = page.has_next_page();
+        collected.extend(page.into_items().into_iter().filter(|alias| keep(alias)));
+        if !has_next {
+            return Ok(collected);
+        }
+        offset += METADATA_ALIASES_BATCH_SIZE;
+    }
+}
+
+/// `metadata_id` 的存活别名总数 `total` 超过上限 `limit` 时返回的错误。
+pub(crate) fn alias_limit_exceeded(
+    metadata_id: BizMetadataId,
+    total: u64,
+    limit: usize,
+) -> DomainError {
+    DomainError::Validation {
+        code: error_code::ALIAS_LIMIT_EXCEEDED,
+        message: format!(
+            "metadata {} would have {total} live aliases, exceeding the limit of {limit}",
+            metadata_id.value()
+        ),
+    }
+}
+
+/// 按所属元数据统计 `items` 的条数，供批量写入前后校验存活别名上限。
+pub(crate) fn count_by_metadata(items: &[BizMetadataAlias]) -> HashMap<BizMetadataId, u64> {
+    let mut counts = HashMap::new();
+    for alias in items {
+        *counts.entry(alias.metadata_id()).or_default() += 1;
+    }
+    counts
+}
+
+/// 规划将 `moving`（`from` 的存活别名）改挂到 `to` 的变更，`survivors` 为 `to` 当前的存活别名；
+/// 返回待写入的别名与其中被改挂（而非去重删除）的别名 ID。
+///
+/// 与 `to` 已有别名（或先改挂的别名）的 (`alias`, `language`) 重复者就地软删除；`to` 已有首选别名时，
+/// 改挂过来的首选别名降级。改挂后 `to` 的存活别名数超过 `max_live` 时返回 `biz_metadata_alias.limit_exceeded`。
+/// 只做计算不写入，供仓储在事务内加锁读取后复用。
+pub(crate) fn plan_alias_reassignment(
+    moving: Vec<BizMetadataAlias>,
+    survivors: &[BizMetadataAlias],
+    to: BizMetadataId,
+    now: DateTime<Utc>,
+    max_live: usize,
+) -> Result<(Vec<BizMetadataAlias>, Vec<BizMetadataAliasId>), DomainError> {
+    let mut taken: HashSet<(String, String)> = survivors
+        .iter()
+        .map(|alias| {
+            (
+                alias.alias().as_str().to_string(),
+                alias.language().as_str().to_string(),
+            )
+        })
+        .collect();
+    let mut has_primary = survivors.iter().any(BizMetadataAlias::is_primary);
+    let mut changes = Vec::with_capacity(moving.len());
+    let mut moved_ids = Vec::new();
+    for mut alias in moving {
+        let key = (
+            alias.alias().as_str().to_string(),
+            alias.language().as_str().to_string(),
+        );
+        if !taken.insert(key) {
+            alias.mark_deleted(now)?;
+            changes.push(alias);
+            continue;
+        }
+        alias.change_metadata_id(to, now)?;
+        if alias.is_primary() {
+            if has_primary {
+                alias.set_primary(false, now)?;
+            }
+            has_primary = true;
+        }
+        moved_ids.push(alias.id());
+        changes.push(alias);
+    }
+    let total = (survivors.len() + moved_ids.len()) as u64;
+    if total > max_live as u64 {
+        return Err(alias_limit_exceeded(to, total, max_live));
+    }
+    Ok((changes, moved_ids))
+}
+
+/// 将 `from` 的存活别名按 [`plan_alias_reassignment`] 的规则改挂到 `to`，返回被改挂的别名。
+///
+/// 超过上限时不做任何写入；全部变更通过 [`BizMetadataAliasRepository::update_alias_many`] 一次写入。
+pub(crate) async fn reassign_aliases<R>(
+    repository: &R,
+    from: BizMetadataId,
+    to: BizMetadataId,
+    now: DateTime<Utc>,
+    max_live: usize,
+) -> Result<Vec<BizMetadataAlias>, DomainError>
+where
+    R: BizMetadataAliasRepository + ?Sized,
+{
+    if from == to {
+        return Ok(Vec::new());
+    }
+    let alive = |alias: &BizMetadataAlias| alias.delete_at().is_none();
+    let moving = collect_aliases_of(repository, from, alive).await?;
+    let survivors = collect_aliases_of(repository, to, alive).await?;
+    let (changes, moved_ids) = plan_alias_reassignment(moving, &survivors, to, now, max_live)?;
+
+    let updated = repository.update_alias_many(changes).await?;
+    Ok(updated
+        .into_iter()
+        .filter(|alias| moved_ids.contains(&alias.id()))
+        .collect())
+}
 
 /// `biz_metadata_alias` 的仓储抽象。
 pub trait BizMetadataAliasRepository: Repository<BizMetadataAlias> {
@@ -24,4 +182,172 @@ pub trait BizMetadataAliasRepository: Repository<BizMetadataAlias> {
     fn query_alias(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
         self.query(expr, options)
     }
+
+    /// 过滤与排序可引用的字段名，供服务层严格模式校验；默认返回 `None`，表示不做校验。
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        None
+    }
+
+    /// 统计 `metadata_id` 下的存活别名数。
+    ///
+    /// 默认实现分页加载全部别名后计数，持久化实现应以单条 `COUNT` 重写该方法。
+    fn count_live_aliases(
+        &self,
+        metadata_id: BizMetadataId,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        async move {
+            let alive =
+                collect_aliases_of(self, metadata_id, |alias| alias.delete_at().is_none()).await?;
+            Ok(alive.len() as u64)
+        }
+    }
+
+    /// 批量插入别名，按输入顺序返回持久化后的别名，任一失败时整体失败。
+    ///
+    /// 任一元数据插入后的存活别名总数超过 `max_live` 时返回 `biz_metadata_alias.limit_exceeded`，不做任何写入。
+    /// 默认实现先计数再逐条调用 `insert`，无法保证原子性；持久化实现应在单个事务内按元数据加锁后计数并插入。
+    fn insert_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+        max_live: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        async move {
+            for (metadata_id, adding) in count_by_metadata(&items) {
+                let total = self.count_live_aliases(metadata_id).await? + adding;
+                if total > max_live as u64 {
+                    return Err(alias_limit_exceeded(metadata_id, total, max_live));
+                }
+            }
+            let mut inserted = Vec::with_capacity(items.len());
+            for item in items {
+                inserted.push(self.insert(item).await?);
+            }
+            Ok(inserted)
+        }
+    }
+
+    /// 批量覆盖已存在的别名，返回更新后的别名，任一不存在时整体失败。
+    ///
+    /// 默认实现逐条调用 `update`，无法保证原子性，持久化实现应在单个事务内重写该方法。
+    fn update_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        async move {
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                updated.push(self.update(item).await?);
+            }
+            Ok(updated)
+        }
+    }
+
+    /// 返回与 `alias` 的 (`metadata_id`, `alias`, `language`) 相同的存活别名，不存在时插入 `alias` 并返回。
+    ///
+    /// 需要插入且插入后存活别名数超过 `max_live` 时返回 `biz_metadata_alias.limit_exceeded`。
+    /// 默认实现先查后插且不加锁，并发调用可能重复插入或越过上限；持久化实现应依赖唯一索引在单个事务内完成。
+    fn find_or_insert_alias(
+        &self,
+        alias: BizMetadataAlias,
+        max_live: usize,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        async move {
+            let filter = Expression::and(vec![
+                Expression::cmp(eq("metadata_id", alias.metadata_id().value())),
+                Expression::cmp(eq("alias", alias.alias().as_str())),
+                Expression::cmp(eq("language", alias.language().as_str())),
+            ]);
+            let existing = self.query(filter, QueryOptions::default()).await?;
+            if let Some(found) = existing
+                .into_items()
+                .into_iter()
+                .find(|candidate| candidate.delete_at().is_none())
+            {
+                return Ok(found);
+            }
+            let total = self.count_live_aliases(alias.metadata_id()).await? + 1;
+            if total > max_live as u64 {
+                return Err(alias_limit_exceeded(alias.metadata_id(), total, max_live));
+            }
+            self.insert(alias).await
+        }
+    }
+
+    /// 切换首选别名：保存已降级的 `demoted` 与已提升的 `promoted`，并写入 `change` 历史，返回提升后的别名。
+    ///
+    /// 默认实现逐条调用 `update` 且不记录历史，无法保证原子性，持久化实现应在单个事务内重写该方法，
+    /// 并在事务内按 `change.metadata_id` 加锁后重新定位当前首选别名，不以调用方事务外读取的 `demoted` 快照覆盖行。
+    fn swap_primary_alias(
+        &self,
+        demoted: Vec<BizMetadataAlias>,
+        promoted: BizMetadataAlias,
+        change: AliasPrimaryChange,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        let _ = change;
+        async move {
+            for alias in demoted {
+                self.update(alias).await?;
+            }
+            self.update(promoted).await
+        }
+    }
+
+    /// 软删除 `metadata_id` 下的全部存活别名，返回受影响行数。
+    ///
+    /// 供元数据删除时级联使用，删除时间不早于 `deleted_at`（持久化实现可按库内时钟取较大值）；默认实现逐条调用 `update`，无法保证原子性，持久化实现应以单条 `UPDATE` 重写该方法。
+    fn soft_delete_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        async move {
+            let alive =
+                collect_aliases_of(self, metadata_id, |alias| alias.delete_at().is_none()).await?;
+            let mut affected = 0;
+            for mut alias in alive {
+                alias.mark_deleted(deleted_at)?;
+                self.update(alias).await?;
+                affected += 1;
+            }
+            Ok(affected)
+        }
+    }
+
+    /// 恢复 `metadata_id` 下删除时间不早于 `deleted_at` 的别名，`updated_at` 记为 `restored_at`，返回受影响行数。
+    ///
+    /// `deleted_at` 为级联删除时元数据的删除时间，早于它被单独删除的别名保持删除状态；
+    /// 元数据删除期间同一 (`alias`, `language`) 已被新的存活别名占用时跳过该行（同批内重复时只恢复 ID 最小者），
+    /// 避免违反存活别名唯一索引。默认实现逐条调用 `update`，无法保证原子性，持久化实现应以单条 `UPDATE` 重写该方法。
+    fn restore_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+        restored_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        async move {
+            let key = |alias: &BizMetadataAlias| {
+                (
+                    alias.alias().as_str().to_string(),
+                    alias.language().as_str().to_string(),
+                )
+            };
+            let all = collect_aliases_of(self, metadata_id, |_| true).await?;
+            let mut taken: HashSet<_> = all
+                .iter()
+                .filter(|alias| alias.delete_at().is_none())
+                .map(key)
+                .collect();
+            let mut affected = 0;
+            for mut alias in all {
+                if alias.delete_at().is_none_or(|at| at < deleted_at) || !taken.insert(key(&alias))
+                {
+                    continue;
+                }
+                alias.restore(restored_at)?;
+                self.update(alias).await?;
+                affected += 1;
+            }
+            Ok(affected)
+        }
+    }
 }
@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 别名来源枚举，标记别名的生成方式。
@@ -22,6 +23,7 @@ impl AliasSource {
             "log" => Ok(Self::Log),
             "embedding" => Ok(Self::Embedding),
             other => Err(DomainError::Validation {
+                code: error_code::ALIAS_SOURCE_INVALID,
                 message: format!("invalid alias source: {other}"),
             }),
         }
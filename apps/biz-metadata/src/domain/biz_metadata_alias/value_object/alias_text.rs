@@ -1,13 +1,51 @@
 use domain_core::prelude::{DomainError, ValueObject, validate_non_empty};
+use unicode_normalization::UnicodeNormalization;
 
-/// 自然语言别名值对象，要求非空且包含可见字符。
+/// 别名文本的规范化方式，决定入库与匹配时使用的形式。
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AliasNormalization {
+    /// Unicode NFKC：全角字母数字与标点折叠为半角、兼容字符与组合符号合成为标准形式。
+    #[default]
+    Nfkc,
+    /// 原样保留，仅校验非空。
+    Verbatim,
+}
+
+impl AliasNormalization {
+    /// 按规范化方式转换文本。
+    pub fn apply(self, raw: &str) -> String {
+        match self {
+            Self::Nfkc => raw.nfkc().collect(),
+            Self::Verbatim => raw.to_string(),
+        }
+    }
+}
+
+/// 自然语言别名值对象，要求非空且包含可见字符，默认以 NFKC 规范化后存储。
+///
+/// ```
+/// use biz_metadata::{AliasNormalization, AliasText};
+///
+/// assert_eq!(AliasText::new("ＡＢＣ")?, AliasText::new("ABC")?);
+/// let raw = AliasText::with_normalization("ＡＢＣ", AliasNormalization::Verbatim)?;
+/// assert_eq!(raw.as_str(), "ＡＢＣ");
+/// # Ok::<(), domain_core::domain_error::DomainError>(())
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AliasText(String);
 
 impl AliasText {
-    /// 根据输入字符串创建别名并校验。
+    /// 根据输入字符串创建别名，按 [`AliasNormalization::Nfkc`] 规范化后校验。
     pub fn new(alias: impl Into<String>) -> Result<Self, DomainError> {
-        let alias = alias.into();
+        Self::with_normalization(alias, AliasNormalization::default())
+    }
+
+    /// 使用指定规范化方式创建别名，规范化后的文本须包含可见字符。
+    pub fn with_normalization(
+        alias: impl Into<String>,
+        normalization: AliasNormalization,
+    ) -> Result<Self, DomainError> {
+        let alias = normalization.apply(&alias.into());
         validate_non_empty(&alias, "biz_metadata_alias.alias")?;
         Ok(Self(alias))
     }
@@ -28,3 +66,22 @@ impl ValueObject for AliasText {
         validate_non_empty(&self.0, "biz_metadata_alias.alias")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn folds_full_width_and_composes_marks() {
+        assert_eq!(AliasText::new("ＡＢＣ１２３").unwrap().as_str(), "ABC123");
+        assert_eq!(AliasText::new("Cafe\u{301}").unwrap().as_str(), "Caf\u{e9}");
+        assert_eq!(AliasText::new("营业收入").unwrap().as_str(), "营业收入");
+    }
+
+    #[test]
+    fn rejects_blank_alias_after_normalization() {
+        for blank in ["", "   ", "\u{3000}\u{3000}", "\t\n"] {
+            assert!(AliasText::new(blank).is_err(), "{blank:?}");
+        }
+    }
+}
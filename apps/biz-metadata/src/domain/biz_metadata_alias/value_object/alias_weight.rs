@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject};
 
 /// 匹配权重，取值范围 0~100，数值越高优先级越高。
@@ -11,6 +12,7 @@ impl AliasWeight {
             Ok(Self(weight))
         } else {
             Err(DomainError::Validation {
+                code: error_code::ALIAS_WEIGHT_INVALID,
                 message: format!("weight must be between 0 and 100, got {weight}"),
             })
         }
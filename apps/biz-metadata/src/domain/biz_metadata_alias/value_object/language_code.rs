@@ -1,3 +1,4 @@
+use crate::domain::error_code;
 use domain_core::prelude::{DomainError, ValueObject, validate_non_empty};
 
 /// 语言编码（如 zh-CN / en-US），最长 16 字符。
@@ -11,6 +12,7 @@ impl LanguageCode {
         validate_non_empty(&code, "biz_metadata_alias.language")?;
         if code.len() > 16 {
             return Err(DomainError::Validation {
+                code: error_code::LANGUAGE_CODE_INVALID,
                 message: "language code length must be <= 16".into(),
             });
         }
@@ -32,6 +34,7 @@ impl ValueObject for LanguageCode {
     fn validate(&self) -> Result<(), DomainError> {
         if self.0.len() > 16 {
             Err(DomainError::Validation {
+                code: error_code::LANGUAGE_CODE_INVALID,
                 message: "language code length must be <= 16".into(),
             })
         } else {
@@ -8,6 +8,6 @@ mod language_code;
 
 pub use alias_id::BizMetadataAliasId;
 pub use alias_source::AliasSource;
-pub use alias_text::AliasText;
+pub use alias_text::{AliasNormalization, AliasText};
 pub use alias_weight::AliasWeight;
 pub use language_code::LanguageCode;
@@ -0,0 +1,107 @@
+//! 业务元数据服务的稳定错误码，集中登记于此。
+//!
+//! 错误码随 [`DomainError`](domain_core::domain_error::DomainError) 与 HTTP problem-details 的 `code`
+//! 字段返回，格式为 `<领域>.<失败原因>`，发布后不再修改。通用兜底码见 [`domain_core::error_code`]。
+//!
+//! | 错误码 | 含义 |
+//! | --- | --- |
+//! | `biz_metadata.not_found` | 元数据不存在或已删除 |
+//! | `biz_metadata.version_conflict` | 乐观锁版本不一致 |
+//! | `biz_metadata.code_conflict` | 同租户下存活记录的编码重复 |
+//! | `biz_metadata.duplicate_code` | 创建前预检发现编码已被存活记录占用 |
+//! | `biz_metadata.feature_field_required` | feature 缺少 data_class/value_type |
+//! | `biz_metadata.object_type_mismatch` | 非 feature 节点设置了 feature 专属字段，构造方式与类型不符，或合并双方类型不同 |
+//! | `biz_metadata.parent_chain_invalid` | 父节点链成环或过深 |
+//! | `biz_metadata.tree_too_deep` | 设置父节点后树深度超过服务配置的上限 |
+//! | `biz_metadata.delete_requires_version` | 删除必须携带版本号（软删） |
+//! | `biz_metadata.has_dependents` | 仍被存活子节点引用，拒绝删除 |
+//! | `biz_metadata.filter_unconfirmed` | 批量删除使用恒真过滤条件但未确认 |
+//! | `biz_metadata.merge_invalid` | 合并的双方为同一节点 |
+//! | `biz_metadata.id_conflict` | 预分配的 id 非法、已被占用或聚合已持有 id |
+//! | `biz_metadata.code_ambiguous` | 按父节点唯一时多个存活记录共用同一编码，无法按编码定位 |
+//! | `biz_metadata.code_index_mismatch` | 数据库中的编码唯一索引与配置的编码唯一性不一致 |
+//! | `catalog.schema_unsupported` | 目录导出的格式版本不受支持 |
+//! | `catalog.parent_unresolved` | 导入时父节点编码无法解析或父子关系成环 |
+//! | `code_policy.max_depth_exceeded` | 编码段数超过命名策略上限 |
+//! | `code_policy.root_not_allowed` | 编码首段不在命名策略白名单内 |
+//! | `code_policy.segment_forbidden` | 编码包含命名策略禁用的段 |
+//! | `value_type.invalid` | 值类型为空、语法错误、含未登记的标量，或不被当前数据分类允许 |
+//! | `unit.not_allowed` | 当前数据分类不允许设置单位 |
+//! | `version.invalid` | 版本号不是正整数 |
+//! | `object_type.invalid` / `data_class.invalid` / `status.invalid` / `source.invalid` | 枚举取值非法 |
+//! | `biz_metadata_alias.not_found` | 别名不存在 |
+//! | `biz_metadata_alias.alias_required` | 别名文本不可清空 |
+//! | `biz_metadata_alias.metadata_mismatch` | 别名不属于指定的元数据 |
+//! | `biz_metadata_alias.source_invalid` | 别名来源取值非法 |
+//! | `biz_metadata_alias.weight_invalid` | 别名权重越界 |
+//! | `biz_metadata_alias.duplicate` | 同一元数据下已存在文本与语言相同的存活别名 |
+//! | `biz_metadata_alias.primary_weight_too_low` | 首选别名的权重低于服务配置的下限 |
+//! | `biz_metadata_alias.limit_exceeded` | 元数据的存活别名数将超过服务配置的上限 |
+//! | `language_code.invalid` | 语言代码非法 |
+//! | `persistence.timeout` | 数据库调用超时，或语句因 `statement_timeout` 被服务端取消 |
+//! | `persistence.serialization_failure` | 事务与并发事务冲突被数据库中止，可整体重试 |
+//! | `persistence.row_missing` | 写入成功后回读不到记录 |
+//! | `persistence.unique_violation` | 写入违反未单独登记的唯一约束 |
+//! | `persistence.reference_missing` | 写入引用的记录不存在（外键约束） |
+//! | `query.field_unknown` | 过滤或排序引用了仓储未登记的字段 |
+//! | `query.field_not_facetable` | 字段不在可统计取值分布的白名单内 |
+//! | `query.value_invalid` | 过滤值无法转换为字段的类型 |
+//! | `request.invalid` | HTTP 请求参数或载荷非法 |
+//! | `resource.not_found` | HTTP 资源不存在 |
+//! | `resource.gone` | HTTP 资源已被软删除 |
+//! | `request.too_large` | HTTP 请求体超过大小上限 |
+//! | `request.rate_limited` | 写操作超过限流速率 |
+//! | `auth.unauthorized` | 缺少或无效的 Bearer Token |
+//! | `auth.tenant_forbidden` | Token 中的租户不是本服务所服务的租户 |
+
+pub const BIZ_METADATA_NOT_FOUND: &str = "biz_metadata.not_found";
+pub const BIZ_METADATA_VERSION_CONFLICT: &str = "biz_metadata.version_conflict";
+pub const BIZ_METADATA_CODE_CONFLICT: &str = "biz_metadata.code_conflict";
+pub const BIZ_METADATA_DUPLICATE_CODE: &str = "biz_metadata.duplicate_code";
+pub const BIZ_METADATA_FEATURE_FIELD_REQUIRED: &str = "biz_metadata.feature_field_required";
+pub const BIZ_METADATA_OBJECT_TYPE_MISMATCH: &str = "biz_metadata.object_type_mismatch";
+pub const BIZ_METADATA_PARENT_CHAIN_INVALID: &str = "biz_metadata.parent_chain_invalid";
+pub const BIZ_METADATA_TREE_TOO_DEEP: &str = "biz_metadata.tree_too_deep";
+pub const BIZ_METADATA_DELETE_REQUIRES_VERSION: &str = "biz_metadata.delete_requires_version";
+pub const BIZ_METADATA_HAS_DEPENDENTS: &str = "biz_metadata.has_dependents";
+pub const BIZ_METADATA_FILTER_UNCONFIRMED: &str = "biz_metadata.filter_unconfirmed";
+pub const BIZ_METADATA_MERGE_INVALID: &str = "biz_metadata.merge_invalid";
+pub const BIZ_METADATA_ID_CONFLICT: &str = "biz_metadata.id_conflict";
+pub const BIZ_METADATA_CODE_AMBIGUOUS: &str = "biz_metadata.code_ambiguous";
+pub const BIZ_METADATA_CODE_INDEX_MISMATCH: &str = "biz_metadata.code_index_mismatch";
+pub const CATALOG_SCHEMA_UNSUPPORTED: &str = "catalog.schema_unsupported";
+pub const CATALOG_PARENT_UNRESOLVED: &str = "catalog.parent_unresolved";
+pub const CODE_POLICY_MAX_DEPTH_EXCEEDED: &str = "code_policy.max_depth_exceeded";
+pub const CODE_POLICY_ROOT_NOT_ALLOWED: &str = "code_policy.root_not_allowed";
+pub const CODE_POLICY_SEGMENT_FORBIDDEN: &str = "code_policy.segment_forbidden";
+pub const VALUE_TYPE_INVALID: &str = "value_type.invalid";
+pub const UNIT_NOT_ALLOWED: &str = "unit.not_allowed";
+pub const VERSION_INVALID: &str = "version.invalid";
+pub const OBJECT_TYPE_INVALID: &str = "object_type.invalid";
+pub const DATA_CLASS_INVALID: &str = "data_class.invalid";
+pub const STATUS_INVALID: &str = "status.invalid";
+pub const SOURCE_INVALID: &str = "source.invalid";
+pub const ALIAS_NOT_FOUND: &str = "biz_metadata_alias.not_found";
+pub const ALIAS_REQUIRED: &str = "biz_metadata_alias.alias_required";
+pub const ALIAS_METADATA_MISMATCH: &str = "biz_metadata_alias.metadata_mismatch";
+pub const ALIAS_SOURCE_INVALID: &str = "biz_metadata_alias.source_invalid";
+pub const ALIAS_WEIGHT_INVALID: &str = "biz_metadata_alias.weight_invalid";
+pub const ALIAS_DUPLICATE: &str = "biz_metadata_alias.duplicate";
+pub const ALIAS_PRIMARY_WEIGHT_TOO_LOW: &str = "biz_metadata_alias.primary_weight_too_low";
+pub const ALIAS_LIMIT_EXCEEDED: &str = "biz_metadata_alias.limit_exceeded";
+pub const LANGUAGE_CODE_INVALID: &str = "language_code.invalid";
+pub const PERSISTENCE_TIMEOUT: &str = "persistence.timeout";
+pub const PERSISTENCE_SERIALIZATION_FAILURE: &str = "persistence.serialization_failure";
+pub const PERSISTENCE_ROW_MISSING: &str = "persistence.row_missing";
+pub const PERSISTENCE_UNIQUE_VIOLATION: &str = "persistence.unique_violation";
+pub const PERSISTENCE_REFERENCE_MISSING: &str = "persistence.reference_missing";
+pub const QUERY_FIELD_UNKNOWN: &str = "query.field_unknown";
+pub const QUERY_FIELD_NOT_FACETABLE: &str = "query.field_not_facetable";
+pub const QUERY_VALUE_INVALID: &str = "query.value_invalid";
+pub const REQUEST_INVALID: &str = "request.invalid";
+pub const RESOURCE_NOT_FOUND: &str = "resource.not_found";
+pub const RESOURCE_GONE: &str = "resource.gone";
+pub const REQUEST_TOO_LARGE: &str = "request.too_large";
+pub const REQUEST_RATE_LIMITED: &str = "request.rate_limited";
+pub const AUTH_UNAUTHORIZED: &str = "auth.unauthorized";
+pub const AUTH_TENANT_FORBIDDEN: &str = "auth.tenant_forbidden";
@@ -1,2 +1,3 @@
 pub mod biz_metadata;
 pub mod biz_metadata_alias;
+pub mod error_code;
@@ -0,0 +1,236 @@
+//! 启动时的数据库连接重试：容器化部署中数据库可能晚于服务就绪，按指数退避重试直至次数上限。
+//!
+//! 连接参数另可配置会话级 `statement_timeout`，由 PostgreSQL 在服务端取消超时语句。
+
+use std::future::Future;
+use std::time::Duration;
+
+use sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr};
+
+/// 最大尝试次数的环境变量名。
+pub const CONNECT_MAX_ATTEMPTS_ENV: &str = "BIZ_METADATA_DB_CONNECT_MAX_ATTEMPTS";
+/// 首次重试前等待毫秒数的环境变量名。
+pub const CONNECT_BASE_DELAY_MS_ENV: &str = "BIZ_METADATA_DB_CONNECT_BASE_DELAY_MS";
+/// 默认最大尝试次数（含首次）。
+pub const DEFAULT_CONNECT_MAX_ATTEMPTS: u32 = 5;
+/// 默认首次重试前的等待时间。
+pub const DEFAULT_CONNECT_BASE_DELAY: Duration = Duration::from_millis(500);
+/// 单次等待的上限，避免指数增长后等待过久。
+pub const MAX_CONNECT_DELAY: Duration = Duration::from_secs(30);
+/// 会话级 `statement_timeout` 毫秒数的环境变量名，未设置或为 0 时不限制。
+pub const STATEMENT_TIMEOUT_MS_ENV: &str = "BIZ_METADATA_DB_STATEMENT_TIMEOUT_MS";
+
+/// 连接重试策略：第 `n` 次失败后等待 `base_delay * 2^(n-1)`（不超过 [`MAX_CONNECT_DELAY`]）。
+///
+/// ```
+/// use std::time::Duration;
+/// use biz_metadata::infrastructure::persistence::connect::RetryPolicy;
+///
+/// let policy = RetryPolicy::new(4, Duration::from_millis(100));
+/// assert_eq!(policy.delay_after(1), Duration::from_millis(100));
+/// assert_eq!(policy.delay_after(3), Duration::from_millis(400));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（含首次），至少为 1。
+    pub max_attempts: u32,
+    /// 首次重试前的等待时间。
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_CONNECT_MAX_ATTEMPTS, DEFAULT_CONNECT_BASE_DELAY)
+    }
+}
+
+impl RetryPolicy {
+    /// 以最大尝试次数与基础等待时间构造，次数为 0 时按 1 处理。
+    pub fn new(max_attempts: u32, base_delay: Duration) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+        }
+    }
+
+    /// 从环境变量读取策略，未设置的项使用默认值，非法取值返回错误信息。
+    pub fn from_env() -> Result<Self, String> {
+        let max_attempts = match std::env::var(CONNECT_MAX_ATTEMPTS_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|attempts| *attempts > 0)
+                .ok_or_else(|| format!("{CONNECT_MAX_ATTEMPTS_ENV} 必须是正整数"))?,
+            Err(_) => DEFAULT_CONNECT_MAX_ATTEMPTS,
+        };
+        let base_delay = match std::env::var(CONNECT_BASE_DELAY_MS_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map(Duration::from_millis)
+                .map_err(|_| format!("{CONNECT_BASE_DELAY_MS_ENV} 必须是非负整数毫秒数"))?,
+            Err(_) => DEFAULT_CONNECT_BASE_DELAY,
+        };
+        Ok(Self::new(max_attempts, base_delay))
+    }
+
+    /// 第 `attempt` 次（从 1 开始）失败后的等待时间。
+    pub fn delay_after(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay
+            .saturating_mul(factor)
+            .min(MAX_CONNECT_DELAY)
+    }
+}
+
+/// 按策略重试 `operation`，成功即返回；达到最大次数后返回最后一次的错误。
+///
+/// `operation` 接收当前尝试序号（从 1 开始），每次失败以 warn 级别记录。
+pub async fn retry_with_backoff<T, E, F, Fut>(policy: RetryPolicy, mut operation: F) -> Result<T, E>
+where
+    E: std::fmt::Display,
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let mut attempt = 1;
+    loop {
+        match operation(attempt).await {
+            Ok(value) => return Ok(value),
+            Err(err) if attempt >= policy.max_attempts => {
+                tracing::warn!(
+                    attempt,
+                    max_attempts = policy.max_attempts,
+                    error = %err,
+                    "giving up after final attempt"
+                );
+                return Err(err);
+            }
+            Err(err) => {
+                let delay = policy.delay_after(attempt);
+                tracing::warn!(
+                    attempt,
+                    max_attempts = policy.max_attempts,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %err,
+                    "attempt failed, retrying"
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// 从环境变量读取会话级 `statement_timeout`，未设置或为 0 时返回 `None`（不限制）。
+pub fn statement_timeout_from_env() -> Result<Option<Duration>, String> {
+    match std::env::var(STATEMENT_TIMEOUT_MS_ENV) {
+        Ok(raw) => raw
+            .trim()
+            .parse::<u64>()
+            .map(|ms| (ms > 0).then(|| Duration::from_millis(ms)))
+            .map_err(|_| format!("{STATEMENT_TIMEOUT_MS_ENV} 必须是非负整数毫秒数")),
+        Err(_) => Ok(None),
+    }
+}
+
+/// 构造连接参数：`statement_timeout` 为 `Some` 时，连接池中每个连接都以该值设置会话级
+/// `statement_timeout`，超时语句由服务端取消，仓储将其映射为 `persistence.timeout`。
+///
+/// ```
+/// use std::time::Duration;
+/// use biz_metadata::infrastructure::persistence::connect::connect_options;
+///
+/// let options = connect_options("postgres://localhost/db", Some(Duration::from_secs(5)));
+/// assert_eq!(options.get_statement_timeout(), Some(Duration::from_secs(5)));
+/// assert_eq!(connect_options("postgres://localhost/db", None).get_statement_timeout(), None);
+/// ```
+pub fn connect_options(url: &str, statement_timeout: Option<Duration>) -> ConnectOptions {
+    let mut options = ConnectOptions::new(url);
+    if let Some(timeout) = statement_timeout {
+        options.statement_timeout(timeout);
+    }
+    options
+}
+
+/// 连接数据库，失败时按 `policy` 指数退避重试；`options` 可为连接串或 [`connect_options`] 的结果。
+pub async fn connect_with_retry(
+    options: impl Into<ConnectOptions>,
+    policy: RetryPolicy,
+) -> Result<DatabaseConnection, DbErr> {
+    let options = options.into();
+    retry_with_backoff(policy, |_| Database::connect(options.clone())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::error_code;
+    use crate::infrastructure::persistence::repository::biz_metadata_repository_impl::BIZ_METADATA_CONSTRAINTS;
+    use domain_core::domain_error::DomainError;
+    use sea_orm::ConnectionTrait;
+
+    fn fast_policy(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy::new(max_attempts, Duration::from_millis(1))
+    }
+
+    #[tokio::test]
+    async fn succeeds_after_transient_failure() {
+        let mut calls = 0;
+        let result: Result<&str, String> = retry_with_backoff(fast_policy(3), |attempt| {
+            calls += 1;
+            async move {
+                if attempt < 2 {
+                    Err("database not ready".to_string())
+                } else {
+                    Ok("connected")
+                }
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), "connected");
+        assert_eq!(calls, 2);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let mut calls = 0;
+        let result: Result<(), String> = retry_with_backoff(fast_policy(3), |attempt| {
+            calls += 1;
+            async move { Err(format!("refused on attempt {attempt}")) }
+        })
+        .await;
+        assert_eq!(result.unwrap_err(), "refused on attempt 3");
+        assert_eq!(calls, 3);
+    }
+
+    #[tokio::test]
+    async fn statement_timeout_cancels_slow_query_server_side() {
+        let Ok(url) = std::env::var("TEST_DATABASE_URL") else {
+            return;
+        };
+        let options = connect_options(&url, Some(Duration::from_millis(100)));
+        let db = connect_with_retry(options, fast_policy(1)).await.unwrap();
+
+        let err = db
+            .execute_unprepared("SELECT pg_sleep(2)")
+            .await
+            .map_err(|err| BIZ_METADATA_CONSTRAINTS.translate(err))
+            .unwrap_err();
+        assert!(
+            matches!(err, DomainError::Persistence { code, .. } if code == error_code::PERSISTENCE_TIMEOUT),
+            "{err:?}"
+        );
+        db.execute_unprepared("SELECT pg_sleep(0.01)")
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn delay_grows_exponentially_up_to_cap() {
+        let policy = RetryPolicy::new(10, Duration::from_secs(1));
+        assert_eq!(policy.delay_after(2), Duration::from_secs(2));
+        assert_eq!(policy.delay_after(40), MAX_CONNECT_DELAY);
+        assert_eq!(RetryPolicy::new(0, Duration::ZERO).max_attempts, 1);
+    }
+}
@@ -0,0 +1,105 @@
+//! 数据库错误翻译：约束冲突按约束名映射为带稳定错误码的领域错误，服务端取消的语句归为超时，
+//! 序列化失败归为可重试错误，其余错误归为持久化失败。
+
+use domain_core::domain_error::DomainError;
+use sea_orm::{DbErr, RuntimeErr, SqlErr};
+
+use crate::domain::error_code;
+
+/// PostgreSQL `query_canceled`，会话级 `statement_timeout` 到期时返回。
+const SQLSTATE_QUERY_CANCELED: &str = "57014";
+
+/// PostgreSQL `serialization_failure`，`SERIALIZABLE`/`REPEATABLE READ` 事务与并发事务冲突时返回。
+const SQLSTATE_SERIALIZATION_FAILURE: &str = "40001";
+
+/// 约束名到错误码的声明式映射，供仓储把唯一约束冲突（PostgreSQL 23505）与外键缺失（23503）
+/// 翻译为可被 HTTP 层区分处理的 [`DomainError::Validation`]。
+///
+/// 未登记的约束分别落到 `persistence.unique_violation` 与 `persistence.reference_missing`。
+#[derive(Debug)]
+pub struct ConstraintMap {
+    entries: &'static [(&'static str, &'static str)],
+}
+
+impl ConstraintMap {
+    /// 以 `(约束名, 错误码)` 列表构造。
+    pub const fn new(entries: &'static [(&'static str, &'static str)]) -> Self {
+        Self { entries }
+    }
+
+    /// 翻译数据库错误：被服务端取消的语句返回 `persistence.timeout`，序列化失败返回
+    /// `persistence.serialization_failure`，其余非约束冲突错误返回 `persistence.failed`。
+    pub fn translate(&self, err: DbErr) -> DomainError {
+        match err.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(message)) => DomainError::Validation {
+                code: self
+                    .code_for(&message)
+                    .unwrap_or(error_code::PERSISTENCE_UNIQUE_VIOLATION),
+                message,
+            },
+            Some(SqlErr::ForeignKeyConstraintViolation(message)) => DomainError::Validation {
+                code: self
+                    .code_for(&message)
+                    .unwrap_or(error_code::PERSISTENCE_REFERENCE_MISSING),
+                message,
+            },
+            _ => DomainError::Persistence {
+                code: match sqlstate(&err).as_deref() {
+                    Some(SQLSTATE_QUERY_CANCELED) => error_code::PERSISTENCE_TIMEOUT,
+                    Some(SQLSTATE_SERIALIZATION_FAILURE) => {
+                        error_code::PERSISTENCE_SERIALIZATION_FAILURE
+                    }
+                    _ => domain_core::error_code::PERSISTENCE_FAILED,
+                },
+                message: err.to_string(),
+            },
+        }
+    }
+
+    /// 按错误信息中引用的约束名（PostgreSQL 以双引号包裹）查找错误码。
+    fn code_for(&self, message: &str) -> Option<&'static str> {
+        self.entries
+            .iter()
+            .find(|(constraint, _)| message.contains(&format!("\"{constraint}\"")))
+            .map(|(_, code)| *code)
+    }
+}
+
+/// 提取数据库返回的 SQLSTATE，非数据库端错误返回 `None`。
+fn sqlstate(err: &DbErr) -> Option<String> {
+    match err {
+        DbErr::Exec(RuntimeErr::SqlxError(inner)) | DbErr::Query(RuntimeErr::SqlxError(inner)) => {
+            match &**inner {
+                sea_orm::sqlx::Error::Database(db) => db.code().map(|code| code.into_owned()),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: ConstraintMap = ConstraintMap::new(&[
+        ("ux_code_alive", error_code::BIZ_METADATA_CODE_CONFLICT),
+        ("ux_code", error_code::BIZ_METADATA_ID_CONFLICT),
+    ]);
+
+    #[test]
+    fn matches_quoted_constraint_names_exactly() {
+        assert_eq!(
+            MAP.code_for("duplicate key value violates unique constraint \"ux_code_alive\""),
+            Some(error_code::BIZ_METADATA_CODE_CONFLICT)
+        );
+        assert_eq!(
+            MAP.code_for("duplicate key value violates unique constraint \"ux_code\""),
+            Some(error_code::BIZ_METADATA_ID_CONFLICT)
+        );
+        assert_eq!(MAP.code_for("violates constraint \"other\""), None);
+
+        let err = MAP.translate(DbErr::Custom("connection reset".into()));
+        assert_eq!(err.code(), domain_core::error_code::PERSISTENCE_FAILED);
+    }
+}
@@ -92,7 +92,7 @@ mod tests {
 
     #[test]
     fn round_trip_between_model_and_domain() {
-        let alias = BizMetadataAlias::new(BizMetadataId::new(1), "销售额").unwrap();
+        let alias = BizMetadataAlias::new(BizMetadataId::new(1), "销售额", Utc::now()).unwrap();
         let active = BizMetadataAliasMapper::map_to_active_model(&alias).unwrap();
         assert_eq!(active.metadata_id.unwrap(), 1);
 
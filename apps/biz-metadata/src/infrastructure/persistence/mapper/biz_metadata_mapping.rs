@@ -51,10 +51,12 @@ impl EntityMapper<biz_metadata::Model, BizMetadata> for BizMetadataMapper {
         let version = Version::new(model.version)?;
         let code =
             BizMetadataCode::new(model.code.clone()).map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
         let name =
             BizMetadataName::new(model.name.clone()).map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
         let object_type = ObjectType::new(&model.object_type)?;
@@ -64,10 +66,12 @@ impl EntityMapper<biz_metadata::Model, BizMetadata> for BizMetadataMapper {
             .map(DataClass::new)
             .transpose()
             .map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
         let status =
             BizMetadataStatus::new(&model.status).map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
         let source = Source::new(&model.source)?;
@@ -77,6 +81,7 @@ impl EntityMapper<biz_metadata::Model, BizMetadata> for BizMetadataMapper {
             .map(DomainValueType::new)
             .transpose()
             .map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
         let unit = model
@@ -85,10 +90,12 @@ impl EntityMapper<biz_metadata::Model, BizMetadata> for BizMetadataMapper {
             .map(|u| Unit::new(u.clone()))
             .transpose()
             .map_err(|e| DomainError::Validation {
+                code: e.code(),
                 message: e.to_string(),
             })?;
 
-        BizMetadata::from_snapshot(MetadataSnapshot {
+        // 历史数据可能违反后来引入的作用域规则，加载时不拒绝，由 check_invariants 审计。
+        BizMetadata::rehydrate(MetadataSnapshot {
             tenant_id,
             version,
             id,
@@ -145,3 +152,143 @@ impl ActiveModelMapper<BizMetadata, biz_metadata::ActiveModel> for BizMetadataMa
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeZone};
+    use proptest::prelude::*;
+    use sea_orm::TryIntoModel;
+
+    const NODE_TYPES: [ObjectType; 4] = [
+        ObjectType::Entity,
+        ObjectType::Event,
+        ObjectType::Relation,
+        ObjectType::Document,
+    ];
+    const DATA_CLASSES: [DataClass; 6] = [
+        DataClass::Attribute,
+        DataClass::Metric,
+        DataClass::Text,
+        DataClass::Object,
+        DataClass::Array,
+        DataClass::Identifier,
+    ];
+
+    /// 满足作用域不变量的 `(object_type, data_class, value_type, unit)` 组合。
+    fn arb_scope()
+    -> impl Strategy<Value = (ObjectType, Option<DataClass>, Option<String>, Option<Unit>)> {
+        let node = proptest::sample::select(NODE_TYPES.to_vec())
+            .prop_map(|object_type| (object_type, None, None, None));
+        let feature = proptest::sample::select(DATA_CLASSES.to_vec()).prop_flat_map(|data_class| {
+            let value_type = match data_class {
+                DataClass::Identifier => {
+                    proptest::sample::select(vec!["string", "int", "int|string"])
+                        .prop_map(str::to_string)
+                        .boxed()
+                }
+                _ => "[a-z]{1,8}(\\|[a-z]{1,8})?".boxed(),
+            };
+            let unit = match data_class {
+                DataClass::Metric => proptest::option::of(
+                    "(percent|%|元|[a-z]{1,6})".prop_map(|raw| Unit::new(raw).unwrap()),
+                )
+                .boxed(),
+                _ => Just(None).boxed(),
+            };
+            (value_type, unit).prop_map(move |(value_type, unit)| {
+                (
+                    ObjectType::Feature,
+                    Some(data_class),
+                    Some(value_type),
+                    unit,
+                )
+            })
+        });
+        prop_oneof![node, feature]
+    }
+
+    /// 微秒精度的审计时间线（与 PostgreSQL `timestamptz` 一致），保证 created <= updated <= deleted。
+    fn arb_audit() -> impl Strategy<Value = Audit> {
+        let base = 1_600_000_000_000_000_i64;
+        (
+            0..100_000_000_000_i64,
+            0..100_000_000_i64,
+            proptest::option::of(0..100_000_000_i64),
+        )
+            .prop_map(move |(created, updated, deleted)| {
+                let at = |micros: i64| -> DateTime<Utc> { Utc.timestamp_micros(micros).unwrap() };
+                let created_at = at(base + created);
+                let updated_at = at(base + created + updated);
+                let deleted_at = deleted.map(|d| at(base + created + updated + d));
+                Audit::reconstruct(created_at, updated_at, deleted_at).unwrap()
+            })
+    }
+
+    /// 生成可通过 `from_snapshot` 构造的任意已持久化聚合。
+    fn arb_biz_metadata() -> impl Strategy<Value = BizMetadata> {
+        (
+            (
+                "[a-z][a-z0-9_]{0,6}",
+                1..i64::MAX,
+                1..10_000_i32,
+                "[a-z][a-z0-9_]{0,8}(\\.[a-z][a-z0-9_]{0,8}){0,3}",
+                "[\\p{Han}A-Za-z0-9 ]{0,12}[\\p{Han}A-Za-z0-9]",
+                proptest::option::of("[^\\s][\\p{Han}A-Za-z0-9 ,.]{0,24}"),
+            ),
+            (
+                arb_scope(),
+                proptest::option::of(1..i64::MAX),
+                any::<bool>(),
+                proptest::sample::select(vec![Source::Manual, Source::AutoMine, Source::ApiSync]),
+                arb_audit(),
+            ),
+        )
+            .prop_map(
+                |(
+                    (tenant, id, version, code, name, description),
+                    ((object_type, data_class, value_type, unit), parent_id, active, source, audit),
+                )| {
+                    BizMetadata::from_snapshot(MetadataSnapshot {
+                        tenant_id: TenantId::new(tenant).unwrap(),
+                        version: Version::new(version).unwrap(),
+                        id: BizMetadataId::from(id),
+                        code,
+                        name,
+                        description,
+                        object_type,
+                        parent_id: parent_id.map(BizMetadataId::from),
+                        data_class,
+                        value_type,
+                        unit,
+                        status: if active {
+                            BizMetadataStatus::Active
+                        } else {
+                            BizMetadataStatus::Deprecated
+                        },
+                        source,
+                        audit,
+                    })
+                    .unwrap()
+                },
+            )
+    }
+
+    proptest! {
+        #[test]
+        fn snapshot_round_trip_preserves_all_fields(aggregate in arb_biz_metadata()) {
+            let rebuilt = BizMetadata::from_snapshot(aggregate.to_snapshot()).unwrap();
+            prop_assert_eq!(rebuilt, aggregate);
+        }
+
+        #[test]
+        fn model_round_trip_preserves_all_fields(aggregate in arb_biz_metadata()) {
+            let model: biz_metadata::Model = BizMetadataMapper::map_to_active_model(&aggregate)
+                .unwrap()
+                .try_into_model()
+                .unwrap();
+            let rebuilt = BizMetadataMapper::map_to_domain(&model).unwrap();
+            prop_assert_eq!(rebuilt, aggregate);
+        }
+    }
+}
@@ -1,4 +1,7 @@
+pub mod connect;
+pub mod db_error;
 pub mod entity;
 pub mod mapper;
 pub mod query;
 pub mod repository;
+pub mod transaction;
@@ -1,38 +1,204 @@
-use domain_core::expression::{Comparison, Expression, FilterValue, OrderBy, SortDirection};
-use sea_orm::{Condition, EntityTrait, Order, QueryOrder, Select};
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{
+    Comparison, Expression, FieldColumns, FilterValue, NullsOrder, OrderBy, SortDirection,
+};
+use sea_orm::sea_query::{Expr, Func, NullOrdering};
+use sea_orm::{
+    ColumnTrait, ColumnType, Condition, EntityTrait, IdenStatic, IntoSimpleExpr, Order, QueryOrder,
+    Select, Value,
+};
+
+use crate::domain::biz_metadata::repository::ensure_known_fields;
+use crate::domain::error_code;
+
+/// 对外字段名到数据库列的声明式映射，过滤与排序共用同一张表，避免两处各自维护而漂移。
+///
+/// 字段名即领域层 [`Expression`] 与 [`OrderBy`] 使用的名称，可与数据库列名不同。
+#[derive(Debug)]
+pub struct FieldMap<C: 'static> {
+    entries: &'static [(&'static str, C)],
+}
+
+impl<C: Copy + 'static> FieldMap<C> {
+    /// 以 `(字段名, 列)` 列表构造。
+    pub const fn new(entries: &'static [(&'static str, C)]) -> Self {
+        Self { entries }
+    }
+
+    /// 字段对应的列，未登记时返回 `None`。
+    pub fn column(&self, field: &str) -> Option<C> {
+        self.entries
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, column)| *column)
+    }
+
+    /// 是否登记了该字段。
+    pub fn contains(&self, field: &str) -> bool {
+        self.column(field).is_some()
+    }
+
+    /// 全部允许的字段名，按登记顺序。
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.entries.iter().map(|(name, _)| *name)
+    }
+
+    /// 将排序字段解析为列与方向，供 [`apply_ordering`] 使用。
+    pub fn resolve_order(&self, order: &OrderBy) -> Option<(C, Order)> {
+        self.column(&order.field)
+            .map(|column| (column, resolve_order_direction(&order.direction)))
+    }
+
+    /// 校验过滤表达式与排序引用的字段均已登记，未知字段汇总后返回 `query.field_unknown`。
+    pub fn ensure_known(
+        &self,
+        expr: &Expression,
+        order_bys: &[OrderBy],
+    ) -> Result<(), DomainError> {
+        ensure_known_fields(&self.fields().collect::<Vec<_>>(), expr, order_bys)
+    }
+}
+
+/// 以数据库列名提供 [`Expression::to_sql_preview`] 所需的映射，预览与过滤共用同一张字段表。
+impl<C: IdenStatic + Copy + 'static> FieldColumns for FieldMap<C> {
+    fn column_name(&self, field: &str) -> Option<&str> {
+        self.column(field).map(|column| column.as_str())
+    }
+}
+
+/// 恒假条件，对应 [`Expression::False`] 与空集合的 `In`。
+fn never() -> Condition {
+    Condition::all().add(Expr::cust("FALSE"))
+}
+
+/// 过滤值无法转换为字段类型时的校验错误，供各仓储的值解析函数使用。
+pub fn invalid_filter_value(field: &str, value: &FilterValue) -> DomainError {
+    DomainError::Validation {
+        code: error_code::QUERY_VALUE_INVALID,
+        message: format!("filter value {value:?} is not valid for field {field}"),
+    }
+}
 
 /// 根据表达式构建 ORM 条件，比较节点交由 `handler` 解析。
+///
+/// `handler` 返回 `Ok(None)` 表示字段未登记或运算不受支持，按宽松查询语义视为恒真（严格模式由服务层
+/// 预先以 [`FieldMap::ensure_known`] 拒绝）；返回错误时整个条件构建失败，不会丢弃该谓词。
 pub fn build_condition(
     expr: &Expression,
-    handler: &impl Fn(&Comparison) -> Option<Condition>,
-) -> Condition {
-    match expr {
-        Expression::Comparison(cmp) => handler(cmp).unwrap_or_else(Condition::all),
-        Expression::And(children) => children.iter().fold(Condition::all(), |acc, child| {
-            acc.add(build_condition(child, handler))
-        }),
-        Expression::Or(children) => children.iter().fold(Condition::any(), |acc, child| {
-            acc.add(build_condition(child, handler))
-        }),
-        Expression::Not(child) => build_condition(child, handler).not(),
+    handler: &impl Fn(&Comparison) -> Result<Option<Condition>, DomainError>,
+) -> Result<Condition, DomainError> {
+    Ok(match expr {
+        Expression::Comparison(cmp) => handler(cmp)?.unwrap_or_else(Condition::all),
+        Expression::And(children) => {
+            let mut condition = Condition::all();
+            for child in children {
+                condition = condition.add(build_condition(child, handler)?);
+            }
+            condition
+        }
+        Expression::Or(children) => {
+            let mut condition = Condition::any();
+            for child in children {
+                condition = condition.add(build_condition(child, handler)?);
+            }
+            condition
+        }
+        Expression::Not(child) => build_condition(child, handler)?.not(),
         Expression::True => Condition::all(),
-        Expression::False => Condition::all().add(Condition::any()).not(),
-    }
+        Expression::False => never(),
+    })
 }
 
 /// 基于等于/不等于的常用条件构造，字段解析逻辑由调用方提供。
 pub fn build_eq_ne_condition(
     expr: &Expression,
-    resolver: &impl Fn(&str, &FilterValue, bool) -> Option<Condition>,
-) -> Condition {
+    resolver: &impl Fn(&str, &FilterValue, bool) -> Result<Option<Condition>, DomainError>,
+) -> Result<Condition, DomainError> {
     build_condition(expr, &|cmp| match cmp {
         Comparison::Eq { field, value } => resolver(field, value, false),
         Comparison::Ne { field, value } => resolver(field, value, true),
-        _ => None,
+        _ => Ok(None),
+    })
+}
+
+/// 支持全部比较运算的条件构造，`resolver` 负责把字段与筛选值解析为列及对应的数据库值。
+///
+/// `resolver` 对未登记字段返回 `Ok(None)`（视为恒真，见 [`build_condition`]），对无法转换的值返回
+/// [`invalid_filter_value`]；空集合的 `In` 编译为恒假。
+pub fn build_comparison_condition<C>(
+    expr: &Expression,
+    resolver: &impl Fn(&str, &FilterValue) -> Result<Option<(C, Value)>, DomainError>,
+) -> Result<Condition, DomainError>
+where
+    C: ColumnTrait,
+{
+    build_condition(expr, &|cmp| {
+        let expr = match cmp {
+            Comparison::Eq { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.eq(value),
+                None => return Ok(None),
+            },
+            Comparison::Ne { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.ne(value),
+                None => return Ok(None),
+            },
+            Comparison::Gt { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.gt(value),
+                None => return Ok(None),
+            },
+            Comparison::Ge { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.gte(value),
+                None => return Ok(None),
+            },
+            Comparison::Lt { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.lt(value),
+                None => return Ok(None),
+            },
+            Comparison::Le { field, value } => match resolver(field, value)? {
+                Some((column, value)) => column.lte(value),
+                None => return Ok(None),
+            },
+            Comparison::Between { field, start, end } => {
+                match (resolver(field, start)?, resolver(field, end)?) {
+                    (Some((column, start)), Some((_, end))) => column.between(start, end),
+                    _ => return Ok(None),
+                }
+            }
+            Comparison::In { field, values } => {
+                let mut column = None;
+                let mut resolved = Vec::with_capacity(values.len());
+                for value in values {
+                    match resolver(field, value)? {
+                        Some((col, value)) => {
+                            column = Some(col);
+                            resolved.push(value);
+                        }
+                        None => return Ok(None),
+                    }
+                }
+                match column {
+                    Some(column) => column.is_in(resolved),
+                    None => return Ok(Some(never())),
+                }
+            }
+            Comparison::Contains { field, value } => match resolver(field, value)? {
+                Some((column, _)) => column.contains(
+                    value
+                        .as_string()
+                        .ok_or_else(|| invalid_filter_value(field, value))?,
+                ),
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(Condition::all().add(expr)))
     })
 }
 
 /// 应用排序字段，解析逻辑交由 `resolver` 决定。
+///
+/// 排序规则提示仅作用于文本列，且须能由 [`postgres_collation`] 识别，否则按默认规则排序。
+/// 排序未包含 `id` 时追加 `id ASC` 作为最终决胜键，保证全序，避免同值行在分页间漂移。
 pub fn apply_ordering<E>(
     mut query: Select<E>,
     order_bys: &[OrderBy],
@@ -42,13 +208,122 @@ where
     E: EntityTrait,
 {
     for order in order_bys {
-        if let Some((column, direction)) = resolver(order) {
-            query = query.order_by(column, direction);
-        }
+        let Some((column, direction)) = resolver(order) else {
+            continue;
+        };
+        let collation = order
+            .collation
+            .as_deref()
+            .and_then(postgres_collation)
+            .filter(|_| {
+                matches!(
+                    column.def().get_column_type(),
+                    ColumnType::String(_) | ColumnType::Text | ColumnType::Char(_)
+                )
+            });
+        let expr = match collation {
+            Some(collation) => Expr::cust_with_expr(
+                format!("$1 COLLATE \"{collation}\""),
+                column.into_simple_expr(),
+            ),
+            None => column.into_simple_expr(),
+        };
+        query = match order.nulls {
+            NullsOrder::Default => query.order_by(expr, direction),
+            NullsOrder::First => query.order_by_with_nulls(expr, direction, NullOrdering::First),
+            NullsOrder::Last => query.order_by_with_nulls(expr, direction, NullOrdering::Last),
+        };
+    }
+    if !order_bys
+        .iter()
+        .any(|order| order.field == TIEBREAKER_FIELD)
+        && let Some((column, direction)) = resolver(&OrderBy::asc(TIEBREAKER_FIELD))
+    {
+        query = query.order_by(column, direction);
     }
     query
 }
 
+/// 分页排序的决胜字段，须在各实体的 [`FieldMap`] 中登记且唯一。
+const TIEBREAKER_FIELD: &str = "id";
+
+/// 将排序规则提示映射为 PostgreSQL collation 名称，未登记的提示返回 `None`。
+///
+/// 仅返回白名单内的固定名称，可安全拼接进 SQL。
+pub fn postgres_collation(hint: &str) -> Option<&'static str> {
+    match hint.trim().to_ascii_lowercase().as_str() {
+        "pinyin" | "zh" | "zh_pinyin" => Some("zh-x-icu"),
+        "c" | "binary" => Some("C"),
+        _ => None,
+    }
+}
+
+/// 软删除实体：每张表只在此声明一次删除时间列，仓储经由下列条件统一过滤存活与已删除行。
+///
+/// ```
+/// use biz_metadata::infrastructure::persistence::entity::prelude::BizMetadata;
+/// use biz_metadata::infrastructure::persistence::query::SoftDelete;
+/// use sea_orm::{DbBackend, EntityTrait, QueryFilter, QueryTrait};
+///
+/// let sql = BizMetadata::find()
+///     .filter(BizMetadata::visibility(false))
+///     .build(DbBackend::Postgres)
+///     .to_string();
+/// assert!(sql.contains(r#""deleted_at" IS NULL"#));
+///
+/// let sql = BizMetadata::find()
+///     .filter(BizMetadata::visibility(true))
+///     .build(DbBackend::Postgres)
+///     .to_string();
+/// assert!(!sql.contains("deleted_at\" IS"));
+/// ```
+pub trait SoftDelete: EntityTrait {
+    /// 删除时间列，为空表示存活。
+    const DELETED_AT: Self::Column;
+
+    /// 存活行条件。
+    fn alive() -> Expr {
+        Self::DELETED_AT.is_null()
+    }
+
+    /// 已软删除行条件。
+    fn deleted() -> Expr {
+        Self::DELETED_AT.is_not_null()
+    }
+
+    /// 按 `include_deleted` 限定可见行：为真时不加限制，否则只保留存活行。
+    fn visibility(include_deleted: bool) -> Condition {
+        if include_deleted {
+            Condition::all()
+        } else {
+            Condition::all().add(Self::alive())
+        }
+    }
+}
+
+/// 软删除时写入 `deleted_at` 的表达式：取 `deleted_at` 与数据库当前时间的较大值。
+///
+/// 更新触发器会把 `updated_at` 置为 `CURRENT_TIMESTAMP`，直接写入应用侧时间可能早于它，
+/// 回读时违反审计时间线（删除时间不得早于更新时间）。
+///
+/// ```
+/// use biz_metadata::infrastructure::persistence::query::soft_delete_timestamp;
+/// use chrono::Utc;
+/// use sea_orm::sea_query::{PostgresQueryBuilder, Query};
+///
+/// let sql = Query::select()
+///     .expr(soft_delete_timestamp(Utc::now()))
+///     .to_string(PostgresQueryBuilder);
+/// assert!(sql.contains("GREATEST") && sql.contains("CURRENT_TIMESTAMP"));
+/// ```
+pub fn soft_delete_timestamp(deleted_at: DateTime<Utc>) -> Expr {
+    Func::greatest([
+        Expr::value(deleted_at.fixed_offset()),
+        Expr::current_timestamp(),
+    ])
+    .into()
+}
+
 /// 将领域层的排序方向转换为 SeaORM 的排序枚举。
 pub fn resolve_order_direction(direction: &SortDirection) -> Order {
     match direction {
@@ -71,7 +346,7 @@ impl PaginationParams {
     pub fn compute(limit: Option<u64>, offset: Option<u64>, default_page_size: u64) -> Self {
         let limit = limit.unwrap_or(default_page_size).max(1);
         let offset = offset.unwrap_or(0);
-        let page_index = if limit == 0 { 0 } else { offset / limit };
+        let page_index = offset.checked_div(limit).unwrap_or(0);
 
         Self { limit, page_index }
     }
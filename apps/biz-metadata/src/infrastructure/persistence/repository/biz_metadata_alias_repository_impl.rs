@@ -1,59 +1,327 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::domain::biz_metadata::value_object::BizMetadataId;
 use crate::domain::biz_metadata_alias::BizMetadataAlias;
-use crate::domain::biz_metadata_alias::repository::BizMetadataAliasRepository;
+use crate::domain::biz_metadata_alias::history::AliasPrimaryChange;
+use crate::domain::biz_metadata_alias::repository::{
+    BizMetadataAliasRepository, alias_limit_exceeded, count_by_metadata, plan_alias_reassignment,
+};
 use crate::domain::biz_metadata_alias::value_object::BizMetadataAliasId;
+use crate::domain::error_code;
+use crate::infrastructure::persistence::db_error::ConstraintMap;
 use crate::infrastructure::persistence::entity::biz_metadata_alias;
 use crate::infrastructure::persistence::entity::prelude::BizMetadataAlias as BizMetadataAliasEntity;
 use crate::infrastructure::persistence::mapper::{
     ActiveModelMapper, EntityMapper, biz_metadata_alias_mapping::BizMetadataAliasMapper,
 };
 use crate::infrastructure::persistence::query::{
-    PaginationParams, apply_ordering, build_eq_ne_condition, resolve_order_direction,
+    FieldMap, PaginationParams, SoftDelete, apply_ordering, build_condition, invalid_filter_value,
+    soft_delete_timestamp,
+};
+use crate::infrastructure::persistence::repository::future::{
+    DEFAULT_QUERY_TIMEOUT, DEFAULT_SLOW_QUERY_THRESHOLD, RepoFuture, repo_future_with_timeout,
+    warn_if_slow,
 };
-use crate::infrastructure::persistence::repository::future::{RepoFuture, repo_future};
+use chrono::{DateTime, Utc};
 use domain_core::domain_error::DomainError;
-use domain_core::expression::{Expression, FilterValue, OrderBy, QueryOptions};
+use domain_core::expression::{Comparison, Expression, FilterValue, OrderBy, QueryOptions};
 use domain_core::pagination::{DEFAULT_PAGE_SIZE, PageResult};
 use domain_core::repository::Repository;
+use sea_orm::sea_query::{Alias, OnConflict, Query};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order as SeaOrder,
-    PaginatorTrait, QueryFilter,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DbErr,
+    EntityTrait, Order as SeaOrder, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect,
+    Statement, TransactionTrait,
 };
 
+/// 别名变更历史表，由迁移 `m20261016_090000` 创建。
+const ALIAS_HISTORY_TABLE: &str = "biz_metadata_alias_history";
+
+/// 约束冲突到错误码的映射，未登记的约束按通用错误码处理。
+pub const BIZ_METADATA_ALIAS_CONSTRAINTS: ConstraintMap =
+    ConstraintMap::new(&[("ux_biz_metadata_alias_alive", error_code::ALIAS_DUPLICATE)]);
+
+/// `biz_metadata_alias` 可过滤、可排序的字段及其对应列。
+pub const BIZ_METADATA_ALIAS_FIELD_MAP: FieldMap<biz_metadata_alias::Column> = FieldMap::new(&[
+    ("id", biz_metadata_alias::Column::Id),
+    ("metadata_id", biz_metadata_alias::Column::MetadataId),
+    ("alias", biz_metadata_alias::Column::Alias),
+    ("source", biz_metadata_alias::Column::Source),
+    ("weight", biz_metadata_alias::Column::Weight),
+    ("is_primary", biz_metadata_alias::Column::IsPrimary),
+    ("language", biz_metadata_alias::Column::Language),
+    ("created_at", biz_metadata_alias::Column::CreatedAt),
+    ("updated_at", biz_metadata_alias::Column::UpdatedAt),
+    ("deleted_at", biz_metadata_alias::Column::DeletedAt),
+]);
+
+impl SoftDelete for BizMetadataAliasEntity {
+    const DELETED_AT: biz_metadata_alias::Column = biz_metadata_alias::Column::DeletedAt;
+}
+
 /// SeaORM 版 `biz_metadata_alias` 仓储实现。
 pub struct BizMetadataAliasRepositoryImpl {
     db: DatabaseConnection,
+    read_db: DatabaseConnection,
+    query_timeout: Duration,
+    slow_query_threshold: Duration,
 }
 
 impl BizMetadataAliasRepositoryImpl {
     /// 构造仓储。
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self {
+            read_db: db.clone(),
+            db,
+            query_timeout: DEFAULT_QUERY_TIMEOUT,
+            slow_query_threshold: DEFAULT_SLOW_QUERY_THRESHOLD,
+        }
     }
 
-    fn map_db_err(err: sea_orm::DbErr) -> DomainError {
-        DomainError::Persistence {
-            message: err.to_string(),
+    /// 设置只读副本连接：`find_by_id` 与 `query`走该连接，写入与事务内读取仍走主库。
+    ///
+    /// 未设置时读写共用主库连接；副本存在复制延迟，写后立即读取可能看不到最新数据。
+    pub fn with_read_replica(mut self, read_db: DatabaseConnection) -> Self {
+        self.read_db = read_db;
+        self
+    }
+
+    /// 设置单次数据库调用的超时时间，超时返回 `query timed out` 持久化错误。
+    pub fn with_query_timeout(mut self, timeout: Duration) -> Self {
+        self.query_timeout = timeout;
+        self
+    }
+
+    /// 设置慢查询阈值，`query` 耗时超过该值时以 warn 级别记录筛选条件与耗时（默认 1 秒）。
+    pub fn with_slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_query_threshold = threshold;
+        self
+    }
+
+    /// 在给定连接（或事务）内插入并回读别名。
+    pub(crate) async fn insert_in(
+        conn: &impl ConnectionTrait,
+        aggregate: &BizMetadataAlias,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        let active = BizMetadataAliasMapper::map_to_active_model(aggregate)?;
+        let insert_result = BizMetadataAliasEntity::insert(active)
+            .exec(conn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        let model = BizMetadataAliasEntity::find_by_id(insert_result.last_insert_id)
+            .one(conn)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| DomainError::Persistence {
+                code: error_code::PERSISTENCE_ROW_MISSING,
+                message: format!(
+                    "biz_metadata_alias {} not found after insert",
+                    insert_result.last_insert_id
+                ),
+            })?;
+
+        BizMetadataAliasMapper::map_to_domain(&model)
+    }
+
+    /// 在给定连接（通常为事务）内统计元数据的存活别名数。
+    async fn count_live_in(
+        conn: &impl ConnectionTrait,
+        metadata_id: BizMetadataId,
+    ) -> Result<u64, DomainError> {
+        BizMetadataAliasEntity::find()
+            .filter(biz_metadata_alias::Column::MetadataId.eq(metadata_id.value()))
+            .filter(BizMetadataAliasEntity::alive())
+            .count(conn)
+            .await
+            .map_err(Self::map_db_err)
+    }
+
+    /// 按元数据 ID 升序加事务级咨询锁，串行化同一元数据的别名新增，使事务内的计数在提交前保持有效。
+    async fn lock_capacity_in(
+        conn: &impl ConnectionTrait,
+        metadata_ids: impl IntoIterator<Item = BizMetadataId>,
+    ) -> Result<(), DomainError> {
+        let mut ids: Vec<i64> = metadata_ids.into_iter().map(|id| id.value()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        for id in ids {
+            conn.execute_raw(Statement::from_sql_and_values(
+                conn.get_database_backend(),
+                "SELECT pg_advisory_xact_lock(hashtextextended('biz_metadata_alias.capacity:' || $1::text, 0))",
+                [id.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
         }
+        Ok(())
     }
 
-    fn field_condition(field: &str, value: &FilterValue, negate: bool) -> Option<Condition> {
-        let column = Self::column_for(field)?;
+    /// 在给定连接（通常为事务）内覆盖一行别名。
+    async fn update_in(
+        conn: &impl ConnectionTrait,
+        aggregate: &BizMetadataAlias,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        let existing = BizMetadataAliasEntity::find_by_id(aggregate.id().value())
+            .one(conn)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| DomainError::Persistence {
+                code: error_code::ALIAS_NOT_FOUND,
+                message: format!("biz_metadata_alias {} not found", aggregate.id().value()),
+            })?;
+        let mut active: biz_metadata_alias::ActiveModel = existing.into();
+        BizMetadataAliasMapper::apply_changes(aggregate, &mut active)?;
+        let updated_model = active.update(conn).await.map_err(Self::map_db_err)?;
+        BizMetadataAliasMapper::map_to_domain(&updated_model)
+    }
+
+    /// 在事务内将 `from` 的存活别名改挂到 `to`，返回被改挂的别名。
+    ///
+    /// 先按 `to` 加容量咨询锁，再以 `SELECT ... FOR UPDATE` 读取双方存活别名，
+    /// 按 [`plan_alias_reassignment`] 规划后逐行写入，超过 `max_live` 时不做任何写入。
+    pub(crate) async fn reassign_in(
+        conn: &impl ConnectionTrait,
+        from: BizMetadataId,
+        to: BizMetadataId,
+        now: DateTime<Utc>,
+        max_live: usize,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        if from == to {
+            return Ok(Vec::new());
+        }
+        Self::lock_capacity_in(conn, [to]).await?;
+        let moving = Self::find_live_for_update_in(conn, from).await?;
+        let survivors = Self::find_live_for_update_in(conn, to).await?;
+        let (changes, moved_ids) = plan_alias_reassignment(moving, &survivors, to, now, max_live)?;
+        let mut moved = Vec::with_capacity(moved_ids.len());
+        for change in changes {
+            let updated = Self::update_in(conn, &change).await?;
+            if moved_ids.contains(&updated.id()) {
+                moved.push(updated);
+            }
+        }
+        Ok(moved)
+    }
+
+    /// 在事务内按 ID 升序读取并锁定元数据的存活别名。
+    async fn find_live_for_update_in(
+        conn: &impl ConnectionTrait,
+        metadata_id: BizMetadataId,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        let models = BizMetadataAliasEntity::find()
+            .filter(biz_metadata_alias::Column::MetadataId.eq(metadata_id.value()))
+            .filter(BizMetadataAliasEntity::alive())
+            .order_by_asc(biz_metadata_alias::Column::Id)
+            .lock_exclusive()
+            .all(conn)
+            .await
+            .map_err(Self::map_db_err)?;
+        models
+            .iter()
+            .map(BizMetadataAliasMapper::map_to_domain)
+            .collect()
+    }
+
+    /// 在给定连接（或事务）内软删除元数据的全部存活别名，返回受影响行数。
+    pub(crate) async fn soft_delete_by_metadata_id_in(
+        conn: &impl ConnectionTrait,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let result = BizMetadataAliasEntity::update_many()
+            .col_expr(
+                BizMetadataAliasEntity::DELETED_AT,
+                soft_delete_timestamp(deleted_at),
+            )
+            .filter(biz_metadata_alias::Column::MetadataId.eq(metadata_id.value()))
+            .filter(BizMetadataAliasEntity::alive())
+            .exec(conn)
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(result.rows_affected)
+    }
+
+    /// 在给定连接（或事务）内恢复元数据删除时间不早于 `deleted_at` 的别名，返回受影响行数。
+    ///
+    /// 同一 (`alias`, `language`) 已有存活别名时跳过，同批内重复时只恢复 ID 最小者，避免违反 `ux_biz_metadata_alias_alive`。
+    pub(crate) async fn restore_by_metadata_id_in(
+        conn: &impl ConnectionTrait,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+        restored_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let result = conn
+            .execute_raw(Statement::from_sql_and_values(
+                conn.get_database_backend(),
+                r#"
+                UPDATE biz_metadata_alias
+                SET deleted_at = NULL, updated_at = $3
+                WHERE id IN (
+                    SELECT DISTINCT ON (d.alias, d.language) d.id
+                    FROM biz_metadata_alias d
+                    WHERE d.metadata_id = $1
+                      AND d.deleted_at >= $2
+                      AND NOT EXISTS (
+                          SELECT 1 FROM biz_metadata_alias l
+                          WHERE l.metadata_id = d.metadata_id
+                            AND l.alias = d.alias
+                            AND l.language = d.language
+                            AND l.deleted_at IS NULL
+                      )
+                    ORDER BY d.alias, d.language, d.id
+                )
+                "#,
+                [
+                    metadata_id.value().into(),
+                    deleted_at.fixed_offset().into(),
+                    restored_at.fixed_offset().into(),
+                ],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+        Ok(result.rows_affected())
+    }
+
+    fn map_db_err(err: sea_orm::DbErr) -> DomainError {
+        BIZ_METADATA_ALIAS_CONSTRAINTS.translate(err)
+    }
+
+    /// 未登记或不可过滤的字段返回 `Ok(None)`；值无法转换为列类型时返回 `query.value_invalid`。
+    fn field_condition(
+        field: &str,
+        value: &FilterValue,
+        negate: bool,
+    ) -> Result<Option<Condition>, DomainError> {
+        let Some(column) = BIZ_METADATA_ALIAS_FIELD_MAP.column(field) else {
+            return Ok(None);
+        };
+        let invalid = || invalid_filter_value(field, value);
         let condition = match column {
-            biz_metadata_alias::Column::Id => Self::cond_eq(column, value.as_i64()?),
-            biz_metadata_alias::Column::MetadataId => Self::cond_eq(column, value.as_i64()?),
+            biz_metadata_alias::Column::Id | biz_metadata_alias::Column::MetadataId => {
+                Self::cond_eq(column, value.as_i64().ok_or_else(invalid)?)
+            }
             biz_metadata_alias::Column::Alias
             | biz_metadata_alias::Column::Source
-            | biz_metadata_alias::Column::Language => Self::cond_eq(column, value.as_string()?),
+            | biz_metadata_alias::Column::Language => {
+                Self::cond_eq(column, value.as_string().ok_or_else(invalid)?)
+            }
             biz_metadata_alias::Column::Weight => {
-                let weight = value.as_i64()? as i32;
+                let weight = value
+                    .as_i64()
+                    .and_then(|v| i32::try_from(v).ok())
+                    .ok_or_else(invalid)?;
                 Self::cond_eq(column, weight)
             }
-            biz_metadata_alias::Column::IsPrimary => Self::cond_eq(column, value.as_bool()?),
+            biz_metadata_alias::Column::IsPrimary => {
+                Self::cond_eq(column, value.as_bool().ok_or_else(invalid)?)
+            }
             biz_metadata_alias::Column::CreatedAt
             | biz_metadata_alias::Column::UpdatedAt
-            | biz_metadata_alias::Column::DeletedAt => None?,
+            | biz_metadata_alias::Column::DeletedAt => return Ok(None),
         };
-        Some(if negate { condition.not() } else { condition })
+        Ok(Some(if negate { condition.not() } else { condition }))
     }
 
     fn cond_eq<T>(column: biz_metadata_alias::Column, value: T) -> Condition
@@ -64,24 +332,7 @@ impl BizMetadataAliasRepositoryImpl {
     }
 
     fn resolve_order(order: &OrderBy) -> Option<(biz_metadata_alias::Column, SeaOrder)> {
-        Self::column_for(&order.field)
-            .map(|column| (column, resolve_order_direction(&order.direction)))
-    }
-
-    fn column_for(field: &str) -> Option<biz_metadata_alias::Column> {
-        match field {
-            "id" => Some(biz_metadata_alias::Column::Id),
-            "metadata_id" => Some(biz_metadata_alias::Column::MetadataId),
-            "alias" => Some(biz_metadata_alias::Column::Alias),
-            "source" => Some(biz_metadata_alias::Column::Source),
-            "weight" => Some(biz_metadata_alias::Column::Weight),
-            "is_primary" => Some(biz_metadata_alias::Column::IsPrimary),
-            "language" => Some(biz_metadata_alias::Column::Language),
-            "created_at" => Some(biz_metadata_alias::Column::CreatedAt),
-            "updated_at" => Some(biz_metadata_alias::Column::UpdatedAt),
-            "deleted_at" => Some(biz_metadata_alias::Column::DeletedAt),
-            _ => None,
-        }
+        BIZ_METADATA_ALIAS_FIELD_MAP.resolve_order(order)
     }
 }
 
@@ -109,52 +360,21 @@ impl Repository<BizMetadataAlias> for BizMetadataAliasRepositoryImpl {
 
     fn insert(&self, aggregate: BizMetadataAlias) -> Self::InsertFuture<'_> {
         let db = self.db.clone();
-        repo_future(async move {
-            let active = BizMetadataAliasMapper::map_to_active_model(&aggregate)?;
-            let insert_result = BizMetadataAliasEntity::insert(active)
-                .exec(&db)
-                .await
-                .map_err(Self::map_db_err)?;
-
-            let model = BizMetadataAliasEntity::find_by_id(insert_result.last_insert_id)
-                .one(&db)
-                .await
-                .map_err(Self::map_db_err)?
-                .ok_or_else(|| DomainError::Persistence {
-                    message: format!(
-                        "biz_metadata_alias {} not found after insert",
-                        insert_result.last_insert_id
-                    ),
-                })?;
-
-            BizMetadataAliasMapper::map_to_domain(&model)
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::insert_in(&db, &aggregate).await
         })
     }
 
     fn update(&self, aggregate: BizMetadataAlias) -> Self::UpdateFuture<'_> {
         let db = self.db.clone();
-        repo_future(async move {
-            let existing = BizMetadataAliasEntity::find_by_id(aggregate.id().value())
-                .one(&db)
-                .await
-                .map_err(Self::map_db_err)?
-                .ok_or_else(|| DomainError::Persistence {
-                    message: format!("biz_metadata_alias {} not found", aggregate.id().value()),
-                })?;
-
-            let mut active: biz_metadata_alias::ActiveModel = existing.into();
-
-            BizMetadataAliasMapper::apply_changes(&aggregate, &mut active)?;
-
-            let updated_model = active.update(&db).await.map_err(Self::map_db_err)?;
-
-            BizMetadataAliasMapper::map_to_domain(&updated_model)
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::update_in(&db, &aggregate).await
         })
     }
 
     fn delete(&self, id: BizMetadataAliasId) -> Self::DeleteFuture<'_> {
         let db = self.db.clone();
-        repo_future(async move {
+        repo_future_with_timeout(self.query_timeout, async move {
             BizMetadataAliasEntity::delete_many()
                 .filter(biz_metadata_alias::Column::Id.eq(id.value()))
                 .exec(&db)
@@ -165,8 +385,8 @@ impl Repository<BizMetadataAlias> for BizMetadataAliasRepositoryImpl {
     }
 
     fn find_by_id(&self, id: BizMetadataAliasId) -> Self::FindByIdFuture<'_> {
-        let db = self.db.clone();
-        repo_future(async move {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
             let model = BizMetadataAliasEntity::find_by_id(id.value())
                 .one(&db)
                 .await
@@ -178,14 +398,26 @@ impl Repository<BizMetadataAlias> for BizMetadataAliasRepositoryImpl {
     }
 
     fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
-        let db = self.db.clone();
-        repo_future(async move {
+        let db = self.read_db.clone();
+        let slow_query_threshold = self.slow_query_threshold;
+        repo_future_with_timeout(self.query_timeout, async move {
             let pagination =
                 PaginationParams::compute(options.limit, options.offset, DEFAULT_PAGE_SIZE);
+            let started = Instant::now();
 
-            let condition = build_eq_ne_condition(&expr, &|field, value, neg| {
-                Self::field_condition(field, value, neg)
-            });
+            let condition = build_condition(&expr, &|cmp| match cmp {
+                Comparison::Eq { field, value } => Self::field_condition(field, value, false),
+                Comparison::Ne { field, value } => Self::field_condition(field, value, true),
+                Comparison::Contains { field, value } if field == "alias" => {
+                    let needle = value
+                        .as_string()
+                        .ok_or_else(|| invalid_filter_value(field, value))?;
+                    Ok(Some(
+                        Condition::all().add(biz_metadata_alias::Column::Alias.contains(needle)),
+                    ))
+                }
+                _ => Ok(None),
+            })?;
             let base_query = BizMetadataAliasEntity::find().filter(condition);
             let ordered_query =
                 apply_ordering(base_query, &options.order_bys, &Self::resolve_order);
@@ -197,6 +429,13 @@ impl Repository<BizMetadataAlias> for BizMetadataAliasRepositoryImpl {
                 .map_err(Self::map_db_err)?;
 
             let total = paginator.num_items().await.map_err(Self::map_db_err)?;
+            warn_if_slow(
+                "biz_metadata_alias",
+                slow_query_threshold,
+                started.elapsed(),
+                &expr,
+                &options,
+            );
 
             let items = models
                 .iter()
@@ -211,11 +450,227 @@ impl Repository<BizMetadataAlias> for BizMetadataAliasRepositoryImpl {
     }
 }
 
-impl BizMetadataAliasRepository for BizMetadataAliasRepositoryImpl {}
+impl BizMetadataAliasRepository for BizMetadataAliasRepositoryImpl {
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        Some(BIZ_METADATA_ALIAS_FIELD_MAP.fields().collect())
+    }
+
+    fn count_live_aliases(
+        &self,
+        metadata_id: BizMetadataId,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::count_live_in(&db, metadata_id).await
+        })
+    }
+
+    fn find_or_insert_alias(
+        &self,
+        alias: BizMetadataAlias,
+        max_live: usize,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = db.begin().await.map_err(Self::map_db_err)?;
+            // 提前返回时事务随 `txn` 析构自动回滚。
+            Self::lock_capacity_in(&txn, [alias.metadata_id()]).await?;
+            // 与 ux_biz_metadata_alias_alive 冲突时不插入；并发写入者在此等待先行事务提交后再回读。
+            let active = BizMetadataAliasMapper::map_to_active_model(&alias)?;
+            let inserted = BizMetadataAliasEntity::insert(active)
+                .on_conflict(
+                    OnConflict::columns([
+                        biz_metadata_alias::Column::MetadataId,
+                        biz_metadata_alias::Column::Alias,
+                        biz_metadata_alias::Column::Language,
+                    ])
+                    .target_and_where(BizMetadataAliasEntity::alive())
+                    .do_nothing()
+                    .to_owned(),
+                )
+                .exec_without_returning(&txn)
+                .await
+                .map_err(Self::map_db_err)?;
+            if inserted > 0 {
+                let total = Self::count_live_in(&txn, alias.metadata_id()).await?;
+                if total > max_live as u64 {
+                    return Err(alias_limit_exceeded(alias.metadata_id(), total, max_live));
+                }
+            }
+
+            let model = BizMetadataAliasEntity::find()
+                .filter(biz_metadata_alias::Column::MetadataId.eq(alias.metadata_id().value()))
+                .filter(biz_metadata_alias::Column::Alias.eq(alias.alias().as_str()))
+                .filter(biz_metadata_alias::Column::Language.eq(alias.language().as_str()))
+                .filter(BizMetadataAliasEntity::alive())
+                .one(&txn)
+                .await
+                .map_err(Self::map_db_err)?
+                .ok_or_else(|| DomainError::Persistence {
+                    code: error_code::PERSISTENCE_ROW_MISSING,
+                    message: format!(
+                        "biz_metadata_alias {} not found after find_or_insert",
+                        alias.alias().as_str()
+                    ),
+                })?;
+            txn.commit().await.map_err(Self::map_db_err)?;
+            BizMetadataAliasMapper::map_to_domain(&model)
+        })
+    }
+
+    fn insert_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+        max_live: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = db.begin().await.map_err(Self::map_db_err)?;
+            let adding = count_by_metadata(&items);
+            // 提前返回时事务随 `txn` 析构自动回滚。
+            Self::lock_capacity_in(&txn, adding.keys().copied()).await?;
+            let mut inserted = Vec::with_capacity(items.len());
+            for item in items {
+                inserted.push(Self::insert_in(&txn, &item).await?);
+            }
+            // 按插入后的存活总数校验，超限时整批回滚。
+            for metadata_id in adding.into_keys() {
+                let total = Self::count_live_in(&txn, metadata_id).await?;
+                if total > max_live as u64 {
+                    return Err(alias_limit_exceeded(metadata_id, total, max_live));
+                }
+            }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(inserted)
+        })
+    }
+
+    fn update_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = db.begin().await.map_err(Self::map_db_err)?;
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                // 提前返回时事务随 `txn` 析构自动回滚。
+                updated.push(Self::update_in(&txn, &item).await?);
+            }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(updated)
+        })
+    }
+
+    fn swap_primary_alias(
+        &self,
+        demoted: Vec<BizMetadataAlias>,
+        promoted: BizMetadataAlias,
+        change: AliasPrimaryChange,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        // 调用方的 `demoted` 是事务外读取的快照，可能已过期；这里在锁内重新定位并只改写首选标记。
+        let _ = demoted;
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = db.begin().await.map_err(Self::map_db_err)?;
+            let backend = txn.get_database_backend();
+            let metadata_id = change.metadata_id.value();
+            // 提前返回时事务随 `txn` 析构自动回滚。
+            // 按元数据加事务级咨询锁，串行化同一元数据的首选切换（包括当前没有首选别名的情形）。
+            txn.execute_raw(Statement::from_sql_and_values(
+                backend,
+                "SELECT pg_advisory_xact_lock(hashtextextended('biz_metadata_alias.primary:' || $1::text, 0))",
+                [metadata_id.into()],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+            let demoted_rows = txn
+                .query_all_raw(Statement::from_sql_and_values(
+                    backend,
+                    r#"
+                    UPDATE biz_metadata_alias
+                    SET is_primary = FALSE, updated_at = $3
+                    WHERE metadata_id = $1 AND is_primary AND deleted_at IS NULL AND id <> $2
+                    RETURNING id
+                    "#,
+                    [
+                        metadata_id.into(),
+                        change.promoted.value().into(),
+                        change.changed_at.into(),
+                    ],
+                ))
+                .await
+                .map_err(Self::map_db_err)?;
+            let mut previous = None;
+            for row in demoted_rows {
+                let id: i64 = row.try_get("", "id").map_err(Self::map_db_err)?;
+                previous = Some(previous.map_or(id, |current: i64| current.min(id)));
+            }
+            let promoted = Self::update_in(&txn, &promoted).await?;
+
+            let mut insert = Query::insert();
+            insert
+                .into_table(Alias::new(ALIAS_HISTORY_TABLE))
+                .columns([
+                    Alias::new("metadata_id"),
+                    Alias::new("action"),
+                    Alias::new("alias_id"),
+                    Alias::new("previous_alias_id"),
+                    Alias::new("created_at"),
+                ])
+                .values([
+                    change.metadata_id.value().into(),
+                    AliasPrimaryChange::ACTION.into(),
+                    change.promoted.value().into(),
+                    previous.into(),
+                    change.changed_at.into(),
+                ])
+                .map_err(|err| Self::map_db_err(DbErr::Custom(err.to_string())))?;
+            txn.execute(&insert).await.map_err(Self::map_db_err)?;
+
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(promoted)
+        })
+    }
+
+    fn soft_delete_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::soft_delete_by_metadata_id_in(&db, metadata_id, deleted_at).await
+        })
+    }
+
+    fn restore_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+        restored_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::restore_by_metadata_id_in(&db, metadata_id, deleted_at, restored_at).await
+        })
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::application::service::biz_metadata_alias::BizMetadataAliasService;
+    use crate::application::service::biz_metadata_alias::command::CreateBizMetadataAliasCommand;
+    use crate::domain::biz_metadata_alias::repository::collect_aliases_of;
+    use biz_metadata_migration::{Migrator, MigratorTrait};
+
+    async fn pg() -> Option<DatabaseConnection> {
+        let url = std::env::var("TEST_DATABASE_URL").ok()?;
+        let db = sea_orm::Database::connect(url).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        Some(db)
+    }
 
     #[test]
     fn field_resolver_supports_alias() {
@@ -224,7 +679,40 @@ mod tests {
             &FilterValue::from("foo"),
             false,
         );
-        assert!(cond.is_some());
+        assert!(cond.unwrap().is_some());
+        let err = BizMetadataAliasRepositoryImpl::field_condition(
+            "weight",
+            &FilterValue::from("heavy"),
+            false,
+        )
+        .unwrap_err();
+        assert_eq!(err.code(), error_code::QUERY_VALUE_INVALID);
+    }
+
+    #[tokio::test]
+    async fn soft_delete_skips_deleted_rows_via_the_declared_column() {
+        use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+        BizMetadataAliasRepositoryImpl::new(db.clone())
+            .soft_delete_by_metadata_id(BizMetadataId::new(1), Utc::now())
+            .await
+            .unwrap();
+        let log = db.into_transaction_log();
+        assert!(
+            log[0].statements()[0]
+                .sql
+                .contains(r#""deleted_at" IS NULL"#)
+        );
+        assert_eq!(
+            format!("{:?}", BIZ_METADATA_ALIAS_FIELD_MAP.column("deleted_at")),
+            format!("{:?}", Some(BizMetadataAliasEntity::DELETED_AT))
+        );
     }
 
     #[test]
@@ -233,4 +721,355 @@ mod tests {
         let mapped = BizMetadataAliasRepositoryImpl::map_db_err(err);
         assert!(matches!(mapped, DomainError::Persistence { .. }));
     }
+
+    #[tokio::test]
+    async fn promote_primary_writes_history_in_transaction() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db.clone());
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let mut old = BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap();
+        old.set_primary(true, Utc::now()).unwrap();
+        let old = repo.insert_alias(old).await.unwrap();
+        let better = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营业收入", Utc::now()).unwrap())
+            .await
+            .unwrap();
+
+        let service = BizMetadataAliasService::new(repo);
+        service
+            .promote_primary(metadata_id, better.id())
+            .await
+            .unwrap();
+        let old = service.find_by_id(old.id()).await.unwrap().unwrap();
+        assert!(!old.is_primary());
+
+        let row = db
+            .query_one_raw(Statement::from_sql_and_values(
+                db.get_database_backend(),
+                "SELECT alias_id, previous_alias_id FROM biz_metadata_alias_history WHERE metadata_id = $1",
+                [metadata_id.value().into()],
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            row.try_get::<i64>("", "alias_id").unwrap(),
+            better.id().value()
+        );
+        assert_eq!(
+            row.try_get::<Option<i64>>("", "previous_alias_id").unwrap(),
+            Some(old.id().value())
+        );
+    }
+
+    #[tokio::test]
+    async fn swap_primary_demotes_current_primary_not_stale_snapshot() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db.clone());
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let first = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let second = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营业收入", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let snapshot = first.clone();
+
+        // 快照之后 `first` 被并发提升为首选并调整了权重。
+        let mut concurrent = first.clone();
+        concurrent.set_primary(true, Utc::now()).unwrap();
+        concurrent.change_weight(70, Utc::now()).unwrap();
+        repo.update_alias(concurrent).await.unwrap();
+
+        let mut promoted = second.clone();
+        promoted.set_primary(true, Utc::now()).unwrap();
+        let mut stale = snapshot;
+        stale.set_primary(false, Utc::now()).unwrap();
+        repo.swap_primary_alias(
+            vec![stale],
+            promoted,
+            AliasPrimaryChange {
+                metadata_id,
+                promoted: second.id(),
+                demoted: None,
+                changed_at: Utc::now(),
+            },
+        )
+        .await
+        .unwrap();
+
+        let first = repo.find_alias_by_id(first.id()).await.unwrap().unwrap();
+        assert!(!first.is_primary());
+        assert_eq!(first.weight().value(), 70);
+        let row = db
+            .query_one_raw(Statement::from_sql_and_values(
+                db.get_database_backend(),
+                "SELECT previous_alias_id FROM biz_metadata_alias_history WHERE metadata_id = $1",
+                [metadata_id.value().into()],
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            row.try_get::<Option<i64>>("", "previous_alias_id").unwrap(),
+            Some(first.id().value())
+        );
+    }
+
+    #[tokio::test]
+    async fn update_alias_many_rolls_back_on_missing_alias() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db);
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let stored = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let before = stored.weight().value();
+
+        let mut reweighted = stored.clone();
+        reweighted.change_weight(before + 1, Utc::now()).unwrap();
+        let mut missing = BizMetadataAlias::new(metadata_id, "GMV", Utc::now()).unwrap();
+        missing.change_weight(10, Utc::now()).unwrap();
+        let err = repo
+            .update_alias_many(vec![reweighted.clone(), missing])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_NOT_FOUND);
+        let reloaded = repo.find_alias_by_id(stored.id()).await.unwrap().unwrap();
+        assert_eq!(reloaded.weight().value(), before);
+
+        let updated = repo.update_alias_many(vec![reweighted]).await.unwrap();
+        assert_eq!(updated[0].weight().value(), before + 1);
+    }
+
+    #[tokio::test]
+    async fn insert_alias_many_rolls_back_on_duplicate() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db);
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let new = |text: &str| BizMetadataAlias::new(metadata_id, text, Utc::now()).unwrap();
+
+        let err = repo
+            .insert_alias_many(vec![new("营收"), new("GMV"), new("营收")], usize::MAX)
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_DUPLICATE);
+        let stored = collect_aliases_of(&repo, metadata_id, |_| true)
+            .await
+            .unwrap();
+        assert!(stored.is_empty());
+
+        let inserted = repo
+            .insert_alias_many(vec![new("营收"), new("GMV")], usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(inserted.len(), 2);
+        assert_eq!(inserted[1].alias().as_str(), "GMV");
+    }
+
+    #[tokio::test]
+    async fn duplicate_alive_alias_maps_to_duplicate_code() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db);
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        repo.insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let err = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_DUPLICATE);
+    }
+
+    #[tokio::test]
+    async fn cascade_restores_only_aliases_deleted_together() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db.clone());
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let kept = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let retired = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收旧称", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        db.execute_raw(Statement::from_sql_and_values(
+            db.get_database_backend(),
+            "UPDATE biz_metadata_alias SET deleted_at = CURRENT_TIMESTAMP WHERE id = $1",
+            [retired.id().value().into()],
+        ))
+        .await
+        .unwrap();
+
+        let deleted_at = Utc::now();
+        assert_eq!(
+            repo.soft_delete_by_metadata_id(metadata_id, deleted_at)
+                .await
+                .unwrap(),
+            1
+        );
+        let restored_at = deleted_at + chrono::Duration::seconds(1);
+        assert_eq!(
+            repo.restore_by_metadata_id(metadata_id, deleted_at, restored_at)
+                .await
+                .unwrap(),
+            1
+        );
+        let kept = repo.find_alias_by_id(kept.id()).await.unwrap().unwrap();
+        assert!(kept.delete_at().is_none());
+        let retired = repo.find_alias_by_id(retired.id()).await.unwrap().unwrap();
+        assert!(retired.delete_at().is_some());
+    }
+
+    #[tokio::test]
+    async fn cascade_restore_skips_aliases_reused_while_deleted() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataAliasRepositoryImpl::new(db);
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let cascaded = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+        let deleted_at = Utc::now();
+        repo.soft_delete_by_metadata_id(metadata_id, deleted_at)
+            .await
+            .unwrap();
+        let reused = repo
+            .insert_alias(BizMetadataAlias::new(metadata_id, "营收", Utc::now()).unwrap())
+            .await
+            .unwrap();
+
+        let restored_at = Utc::now() + chrono::Duration::seconds(1);
+        assert_eq!(
+            repo.restore_by_metadata_id(metadata_id, deleted_at, restored_at)
+                .await
+                .unwrap(),
+            0
+        );
+        let cascaded = repo.find_alias_by_id(cascaded.id()).await.unwrap().unwrap();
+        assert!(cascaded.delete_at().is_some());
+        let reused = repo.find_alias_by_id(reused.id()).await.unwrap().unwrap();
+        assert!(reused.delete_at().is_none());
+    }
+
+    #[tokio::test]
+    async fn concurrent_find_or_create_yields_single_alias() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let service = std::sync::Arc::new(BizMetadataAliasService::new(
+            BizMetadataAliasRepositoryImpl::new(db),
+        ));
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let language = crate::domain::biz_metadata_alias::LanguageCode::new("zh-CN").unwrap();
+
+        let tasks: Vec<_> = (0..8)
+            .map(|_| {
+                let service = std::sync::Arc::clone(&service);
+                let language = language.clone();
+                tokio::spawn(async move {
+                    service
+                        .find_or_create(metadata_id, "营业收入", language)
+                        .await
+                        .unwrap()
+                        .id()
+                })
+            })
+            .collect();
+        let mut ids = Vec::new();
+        for task in tasks {
+            ids.push(task.await.unwrap());
+        }
+        ids.dedup();
+        assert_eq!(ids.len(), 1);
+
+        let rows = service
+            .repository()
+            .query_alias(
+                Expression::cmp(domain_core::expression::eq(
+                    "metadata_id",
+                    metadata_id.value(),
+                )),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(rows.into_items().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn concurrent_creates_respect_alias_limit() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let service = std::sync::Arc::new(
+            BizMetadataAliasService::new(BizMetadataAliasRepositoryImpl::new(db))
+                .with_max_aliases_per_metadata(3),
+        );
+        let metadata_id = BizMetadataId::new(Utc::now().timestamp_micros());
+        let language = crate::domain::biz_metadata_alias::LanguageCode::new("zh-CN").unwrap();
+
+        let tasks: Vec<_> = (0..8)
+            .map(|i| {
+                let service = std::sync::Arc::clone(&service);
+                let language = language.clone();
+                tokio::spawn(async move {
+                    let text = format!("营收{i}");
+                    if i % 2 == 0 {
+                        service
+                            .find_or_create(metadata_id, text, language)
+                            .await
+                            .map(|_| ())
+                    } else {
+                        service
+                            .create_alias(CreateBizMetadataAliasCommand {
+                                metadata_id,
+                                alias: text,
+                                source: None,
+                                weight: None,
+                                is_primary: None,
+                                language: Some(language),
+                            })
+                            .await
+                            .map(|_| ())
+                    }
+                })
+            })
+            .collect();
+        let mut rejected = 0;
+        for task in tasks {
+            if let Err(err) = task.await.unwrap() {
+                assert_eq!(err.code(), error_code::ALIAS_LIMIT_EXCEEDED);
+                rejected += 1;
+            }
+        }
+        assert_eq!(rejected, 5);
+        assert_eq!(
+            service
+                .repository()
+                .count_live_aliases(metadata_id)
+                .await
+                .unwrap(),
+            3
+        );
+    }
 }
@@ -1,45 +1,202 @@
-use crate::domain::biz_metadata::BizMetadata;
-use crate::domain::biz_metadata::repository::BizMetadataRepository;
-use crate::domain::biz_metadata::value_object::BizMetadataId;
-use crate::infrastructure::persistence::entity::biz_metadata;
-use crate::infrastructure::persistence::entity::prelude::BizMetadata as BizMetadataEntity;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::domain::biz_metadata::repository::{
+    BizMetadataRepository, CatalogReplacement, LockedCheck, VERSION_CONFLICT_MESSAGE,
+    ensure_facetable,
+};
+use crate::domain::biz_metadata::value_object::{BizMetadataId, Version};
+use crate::domain::biz_metadata::{BizMetadata, CodeUniqueness};
+use crate::domain::error_code;
+use crate::infrastructure::persistence::db_error::ConstraintMap;
+use crate::infrastructure::persistence::entity::prelude::{
+    BizMetadata as BizMetadataEntity, BizMetadataAlias as BizMetadataAliasEntity,
+};
+use crate::infrastructure::persistence::entity::{biz_metadata, biz_metadata_alias};
 use crate::infrastructure::persistence::mapper::{
     ActiveModelMapper, EntityMapper, biz_metadata_mapping::BizMetadataMapper,
 };
 use crate::infrastructure::persistence::query::{
-    PaginationParams, apply_ordering, build_eq_ne_condition, resolve_order_direction,
+    FieldMap, PaginationParams, SoftDelete, apply_ordering, build_comparison_condition,
+    invalid_filter_value, soft_delete_timestamp,
 };
-use crate::infrastructure::persistence::repository::future::{RepoFuture, repo_future};
+use crate::infrastructure::persistence::repository::biz_metadata_alias_repository_impl::BizMetadataAliasRepositoryImpl;
+use crate::infrastructure::persistence::repository::future::{
+    DEFAULT_QUERY_TIMEOUT, DEFAULT_SLOW_QUERY_THRESHOLD, RepoFuture, repo_future_with_timeout,
+    warn_if_slow,
+};
+use crate::infrastructure::persistence::transaction::{TransactionIsolation, begin_with_isolation};
+use chrono::{DateTime, Utc};
 use domain_core::domain_error::DomainError;
 use domain_core::expression::{Expression, FilterValue, OrderBy, QueryOptions};
 use domain_core::pagination::{DEFAULT_PAGE_SIZE, PageResult};
 use domain_core::repository::Repository;
+use futures_util::{Stream, StreamExt, TryFutureExt};
+use sea_orm::sea_query::{Expr, ExprTrait, IntoCondition, Query};
 use sea_orm::{
-    ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order as SeaOrder, PaginatorTrait,
-    QueryFilter,
+    ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, DatabaseTransaction, EntityTrait,
+    Order as SeaOrder, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Statement,
+    UpdateMany, Value,
 };
 
 pub struct BizMetadataRepositoryImpl {
     db: DatabaseConnection,
+    read_db: DatabaseConnection,
+    query_timeout: Duration,
+    slow_query_threshold: Duration,
+    isolation: TransactionIsolation,
 }
 
 const DEFAULT_TENANT_ID: &str = "default";
 
+/// 全局唯一时存活记录的编码唯一索引。
+const GLOBAL_CODE_INDEX: &str = "ux_biz_metadata_tenant_code_alive";
+/// 按父节点唯一时子节点的编码唯一索引。
+const PARENT_CODE_INDEX: &str = "ux_biz_metadata_tenant_parent_code_alive";
+/// 按父节点唯一时根节点的编码唯一索引。
+const ROOT_CODE_INDEX: &str = "ux_biz_metadata_tenant_root_code_alive";
+
+/// 约束冲突到错误码的映射，未登记的约束按通用错误码处理。
+pub const BIZ_METADATA_CONSTRAINTS: ConstraintMap = ConstraintMap::new(&[
+    (GLOBAL_CODE_INDEX, error_code::BIZ_METADATA_CODE_CONFLICT),
+    (PARENT_CODE_INDEX, error_code::BIZ_METADATA_CODE_CONFLICT),
+    (ROOT_CODE_INDEX, error_code::BIZ_METADATA_CODE_CONFLICT),
+    ("biz_metadata_pkey", error_code::BIZ_METADATA_ID_CONFLICT),
+]);
+
+/// `biz_metadata` 可过滤、可排序的字段及其对应列。
+pub const BIZ_METADATA_FIELD_MAP: FieldMap<biz_metadata::Column> = FieldMap::new(&[
+    ("id", biz_metadata::Column::Id),
+    ("tenant_id", biz_metadata::Column::TenantId),
+    ("version", biz_metadata::Column::Version),
+    ("code", biz_metadata::Column::Code),
+    ("name", biz_metadata::Column::Name),
+    ("description", biz_metadata::Column::Description),
+    ("object_type", biz_metadata::Column::ObjectType),
+    ("parent_id", biz_metadata::Column::ParentId),
+    ("data_class", biz_metadata::Column::DataClass),
+    ("value_type", biz_metadata::Column::ValueType),
+    ("unit", biz_metadata::Column::Unit),
+    ("status", biz_metadata::Column::Status),
+    ("source", biz_metadata::Column::Source),
+    ("created_at", biz_metadata::Column::CreatedAt),
+    ("updated_at", biz_metadata::Column::UpdatedAt),
+    ("deleted_at", biz_metadata::Column::DeletedAt),
+]);
+
+impl SoftDelete for BizMetadataEntity {
+    const DELETED_AT: biz_metadata::Column = biz_metadata::Column::DeletedAt;
+}
+
 impl BizMetadataRepositoryImpl {
     pub fn new(db: DatabaseConnection) -> Self {
-        Self { db }
+        Self {
+            read_db: db.clone(),
+            db,
+            query_timeout: DEFAULT_QUERY_TIMEOUT,
+            slow_query_threshold: DEFAULT_SLOW_QUERY_THRESHOLD,
+            isolation: TransactionIsolation::default(),
+        }
+    }
+
+    /// 设置只读副本连接：`find_by_id`、`query`、计数与存在性检查等只读方法走该连接，写入与事务内读取仍走主库。
+    ///
+    /// 未设置时读写共用主库连接；副本存在复制延迟，写后立即读取可能看不到最新数据。
+    pub fn with_read_replica(mut self, read_db: DatabaseConnection) -> Self {
+        self.read_db = read_db;
+        self
+    }
+
+    /// 设置单次数据库调用的超时时间，超时返回 `query timed out` 持久化错误。
+    pub fn with_query_timeout(mut self, timeout: Duration) -> Self {
+        self.query_timeout = timeout;
+        self
+    }
+
+    /// 设置慢查询阈值，`query` 耗时超过该值时以 warn 级别记录筛选条件与耗时（默认 1 秒）。
+    pub fn with_slow_query_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_query_threshold = threshold;
+        self
+    }
+
+    /// 设置各类写事务的隔离级别，默认全部沿用数据库默认值。
+    ///
+    /// `SERIALIZABLE` 下的序列化失败返回 `persistence.serialization_failure`，调用方可按
+    /// [`is_retryable`](crate::domain::biz_metadata::repository::is_retryable) 判断后重试。
+    pub fn with_transaction_isolation(mut self, isolation: TransactionIsolation) -> Self {
+        self.isolation = isolation;
+        self
     }
 
     fn map_db_err(err: sea_orm::DbErr) -> DomainError {
-        DomainError::Persistence {
-            message: err.to_string(),
+        BIZ_METADATA_CONSTRAINTS.translate(err)
+    }
+
+    /// 校验数据库中存活记录的编码唯一索引与 `code_uniqueness` 一致，供服务启动时调用。
+    ///
+    /// `Global` 须只有 `ux_biz_metadata_tenant_code_alive`，`PerParent` 须只有按父节点划分的两个索引；
+    /// 迁移与服务读取的配置不一致时返回 `biz_metadata.code_index_mismatch`，避免数据库与服务层按不同规则判定唯一性。
+    pub async fn verify_code_uniqueness(
+        &self,
+        code_uniqueness: CodeUniqueness,
+    ) -> Result<(), DomainError> {
+        let expected: &[&str] = match code_uniqueness {
+            CodeUniqueness::Global => &[GLOBAL_CODE_INDEX],
+            CodeUniqueness::PerParent => &[PARENT_CODE_INDEX, ROOT_CODE_INDEX],
+        };
+        let rows = self
+            .db
+            .query_all_raw(Statement::from_sql_and_values(
+                self.db.get_database_backend(),
+                r#"
+                SELECT indexname FROM pg_indexes
+                WHERE schemaname = current_schema()
+                  AND tablename = 'biz_metadata'
+                  AND indexname = ANY($1)
+                ORDER BY indexname
+                "#,
+                [Value::from(vec![
+                    GLOBAL_CODE_INDEX.to_string(),
+                    PARENT_CODE_INDEX.to_string(),
+                    ROOT_CODE_INDEX.to_string(),
+                ])],
+            ))
+            .await
+            .map_err(Self::map_db_err)?;
+        let mut found = rows
+            .iter()
+            .map(|row| row.try_get::<String>("", "indexname"))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(Self::map_db_err)?;
+        found.sort_unstable();
+        let mut wanted: Vec<String> = expected.iter().map(|name| name.to_string()).collect();
+        wanted.sort_unstable();
+        if found == wanted {
+            return Ok(());
         }
+        Err(DomainError::InvariantViolation {
+            code: error_code::BIZ_METADATA_CODE_INDEX_MISMATCH,
+            message: format!(
+                "code uniqueness {code_uniqueness:?} expects indexes [{}] on biz_metadata, found [{}]; run migrations with the same BIZ_METADATA_CODE_UNIQUENESS",
+                wanted.join(", "),
+                found.join(", ")
+            ),
+        })
     }
 
-    fn field_condition(field: &str, value: &FilterValue, negate: bool) -> Option<Condition> {
-        let column = Self::column_for(field)?;
-        let condition = match column {
-            biz_metadata::Column::Id => Self::cond_eq(column, value.as_i64()?),
+    /// 未登记字段返回 `Ok(None)`；值无法转换为列类型时返回 `query.value_invalid`。
+    fn column_value(
+        field: &str,
+        value: &FilterValue,
+    ) -> Result<Option<(biz_metadata::Column, Value)>, DomainError> {
+        let Some(column) = BIZ_METADATA_FIELD_MAP.column(field) else {
+            return Ok(None);
+        };
+        let converted: Option<Value> = match column {
+            biz_metadata::Column::Id | biz_metadata::Column::ParentId => {
+                value.as_i64().map(Into::into)
+            }
             biz_metadata::Column::Code
             | biz_metadata::Column::Name
             | biz_metadata::Column::Description
@@ -49,49 +206,192 @@ impl BizMetadataRepositoryImpl {
             | biz_metadata::Column::ValueType
             | biz_metadata::Column::Unit
             | biz_metadata::Column::Source
-            | biz_metadata::Column::TenantId => Self::cond_eq(column, value.as_string()?),
-            biz_metadata::Column::ParentId => Self::cond_eq(column, value.as_i64()?),
-            biz_metadata::Column::Version => {
-                let v: i32 = value.as_i64()?.try_into().ok()?;
-                Self::cond_eq(column, v)
+            | biz_metadata::Column::TenantId => value.as_string().map(Into::into),
+            biz_metadata::Column::Version => value
+                .as_i64()
+                .and_then(|v| i32::try_from(v).ok())
+                .map(Into::into),
+            biz_metadata::Column::CreatedAt
+            | biz_metadata::Column::UpdatedAt
+            | biz_metadata::Column::DeletedAt => {
+                value.as_datetime().map(|v| v.fixed_offset().into())
             }
-            _ => None?,
         };
-        Some(if negate { condition.not() } else { condition })
+        converted
+            .map(|converted| Some((column, converted)))
+            .ok_or_else(|| invalid_filter_value(field, value))
+    }
+
+    /// 在事务内以 `SELECT ... FOR UPDATE` 读取未删除记录，行锁持续到事务结束。
+    async fn find_by_id_for_update(
+        txn: &DatabaseTransaction,
+        id: BizMetadataId,
+    ) -> Result<Option<BizMetadata>, DomainError> {
+        let model = BizMetadataEntity::find()
+            .filter(biz_metadata::Column::Id.eq(id.value()))
+            .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+            .filter(BizMetadataEntity::alive())
+            .lock_exclusive()
+            .one(txn)
+            .await
+            .map_err(Self::map_db_err)?;
+        model
+            .map(|m| BizMetadataMapper::map_to_domain(&m))
+            .transpose()
+    }
+
+    /// 在给定连接（或事务）内插入并回读记录。
+    async fn insert_in(
+        conn: &impl ConnectionTrait,
+        aggregate: &BizMetadata,
+    ) -> Result<BizMetadata, DomainError> {
+        let active = BizMetadataMapper::map_to_active_model(aggregate)?;
+        let insert_result = BizMetadataEntity::insert(active)
+            .exec(conn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        let model = BizMetadataEntity::find_by_id(insert_result.last_insert_id)
+            .one(conn)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| DomainError::Persistence {
+                code: error_code::PERSISTENCE_ROW_MISSING,
+                message: format!(
+                    "biz_metadata {} not found after insert",
+                    insert_result.last_insert_id
+                ),
+            })?;
+
+        BizMetadataMapper::map_to_domain(&model)
+    }
+
+    /// 构造按 `id` 与 `version` 匹配、写入聚合全部字段并递增版本号的 `UPDATE`。
+    ///
+    /// 已删除的聚合以 [`soft_delete_timestamp`] 写入 `deleted_at`，保证回读时不早于触发器写入的 `updated_at`。
+    fn versioned_update(
+        aggregate: &BizMetadata,
+    ) -> Result<UpdateMany<BizMetadataEntity>, DomainError> {
+        let expected_version = aggregate.version();
+        let mut active: biz_metadata::ActiveModel = Default::default();
+        BizMetadataMapper::apply_changes(aggregate, &mut active)?;
+        active.version = sea_orm::ActiveValue::Set(i32::from(expected_version.next()?));
+        let mut update = BizMetadataEntity::update_many();
+        if let Some(deleted_at) = aggregate.delete_at() {
+            active.deleted_at = sea_orm::ActiveValue::NotSet;
+            update = update.col_expr(
+                BizMetadataEntity::DELETED_AT,
+                soft_delete_timestamp(deleted_at),
+            );
+        }
+        Ok(update
+            .set(active)
+            .filter(biz_metadata::Column::Id.eq(aggregate.id().value()))
+            .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+            .filter(biz_metadata::Column::Version.eq(i32::from(expected_version))))
     }
 
-    fn cond_eq<T>(column: biz_metadata::Column, value: T) -> Condition
+    /// 按 `version` 乐观锁提交存活聚合并回读最新记录，未命中时返回版本冲突。
+    async fn update_versioned<C>(
+        conn: &C,
+        aggregate: BizMetadata,
+    ) -> Result<BizMetadata, DomainError>
     where
-        T: Into<sea_orm::Value>,
+        C: ConnectionTrait,
     {
-        Condition::all().add(column.eq(value))
+        Self::update_versioned_where(conn, aggregate, BizMetadataEntity::alive()).await
     }
 
-    fn resolve_order(order: &OrderBy) -> Option<(biz_metadata::Column, SeaOrder)> {
-        Self::column_for(&order.field)
-            .map(|column| (column, resolve_order_direction(&order.direction)))
-    }
-
-    fn column_for(field: &str) -> Option<biz_metadata::Column> {
-        match field {
-            "id" => Some(biz_metadata::Column::Id),
-            "tenant_id" => Some(biz_metadata::Column::TenantId),
-            "version" => Some(biz_metadata::Column::Version),
-            "code" => Some(biz_metadata::Column::Code),
-            "name" => Some(biz_metadata::Column::Name),
-            "description" => Some(biz_metadata::Column::Description),
-            "object_type" => Some(biz_metadata::Column::ObjectType),
-            "parent_id" => Some(biz_metadata::Column::ParentId),
-            "data_class" => Some(biz_metadata::Column::DataClass),
-            "value_type" => Some(biz_metadata::Column::ValueType),
-            "unit" => Some(biz_metadata::Column::Unit),
-            "status" => Some(biz_metadata::Column::Status),
-            "source" => Some(biz_metadata::Column::Source),
-            "created_at" => Some(biz_metadata::Column::CreatedAt),
-            "updated_at" => Some(biz_metadata::Column::UpdatedAt),
-            "deleted_at" => Some(biz_metadata::Column::DeletedAt),
-            _ => None,
+    /// 同 [`Self::update_versioned`]，但由 `row_state` 限定被更新行的删除状态。
+    async fn update_versioned_where<C>(
+        conn: &C,
+        aggregate: BizMetadata,
+        row_state: impl IntoCondition,
+    ) -> Result<BizMetadata, DomainError>
+    where
+        C: ConnectionTrait,
+    {
+        let result = Self::versioned_update(&aggregate)?
+            .filter(row_state)
+            .exec(conn)
+            .await
+            .map_err(Self::map_db_err)?;
+
+        if result.rows_affected == 0 {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                message: VERSION_CONFLICT_MESSAGE.into(),
+            });
         }
+
+        let model = BizMetadataEntity::find()
+            .filter(biz_metadata::Column::Id.eq(aggregate.id().value()))
+            .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+            .one(conn)
+            .await
+            .map_err(Self::map_db_err)?
+            .ok_or_else(|| DomainError::Persistence {
+                code: error_code::PERSISTENCE_ROW_MISSING,
+                message: format!(
+                    "biz_metadata {} not found after update",
+                    aggregate.id().value()
+                ),
+            })?;
+
+        BizMetadataMapper::map_to_domain(&model)
+    }
+
+    async fn fetch_page(
+        db: &DatabaseConnection,
+        query: Select<BizMetadataEntity>,
+        options: &QueryOptions,
+    ) -> Result<PageResult<BizMetadata>, DomainError> {
+        let pagination =
+            PaginationParams::compute(options.limit, options.offset, DEFAULT_PAGE_SIZE);
+        let ordered_query = apply_ordering(query.clone(), &options.order_bys, &Self::resolve_order);
+
+        let paginator = ordered_query.paginate(db, pagination.limit);
+        let (models, total) = match paginator.fetch_page(pagination.page_index).await {
+            Ok(models) => (models, paginator.num_items().await),
+            // 数据库未安装所请求的 collation（如缺少 ICU）时退回默认排序规则。
+            Err(err)
+                if err.to_string().contains("collation")
+                    && options.order_bys.iter().any(|o| o.collation.is_some()) =>
+            {
+                let order_bys: Vec<OrderBy> = options
+                    .order_bys
+                    .iter()
+                    .cloned()
+                    .map(|order| OrderBy {
+                        collation: None,
+                        ..order
+                    })
+                    .collect();
+                let paginator = apply_ordering(query, &order_bys, &Self::resolve_order)
+                    .paginate(db, pagination.limit);
+                let models = paginator
+                    .fetch_page(pagination.page_index)
+                    .await
+                    .map_err(Self::map_db_err)?;
+                (models, paginator.num_items().await)
+            }
+            Err(err) => return Err(Self::map_db_err(err)),
+        };
+        let total = total.map_err(Self::map_db_err)?;
+
+        let items = models
+            .iter()
+            .map(BizMetadataMapper::map_to_domain)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PageResult::builder(items, total)
+            .page_index(pagination.page_index)
+            .page_size(pagination.limit)
+            .build())
+    }
+
+    fn resolve_order(order: &OrderBy) -> Option<(biz_metadata::Column, SeaOrder)> {
+        BIZ_METADATA_FIELD_MAP.resolve_order(order)
     }
 }
 
@@ -119,133 +419,1598 @@ impl Repository<BizMetadata> for BizMetadataRepositoryImpl {
 
     fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
         let db = self.db.clone();
-        repo_future(async move {
-            let active = BizMetadataMapper::map_to_active_model(&aggregate)?;
-            let insert_result = BizMetadataEntity::insert(active)
-                .exec(&db)
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::insert_in(&db, &aggregate).await
+        })
+    }
+
+    fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            Self::update_versioned(&db, aggregate).await
+        })
+    }
+
+    fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let _ = db;
+            let _ = id;
+            Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_DELETE_REQUIRES_VERSION,
+                message: "delete requires version; use soft-delete via update".into(),
+            })
+        })
+    }
+
+    fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let model = BizMetadataEntity::find()
+                .filter(biz_metadata::Column::Id.eq(id.value()))
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .one(&db)
                 .await
                 .map_err(Self::map_db_err)?;
+            model
+                .map(|m| BizMetadataMapper::map_to_domain(&m))
+                .transpose()
+        })
+    }
+
+    fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+        let db = self.read_db.clone();
+        let slow_query_threshold = self.slow_query_threshold;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let condition = build_comparison_condition(&expr, &Self::column_value)?;
+            let base_query = BizMetadataEntity::find()
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .filter(condition);
+            let started = Instant::now();
+            let page = Self::fetch_page(&db, base_query, &options).await;
+            warn_if_slow(
+                "biz_metadata",
+                slow_query_threshold,
+                started.elapsed(),
+                &expr,
+                &options,
+            );
+            page
+        })
+    }
+}
+
+impl BizMetadataRepository for BizMetadataRepositoryImpl {
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        Some(BIZ_METADATA_FIELD_MAP.fields().collect())
+    }
 
-            let model = BizMetadataEntity::find_by_id(insert_result.last_insert_id)
+    fn find_deleted_biz_metadata_by_id(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let model = BizMetadataEntity::find()
+                .filter(biz_metadata::Column::Id.eq(id.value()))
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::deleted())
                 .one(&db)
                 .await
-                .map_err(Self::map_db_err)?
-                .ok_or_else(|| DomainError::Persistence {
-                    message: format!(
-                        "biz_metadata {} not found after insert",
-                        insert_result.last_insert_id
-                    ),
-                })?;
-
-            BizMetadataMapper::map_to_domain(&model)
+                .map_err(Self::map_db_err)?;
+            model
+                .map(|m| BizMetadataMapper::map_to_domain(&m))
+                .transpose()
         })
     }
 
-    fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+    fn restore_biz_metadata(
+        &self,
+        biz_metadata: BizMetadata,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
         let db = self.db.clone();
-        repo_future(async move {
-            let expected_version = aggregate.version();
-            let next_version = expected_version.next()?;
+        repo_future_with_timeout(self.query_timeout, async move {
+            // 恢复后与存活行撞号时由编码唯一索引拒绝（按部署为租户内或父节点内唯一）。
+            Self::update_versioned_where(&db, biz_metadata, BizMetadataEntity::deleted()).await
+        })
+    }
 
-            let mut active: biz_metadata::ActiveModel = Default::default();
-            BizMetadataMapper::apply_changes(&aggregate, &mut active)?;
-            active.version = sea_orm::ActiveValue::Set(i32::from(next_version));
+    fn update_biz_metadata_locked_checked<'a, F>(
+        &'a self,
+        id: BizMetadataId,
+        mutate: F,
+        check: LockedCheck<'a>,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + 'a
+    where
+        F: FnOnce(&mut BizMetadata) -> Result<bool, DomainError> + Send + 'static,
+    {
+        let db = self.db.clone();
+        let isolation = self.isolation.locked_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
+                .await
+                .map_err(Self::map_db_err)?;
+            let mut biz_metadata =
+                Self::find_by_id_for_update(&txn, id)
+                    .await?
+                    .ok_or_else(|| DomainError::Validation {
+                        code: error_code::BIZ_METADATA_NOT_FOUND,
+                        message: format!("biz_metadata {} not found", id.value()),
+                    })?;
+            let loaded = biz_metadata.clone();
+            if !mutate(&mut biz_metadata)? {
+                txn.commit().await.map_err(Self::map_db_err)?;
+                return Ok(biz_metadata);
+            }
+            // 在持有行锁期间校验；失败时事务随 `txn` 析构自动回滚。
+            check(loaded, biz_metadata.clone()).await?;
+            let updated = Self::update_versioned(&txn, biz_metadata).await?;
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(updated)
+        })
+    }
 
-            let result = BizMetadataEntity::update_many()
-                .set(active)
-                .filter(biz_metadata::Column::Id.eq(aggregate.id().value()))
-                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
-                .filter(biz_metadata::Column::Version.eq(i32::from(expected_version)))
-                .filter(biz_metadata::Column::DeletedAt.is_null())
-                .exec(&db)
+    fn soft_delete_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        let isolation = self.isolation.batch_soft_delete;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
                 .await
                 .map_err(Self::map_db_err)?;
+            let mut affected = 0;
+            for mut item in items {
+                item.mark_deleted(deleted_at)?;
+                let result = Self::versioned_update(&item)?
+                    .filter(BizMetadataEntity::alive())
+                    .exec(&txn)
+                    .await
+                    .map_err(Self::map_db_err)?;
 
-            if result.rows_affected == 0 {
-                return Err(DomainError::Validation {
-                    message: "biz_metadata not found or version mismatch".into(),
-                });
+                // 提前返回时事务随 `txn` 析构自动回滚。
+                if result.rows_affected == 0 {
+                    return Err(DomainError::Validation {
+                        code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                        message: VERSION_CONFLICT_MESSAGE.into(),
+                    });
+                }
+                affected += result.rows_affected;
             }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(affected)
+        })
+    }
 
-            let model = BizMetadataEntity::find()
-                .filter(biz_metadata::Column::Id.eq(aggregate.id().value()))
-                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
-                .one(&db)
+    fn update_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        let isolation = self.isolation.batch_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
                 .await
-                .map_err(Self::map_db_err)?
+                .map_err(Self::map_db_err)?;
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                // 提前返回时事务随 `txn` 析构自动回滚。
+                updated.push(Self::update_versioned(&txn, item).await?);
+            }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(updated)
+        })
+    }
+
+    /// 元数据与别名同库：在 `update_biz_metadata_many` 的事务内直接级联别名表。
+    fn delete_biz_metadata_with_aliases(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        let isolation = self.isolation.batch_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
+                .await
+                .map_err(Self::map_db_err)?;
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                // 提前返回时事务随 `txn` 析构自动回滚。
+                updated.push(Self::update_versioned(&txn, item).await?);
+            }
+            let deleted_at = updated
+                .iter()
+                .find(|item| item.id() == deleted)
+                .and_then(BizMetadata::delete_at)
                 .ok_or_else(|| DomainError::Persistence {
-                    message: format!(
-                        "biz_metadata {} not found after update",
-                        aggregate.id().value()
-                    ),
+                    code: error_code::PERSISTENCE_ROW_MISSING,
+                    message: format!("biz_metadata {} not deleted in batch", deleted.value()),
                 })?;
+            BizMetadataAliasRepositoryImpl::soft_delete_by_metadata_id_in(
+                &txn, deleted, deleted_at,
+            )
+            .await?;
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(updated)
+        })
+    }
 
-            BizMetadataMapper::map_to_domain(&model)
+    fn merge_biz_metadata(
+        &self,
+        items: Vec<BizMetadata>,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+        max_live: usize,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        let isolation = self.isolation.batch_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
+                .await
+                .map_err(Self::map_db_err)?;
+            // 提前返回时事务随 `txn` 析构自动回滚。
+            BizMetadataAliasRepositoryImpl::reassign_in(&txn, loser, survivor, now, max_live)
+                .await?;
+            let mut updated = Vec::with_capacity(items.len());
+            for item in items {
+                updated.push(Self::update_versioned(&txn, item).await?);
+            }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(updated)
         })
     }
 
-    fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+    fn restore_biz_metadata_with_aliases(
+        &self,
+        biz_metadata: BizMetadata,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
         let db = self.db.clone();
-        repo_future(async move {
-            let _ = db;
-            let _ = id;
-            Err(DomainError::Validation {
-                message: "delete requires version; use soft-delete via update".into(),
-            })
+        let isolation = self.isolation.batch_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
+                .await
+                .map_err(Self::map_db_err)?;
+            let restored_at = biz_metadata.updated_at();
+            // 提前返回时事务随 `txn` 析构自动回滚。
+            let restored =
+                Self::update_versioned_where(&txn, biz_metadata, BizMetadataEntity::deleted())
+                    .await?;
+            BizMetadataAliasRepositoryImpl::restore_by_metadata_id_in(
+                &txn,
+                restored.id(),
+                deleted_at,
+                restored_at,
+            )
+            .await?;
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(restored)
         })
     }
 
-    fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+    /// 在单个事务内以两条 `UPDATE` 软删除租户的存活别名与存活元数据，再逐条插入条目与别名。
+    fn replace_catalog(
+        &self,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
         let db = self.db.clone();
-        repo_future(async move {
-            let model = BizMetadataEntity::find()
-                .filter(biz_metadata::Column::Id.eq(id.value()))
+        let isolation = self.isolation.batch_update;
+        repo_future_with_timeout(self.query_timeout, async move {
+            let txn = begin_with_isolation(&db, isolation)
+                .await
+                .map_err(Self::map_db_err)?;
+            let live_ids = Query::select()
+                .column(biz_metadata::Column::Id)
+                .from(BizMetadataEntity)
+                .and_where(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .and_where(BizMetadataEntity::alive())
+                .to_owned();
+            BizMetadataAliasEntity::update_many()
+                .col_expr(
+                    BizMetadataAliasEntity::DELETED_AT,
+                    soft_delete_timestamp(now),
+                )
+                .filter(biz_metadata_alias::Column::MetadataId.in_subquery(live_ids))
+                .filter(BizMetadataAliasEntity::alive())
+                .exec(&txn)
+                .await
+                .map_err(Self::map_db_err)?;
+            BizMetadataEntity::update_many()
+                .col_expr(BizMetadataEntity::DELETED_AT, soft_delete_timestamp(now))
+                .col_expr(
+                    biz_metadata::Column::Version,
+                    Expr::col(biz_metadata::Column::Version).add(1),
+                )
                 .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
-                .filter(biz_metadata::Column::DeletedAt.is_null())
-                .one(&db)
+                .filter(BizMetadataEntity::alive())
+                .exec(&txn)
                 .await
                 .map_err(Self::map_db_err)?;
-            model
-                .map(|m| BizMetadataMapper::map_to_domain(&m))
-                .transpose()
+
+            let mut ids_by_code = HashMap::new();
+            let mut inserted = Vec::with_capacity(entries.len());
+            for mut entry in entries {
+                // 提前返回时事务随 `txn` 析构自动回滚。
+                entry.resolve_parent(&ids_by_code, now)?;
+                let stored = Self::insert_in(&txn, &entry.metadata).await?;
+                ids_by_code.insert(stored.code().as_str().to_string(), stored.id());
+                for mut alias in entry.aliases {
+                    alias.change_metadata_id(stored.id(), now)?;
+                    BizMetadataAliasRepositoryImpl::insert_in(&txn, &alias).await?;
+                }
+                inserted.push(stored);
+            }
+            txn.commit().await.map_err(Self::map_db_err)?;
+            Ok(inserted)
         })
     }
 
-    fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+    fn purge_biz_metadata_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
         let db = self.db.clone();
-        repo_future(async move {
-            let pagination =
-                PaginationParams::compute(options.limit, options.offset, DEFAULT_PAGE_SIZE);
+        repo_future_with_timeout(self.query_timeout, async move {
+            let limit = i64::try_from(limit).unwrap_or(i64::MAX);
+            // 单条语句内同时删除被清理行的已软删除别名，两张表同属一个隐式事务。
+            let stmt = Statement::from_sql_and_values(
+                db.get_database_backend(),
+                r#"
+                WITH purged AS (
+                    DELETE FROM biz_metadata
+                    WHERE id IN (
+                        SELECT m.id
+                        FROM biz_metadata m
+                        WHERE m.tenant_id = $1
+                          AND m.deleted_at IS NOT NULL
+                          AND m.deleted_at < $2
+                          AND NOT EXISTS (
+                              SELECT 1 FROM biz_metadata c
+                              WHERE c.parent_id = m.id AND c.deleted_at IS NULL
+                          )
+                          AND NOT EXISTS (
+                              SELECT 1 FROM biz_metadata_alias a
+                              WHERE a.metadata_id = m.id AND a.deleted_at IS NULL
+                          )
+                        ORDER BY m.id
+                        LIMIT $3
+                        FOR UPDATE SKIP LOCKED
+                    )
+                    RETURNING id
+                ),
+                purged_aliases AS (
+                    DELETE FROM biz_metadata_alias a
+                    USING purged p
+                    WHERE a.metadata_id = p.id
+                )
+                SELECT count(*) AS purged FROM purged
+                "#,
+                [
+                    DEFAULT_TENANT_ID.into(),
+                    cutoff.fixed_offset().into(),
+                    limit.into(),
+                ],
+            );
+            let row = db
+                .query_one_raw(stmt)
+                .await
+                .map_err(Self::map_db_err)?
+                .ok_or_else(|| DomainError::Persistence {
+                    code: error_code::PERSISTENCE_ROW_MISSING,
+                    message: "purge returned no count".into(),
+                })?;
+            let purged: i64 = row.try_get("", "purged").map_err(Self::map_db_err)?;
+            Ok(u64::try_from(purged).unwrap_or_default())
+        })
+    }
 
-            let condition = build_eq_ne_condition(&expr, &|field, value, neg| {
-                Self::field_condition(field, value, neg)
-            });
+    fn find_subtree(
+        &self,
+        root: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            // `UNION` 对已访问的 id 去重，成环的脏数据也能终止递归。
+            let stmt = Statement::from_sql_and_values(
+                db.get_database_backend(),
+                r#"
+                WITH RECURSIVE tree (id) AS (
+                    SELECT r.id
+                    FROM biz_metadata r
+                    WHERE r.id = $1 AND r.tenant_id = $2 AND r.deleted_at IS NULL
+                    UNION
+                    SELECT c.id
+                    FROM biz_metadata c
+                    JOIN tree t ON c.parent_id = t.id
+                    WHERE c.tenant_id = $2 AND c.deleted_at IS NULL
+                )
+                SELECT m.*
+                FROM biz_metadata m
+                JOIN tree t ON m.id = t.id
+                ORDER BY m.id <> $1, m.id
+                "#,
+                [root.value().into(), DEFAULT_TENANT_ID.into()],
+            );
+            let models = BizMetadataEntity::find()
+                .from_raw_sql(stmt)
+                .all(&db)
+                .await
+                .map_err(Self::map_db_err)?;
+            models
+                .iter()
+                .map(BizMetadataMapper::map_to_domain)
+                .collect()
+        })
+    }
+
+    fn find_subtree_within_depth(
+        &self,
+        root: BizMetadataId,
+        max_depth: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            // 递归在 `max_depth` 层处截断，成环的脏数据同样由层数上限终止；环上节点可能在多层出现，外层去重。
+            let max_depth = i64::try_from(max_depth).unwrap_or(i64::MAX);
+            let stmt = Statement::from_sql_and_values(
+                db.get_database_backend(),
+                r#"
+                WITH RECURSIVE tree (id, depth) AS (
+                    SELECT r.id, 0::bigint
+                    FROM biz_metadata r
+                    WHERE r.id = $1 AND r.tenant_id = $2 AND r.deleted_at IS NULL
+                    UNION
+                    SELECT c.id, t.depth + 1
+                    FROM biz_metadata c
+                    JOIN tree t ON c.parent_id = t.id
+                    WHERE c.tenant_id = $2 AND c.deleted_at IS NULL AND t.depth < $3
+                )
+                SELECT m.*
+                FROM biz_metadata m
+                WHERE m.id IN (SELECT id FROM tree)
+                ORDER BY m.id <> $1, m.id
+                "#,
+                [
+                    root.value().into(),
+                    DEFAULT_TENANT_ID.into(),
+                    max_depth.into(),
+                ],
+            );
+            let models = BizMetadataEntity::find()
+                .from_raw_sql(stmt)
+                .all(&db)
+                .await
+                .map_err(Self::map_db_err)?;
+            models
+                .iter()
+                .map(BizMetadataMapper::map_to_domain)
+                .collect()
+        })
+    }
+
+    fn query_biz_metadata_changed_since(
+        &self,
+        since: DateTime<Utc>,
+        include_deleted: bool,
+        options: QueryOptions,
+    ) -> Self::QueryFuture<'_> {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let since = since.fixed_offset();
+            let changed = if include_deleted {
+                Condition::any()
+                    .add(biz_metadata::Column::UpdatedAt.gte(since))
+                    .add(BizMetadataEntity::DELETED_AT.gte(since))
+            } else {
+                Condition::all()
+                    .add(biz_metadata::Column::UpdatedAt.gte(since))
+                    .add(BizMetadataEntity::alive())
+            };
             let base_query = BizMetadataEntity::find()
                 .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
-                .filter(biz_metadata::Column::DeletedAt.is_null())
-                .filter(condition);
-            let ordered_query =
-                apply_ordering(base_query, &options.order_bys, &Self::resolve_order);
+                .filter(changed);
+            Self::fetch_page(&db, base_query, &options).await
+        })
+    }
 
-            let paginator = ordered_query.paginate(&db, pagination.limit);
-            let models = paginator
-                .fetch_page(pagination.page_index)
+    fn count_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let condition = build_comparison_condition(&expr, &Self::column_value)?;
+            BizMetadataEntity::find()
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .filter(condition)
+                .count(&db)
+                .await
+                .map_err(Self::map_db_err)
+        })
+    }
+
+    /// 以 SeaORM `stream` 在只读连接上逐行读取，流结束前持续占用该连接；不受仓储查询超时约束，
+    /// 会话级 `statement_timeout` 仍然生效。
+    fn stream_all_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Stream<Item = Result<BizMetadata, DomainError>> + Send + '_ {
+        async move {
+            let condition = build_comparison_condition(&expr, &Self::column_value)?;
+            let rows = BizMetadataEntity::find()
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .filter(condition)
+                .order_by_asc(biz_metadata::Column::Id)
+                .stream(&self.read_db)
                 .await
                 .map_err(Self::map_db_err)?;
+            Ok(rows.map(|row| {
+                row.map_err(Self::map_db_err)
+                    .and_then(|model| BizMetadataMapper::map_to_domain(&model))
+            }))
+        }
+        .try_flatten_stream()
+    }
 
-            let total = paginator.num_items().await.map_err(Self::map_db_err)?;
+    fn count_biz_metadata_values(
+        &self,
+        field: &str,
+    ) -> impl Future<Output = Result<Vec<(String, u64)>, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        let field = field.to_string();
+        repo_future_with_timeout(self.query_timeout, async move {
+            ensure_facetable(&field)?;
+            let column = BIZ_METADATA_FIELD_MAP
+                .column(&field)
+                .expect("facet fields are registered in the field map");
+            let rows: Vec<(String, i64)> = BizMetadataEntity::find()
+                .select_only()
+                .column(column)
+                .column_as(column.count(), "count")
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .filter(column.is_not_null())
+                .group_by(column)
+                .order_by_asc(column)
+                .into_tuple()
+                .all(&db)
+                .await
+                .map_err(Self::map_db_err)?;
+            Ok(rows
+                .into_iter()
+                .map(|(value, count)| (value, count.unsigned_abs()))
+                .collect())
+        })
+    }
 
-            let items = models
-                .iter()
-                .map(BizMetadataMapper::map_to_domain)
-                .collect::<Result<Vec<_>, _>>()?;
+    /// 读主库：版本号用于随后的乐观锁更新，从库延迟只会徒增冲突。
+    fn find_version(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<Version>, DomainError>> + Send + '_ {
+        let db = self.db.clone();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let version: Option<i32> = BizMetadataEntity::find_by_id(id.value())
+                .select_only()
+                .column(biz_metadata::Column::Version)
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(BizMetadataEntity::alive())
+                .into_tuple()
+                .one(&db)
+                .await
+                .map_err(Self::map_db_err)?;
+            version.map(Version::new).transpose()
+        })
+    }
 
-            Ok(PageResult::builder(items, total)
-                .page_index(pagination.page_index)
-                .page_size(pagination.limit)
-                .build())
+    /// 条件与部分唯一索引 `ux_biz_metadata_tenant_code_alive` 一致：同租户、同编码且未软删除。
+    fn code_exists(
+        &self,
+        code: &str,
+    ) -> impl Future<Output = Result<bool, DomainError>> + Send + '_ {
+        let db = self.read_db.clone();
+        let code = code.to_string();
+        repo_future_with_timeout(self.query_timeout, async move {
+            let count = BizMetadataEntity::find()
+                .filter(biz_metadata::Column::TenantId.eq(DEFAULT_TENANT_ID))
+                .filter(biz_metadata::Column::Code.eq(code))
+                .filter(BizMetadataEntity::alive())
+                .count(&db)
+                .await
+                .map_err(Self::map_db_err)?;
+            Ok(count > 0)
         })
     }
 }
 
-impl BizMetadataRepository for BizMetadataRepositoryImpl {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::biz_metadata::{BizMetadataService, UpdateBizMetadataCommand};
+    use crate::domain::biz_metadata::value_object::{
+        BizMetadataName, DataClass, ObjectType, TenantId, ValueType,
+    };
+    use crate::domain::biz_metadata_alias::{BizMetadataAlias, BizMetadataAliasRepository};
+    use biz_metadata_migration::{Migrator, MigratorTrait};
+    use domain_core::expression::{NullsOrder, contains, eq, r#in};
+    use std::sync::Arc;
+
+    /// 仅在设置 `TEST_DATABASE_URL` 时连接 PostgreSQL 并执行迁移，否则返回 `None` 跳过测试。
+    async fn pg() -> Option<DatabaseConnection> {
+        let url = std::env::var("TEST_DATABASE_URL").ok()?;
+        let db = sea_orm::Database::connect(url).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        Some(db)
+    }
+
+    #[test]
+    fn filtering_and_ordering_share_the_field_map() {
+        for field in BIZ_METADATA_FIELD_MAP.fields() {
+            let (order_column, _) =
+                BizMetadataRepositoryImpl::resolve_order(&OrderBy::asc(field)).unwrap();
+            assert_eq!(
+                format!("{:?}", Some(order_column)),
+                format!("{:?}", BIZ_METADATA_FIELD_MAP.column(field))
+            );
+        }
+        let (filter_column, _) =
+            BizMetadataRepositoryImpl::column_value("code", &FilterValue::from("company"))
+                .unwrap()
+                .unwrap();
+        let (order_column, _) =
+            BizMetadataRepositoryImpl::resolve_order(&OrderBy::desc("code")).unwrap();
+        assert_eq!(format!("{filter_column:?}"), format!("{order_column:?}"));
+
+        // 对外可投影的字段必须都能用于过滤与排序。
+        for field in crate::interface::http::mapper::biz_metadata_mapper::BIZ_METADATA_FIELDS {
+            assert!(BIZ_METADATA_FIELD_MAP.contains(field), "{field}");
+        }
+    }
+
+    #[tokio::test]
+    async fn reads_route_to_the_read_replica() {
+        use sea_orm::{DatabaseBackend, MockDatabase};
+
+        let empty = || {
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([Vec::<biz_metadata::Model>::new()])
+                .into_connection()
+        };
+        let (primary, replica) = (empty(), empty());
+        let repo =
+            BizMetadataRepositoryImpl::new(primary.clone()).with_read_replica(replica.clone());
+        assert!(
+            repo.find_biz_metadata_by_id(BizMetadataId::new(1))
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(replica.into_transaction_log().len(), 1);
+        assert!(primary.clone().into_transaction_log().is_empty());
+
+        // 未设置副本时读取走主库。
+        let repo = BizMetadataRepositoryImpl::new(primary.clone());
+        repo.find_biz_metadata_by_id(BizMetadataId::new(1))
+            .await
+            .unwrap();
+        assert_eq!(primary.into_transaction_log().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn empty_in_matches_nothing_and_bad_values_are_rejected() {
+        use domain_core::expression::r#in;
+        use futures_util::TryStreamExt;
+        use sea_orm::{DatabaseBackend, MockDatabase};
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<biz_metadata::Model>::new()])
+            .into_connection();
+        let repo = BizMetadataRepositoryImpl::new(db.clone());
+        repo.stream_all_biz_metadata(Expression::cmp(r#in("id", Vec::<i64>::new())))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+        let sql = db.into_transaction_log()[0].statements()[0].sql.clone();
+        assert!(
+            sql.contains(r#""deleted_at" IS NULL AND (FALSE)"#),
+            "empty In must compile to FALSE: {sql}"
+        );
+
+        let err = repo
+            .count_biz_metadata(Expression::cmp(eq("parent_id", "root")))
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::QUERY_VALUE_INVALID);
+    }
+
+    #[tokio::test]
+    async fn reads_filter_soft_deleted_rows_via_the_declared_column() {
+        use sea_orm::{DatabaseBackend, MockDatabase};
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<biz_metadata::Model>::new()])
+            .into_connection();
+        BizMetadataRepositoryImpl::new(db.clone())
+            .find_biz_metadata_by_id(BizMetadataId::new(1))
+            .await
+            .unwrap();
+        let log = db.into_transaction_log();
+        assert!(
+            log[0].statements()[0]
+                .sql
+                .contains(r#""deleted_at" IS NULL"#)
+        );
+        assert_eq!(
+            format!("{:?}", BIZ_METADATA_FIELD_MAP.column("deleted_at")),
+            format!("{:?}", Some(BizMetadataEntity::DELETED_AT))
+        );
+    }
+
+    #[tokio::test]
+    async fn stream_all_matches_full_query_in_id_order() {
+        use futures_util::TryStreamExt;
+
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let prefix = format!("stream_{}", Utc::now().timestamp_micros());
+        for index in 0..3 {
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                format!("{prefix}_{index}"),
+                "stream",
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            repo.insert(node).await.unwrap();
+        }
+        let filter = || Expression::cmp(contains("code", prefix.as_str()));
+
+        let streamed: Vec<_> = repo
+            .stream_all_biz_metadata(filter())
+            .try_collect()
+            .await
+            .unwrap();
+        let queried = repo
+            .query(
+                filter(),
+                QueryOptions::new(Some(100), Some(0)).with_order_by(OrderBy::asc("id")),
+            )
+            .await
+            .unwrap()
+            .into_items();
+        assert_eq!(streamed.len(), 3);
+        assert_eq!(
+            streamed.iter().map(|item| item.id()).collect::<Vec<_>>(),
+            queried.iter().map(|item| item.id()).collect::<Vec<_>>()
+        );
+    }
+
+    #[tokio::test]
+    async fn serializable_conflict_surfaces_as_retryable_error() {
+        use crate::domain::biz_metadata::repository::is_retryable;
+        use sea_orm::IsolationLevel;
+
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db.clone()).with_transaction_isolation(
+            TransactionIsolation {
+                batch_update: Some(IsolationLevel::Serializable),
+                ..Default::default()
+            },
+        );
+        let suffix = Utc::now().timestamp_micros();
+        let insert = async |segment: &str| {
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                format!("serializable_{suffix}_{segment}"),
+                "serializable",
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            repo.insert(node).await.unwrap()
+        };
+        let mut first = insert("first").await;
+        let second = insert("second").await;
+        first
+            .rename(BizMetadataName::new("renamed").unwrap(), Utc::now())
+            .unwrap();
+
+        // 外部事务先改写并锁住 `second`；批量更新写完 `first` 后在 `second` 上等待，
+        // 外部事务提交后 `SERIALIZABLE` 事务无法在原快照上继续写入该行。
+        let blocker = begin_with_isolation(&db, None).await.unwrap();
+        blocker
+            .execute_unprepared(&format!(
+                "UPDATE biz_metadata SET description = 'concurrent' WHERE id = {}",
+                second.id().value()
+            ))
+            .await
+            .unwrap();
+        let waiting = Statement::from_string(
+            db.get_database_backend(),
+            "SELECT count(*) AS waiting FROM pg_stat_activity \
+             WHERE datname = current_database() AND wait_event_type = 'Lock' \
+             AND query ILIKE 'UPDATE%biz_metadata%'",
+        );
+        let (result, ()) = tokio::join!(
+            repo.update_biz_metadata_many(vec![first.clone(), second.clone()]),
+            async {
+                for _ in 0..100 {
+                    let row = db.query_one_raw(waiting.clone()).await.unwrap().unwrap();
+                    if row.try_get::<i64>("", "waiting").unwrap() > 0 {
+                        break;
+                    }
+                    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+                }
+                blocker.commit().await.unwrap();
+            }
+        );
+
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), error_code::PERSISTENCE_SERIALIZATION_FAILURE);
+        assert!(is_retryable(&err));
+        // 整批回滚，`first` 的改名没有落库。
+        let stored = repo.find_by_id(first.id()).await.unwrap().unwrap();
+        assert_eq!(stored.name().as_str(), "serializable");
+
+        // 重新读取后重放即可成功。
+        let current = repo.find_by_id(second.id()).await.unwrap().unwrap();
+        let updated = repo
+            .update_biz_metadata_many(vec![first, current])
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+    }
+
+    #[test]
+    fn sql_preview_uses_the_repository_field_map() {
+        let expr = Expression::and(vec![
+            Expression::cmp(eq("status", "active")),
+            Expression::cmp(r#in("object_type", vec!["entity", "feature"])),
+            Expression::cmp(eq("owner", "ops")),
+        ]);
+        assert_eq!(
+            expr.to_sql_preview(&BIZ_METADATA_FIELD_MAP),
+            "status = $1 AND object_type IN ($2,$3) AND <unknown>:owner = $4"
+        );
+    }
+
+    #[test]
+    fn unknown_field_is_rejected_for_filter_and_order_alike() {
+        let unknown_filter = Expression::and(vec![
+            Expression::cmp(domain_core::expression::eq("code", "company")),
+            Expression::cmp(domain_core::expression::eq("secret", "x")),
+        ]);
+        let filter_err = BIZ_METADATA_FIELD_MAP
+            .ensure_known(&unknown_filter, &[])
+            .unwrap_err();
+        let order_err = BIZ_METADATA_FIELD_MAP
+            .ensure_known(&Expression::True, &[OrderBy::asc("secret")])
+            .unwrap_err();
+        assert_eq!(filter_err.code(), error_code::QUERY_FIELD_UNKNOWN);
+        assert_eq!(filter_err.to_string(), order_err.to_string());
+        assert!(
+            BizMetadataRepositoryImpl::column_value("secret", &FilterValue::from("x"))
+                .unwrap()
+                .is_none()
+        );
+        assert!(BizMetadataRepositoryImpl::resolve_order(&OrderBy::asc("secret")).is_none());
+        assert!(
+            BIZ_METADATA_FIELD_MAP
+                .ensure_known(
+                    &Expression::cmp(domain_core::expression::eq("code", "company")),
+                    &[OrderBy::asc("name")]
+                )
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn concurrent_updates_conflict_cleanly() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let service = Arc::new(BizMetadataService::new(BizMetadataRepositoryImpl::new(
+            db.clone(),
+        )));
+        let code = format!("locked_update_{}", Utc::now().timestamp_micros());
+        let node = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code.as_str(),
+            code.as_str(),
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let created = service
+            .repository()
+            .insert_biz_metadata(node)
+            .await
+            .unwrap();
+
+        let update = |name: &str| {
+            let service = Arc::clone(&service);
+            let cmd = UpdateBizMetadataCommand {
+                id: created.id(),
+                version: created.version(),
+                name: Some(name.into()),
+                ..Default::default()
+            };
+            tokio::spawn(async move { service.update_biz_metadata(cmd).await })
+        };
+        let (first, second) = tokio::join!(update("first"), update("second"));
+        let results = [first.unwrap(), second.unwrap()];
+
+        assert_eq!(results.iter().filter(|r| r.is_ok()).count(), 1);
+        let conflict = results.iter().find_map(|r| r.as_ref().err()).unwrap();
+        assert_eq!(conflict.code(), error_code::BIZ_METADATA_VERSION_CONFLICT);
+        assert!(conflict.message().contains("expected 1, current 2"));
+
+        BizMetadataEntity::delete_by_id(created.id().value())
+            .exec(&db)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn name_ordering_honours_collation_hint() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let mut codes = Vec::new();
+        // 默认 C 排序按码点比较："张"(U+5F20) 在 "阿"(U+963F) 之前；拼音排序中 a 在 zhang 之前。
+        for (segment, name) in [("zhang", "张三"), ("a", "阿里")] {
+            let code = format!("collate_{suffix}_{segment}");
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code.as_str(),
+                name,
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            repo.insert_biz_metadata(node).await.unwrap();
+            codes.push(code);
+        }
+
+        let names = |order: OrderBy| {
+            let filter = Expression::cmp(domain_core::expression::r#in("code", codes.clone()));
+            let query =
+                repo.query_biz_metadata(filter, QueryOptions::default().with_order_by(order));
+            async move {
+                query
+                    .await
+                    .unwrap()
+                    .into_items()
+                    .into_iter()
+                    .map(|item| item.name().as_str().to_string())
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(names(OrderBy::asc("name")).await, ["张三", "阿里"]);
+        // 非 UTF8 库（如 SQL_ASCII）没有 ICU collation，此时应退回默认排序。
+        let icu_available = repo
+            .db
+            .query_one_raw(Statement::from_string(
+                repo.db.get_database_backend(),
+                "SELECT 'a' COLLATE \"zh-x-icu\"",
+            ))
+            .await
+            .is_ok();
+        let expected = if icu_available {
+            ["阿里", "张三"]
+        } else {
+            ["张三", "阿里"]
+        };
+        assert_eq!(
+            names(OrderBy::asc("name").with_collation("pinyin")).await,
+            expected
+        );
+        assert_eq!(
+            names(OrderBy::asc("name").with_collation("klingon")).await,
+            ["张三", "阿里"]
+        );
+    }
+
+    #[tokio::test]
+    async fn equal_names_keep_a_stable_order_across_pages() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let mut codes = Vec::new();
+        for idx in 0..2 {
+            let code = format!("tiebreak_{suffix}_{idx}");
+            repo.insert_biz_metadata(
+                BizMetadata::new_node(
+                    TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                    code.as_str(),
+                    "same name",
+                    ObjectType::Entity,
+                    Utc::now(),
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+            codes.push(code);
+        }
+
+        let page = |offset| {
+            let filter = Expression::cmp(domain_core::expression::r#in("code", codes.clone()));
+            let query = repo.query_biz_metadata(
+                filter,
+                QueryOptions::new(Some(1), Some(offset)).with_order_by(OrderBy::asc("name")),
+            );
+            async move { query.await.unwrap().into_items() }
+        };
+        let mut seen = Vec::new();
+        for _ in 0..2 {
+            let first = page(0).await;
+            let second = page(1).await;
+            assert_eq!(first.len() + second.len(), 2);
+            let ids = [first[0].id().value(), second[0].id().value()];
+            assert!(ids[0] < ids[1], "id 作为决胜键升序：{ids:?}");
+            seen.push(ids);
+        }
+        assert_eq!(seen[0], seen[1]);
+    }
+
+    #[tokio::test]
+    async fn constraint_violations_map_to_specific_codes() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let node = |code: String, parent: Option<i64>| {
+            let mut node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code.as_str(),
+                "dup",
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            node.set_parent_id(parent.map(BizMetadataId::new), Utc::now())
+                .unwrap();
+            node
+        };
+        let code = format!("dup_{suffix}");
+        let root = repo
+            .insert_biz_metadata(node(code.clone(), None))
+            .await
+            .unwrap();
+
+        let duplicate = repo
+            .insert_biz_metadata(node(code.clone(), None))
+            .await
+            .unwrap_err();
+        assert_eq!(duplicate.code(), error_code::BIZ_METADATA_CODE_CONFLICT);
+
+        // 默认部署保留租户内全局唯一索引：不同父节点下复用同一编码同样冲突。
+        let other_root = repo
+            .insert_biz_metadata(node(format!("dup_other_{suffix}"), None))
+            .await
+            .unwrap();
+        let leaf = format!("dup_leaf_{suffix}");
+        repo.insert_biz_metadata(node(leaf.clone(), Some(root.id().value())))
+            .await
+            .unwrap();
+        let across_parents = repo
+            .insert_biz_metadata(node(leaf, Some(other_root.id().value())))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            across_parents.code(),
+            error_code::BIZ_METADATA_CODE_CONFLICT
+        );
+
+        let dangling = repo
+            .insert_biz_metadata(node(format!("dangling_{suffix}"), Some(i64::MAX)))
+            .await
+            .unwrap_err();
+        assert_eq!(dangling.code(), error_code::PERSISTENCE_REFERENCE_MISSING);
+    }
+
+    #[tokio::test]
+    async fn nulls_order_is_applied_in_sql() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let root_code = format!("nulls_{suffix}_root");
+        let root = repo
+            .insert_biz_metadata(
+                BizMetadata::new_node(
+                    TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                    root_code.as_str(),
+                    "root",
+                    ObjectType::Entity,
+                    Utc::now(),
+                )
+                .unwrap(),
+            )
+            .await
+            .unwrap();
+        let child_code = format!("nulls_{suffix}_child");
+        let mut child = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            child_code.as_str(),
+            "child",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        child.set_parent_id(Some(root.id()), Utc::now()).unwrap();
+        repo.insert_biz_metadata(child).await.unwrap();
+
+        let codes = vec![root_code.clone(), child_code.clone()];
+        let ordered = |order: OrderBy| {
+            let filter = Expression::cmp(domain_core::expression::r#in("code", codes.clone()));
+            let query =
+                repo.query_biz_metadata(filter, QueryOptions::default().with_order_by(order));
+            async move {
+                query
+                    .await
+                    .unwrap()
+                    .into_items()
+                    .into_iter()
+                    .map(|item| item.code().as_str().to_string())
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(
+            ordered(OrderBy::asc("parent_id")).await,
+            [child_code.clone(), root_code.clone()]
+        );
+        assert_eq!(
+            ordered(OrderBy::asc("parent_id").with_nulls(NullsOrder::First)).await,
+            [root_code.clone(), child_code.clone()]
+        );
+        assert_eq!(
+            ordered(OrderBy::desc("parent_id").with_nulls(NullsOrder::Last)).await,
+            [child_code, root_code]
+        );
+    }
+
+    #[tokio::test]
+    async fn pre_assigned_id_is_preserved_through_insert() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let node = |code: String| {
+            BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code,
+                "node",
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap()
+        };
+
+        let mut assigned = node(format!("preassigned_{suffix}"));
+        assigned.assign_id(BizMetadataId::new(suffix)).unwrap();
+        let inserted = repo.insert_biz_metadata(assigned).await.unwrap();
+        assert_eq!(inserted.id().value(), suffix);
+
+        let auto = repo
+            .insert_biz_metadata(node(format!("autoincrement_{suffix}")))
+            .await
+            .unwrap();
+        assert!(auto.has_id());
+        assert_ne!(auto.id().value(), suffix);
+    }
+
+    #[tokio::test]
+    async fn value_counts_group_alive_rows_in_sql() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let before = repo.count_biz_metadata_values("object_type").await.unwrap();
+        let entities = |counts: &[(String, u64)]| {
+            counts
+                .iter()
+                .find(|(value, _)| value == "entity")
+                .map_or(0, |(_, count)| *count)
+        };
+        let code = format!("facet_{}", Utc::now().timestamp_micros());
+        let node = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code.as_str(),
+            code.as_str(),
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let inserted = repo.insert_biz_metadata(node).await.unwrap();
+        let after = repo.count_biz_metadata_values("object_type").await.unwrap();
+        assert_eq!(entities(&after), entities(&before) + 1);
+        assert!(after.windows(2).all(|pair| pair[0].0 < pair[1].0));
+
+        repo.soft_delete_biz_metadata_many(vec![inserted], Utc::now())
+            .await
+            .unwrap();
+        let deleted = repo.count_biz_metadata_values("object_type").await.unwrap();
+        assert_eq!(entities(&deleted), entities(&before));
+
+        let err = repo.count_biz_metadata_values("code").await.unwrap_err();
+        assert_eq!(err.code(), error_code::QUERY_FIELD_NOT_FACETABLE);
+    }
+
+    #[tokio::test]
+    async fn restore_only_matches_soft_deleted_rows() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let code = format!("restore_{}", Utc::now().timestamp_micros());
+        let node = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code.as_str(),
+            code.as_str(),
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let inserted = repo.insert_biz_metadata(node).await.unwrap();
+        let id = inserted.id();
+        assert!(
+            repo.find_deleted_biz_metadata_by_id(id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(repo.restore_biz_metadata(inserted.clone()).await.is_err());
+
+        repo.soft_delete_biz_metadata_many(vec![inserted], Utc::now())
+            .await
+            .unwrap();
+        let mut deleted = repo
+            .find_deleted_biz_metadata_by_id(id)
+            .await
+            .unwrap()
+            .unwrap();
+        deleted.restore(Utc::now()).unwrap();
+        let restored = repo.restore_biz_metadata(deleted).await.unwrap();
+        assert!(!restored.is_deleted());
+        assert_eq!(i32::from(restored.version()), 3);
+        assert!(repo.find_biz_metadata_by_id(id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn code_uniqueness_check_accepts_only_the_migrated_policy() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let global = repo.verify_code_uniqueness(CodeUniqueness::Global).await;
+        let per_parent = repo.verify_code_uniqueness(CodeUniqueness::PerParent).await;
+        // 迁移按运行时的配置二选一建索引，恰好一种取值与之匹配。
+        let mismatch = match (global, per_parent) {
+            (Ok(()), Err(err)) | (Err(err), Ok(())) => err,
+            other => panic!("expected exactly one matching policy, got {other:?}"),
+        };
+        assert_eq!(
+            mismatch.code(),
+            error_code::BIZ_METADATA_CODE_INDEX_MISMATCH
+        );
+    }
+
+    #[tokio::test]
+    async fn code_exists_matches_alive_unique_index() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let code = format!("code_exists_{}", Utc::now().timestamp_micros());
+        let node = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code.as_str(),
+            code.as_str(),
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let inserted = repo.insert_biz_metadata(node).await.unwrap();
+        assert!(repo.code_exists(&code).await.unwrap());
+
+        repo.soft_delete_biz_metadata_many(vec![inserted], Utc::now())
+            .await
+            .unwrap();
+        assert!(!repo.code_exists(&code).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn count_matches_query_total_for_the_same_filter() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let prefix = format!("count_{}", Utc::now().timestamp_micros());
+        for segment in ["a", "b", "c"] {
+            let code = format!("{prefix}_{segment}");
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code.as_str(),
+                code.as_str(),
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            let stored = repo.insert_biz_metadata(node).await.unwrap();
+            if segment == "c" {
+                let mut deleted = stored;
+                deleted.mark_deleted(Utc::now()).unwrap();
+                repo.update_biz_metadata(deleted).await.unwrap();
+            }
+        }
+
+        let filter = Expression::cmp(domain_core::expression::contains("code", prefix.as_str()));
+        let page = repo
+            .query_biz_metadata(filter.clone(), QueryOptions::default())
+            .await
+            .unwrap();
+        let count = repo.count_biz_metadata(filter).await.unwrap();
+        assert_eq!(count, domain_core::pagination::Page::total_count(&page));
+        assert_eq!(count, 2);
+    }
+
+    #[tokio::test]
+    async fn subtree_is_fetched_with_one_recursive_query() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let insert = async |segment: &str, object_type, parent: Option<BizMetadataId>| {
+            let code = format!("descendants_{suffix}_{segment}");
+            let tenant_id = TenantId::new(DEFAULT_TENANT_ID).unwrap();
+            let mut node = if object_type == ObjectType::Feature {
+                BizMetadata::new_feature(
+                    tenant_id,
+                    code.as_str(),
+                    code.as_str(),
+                    DataClass::Metric,
+                    ValueType::new("decimal").unwrap(),
+                    Utc::now(),
+                )
+            } else {
+                BizMetadata::new_node(
+                    tenant_id,
+                    code.as_str(),
+                    code.as_str(),
+                    object_type,
+                    Utc::now(),
+                )
+            }
+            .unwrap();
+            node.set_parent_id(parent, Utc::now()).unwrap();
+            repo.insert_biz_metadata(node).await.unwrap().id()
+        };
+        let company = insert("company", ObjectType::Entity, None).await;
+        let finance = insert("finance", ObjectType::Entity, Some(company)).await;
+        let headcount = insert("headcount", ObjectType::Feature, Some(company)).await;
+        let revenue = insert("revenue", ObjectType::Feature, Some(finance)).await;
+        let other = insert("other", ObjectType::Entity, None).await;
+        insert("other_size", ObjectType::Feature, Some(other)).await;
+
+        let service = BizMetadataService::new(repo);
+        let ids: Vec<_> = service
+            .descendant_features(company)
+            .await
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+        assert_eq!(ids, [headcount, revenue]);
+
+        // 三层子树由一次调用返回，根节点在首位，兄弟树不混入。
+        let subtree: Vec<_> = service
+            .repository()
+            .find_subtree(company)
+            .await
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+        assert_eq!(subtree, [company, finance, headcount, revenue]);
+        assert!(
+            service
+                .repository()
+                .find_subtree(BizMetadataId::new(-1))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        // 递归在层数上限处截断，深层节点不会被加载。
+        let bounded: Vec<_> = service
+            .repository()
+            .find_subtree_within_depth(company, 1)
+            .await
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+        assert_eq!(bounded, [company, finance, headcount]);
+        let service = service.with_max_depth(1);
+        let ids: Vec<_> = service
+            .descendant_features(company)
+            .await
+            .unwrap()
+            .iter()
+            .map(|node| node.id())
+            .collect();
+        assert_eq!(ids, [headcount]);
+    }
+
+    #[tokio::test]
+    async fn update_many_rolls_back_on_version_conflict() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let mut inserted = Vec::new();
+        for segment in ["a", "b"] {
+            let code = format!("update_many_{suffix}_{segment}");
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code.as_str(),
+                code.as_str(),
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            inserted.push(repo.insert_biz_metadata(node).await.unwrap());
+        }
+        let parent = inserted[0].id();
+
+        let mut moved = inserted[1].clone();
+        moved.set_parent_id(Some(parent), Utc::now()).unwrap();
+        let stale = repo.update_biz_metadata(moved.clone()).await.unwrap();
+        let mut first = inserted[0].clone();
+        first
+            .rename(BizMetadataName::new("renamed").unwrap(), Utc::now())
+            .unwrap();
+
+        // 第二条携带过期版本，整批回滚，第一条的改名不得落库。
+        let err = repo
+            .update_biz_metadata_many(vec![first.clone(), moved])
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_VERSION_CONFLICT);
+        let reloaded = repo.find_biz_metadata_by_id(parent).await.unwrap().unwrap();
+        assert_eq!(reloaded.name(), inserted[0].name());
+
+        let updated = repo
+            .update_biz_metadata_many(vec![first, stale])
+            .await
+            .unwrap();
+        assert_eq!(updated[0].name().as_str(), "renamed");
+        assert_eq!(updated[1].parent_id(), Some(parent));
+    }
+
+    #[tokio::test]
+    async fn replace_catalog_rolls_back_on_alias_failure() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db.clone());
+        let aliases = BizMetadataAliasRepositoryImpl::new(db);
+        let code = format!("replace_keep_{}", Utc::now().timestamp_micros());
+        let node = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code.as_str(),
+            code.as_str(),
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let kept = repo.insert_biz_metadata(node).await.unwrap();
+        let kept_alias = aliases
+            .insert_alias(BizMetadataAlias::new(kept.id(), code.as_str(), Utc::now()).unwrap())
+            .await
+            .unwrap();
+
+        // 同一条目携带两条相同别名，插入第二条时触发唯一索引，整个替换回滚。
+        let replacement = BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            format!("{code}_new").as_str(),
+            "new",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let duplicated = BizMetadataAlias::new(BizMetadataId::new(0), "重复", Utc::now()).unwrap();
+        let err = repo
+            .replace_catalog(
+                vec![CatalogReplacement {
+                    parent_code: None,
+                    metadata: replacement,
+                    aliases: vec![duplicated.clone(), duplicated],
+                }],
+                Utc::now(),
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::ALIAS_DUPLICATE);
+
+        let reloaded = repo.find_biz_metadata_by_id(kept.id()).await.unwrap();
+        assert_eq!(reloaded.map(|item| item.version()), Some(kept.version()));
+        let alias = aliases
+            .find_alias_by_id(kept_alias.id())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(alias.delete_at().is_none());
+    }
+
+    #[tokio::test]
+    async fn merge_rolls_back_reassigned_aliases_on_version_conflict() {
+        let Some(db) = pg().await else {
+            return;
+        };
+        let repo = BizMetadataRepositoryImpl::new(db.clone());
+        let aliases = BizMetadataAliasRepositoryImpl::new(db);
+        let suffix = Utc::now().timestamp_micros();
+        let mut inserted = Vec::new();
+        for segment in ["survivor", "loser"] {
+            let code = format!("merge_{suffix}_{segment}");
+            let node = BizMetadata::new_node(
+                TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+                code.as_str(),
+                code.as_str(),
+                ObjectType::Entity,
+                Utc::now(),
+            )
+            .unwrap();
+            inserted.push(repo.insert_biz_metadata(node).await.unwrap());
+        }
+        let (survivor, loser) = (inserted[0].id(), inserted[1].id());
+        let alias = aliases
+            .insert_alias(
+                BizMetadataAlias::new(loser, format!("合并_{suffix}").as_str(), Utc::now())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        // 先改挂别名，再以过期版本删除 `loser`，整笔事务回滚，别名仍挂在 `loser` 下。
+        let mut stale = inserted[1].clone();
+        repo.update_biz_metadata(stale.clone()).await.unwrap();
+        stale.mark_deleted(Utc::now()).unwrap();
+        let err = repo
+            .merge_biz_metadata(vec![stale], loser, survivor, 100, Utc::now())
+            .await
+            .unwrap_err();
+        assert_eq!(err.code(), error_code::BIZ_METADATA_VERSION_CONFLICT);
+
+        assert!(repo.find_biz_metadata_by_id(loser).await.unwrap().is_some());
+        let unchanged = aliases.find_alias_by_id(alias.id()).await.unwrap().unwrap();
+        assert_eq!(unchanged.metadata_id(), loser);
+    }
+}
@@ -0,0 +1,780 @@
+//! 按编码读穿透缓存的元数据仓储装饰器。
+//!
+//! - `find_biz_metadata_by_code` 优先读缓存，未命中时委托内部仓储并回填；编码有歧义时内部仓储返回错误，不回填
+//! - `insert` 前后按编码失效缓存：按父节点唯一时新行可能与已缓存的行同编码，使该编码变为有歧义
+//! - `update`/`delete` 前后均按 ID 与新旧编码失效缓存，编码变更时旧编码同样失效
+//! - `replace_catalog` 前后清空整个缓存
+//! - 失效会推进代次（generation），失效期间发起的回源结果不会写回缓存，避免回填旧值
+//! - 其余查询（含子树、子节点等树查询）直接转发内部仓储，保留其单次查询等专门实现
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{Expression, QueryOptions};
+use domain_core::repository::Repository;
+use futures_util::stream::Stream;
+
+use crate::domain::biz_metadata::BizMetadata;
+use crate::domain::biz_metadata::repository::{
+    BizMetadataRepository, CatalogReplacement, LockedCheck,
+};
+use crate::domain::biz_metadata::value_object::{BizMetadataId, Version};
+use crate::infrastructure::persistence::repository::future::{RepoFuture, repo_future};
+
+/// 默认缓存容量。
+pub const DEFAULT_CACHE_CAPACITY: usize = 1024;
+/// 默认缓存过期时间。
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 为内部仓储增加 code→聚合 的有界 TTL 缓存。
+///
+/// ```
+/// use biz_metadata::{BizMetadataRepository, CachingBizMetadataRepository, InMemoryBizMetadataRepository};
+/// use std::time::Duration;
+///
+/// let repo = CachingBizMetadataRepository::new(InMemoryBizMetadataRepository::new())
+///     .with_capacity(128)
+///     .with_ttl(Duration::from_secs(60));
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// let found = rt.block_on(repo.find_biz_metadata_by_code("company")).unwrap();
+/// assert!(found.is_none());
+/// ```
+#[derive(Debug)]
+pub struct CachingBizMetadataRepository<R> {
+    inner: R,
+    capacity: usize,
+    ttl: Duration,
+    state: Mutex<CacheState>,
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    generation: u64,
+    entries: HashMap<String, CacheEntry>,
+    codes_by_id: HashMap<i64, String>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    value: BizMetadata,
+    inserted_at: Instant,
+}
+
+impl<R> CachingBizMetadataRepository<R>
+where
+    R: BizMetadataRepository,
+{
+    /// 使用默认容量与过期时间包装内部仓储。
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            capacity: DEFAULT_CACHE_CAPACITY,
+            ttl: DEFAULT_CACHE_TTL,
+            state: Mutex::new(CacheState::default()),
+        }
+    }
+
+    /// 设置缓存容量上限，至少为 1。
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity.max(1);
+        self
+    }
+
+    /// 设置缓存条目的过期时间。
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// 返回被装饰的内部仓储。
+    pub fn inner(&self) -> &R {
+        &self.inner
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, CacheState>, DomainError> {
+        self.state.lock().map_err(|err| DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: err.to_string(),
+        })
+    }
+
+    /// 读取未过期的缓存；未命中时返回当前代次，供回源后判断能否回填。
+    fn lookup(&self, code: &str) -> Result<Result<BizMetadata, u64>, DomainError> {
+        let mut state = self.lock()?;
+        match state.entries.get(code) {
+            Some(entry) if entry.inserted_at.elapsed() < self.ttl => Ok(Ok(entry.value.clone())),
+            Some(entry) => {
+                let id = entry.value.id().value();
+                state.entries.remove(code);
+                state.codes_by_id.remove(&id);
+                Ok(Err(state.generation))
+            }
+            None => Ok(Err(state.generation)),
+        }
+    }
+
+    fn fill(&self, generation: u64, value: &BizMetadata) -> Result<(), DomainError> {
+        let mut state = self.lock()?;
+        if state.generation != generation {
+            return Ok(());
+        }
+        if state.entries.len() >= self.capacity {
+            let now = Instant::now();
+            state
+                .entries
+                .retain(|_, entry| now.duration_since(entry.inserted_at) < self.ttl);
+            if state.entries.len() >= self.capacity
+                && let Some(oldest) = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.inserted_at)
+                    .map(|(code, _)| code.clone())
+            {
+                state.entries.remove(&oldest);
+            }
+            let CacheState {
+                entries,
+                codes_by_id,
+                ..
+            } = &mut *state;
+            codes_by_id.retain(|_, code| entries.contains_key(code));
+        }
+        let code = value.code().as_str().to_string();
+        state.codes_by_id.insert(value.id().value(), code.clone());
+        state.entries.insert(
+            code,
+            CacheEntry {
+                value: value.clone(),
+                inserted_at: Instant::now(),
+            },
+        );
+        Ok(())
+    }
+
+    /// 失效指定 ID 当前缓存的编码以及额外给定的编码，并推进代次。
+    fn invalidate(&self, id: BizMetadataId, code: Option<&str>) -> Result<(), DomainError> {
+        let mut state = self.lock()?;
+        state.generation = state.generation.wrapping_add(1);
+        if let Some(cached) = state.codes_by_id.remove(&id.value()) {
+            state.entries.remove(&cached);
+        }
+        if let Some(code) = code {
+            state.entries.remove(code);
+        }
+        Ok(())
+    }
+
+    /// 清空全部缓存并推进代次。
+    fn invalidate_all(&self) -> Result<(), DomainError> {
+        let mut state = self.lock()?;
+        state.generation = state.generation.wrapping_add(1);
+        state.entries.clear();
+        state.codes_by_id.clear();
+        Ok(())
+    }
+}
+
+impl<R> Repository<BizMetadata> for CachingBizMetadataRepository<R>
+where
+    R: BizMetadataRepository,
+{
+    type InsertFuture<'a>
+        = RepoFuture<'a, BizMetadata>
+    where
+        Self: 'a;
+    type UpdateFuture<'a>
+        = RepoFuture<'a, BizMetadata>
+    where
+        Self: 'a;
+    type DeleteFuture<'a>
+        = RepoFuture<'a, ()>
+    where
+        Self: 'a;
+    type FindByIdFuture<'a>
+        = R::FindByIdFuture<'a>
+    where
+        Self: 'a;
+    type QueryFuture<'a>
+        = R::QueryFuture<'a>
+    where
+        Self: 'a;
+
+    fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
+        repo_future(async move {
+            let id = aggregate.id();
+            let code = aggregate.code().as_str().to_string();
+            self.invalidate(id, Some(&code))?;
+            let result = self.inner.insert(aggregate).await;
+            self.invalidate(id, Some(&code))?;
+            result
+        })
+    }
+
+    fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+        repo_future(async move {
+            let id = aggregate.id();
+            let code = aggregate.code().as_str().to_string();
+            self.invalidate(id, Some(&code))?;
+            let result = self.inner.update(aggregate).await;
+            self.invalidate(id, Some(&code))?;
+            result
+        })
+    }
+
+    fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+        repo_future(async move {
+            self.invalidate(id, None)?;
+            let result = self.inner.delete(id).await;
+            self.invalidate(id, None)?;
+            result
+        })
+    }
+
+    fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+        self.inner.find_by_id(id)
+    }
+
+    fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+        self.inner.query(expr, options)
+    }
+}
+
+impl<R> BizMetadataRepository for CachingBizMetadataRepository<R>
+where
+    R: BizMetadataRepository,
+{
+    fn find_biz_metadata_by_code(
+        &self,
+        code: &str,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        let code = code.to_string();
+        async move {
+            let generation = match self.lookup(&code)? {
+                Ok(cached) => return Ok(Some(cached)),
+                Err(generation) => generation,
+            };
+            let found = self.inner.find_biz_metadata_by_code(&code).await?;
+            if let Some(value) = &found {
+                self.fill(generation, value)?;
+            }
+            Ok(found)
+        }
+    }
+
+    fn count_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        self.inner.count_biz_metadata(expr)
+    }
+
+    fn stream_all_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Stream<Item = Result<BizMetadata, DomainError>> + Send + '_ {
+        self.inner.stream_all_biz_metadata(expr)
+    }
+
+    fn count_biz_metadata_values(
+        &self,
+        field: &str,
+    ) -> impl Future<Output = Result<Vec<(String, u64)>, DomainError>> + Send + '_ {
+        self.inner.count_biz_metadata_values(field)
+    }
+
+    /// 可用性检查直接读底层仓储，不经缓存，保证与插入时的唯一约束判断一致。
+    /// 不走缓存：版本号用于乐观锁更新，必须读取最新值。
+    fn find_version(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<Version>, DomainError>> + Send + '_ {
+        self.inner.find_version(id)
+    }
+
+    fn code_exists(
+        &self,
+        code: &str,
+    ) -> impl Future<Output = Result<bool, DomainError>> + Send + '_ {
+        self.inner.code_exists(code)
+    }
+
+    async fn update_biz_metadata_locked_checked<'a, F>(
+        &'a self,
+        id: BizMetadataId,
+        mutate: F,
+        check: LockedCheck<'a>,
+    ) -> Result<BizMetadata, DomainError>
+    where
+        F: FnOnce(&mut BizMetadata) -> Result<bool, DomainError> + Send + 'static,
+    {
+        self.invalidate(id, None)?;
+        let result = self
+            .inner
+            .update_biz_metadata_locked_checked(id, mutate, check)
+            .await;
+        let code = result
+            .as_ref()
+            .ok()
+            .map(|updated| updated.code().as_str().to_string());
+        self.invalidate(id, code.as_deref())?;
+        result
+    }
+
+    async fn soft_delete_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let keys: Vec<_> = items
+            .iter()
+            .map(|item| (item.id(), item.code().as_str().to_string()))
+            .collect();
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        let result = self
+            .inner
+            .soft_delete_biz_metadata_many(items, deleted_at)
+            .await;
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        result
+    }
+
+    async fn update_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let keys: Vec<_> = items
+            .iter()
+            .map(|item| (item.id(), item.code().as_str().to_string()))
+            .collect();
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        let result = self.inner.update_biz_metadata_many(items).await;
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        result
+    }
+
+    async fn delete_biz_metadata_with_aliases(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted: BizMetadataId,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let keys: Vec<_> = items
+            .iter()
+            .map(|item| (item.id(), item.code().as_str().to_string()))
+            .collect();
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        let result = self
+            .inner
+            .delete_biz_metadata_with_aliases(items, deleted)
+            .await;
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        result
+    }
+
+    async fn merge_biz_metadata(
+        &self,
+        items: Vec<BizMetadata>,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+        max_live: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let keys: Vec<_> = items
+            .iter()
+            .map(|item| (item.id(), item.code().as_str().to_string()))
+            .collect();
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        let result = self
+            .inner
+            .merge_biz_metadata(items, loser, survivor, max_live, now)
+            .await;
+        for (id, code) in &keys {
+            self.invalidate(*id, Some(code))?;
+        }
+        result
+    }
+
+    fn find_deleted_biz_metadata_by_id(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        self.inner.find_deleted_biz_metadata_by_id(id)
+    }
+
+    async fn restore_biz_metadata(
+        &self,
+        biz_metadata: BizMetadata,
+    ) -> Result<BizMetadata, DomainError> {
+        let id = biz_metadata.id();
+        let code = biz_metadata.code().as_str().to_string();
+        self.invalidate(id, Some(&code))?;
+        let result = self.inner.restore_biz_metadata(biz_metadata).await;
+        self.invalidate(id, Some(&code))?;
+        result
+    }
+
+    async fn restore_biz_metadata_with_aliases(
+        &self,
+        biz_metadata: BizMetadata,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<BizMetadata, DomainError> {
+        let id = biz_metadata.id();
+        let code = biz_metadata.code().as_str().to_string();
+        self.invalidate(id, Some(&code))?;
+        let result = self
+            .inner
+            .restore_biz_metadata_with_aliases(biz_metadata, deleted_at)
+            .await;
+        self.invalidate(id, Some(&code))?;
+        result
+    }
+
+    async fn replace_catalog(
+        &self,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        self.invalidate_all()?;
+        let result = self.inner.replace_catalog(entries, now).await;
+        self.invalidate_all()?;
+        result
+    }
+
+    fn purge_biz_metadata_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        // 缓存只保存存活记录，物理删除已软删的行无需失效。
+        self.inner.purge_biz_metadata_deleted_before(cutoff, limit)
+    }
+
+    fn query_biz_metadata_changed_since(
+        &self,
+        since: DateTime<Utc>,
+        include_deleted: bool,
+        options: QueryOptions,
+    ) -> Self::QueryFuture<'_> {
+        self.inner
+            .query_biz_metadata_changed_since(since, include_deleted, options)
+    }
+
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        self.inner.query_fields()
+    }
+
+    fn find_biz_metadata_children(
+        &self,
+        parent_id: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        self.inner.find_biz_metadata_children(parent_id)
+    }
+
+    fn find_subtree(
+        &self,
+        root: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        self.inner.find_subtree(root)
+    }
+
+    fn find_subtree_within_depth(
+        &self,
+        root: BizMetadataId,
+        max_depth: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        self.inner.find_subtree_within_depth(root, max_depth)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::CodeUniqueness;
+    use crate::domain::biz_metadata::value_object::{BizMetadataName, ObjectType, TenantId};
+    use crate::domain::error_code;
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository;
+
+    type Repo = CachingBizMetadataRepository<InMemoryBizMetadataRepository>;
+
+    async fn seed(repo: &Repo, code: &str) -> BizMetadata {
+        let node = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            code,
+            code,
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        repo.insert(node).await.unwrap()
+    }
+
+    /// 树查询一律返回空列表、可查询字段固定为 `code`，用于区分装饰器是转发给内部仓储还是走了默认实现。
+    struct EmptyTreeRepository(InMemoryBizMetadataRepository);
+
+    impl Repository<BizMetadata> for EmptyTreeRepository {
+        type InsertFuture<'a> =
+            <InMemoryBizMetadataRepository as Repository<BizMetadata>>::InsertFuture<'a>;
+        type UpdateFuture<'a> =
+            <InMemoryBizMetadataRepository as Repository<BizMetadata>>::UpdateFuture<'a>;
+        type DeleteFuture<'a> =
+            <InMemoryBizMetadataRepository as Repository<BizMetadata>>::DeleteFuture<'a>;
+        type FindByIdFuture<'a> =
+            <InMemoryBizMetadataRepository as Repository<BizMetadata>>::FindByIdFuture<'a>;
+        type QueryFuture<'a> =
+            <InMemoryBizMetadataRepository as Repository<BizMetadata>>::QueryFuture<'a>;
+
+        fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
+            self.0.insert(aggregate)
+        }
+
+        fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+            self.0.update(aggregate)
+        }
+
+        fn delete(&self, id: BizMetadataId) -> Self::DeleteFuture<'_> {
+            self.0.delete(id)
+        }
+
+        fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+            self.0.find_by_id(id)
+        }
+
+        fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+            self.0.query(expr, options)
+        }
+    }
+
+    impl BizMetadataRepository for EmptyTreeRepository {
+        fn query_fields(&self) -> Option<Vec<&'static str>> {
+            Some(vec!["code"])
+        }
+
+        fn find_biz_metadata_children(
+            &self,
+            _parent_id: BizMetadataId,
+        ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+            std::future::ready(Ok(Vec::new()))
+        }
+
+        fn find_subtree(
+            &self,
+            _root: BizMetadataId,
+        ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+            std::future::ready(Ok(Vec::new()))
+        }
+
+        fn find_subtree_within_depth(
+            &self,
+            _root: BizMetadataId,
+            _max_depth: usize,
+        ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+            std::future::ready(Ok(Vec::new()))
+        }
+    }
+
+    #[tokio::test]
+    async fn tree_queries_are_forwarded_to_inner() {
+        let inner = InMemoryBizMetadataRepository::new();
+        let node = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "company",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let root = inner.insert(node).await.unwrap().id();
+        let mut child = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "finance",
+            "finance",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        child.set_parent_id(Some(root), Utc::now()).unwrap();
+        inner.insert(child).await.unwrap();
+
+        let repo = CachingBizMetadataRepository::new(EmptyTreeRepository(inner));
+        assert!(repo.find_subtree(root).await.unwrap().is_empty());
+        assert!(
+            repo.find_subtree_within_depth(root, 1)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            repo.find_biz_metadata_children(root)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn query_fields_are_forwarded_to_inner() {
+        let repo = CachingBizMetadataRepository::new(EmptyTreeRepository(
+            InMemoryBizMetadataRepository::new(),
+        ));
+        assert_eq!(repo.query_fields(), Some(vec!["code"]));
+    }
+
+    #[tokio::test]
+    async fn second_lookup_hits_cache() {
+        let repo = CachingBizMetadataRepository::new(InMemoryBizMetadataRepository::new());
+        let mut stored = seed(&repo, "company").await;
+        assert!(
+            repo.find_biz_metadata_by_code("company")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // 绕过装饰器直接改写内部仓储，缓存命中时仍返回旧值。
+        stored
+            .rename(BizMetadataName::new("Company").unwrap(), Utc::now())
+            .unwrap();
+        repo.inner().update(stored).await.unwrap();
+
+        let cached = repo
+            .find_biz_metadata_by_code("company")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(cached.name().as_str(), "company");
+    }
+
+    #[tokio::test]
+    async fn update_invalidates_entry() {
+        let repo = CachingBizMetadataRepository::new(InMemoryBizMetadataRepository::new());
+        let mut stored = seed(&repo, "company").await;
+        assert!(
+            repo.find_biz_metadata_by_code("company")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        stored
+            .rename(BizMetadataName::new("Company").unwrap(), Utc::now())
+            .unwrap();
+        repo.update(stored).await.unwrap();
+
+        let fresh = repo
+            .find_biz_metadata_by_code("company")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fresh.name().as_str(), "Company");
+    }
+
+    #[tokio::test]
+    async fn code_change_invalidates_old_code() {
+        let repo = CachingBizMetadataRepository::new(InMemoryBizMetadataRepository::new());
+        let stored = seed(&repo, "company").await;
+        assert!(
+            repo.find_biz_metadata_by_code("company")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        let mut snapshot = stored.to_snapshot();
+        snapshot.code = "enterprise".into();
+        repo.update(BizMetadata::from_snapshot(snapshot).unwrap())
+            .await
+            .unwrap();
+
+        assert!(
+            repo.find_biz_metadata_by_code("company")
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            repo.find_biz_metadata_by_code("enterprise")
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn duplicate_code_insert_invalidates_and_is_never_cached() {
+        let repo = CachingBizMetadataRepository::new(
+            InMemoryBizMetadataRepository::new().with_code_uniqueness(CodeUniqueness::PerParent),
+        );
+        let company = seed(&repo, "company").await;
+        let corp = seed(&repo, "corp").await;
+        let mut first = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "shared.revenue",
+            "revenue",
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap();
+        let mut second = first.clone();
+        first.set_parent_id(Some(company.id()), Utc::now()).unwrap();
+        second.set_parent_id(Some(corp.id()), Utc::now()).unwrap();
+        repo.insert(first).await.unwrap();
+        assert!(
+            repo.find_biz_metadata_by_code("shared.revenue")
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        // 另一父节点下插入同编码后，缓存不得继续返回先前的单条结果。
+        repo.insert(second).await.unwrap();
+        for _ in 0..2 {
+            let err = repo
+                .find_biz_metadata_by_code("shared.revenue")
+                .await
+                .unwrap_err();
+            assert_eq!(err.code(), error_code::BIZ_METADATA_CODE_AMBIGUOUS);
+        }
+        assert!(repo.lock().unwrap().entries.is_empty());
+        assert!(repo.code_exists("shared.revenue").await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn expired_and_overflowing_entries_are_evicted() {
+        let repo = CachingBizMetadataRepository::new(InMemoryBizMetadataRepository::new())
+            .with_capacity(1)
+            .with_ttl(Duration::ZERO);
+        let mut stored = seed(&repo, "company").await;
+        seed(&repo, "person").await;
+        repo.find_biz_metadata_by_code("company").await.unwrap();
+        repo.find_biz_metadata_by_code("person").await.unwrap();
+        assert_eq!(repo.lock().unwrap().entries.len(), 1);
+
+        stored
+            .rename(BizMetadataName::new("Company").unwrap(), Utc::now())
+            .unwrap();
+        repo.inner().update(stored).await.unwrap();
+        let fresh = repo
+            .find_biz_metadata_by_code("company")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(fresh.name().as_str(), "Company");
+    }
+}
@@ -1,13 +1,118 @@
 use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
 
 use domain_core::domain_error::DomainError;
+use domain_core::expression::{Expression, QueryOptions};
+
+use crate::domain::error_code;
 
 pub type RepoFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, DomainError>> + Send + 'a>>;
 
+/// 仓储数据库调用的默认超时时间。
+pub const DEFAULT_QUERY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 慢查询日志的默认阈值。
+pub const DEFAULT_SLOW_QUERY_THRESHOLD: Duration = Duration::from_secs(1);
+
+/// 慢查询阈值毫秒数的环境变量名，未设置时使用 [`DEFAULT_SLOW_QUERY_THRESHOLD`]。
+pub const SLOW_QUERY_THRESHOLD_MS_ENV: &str = "BIZ_METADATA_SLOW_QUERY_THRESHOLD_MS";
+
+/// 从环境变量读取慢查询阈值，未设置时返回 [`DEFAULT_SLOW_QUERY_THRESHOLD`]。
+pub fn slow_query_threshold_from_env() -> Result<Duration, String> {
+    match std::env::var(SLOW_QUERY_THRESHOLD_MS_ENV) {
+        Ok(raw) => raw
+            .trim()
+            .parse::<u64>()
+            .map(Duration::from_millis)
+            .map_err(|_| format!("{SLOW_QUERY_THRESHOLD_MS_ENV} 必须是非负整数毫秒数")),
+        Err(_) => Ok(DEFAULT_SLOW_QUERY_THRESHOLD),
+    }
+}
+
+/// 查询耗时超过 `threshold` 时以 warn 级别记录筛选表达式、查询选项与耗时，返回是否判定为慢查询。
+///
+/// 表达式按 [`Expression`] 的 `Display` 渲染，字符串取值会被截断。
+pub fn warn_if_slow(
+    repository: &'static str,
+    threshold: Duration,
+    elapsed: Duration,
+    expr: &Expression,
+    options: &QueryOptions,
+) -> bool {
+    if elapsed <= threshold {
+        return false;
+    }
+    tracing::warn!(
+        target: "biz_metadata::slow_query",
+        repository,
+        elapsed_ms = elapsed.as_millis() as u64,
+        expression = %expr,
+        options = ?options,
+        "slow query"
+    );
+    true
+}
+
 pub fn repo_future<'a, T, Fut>(future: Fut) -> RepoFuture<'a, T>
 where
     Fut: Future<Output = Result<T, DomainError>> + Send + 'a,
 {
     Box::pin(future)
 }
+
+/// 与 [`repo_future`] 相同，但在超过 `timeout` 时返回 `query timed out` 的持久化错误。
+pub fn repo_future_with_timeout<'a, T, Fut>(timeout: Duration, future: Fut) -> RepoFuture<'a, T>
+where
+    Fut: Future<Output = Result<T, DomainError>> + Send + 'a,
+{
+    Box::pin(async move {
+        tokio::time::timeout(timeout, future)
+            .await
+            .unwrap_or_else(|_| {
+                Err(DomainError::Persistence {
+                    code: error_code::PERSISTENCE_TIMEOUT,
+                    message: "query timed out".into(),
+                })
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn times_out_blocked_future() {
+        let blocked = std::future::pending::<Result<(), DomainError>>();
+        let err = repo_future_with_timeout(Duration::from_millis(10), blocked)
+            .await
+            .unwrap_err();
+        assert!(
+            matches!(err, DomainError::Persistence { code, .. } if code == error_code::PERSISTENCE_TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn only_queries_over_threshold_are_slow() {
+        let expr = Expression::True;
+        let options = QueryOptions::default();
+        let threshold = Duration::from_millis(100);
+        assert!(!warn_if_slow("test", threshold, threshold, &expr, &options));
+        assert!(warn_if_slow(
+            "test",
+            threshold,
+            Duration::from_millis(101),
+            &expr,
+            &options
+        ));
+    }
+
+    #[tokio::test]
+    async fn passes_through_completed_future() {
+        let value = repo_future_with_timeout(DEFAULT_QUERY_TIMEOUT, async { Ok(7) })
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+    }
+}
@@ -0,0 +1,445 @@
+//! 基于内存的别名仓储实现，供服务层测试在无数据库环境下使用。
+//!
+//! 行为对齐 [`BizMetadataAliasRepositoryImpl`](super::biz_metadata_alias_repository_impl::BizMetadataAliasRepositoryImpl)：
+//! - `insert` 分配自增 ID，`update` 覆盖整行，`delete` 为物理删除
+//! - `query` 不过滤软删除记录，由调用方按需判断
+//! - `swap_primary_alias` 在同一把锁内完成切换并记录历史
+//! - `find_or_insert_alias` 在同一把锁内查找并插入，模拟存活别名唯一索引
+//! - `insert_alias_many`/`find_or_insert_alias` 在同一把锁内校验存活别名上限后再插入
+//! - `soft_delete_by_metadata_id`/`restore_by_metadata_id` 在同一把锁内批量改写，全有或全无；
+//!   恢复时跳过与存活别名 (`alias`, `language`) 重复者，对应 `ux_biz_metadata_alias_alive`
+//! - 克隆得到的句柄共享同一份数据，[`InMemoryBizMetadataRepository::with_aliases`](super::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository::with_aliases)
+//!   据此在模拟事务内联动写入别名表
+
+use std::collections::{HashMap, HashSet};
+use std::future::{Future, Ready, ready};
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{Expression, FilterValue, QueryOptions, evaluate};
+use domain_core::pagination::{DEFAULT_PAGE_SIZE, PageResult};
+use domain_core::repository::Repository;
+
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+use crate::domain::biz_metadata_alias::history::AliasPrimaryChange;
+use crate::domain::biz_metadata_alias::repository::{
+    BizMetadataAliasRepository, alias_limit_exceeded, count_by_metadata, plan_alias_reassignment,
+};
+use crate::domain::biz_metadata_alias::value_object::BizMetadataAliasId;
+use crate::domain::error_code;
+use crate::infrastructure::persistence::query::PaginationParams;
+use crate::infrastructure::persistence::repository::biz_metadata_alias_repository_impl::BIZ_METADATA_ALIAS_FIELD_MAP;
+
+/// 以 `Mutex<HashMap>` 保存别名聚合的仓储。
+///
+/// ```
+/// use biz_metadata::{BizMetadataAlias, BizMetadataId, InMemoryBizMetadataAliasRepository};
+/// use domain_core::repository::Repository;
+///
+/// let repo = InMemoryBizMetadataAliasRepository::new();
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// let stored = rt
+///     .block_on(repo.insert(BizMetadataAlias::new(BizMetadataId::new(1), "营收", chrono::Utc::now()).unwrap()))
+///     .unwrap();
+/// assert_eq!(i64::from(stored.id()), 1);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct InMemoryBizMetadataAliasRepository {
+    state: Arc<Mutex<State>>,
+}
+
+/// 别名表的内存状态，跨表写入时由元数据仓储在副本上暂存变更。
+#[derive(Debug, Default, Clone)]
+pub(super) struct State {
+    next_id: i64,
+    rows: HashMap<i64, BizMetadataAlias>,
+    primary_history: Vec<AliasPrimaryChange>,
+}
+
+impl InMemoryBizMetadataAliasRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 已记录的首选别名切换历史，按写入顺序排列。
+    pub fn primary_history(&self) -> Vec<AliasPrimaryChange> {
+        self.lock()
+            .map(|state| state.primary_history.clone())
+            .unwrap_or_default()
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, State>, DomainError> {
+        self.state.lock().map_err(|err| DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: err.to_string(),
+        })
+    }
+
+    /// 在状态副本上执行 `apply`，成功后整体替换当前状态，失败时不留下任何写入。
+    pub(super) fn transact<T>(
+        &self,
+        apply: impl FnOnce(&mut State) -> Result<T, DomainError>,
+    ) -> Result<T, DomainError> {
+        let mut state = self.lock()?;
+        let mut staged = state.clone();
+        let result = apply(&mut staged)?;
+        *state = staged;
+        Ok(result)
+    }
+
+    fn field_value(item: &BizMetadataAlias, field: &str) -> Option<FilterValue> {
+        match field {
+            "id" => Some(FilterValue::I64(i64::from(item.id()))),
+            "metadata_id" => Some(FilterValue::I64(item.metadata_id().value())),
+            "alias" => Some(FilterValue::from(item.alias().as_str())),
+            "source" => Some(FilterValue::from(item.source().as_str())),
+            "weight" => Some(FilterValue::I64(item.weight().value().into())),
+            "is_primary" => Some(FilterValue::Bool(item.is_primary())),
+            "language" => Some(FilterValue::from(item.language().as_str())),
+            "created_at" => Some(item.created_at().into()),
+            "updated_at" => Some(item.updated_at().into()),
+            "deleted_at" => item.delete_at().map(FilterValue::from),
+            _ => None,
+        }
+    }
+
+    fn do_insert(&self, aggregate: BizMetadataAlias) -> Result<BizMetadataAlias, DomainError> {
+        self.lock()?.insert(aggregate)
+    }
+
+    fn do_update(&self, aggregate: BizMetadataAlias) -> Result<BizMetadataAlias, DomainError> {
+        let mut state = self.lock()?;
+        let id = i64::from(aggregate.id());
+        if !state.rows.contains_key(&id) {
+            return Err(DomainError::Persistence {
+                code: error_code::ALIAS_NOT_FOUND,
+                message: format!("biz_metadata_alias {id} not found"),
+            });
+        }
+        state.rows.insert(id, aggregate.clone());
+        Ok(aggregate)
+    }
+
+    fn do_query(
+        &self,
+        expr: &Expression,
+        options: &QueryOptions,
+    ) -> Result<PageResult<BizMetadataAlias>, DomainError> {
+        let state = self.lock()?;
+        let pagination =
+            PaginationParams::compute(options.limit, options.offset, DEFAULT_PAGE_SIZE);
+
+        let mut matched: Vec<BizMetadataAlias> = state
+            .rows
+            .values()
+            .filter(|item| evaluate(expr, |field| Self::field_value(item, field)))
+            .cloned()
+            .collect();
+        matched.sort_by_key(|item| i64::from(item.id()));
+        for order in options.order_bys.iter().rev() {
+            matched.sort_by(|a, b| {
+                order.compare(
+                    Self::field_value(a, &order.field),
+                    Self::field_value(b, &order.field),
+                )
+            });
+        }
+
+        let total = matched.len() as u64;
+        let start = pagination.page_index.saturating_mul(pagination.limit);
+        let items = matched
+            .into_iter()
+            .skip(usize::try_from(start).unwrap_or(usize::MAX))
+            .take(usize::try_from(pagination.limit).unwrap_or(usize::MAX))
+            .collect();
+
+        Ok(PageResult::builder(items, total)
+            .page_index(pagination.page_index)
+            .page_size(pagination.limit)
+            .build())
+    }
+}
+
+impl State {
+    /// 分配自增 ID 后插入别名。
+    pub(super) fn insert(
+        &mut self,
+        aggregate: BizMetadataAlias,
+    ) -> Result<BizMetadataAlias, DomainError> {
+        self.next_id += 1;
+        let id = self.next_id;
+        let mut snapshot = aggregate.to_snapshot();
+        snapshot.id = BizMetadataAliasId::new(id);
+        let stored = BizMetadataAlias::from_snapshot(snapshot)?;
+        self.rows.insert(id, stored.clone());
+        Ok(stored)
+    }
+
+    /// 统计 `metadata_id` 下的存活别名数。
+    pub(super) fn count_live(&self, metadata_id: BizMetadataId) -> u64 {
+        self.rows
+            .values()
+            .filter(|row| row.delete_at().is_none() && row.metadata_id() == metadata_id)
+            .count() as u64
+    }
+
+    /// 物理删除属于 `metadata_ids` 的全部别名。
+    pub(super) fn purge_of(&mut self, metadata_ids: &[BizMetadataId]) {
+        self.rows
+            .retain(|_, row| !metadata_ids.contains(&row.metadata_id()));
+    }
+
+    /// 软删除 `metadata_id` 下的全部存活别名，返回受影响行数。
+    pub(super) fn soft_delete_of(
+        &mut self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let mut affected = 0;
+        for row in self.rows.values_mut() {
+            if row.metadata_id() == metadata_id && row.delete_at().is_none() {
+                row.mark_deleted(deleted_at)?;
+                affected += 1;
+            }
+        }
+        Ok(affected)
+    }
+
+    /// 按 [`plan_alias_reassignment`] 将 `from` 的存活别名改挂到 `to`，返回被改挂的别名。
+    pub(super) fn reassign(
+        &mut self,
+        from: BizMetadataId,
+        to: BizMetadataId,
+        now: DateTime<Utc>,
+        max_live: usize,
+    ) -> Result<Vec<BizMetadataAlias>, DomainError> {
+        if from == to {
+            return Ok(Vec::new());
+        }
+        let live_of = |metadata_id: BizMetadataId| {
+            let mut live: Vec<BizMetadataAlias> = self
+                .rows
+                .values()
+                .filter(|row| row.metadata_id() == metadata_id && row.delete_at().is_none())
+                .cloned()
+                .collect();
+            live.sort_by_key(|row| i64::from(row.id()));
+            live
+        };
+        let moving = live_of(from);
+        let survivors = live_of(to);
+        let (changes, moved_ids) = plan_alias_reassignment(moving, &survivors, to, now, max_live)?;
+        let mut moved = Vec::with_capacity(moved_ids.len());
+        for change in changes {
+            if moved_ids.contains(&change.id()) {
+                moved.push(change.clone());
+            }
+            self.rows.insert(i64::from(change.id()), change);
+        }
+        Ok(moved)
+    }
+
+    /// 恢复 `metadata_id` 下删除时间不早于 `deleted_at` 的别名，返回受影响行数。
+    ///
+    /// 与存活别名或同批先恢复的别名 (`alias`, `language`) 重复者保持删除，按 ID 升序优先恢复。
+    pub(super) fn restore_of(
+        &mut self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+        restored_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let key = |alias: &BizMetadataAlias| {
+            (
+                alias.alias().as_str().to_string(),
+                alias.language().as_str().to_string(),
+            )
+        };
+        let mut taken: HashSet<_> = self
+            .rows
+            .values()
+            .filter(|row| row.metadata_id() == metadata_id && row.delete_at().is_none())
+            .map(key)
+            .collect();
+        let mut cascaded: Vec<i64> = self
+            .rows
+            .values()
+            .filter(|row| {
+                row.metadata_id() == metadata_id
+                    && row.delete_at().is_some_and(|at| at >= deleted_at)
+            })
+            .map(|row| i64::from(row.id()))
+            .collect();
+        cascaded.sort_unstable();
+        let mut affected = 0;
+        for id in cascaded {
+            let Some(row) = self.rows.get_mut(&id) else {
+                continue;
+            };
+            if taken.insert(key(row)) {
+                row.restore(restored_at)?;
+                affected += 1;
+            }
+        }
+        Ok(affected)
+    }
+}
+
+impl Repository<BizMetadataAlias> for InMemoryBizMetadataAliasRepository {
+    type InsertFuture<'a> = Ready<Result<BizMetadataAlias, DomainError>>;
+    type UpdateFuture<'a> = Ready<Result<BizMetadataAlias, DomainError>>;
+    type DeleteFuture<'a> = Ready<Result<(), DomainError>>;
+    type FindByIdFuture<'a> = Ready<Result<Option<BizMetadataAlias>, DomainError>>;
+    type QueryFuture<'a> = Ready<Result<PageResult<BizMetadataAlias>, DomainError>>;
+
+    fn insert(&self, aggregate: BizMetadataAlias) -> Self::InsertFuture<'_> {
+        ready(self.do_insert(aggregate))
+    }
+
+    fn update(&self, aggregate: BizMetadataAlias) -> Self::UpdateFuture<'_> {
+        ready(self.do_update(aggregate))
+    }
+
+    fn delete(&self, id: BizMetadataAliasId) -> Self::DeleteFuture<'_> {
+        ready(self.lock().map(|mut state| {
+            state.rows.remove(&i64::from(id));
+        }))
+    }
+
+    fn find_by_id(&self, id: BizMetadataAliasId) -> Self::FindByIdFuture<'_> {
+        ready(
+            self.lock()
+                .map(|state| state.rows.get(&i64::from(id)).cloned()),
+        )
+    }
+
+    fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+        ready(self.do_query(&expr, &options))
+    }
+}
+
+impl BizMetadataAliasRepository for InMemoryBizMetadataAliasRepository {
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        Some(BIZ_METADATA_ALIAS_FIELD_MAP.fields().collect())
+    }
+
+    fn count_live_aliases(
+        &self,
+        metadata_id: BizMetadataId,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        ready(self.lock().map(|state| state.count_live(metadata_id)))
+    }
+
+    fn find_or_insert_alias(
+        &self,
+        alias: BizMetadataAlias,
+        max_live: usize,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        ready(self.lock().and_then(|mut state| {
+            let existing = state
+                .rows
+                .values()
+                .filter(|row| {
+                    row.delete_at().is_none()
+                        && row.metadata_id() == alias.metadata_id()
+                        && row.alias() == alias.alias()
+                        && row.language() == alias.language()
+                })
+                .min_by_key(|row| i64::from(row.id()))
+                .cloned();
+            if let Some(found) = existing {
+                return Ok(found);
+            }
+            let total = state.count_live(alias.metadata_id()) + 1;
+            if total > max_live as u64 {
+                return Err(alias_limit_exceeded(alias.metadata_id(), total, max_live));
+            }
+            state.insert(alias)
+        }))
+    }
+
+    fn insert_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+        max_live: usize,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        ready(self.lock().and_then(|mut state| {
+            for (metadata_id, adding) in count_by_metadata(&items) {
+                let total = state.count_live(metadata_id) + adding;
+                if total > max_live as u64 {
+                    return Err(alias_limit_exceeded(metadata_id, total, max_live));
+                }
+            }
+            items.into_iter().map(|alias| state.insert(alias)).collect()
+        }))
+    }
+
+    fn update_alias_many(
+        &self,
+        items: Vec<BizMetadataAlias>,
+    ) -> impl Future<Output = Result<Vec<BizMetadataAlias>, DomainError>> + Send + '_ {
+        ready(self.lock().and_then(|mut state| {
+            // 先校验全部存在再写入，保证失败时不留下部分更新。
+            if let Some(missing) = items
+                .iter()
+                .find(|alias| !state.rows.contains_key(&i64::from(alias.id())))
+            {
+                return Err(DomainError::Persistence {
+                    code: error_code::ALIAS_NOT_FOUND,
+                    message: format!("biz_metadata_alias {} not found", missing.id().value()),
+                });
+            }
+            for alias in &items {
+                state.rows.insert(i64::from(alias.id()), alias.clone());
+            }
+            Ok(items)
+        }))
+    }
+
+    fn swap_primary_alias(
+        &self,
+        demoted: Vec<BizMetadataAlias>,
+        promoted: BizMetadataAlias,
+        change: AliasPrimaryChange,
+    ) -> impl Future<Output = Result<BizMetadataAlias, DomainError>> + Send + '_ {
+        ready(self.lock().and_then(|mut state| {
+            let updates: Vec<_> = demoted
+                .into_iter()
+                .chain(std::iter::once(promoted.clone()))
+                .collect();
+            // 先校验全部存在再写入，保证失败时不留下半切换状态。
+            if let Some(missing) = updates
+                .iter()
+                .find(|alias| !state.rows.contains_key(&i64::from(alias.id())))
+            {
+                return Err(DomainError::Persistence {
+                    code: error_code::ALIAS_NOT_FOUND,
+                    message: format!("biz_metadata_alias {} not found", missing.id().value()),
+                });
+            }
+            for alias in updates {
+                state.rows.insert(i64::from(alias.id()), alias);
+            }
+            state.primary_history.push(change);
+            Ok(promoted)
+        }))
+    }
+
+    fn soft_delete_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        ready(self.transact(|state| state.soft_delete_of(metadata_id, deleted_at)))
+    }
+
+    fn restore_by_metadata_id(
+        &self,
+        metadata_id: BizMetadataId,
+        deleted_at: DateTime<Utc>,
+        restored_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        ready(self.transact(|state| state.restore_of(metadata_id, deleted_at, restored_at)))
+    }
+}
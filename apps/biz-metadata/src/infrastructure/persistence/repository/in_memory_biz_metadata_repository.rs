@@ -0,0 +1,913 @@
+//! 基于内存的元数据仓储实现，供服务层测试在无数据库环境下使用。
+//!
+//! 行为对齐 [`BizMetadataRepositoryImpl`](super::biz_metadata_repository_impl::BizMetadataRepositoryImpl)：
+//! - 仅可见默认租户且未软删除的记录
+//! - `update` 基于 `version` 做乐观锁校验，成功后版本号递增
+//! - 同租户下存活记录的 `code` 唯一（对应 `ux_biz_metadata_tenant_code_alive`）；
+//!   [`with_code_uniqueness`](InMemoryBizMetadataRepository::with_code_uniqueness) 设为 `PerParent` 时只在同一父节点内唯一，
+//!   对应以 `per_parent` 运行迁移后的 `ux_biz_metadata_tenant_parent_code_alive` 与 `ux_biz_metadata_tenant_root_code_alive`
+//! - `restore_biz_metadata` 仅匹配已软删除的行，恢复后同样受 `code` 唯一约束
+//! - 别名表由 [`with_aliases`](InMemoryBizMetadataRepository::with_aliases) 共享，级联删除、恢复、合并、目录替换与物理清理在两把锁内
+//!   先暂存元数据、再在别名副本上写入，全部成功后统一提交，对应持久化实现中两张表同库同事务
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::future::{Future, Ready, ready};
+use std::sync::Mutex;
+
+use chrono::{DateTime, Utc};
+use domain_core::domain_error::DomainError;
+use domain_core::expression::{Expression, FilterValue, OrderBy, QueryOptions, evaluate};
+use domain_core::pagination::{DEFAULT_PAGE_SIZE, PageResult};
+use domain_core::repository::Repository;
+use futures_util::stream::{self, Stream};
+
+use crate::domain::biz_metadata::repository::{
+    BizMetadataRepository, CatalogReplacement, VERSION_CONFLICT_MESSAGE,
+};
+use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::biz_metadata::{BizMetadata, CodeUniqueness};
+use crate::domain::biz_metadata_alias::BizMetadataAlias;
+use crate::domain::error_code;
+use crate::infrastructure::persistence::query::PaginationParams;
+use crate::infrastructure::persistence::repository::biz_metadata_repository_impl::BIZ_METADATA_FIELD_MAP;
+use crate::infrastructure::persistence::repository::in_memory_biz_metadata_alias_repository::InMemoryBizMetadataAliasRepository;
+
+const DEFAULT_TENANT_ID: &str = "default";
+
+/// 以 `Mutex<HashMap>` 保存聚合的元数据仓储。
+///
+/// ```
+/// use biz_metadata::{
+///     BizMetadataService, CreateBizMetadataCommand, InMemoryBizMetadataRepository, ObjectType,
+/// };
+///
+/// let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+/// let rt = tokio::runtime::Runtime::new().unwrap();
+/// rt.block_on(async {
+///     let created = service.create_biz_metadata(CreateBizMetadataCommand {
+///         code: "company".into(),
+///         name: "公司".into(),
+///         description: None,
+///         object_type: ObjectType::Entity,
+///         parent_id: None,
+///         data_class: None,
+///         value_type: None,
+///         unit: None,
+///         status: None,
+///         source: None,
+///     }).await?;
+///     assert_eq!(created.id().value(), 1);
+///     assert!(service.find_biz_metadata_by_id(created.id()).await?.is_some());
+///     Ok::<(), domain_core::domain_error::DomainError>(())
+/// }).unwrap();
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryBizMetadataRepository {
+    state: Mutex<State>,
+    code_uniqueness: CodeUniqueness,
+    aliases: InMemoryBizMetadataAliasRepository,
+}
+
+#[derive(Debug, Default, Clone)]
+struct State {
+    next_id: i64,
+    rows: HashMap<i64, BizMetadata>,
+}
+
+/// [`InMemoryBizMetadataRepository::stage_replacement`] 的暂存结果。
+struct StagedReplacement {
+    state: State,
+    /// 被软删除的存活行 id。
+    wiped: Vec<BizMetadataId>,
+    inserted: Vec<BizMetadata>,
+    /// 已回填 `metadata_id` 的待插入别名。
+    pending_aliases: Vec<BizMetadataAlias>,
+}
+
+impl InMemoryBizMetadataRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 模拟对应部署下的编码唯一索引，默认为租户内全局唯一。
+    pub fn with_code_uniqueness(mut self, code_uniqueness: CodeUniqueness) -> Self {
+        self.code_uniqueness = code_uniqueness;
+        self
+    }
+
+    /// 与 `aliases` 共享别名表，级联删除与恢复写入其中；未设置时使用仓储私有的空别名表。
+    ///
+    /// ```
+    /// use biz_metadata::{InMemoryBizMetadataAliasRepository, InMemoryBizMetadataRepository};
+    ///
+    /// let aliases = InMemoryBizMetadataAliasRepository::new();
+    /// let repo = InMemoryBizMetadataRepository::new().with_aliases(aliases.clone());
+    /// # let _ = repo;
+    /// ```
+    pub fn with_aliases(mut self, aliases: InMemoryBizMetadataAliasRepository) -> Self {
+        self.aliases = aliases;
+        self
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, State>, DomainError> {
+        self.state.lock().map_err(|err| DomainError::Persistence {
+            code: domain_core::error_code::PERSISTENCE_FAILED,
+            message: err.to_string(),
+        })
+    }
+
+    fn is_visible(item: &BizMetadata) -> bool {
+        item.tenant_id().as_str() == DEFAULT_TENANT_ID && !item.is_deleted()
+    }
+
+    fn ensure_code_unique(&self, state: &State, item: &BizMetadata) -> Result<(), DomainError> {
+        if item.is_deleted() {
+            return Ok(());
+        }
+        let per_parent = self.code_uniqueness == CodeUniqueness::PerParent;
+        let duplicated = state.rows.values().any(|other| {
+            other.id() != item.id()
+                && !other.is_deleted()
+                && other.tenant_id() == item.tenant_id()
+                && (!per_parent || other.parent_id() == item.parent_id())
+                && other.code() == item.code()
+        });
+        if duplicated {
+            let index = match (per_parent, item.parent_id()) {
+                (false, _) => "ux_biz_metadata_tenant_code_alive",
+                (true, Some(_)) => "ux_biz_metadata_tenant_parent_code_alive",
+                (true, None) => "ux_biz_metadata_tenant_root_code_alive",
+            };
+            return Err(DomainError::Persistence {
+                code: error_code::BIZ_METADATA_CODE_CONFLICT,
+                message: format!(
+                    "duplicate key value violates unique constraint \"{index}\": code={}",
+                    item.code().as_str()
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    fn field_value(item: &BizMetadata, field: &str) -> Option<FilterValue> {
+        match field {
+            "id" => Some(FilterValue::I64(item.id().value())),
+            "tenant_id" => Some(FilterValue::from(item.tenant_id().as_str())),
+            "version" => Some(FilterValue::I64(i32::from(item.version()).into())),
+            "code" => Some(FilterValue::from(item.code().as_str())),
+            "name" => Some(FilterValue::from(item.name().as_str())),
+            "description" => item.description().map(FilterValue::from),
+            "object_type" => Some(FilterValue::from(item.object_type().as_str())),
+            "parent_id" => item.parent_id().map(|id| FilterValue::I64(id.value())),
+            "data_class" => item.data_class().map(|v| FilterValue::from(v.as_str())),
+            "value_type" => item.value_type().map(|v| FilterValue::from(v.as_str())),
+            "unit" => item.unit().map(|v| FilterValue::from(v.as_str())),
+            "status" => Some(FilterValue::from(item.status().as_str())),
+            "source" => Some(FilterValue::from(item.source().as_str())),
+            "created_at" => Some(item.created_at().into()),
+            "updated_at" => Some(item.updated_at().into()),
+            "deleted_at" => item.delete_at().map(FilterValue::from),
+            _ => None,
+        }
+    }
+
+    /// 与 PostgreSQL 一致：默认升序时 NULL 排在最后、降序时排在最前，可由 `nulls` 覆盖。
+    fn order(a: &BizMetadata, b: &BizMetadata, order_bys: &[OrderBy]) -> Ordering {
+        for order in order_bys {
+            let ordering = order.compare(
+                Self::field_value(a, &order.field),
+                Self::field_value(b, &order.field),
+            );
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+        Ordering::Equal
+    }
+
+    fn with_identity(
+        aggregate: &BizMetadata,
+        id: BizMetadataId,
+        version: crate::domain::biz_metadata::value_object::Version,
+    ) -> Result<BizMetadata, DomainError> {
+        let mut snapshot = aggregate.to_snapshot();
+        snapshot.id = id;
+        snapshot.version = version;
+        BizMetadata::rehydrate(snapshot)
+    }
+
+    fn do_insert(&self, aggregate: BizMetadata) -> Result<BizMetadata, DomainError> {
+        let mut state = self.lock()?;
+        self.insert_in(&mut state, aggregate)
+    }
+
+    fn insert_in(
+        &self,
+        state: &mut State,
+        aggregate: BizMetadata,
+    ) -> Result<BizMetadata, DomainError> {
+        let id = if aggregate.has_id() {
+            // 预分配的 id 原样保留，并推进自增游标避免后续自增撞号。
+            let id = aggregate.id();
+            if state.rows.contains_key(&id.value()) {
+                return Err(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_ID_CONFLICT,
+                    message: format!("biz_metadata id {} already exists", id.value()),
+                });
+            }
+            state.next_id = state.next_id.max(id.value());
+            id
+        } else {
+            state.next_id += 1;
+            BizMetadataId::new(state.next_id)
+        };
+        let stored = Self::with_identity(&aggregate, id, aggregate.version())?;
+        self.ensure_code_unique(state, &stored)?;
+        state.rows.insert(id.value(), stored.clone());
+        Ok(stored)
+    }
+
+    /// 在两把锁内暂存元数据替换，再在别名副本上清空被替换行的存活别名并插入条目别名，全部成功后统一提交。
+    fn do_replace_catalog(
+        &self,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut state = self.lock()?;
+        let StagedReplacement {
+            state: staged,
+            wiped,
+            inserted,
+            pending_aliases,
+        } = self.stage_replacement(&state, entries, now)?;
+        // 条目自带的别名数已由调用方按上限校验，且所属元数据均为新插入，这里不再限制。
+        self.aliases.transact(|aliases| {
+            for metadata_id in wiped {
+                aliases.soft_delete_of(metadata_id, now)?;
+            }
+            for alias in pending_aliases {
+                aliases.insert(alias)?;
+            }
+            Ok(())
+        })?;
+        *state = staged;
+        Ok(inserted)
+    }
+
+    /// 在状态副本上软删除全部存活行并按顺序插入 `entries`，不修改 `state`。
+    fn stage_replacement(
+        &self,
+        state: &State,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> Result<StagedReplacement, DomainError> {
+        let mut staged = state.clone();
+        let live: Vec<BizMetadata> = staged
+            .rows
+            .values()
+            .filter(|item| Self::is_visible(item))
+            .cloned()
+            .collect();
+        let mut wiped = Vec::with_capacity(live.len());
+        for mut item in live {
+            item.mark_deleted(now)?;
+            let stored = Self::with_identity(&item, item.id(), item.version().next()?)?;
+            wiped.push(stored.id());
+            staged.rows.insert(stored.id().value(), stored);
+        }
+
+        let mut ids_by_code = HashMap::new();
+        let mut inserted = Vec::with_capacity(entries.len());
+        let mut pending_aliases = Vec::new();
+        for mut entry in entries {
+            entry.resolve_parent(&ids_by_code, now)?;
+            let stored = self.insert_in(&mut staged, entry.metadata)?;
+            ids_by_code.insert(stored.code().as_str().to_string(), stored.id());
+            for mut alias in entry.aliases {
+                alias.change_metadata_id(stored.id(), now)?;
+                pending_aliases.push(alias);
+            }
+            inserted.push(stored);
+        }
+        Ok(StagedReplacement {
+            state: staged,
+            wiped,
+            inserted,
+            pending_aliases,
+        })
+    }
+
+    fn do_update(&self, aggregate: BizMetadata) -> Result<BizMetadata, DomainError> {
+        let mut state = self.lock()?;
+        let matched = state
+            .rows
+            .get(&aggregate.id().value())
+            .is_some_and(|current| {
+                Self::is_visible(current) && current.version() == aggregate.version()
+            });
+        if !matched {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                message: VERSION_CONFLICT_MESSAGE.into(),
+            });
+        }
+        let stored = Self::with_identity(&aggregate, aggregate.id(), aggregate.version().next()?)?;
+        self.ensure_code_unique(&state, &stored)?;
+        state.rows.insert(stored.id().value(), stored.clone());
+        Ok(stored)
+    }
+
+    fn do_restore(&self, aggregate: BizMetadata) -> Result<BizMetadata, DomainError> {
+        let mut state = self.lock()?;
+        let stored = self.stage_restore(&state, aggregate)?;
+        state.rows.insert(stored.id().value(), stored.clone());
+        Ok(stored)
+    }
+
+    /// 校验 `aggregate` 匹配仍处于删除状态的行并返回恢复后的行，不修改 `state`。
+    fn stage_restore(
+        &self,
+        state: &State,
+        aggregate: BizMetadata,
+    ) -> Result<BizMetadata, DomainError> {
+        let matched = state
+            .rows
+            .get(&aggregate.id().value())
+            .is_some_and(|current| {
+                current.tenant_id().as_str() == DEFAULT_TENANT_ID
+                    && current.is_deleted()
+                    && current.version() == aggregate.version()
+            });
+        if !matched {
+            return Err(DomainError::Validation {
+                code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                message: VERSION_CONFLICT_MESSAGE.into(),
+            });
+        }
+        let stored = Self::with_identity(&aggregate, aggregate.id(), aggregate.version().next()?)?;
+        self.ensure_code_unique(state, &stored)?;
+        Ok(stored)
+    }
+
+    /// 在两把锁内恢复元数据与级联删除的别名，别名写入失败时元数据保持删除。
+    fn do_restore_with_aliases(
+        &self,
+        aggregate: BizMetadata,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<BizMetadata, DomainError> {
+        let mut state = self.lock()?;
+        let restored_at = aggregate.updated_at();
+        let stored = self.stage_restore(&state, aggregate)?;
+        self.aliases
+            .transact(|aliases| aliases.restore_of(stored.id(), deleted_at, restored_at))?;
+        state.rows.insert(stored.id().value(), stored.clone());
+        Ok(stored)
+    }
+
+    /// 在同一把锁内先校验全部版本再统一写入，模拟事务的全有或全无语义。
+    fn do_soft_delete_many(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let mut state = self.lock()?;
+        let mut staged = Vec::with_capacity(items.len());
+        for mut item in items {
+            let matched = state.rows.get(&item.id().value()).is_some_and(|current| {
+                Self::is_visible(current) && current.version() == item.version()
+            });
+            if !matched {
+                return Err(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                    message: VERSION_CONFLICT_MESSAGE.into(),
+                });
+            }
+            item.mark_deleted(deleted_at)?;
+            staged.push(Self::with_identity(
+                &item,
+                item.id(),
+                item.version().next()?,
+            )?);
+        }
+        let affected = staged.len() as u64;
+        for stored in staged {
+            state.rows.insert(stored.id().value(), stored);
+        }
+        Ok(affected)
+    }
+
+    /// 在同一把锁内先校验全部版本与编码再统一写入，模拟事务的全有或全无语义。
+    fn do_update_many(&self, items: Vec<BizMetadata>) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut state = self.lock()?;
+        let staged = self.stage_updates(&state, items)?;
+        for stored in &staged {
+            state.rows.insert(stored.id().value(), stored.clone());
+        }
+        Ok(staged)
+    }
+
+    /// 在两把锁内提交 `items` 并软删除 `deleted` 的存活别名，别名写入失败时元数据保持不变。
+    fn do_delete_with_aliases(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted: BizMetadataId,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut state = self.lock()?;
+        let staged = self.stage_updates(&state, items)?;
+        let deleted_at = staged
+            .iter()
+            .find(|item| item.id() == deleted)
+            .and_then(BizMetadata::delete_at)
+            .ok_or_else(|| DomainError::Persistence {
+                code: error_code::PERSISTENCE_ROW_MISSING,
+                message: format!("biz_metadata {} not deleted in batch", deleted.value()),
+            })?;
+        self.aliases
+            .transact(|aliases| aliases.soft_delete_of(deleted, deleted_at))?;
+        for stored in &staged {
+            state.rows.insert(stored.id().value(), stored.clone());
+        }
+        Ok(staged)
+    }
+
+    /// 在两把锁内改挂 `loser` 的别名并提交 `items`，任一步失败时两张表均保持不变。
+    fn do_merge(
+        &self,
+        items: Vec<BizMetadata>,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+        max_live: usize,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut state = self.lock()?;
+        let staged = self.stage_updates(&state, items)?;
+        self.aliases
+            .transact(|aliases| aliases.reassign(loser, survivor, now, max_live))?;
+        for stored in &staged {
+            state.rows.insert(stored.id().value(), stored.clone());
+        }
+        Ok(staged)
+    }
+
+    /// 校验 `items` 的版本与编码并返回递增版本后的行，不修改 `state`。
+    fn stage_updates(
+        &self,
+        state: &State,
+        items: Vec<BizMetadata>,
+    ) -> Result<Vec<BizMetadata>, DomainError> {
+        let mut staged = Vec::with_capacity(items.len());
+        for item in items {
+            let matched = state.rows.get(&item.id().value()).is_some_and(|current| {
+                Self::is_visible(current) && current.version() == item.version()
+            });
+            if !matched {
+                return Err(DomainError::Validation {
+                    code: error_code::BIZ_METADATA_VERSION_CONFLICT,
+                    message: VERSION_CONFLICT_MESSAGE.into(),
+                });
+            }
+            let stored = Self::with_identity(&item, item.id(), item.version().next()?)?;
+            self.ensure_code_unique(state, &stored)?;
+            staged.push(stored);
+        }
+        Ok(staged)
+    }
+
+    fn do_purge(&self, cutoff: DateTime<Utc>, limit: u64) -> Result<u64, DomainError> {
+        let mut state = self.lock()?;
+        let referenced: Vec<BizMetadataId> = state
+            .rows
+            .values()
+            .filter(|item| !item.is_deleted())
+            .filter_map(BizMetadata::parent_id)
+            .collect();
+        let mut candidates: Vec<BizMetadataId> = state
+            .rows
+            .values()
+            .filter(|item| item.delete_at().is_some_and(|at| at < cutoff))
+            .filter(|item| !referenced.contains(&item.id()))
+            .map(BizMetadata::id)
+            .collect();
+        candidates.sort_unstable_by_key(|id| id.value());
+        let purgeable = self.aliases.transact(|aliases| {
+            let mut purgeable: Vec<BizMetadataId> = candidates
+                .into_iter()
+                .filter(|id| aliases.count_live(*id) == 0)
+                .collect();
+            purgeable.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+            aliases.purge_of(&purgeable);
+            Ok(purgeable)
+        })?;
+        for id in &purgeable {
+            state.rows.remove(&id.value());
+        }
+        Ok(purgeable.len() as u64)
+    }
+
+    fn do_query(
+        &self,
+        predicate: impl Fn(&BizMetadata) -> bool,
+        options: &QueryOptions,
+    ) -> Result<PageResult<BizMetadata>, DomainError> {
+        let state = self.lock()?;
+        let pagination =
+            PaginationParams::compute(options.limit, options.offset, DEFAULT_PAGE_SIZE);
+
+        let mut matched: Vec<BizMetadata> = state
+            .rows
+            .values()
+            .filter(|item| item.tenant_id().as_str() == DEFAULT_TENANT_ID && predicate(item))
+            .cloned()
+            .collect();
+        matched.sort_by_key(|item| item.id().value());
+        matched.sort_by(|a, b| Self::order(a, b, &options.order_bys));
+
+        let total = matched.len() as u64;
+        let start = pagination.page_index.saturating_mul(pagination.limit);
+        let items = matched
+            .into_iter()
+            .skip(usize::try_from(start).unwrap_or(usize::MAX))
+            .take(usize::try_from(pagination.limit).unwrap_or(usize::MAX))
+            .collect();
+
+        Ok(PageResult::builder(items, total)
+            .page_index(pagination.page_index)
+            .page_size(pagination.limit)
+            .build())
+    }
+}
+
+impl Repository<BizMetadata> for InMemoryBizMetadataRepository {
+    type InsertFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+    type UpdateFuture<'a> = Ready<Result<BizMetadata, DomainError>>;
+    type DeleteFuture<'a> = Ready<Result<(), DomainError>>;
+    type FindByIdFuture<'a> = Ready<Result<Option<BizMetadata>, DomainError>>;
+    type QueryFuture<'a> = Ready<Result<PageResult<BizMetadata>, DomainError>>;
+
+    fn insert(&self, aggregate: BizMetadata) -> Self::InsertFuture<'_> {
+        ready(self.do_insert(aggregate))
+    }
+
+    fn update(&self, aggregate: BizMetadata) -> Self::UpdateFuture<'_> {
+        ready(self.do_update(aggregate))
+    }
+
+    fn delete(&self, _id: BizMetadataId) -> Self::DeleteFuture<'_> {
+        ready(Err(DomainError::Validation {
+            code: error_code::BIZ_METADATA_DELETE_REQUIRES_VERSION,
+            message: "delete requires version; use soft-delete via update".into(),
+        }))
+    }
+
+    fn find_by_id(&self, id: BizMetadataId) -> Self::FindByIdFuture<'_> {
+        ready(self.lock().map(|state| {
+            state
+                .rows
+                .get(&id.value())
+                .filter(|item| Self::is_visible(item))
+                .cloned()
+        }))
+    }
+
+    fn query(&self, expr: Expression, options: QueryOptions) -> Self::QueryFuture<'_> {
+        ready(self.do_query(
+            |item| !item.is_deleted() && evaluate(&expr, |field| Self::field_value(item, field)),
+            &options,
+        ))
+    }
+}
+
+impl BizMetadataRepository for InMemoryBizMetadataRepository {
+    fn query_fields(&self) -> Option<Vec<&'static str>> {
+        Some(BIZ_METADATA_FIELD_MAP.fields().collect())
+    }
+
+    fn find_deleted_biz_metadata_by_id(
+        &self,
+        id: BizMetadataId,
+    ) -> impl Future<Output = Result<Option<BizMetadata>, DomainError>> + Send + '_ {
+        ready(self.lock().map(|state| {
+            state
+                .rows
+                .get(&id.value())
+                .filter(|item| item.tenant_id().as_str() == DEFAULT_TENANT_ID && item.is_deleted())
+                .cloned()
+        }))
+    }
+
+    fn restore_biz_metadata(
+        &self,
+        biz_metadata: BizMetadata,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
+        ready(self.do_restore(biz_metadata))
+    }
+
+    fn delete_biz_metadata_with_aliases(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted: BizMetadataId,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        ready(self.do_delete_with_aliases(items, deleted))
+    }
+
+    fn restore_biz_metadata_with_aliases(
+        &self,
+        biz_metadata: BizMetadata,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<BizMetadata, DomainError>> + Send + '_ {
+        ready(self.do_restore_with_aliases(biz_metadata, deleted_at))
+    }
+
+    fn merge_biz_metadata(
+        &self,
+        items: Vec<BizMetadata>,
+        loser: BizMetadataId,
+        survivor: BizMetadataId,
+        max_live: usize,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        ready(self.do_merge(items, loser, survivor, max_live, now))
+    }
+
+    fn purge_biz_metadata_deleted_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        limit: u64,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        ready(self.do_purge(cutoff, limit))
+    }
+
+    fn soft_delete_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+        deleted_at: DateTime<Utc>,
+    ) -> impl Future<Output = Result<u64, DomainError>> + Send + '_ {
+        ready(self.do_soft_delete_many(items, deleted_at))
+    }
+
+    fn update_biz_metadata_many(
+        &self,
+        items: Vec<BizMetadata>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        ready(self.do_update_many(items))
+    }
+
+    /// 一次性收集匹配行后按 `id` 升序逐条产出。
+    fn stream_all_biz_metadata(
+        &self,
+        expr: Expression,
+    ) -> impl Stream<Item = Result<BizMetadata, DomainError>> + Send + '_ {
+        let options = QueryOptions::new(Some(u64::MAX), Some(0)).with_order_by(OrderBy::asc("id"));
+        let rows = match self.do_query(
+            |item| !item.is_deleted() && evaluate(&expr, |field| Self::field_value(item, field)),
+            &options,
+        ) {
+            Ok(page) => page.into_items().into_iter().map(Ok).collect(),
+            Err(err) => vec![Err(err)],
+        };
+        stream::iter(rows)
+    }
+
+    fn replace_catalog(
+        &self,
+        entries: Vec<CatalogReplacement>,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<BizMetadata>, DomainError>> + Send + '_ {
+        ready(self.do_replace_catalog(entries, now))
+    }
+
+    fn query_biz_metadata_changed_since(
+        &self,
+        since: DateTime<Utc>,
+        include_deleted: bool,
+        options: QueryOptions,
+    ) -> Self::QueryFuture<'_> {
+        ready(self.do_query(
+            |item| match item.delete_at() {
+                None => item.updated_at() >= since,
+                Some(delete_at) => include_deleted && delete_at >= since,
+            },
+            &options,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::value_object::{
+        BizMetadataStatus, DataClass, ObjectType, TenantId, ValueType,
+    };
+    use domain_core::expression::{NullsOrder, eq, ne};
+    use domain_core::pagination::Page;
+
+    fn node(code: &str) -> BizMetadata {
+        BizMetadata::new_node(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code,
+            code,
+            ObjectType::Entity,
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    fn feature(code: &str) -> BizMetadata {
+        BizMetadata::new_feature(
+            TenantId::new(DEFAULT_TENANT_ID).unwrap(),
+            code,
+            code,
+            DataClass::Attribute,
+            ValueType::new("string").unwrap(),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn nulls_order_controls_null_placement() {
+        let repo = InMemoryBizMetadataRepository::new();
+        repo.insert(node("company")).await.unwrap();
+        repo.insert(feature("company.name")).await.unwrap();
+
+        let codes = |order: OrderBy| {
+            let query = repo.query(
+                Expression::True,
+                QueryOptions::default().with_order_by(order),
+            );
+            async move {
+                query
+                    .await
+                    .unwrap()
+                    .into_items()
+                    .into_iter()
+                    .map(|item| item.code().as_str().to_string())
+                    .collect::<Vec<_>>()
+            }
+        };
+        assert_eq!(
+            codes(OrderBy::asc("data_class")).await,
+            ["company.name", "company"]
+        );
+        assert_eq!(
+            codes(OrderBy::asc("data_class").with_nulls(NullsOrder::First)).await,
+            ["company", "company.name"]
+        );
+        assert_eq!(
+            codes(OrderBy::desc("data_class")).await,
+            ["company", "company.name"]
+        );
+        assert_eq!(
+            codes(OrderBy::desc("data_class").with_nulls(NullsOrder::Last)).await,
+            ["company.name", "company"]
+        );
+    }
+
+    #[tokio::test]
+    async fn filters_with_eq_and_ne() {
+        let repo = InMemoryBizMetadataRepository::new();
+        repo.insert(node("company")).await.unwrap();
+        repo.insert(feature("company.name")).await.unwrap();
+        repo.insert(feature("company.code")).await.unwrap();
+
+        let features = repo
+            .query(
+                Expression::cmp(eq("object_type", "feature")),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(features.total_count(), 2);
+
+        let others = repo
+            .query(
+                Expression::cmp(ne("code", "company")),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        assert!(
+            others
+                .items()
+                .iter()
+                .all(|item| item.code().as_str() != "company")
+        );
+        assert_eq!(others.total_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn negated_filters_skip_null_fields_like_sql() {
+        let repo = InMemoryBizMetadataRepository::new();
+        repo.insert(node("company")).await.unwrap();
+        repo.insert(feature("company.name")).await.unwrap();
+
+        // SQL 中 `NOT (data_class = 'metric')` 对 NULL 不成立，实体节点不应命中。
+        let page = repo
+            .query(
+                !Expression::cmp(eq("data_class", "metric")),
+                QueryOptions::default(),
+            )
+            .await
+            .unwrap();
+        let codes: Vec<_> = page
+            .items()
+            .iter()
+            .map(|item| item.code().as_str())
+            .collect();
+        assert_eq!(codes, ["company.name"]);
+    }
+
+    #[tokio::test]
+    async fn stream_all_yields_query_rows_in_id_order() {
+        use futures_util::TryStreamExt;
+
+        let repo = InMemoryBizMetadataRepository::new();
+        for code in ["b", "c", "a", "d"] {
+            repo.insert(node(code)).await.unwrap();
+        }
+        let mut removed = repo.insert(node("removed")).await.unwrap();
+        removed.mark_deleted(Utc::now()).unwrap();
+        repo.update(removed).await.unwrap();
+
+        let streamed: Vec<_> = repo
+            .stream_all_biz_metadata(Expression::True)
+            .try_collect()
+            .await
+            .unwrap();
+        let queried = repo
+            .query(
+                Expression::True,
+                QueryOptions::new(Some(100), Some(0)).with_order_by(OrderBy::asc("id")),
+            )
+            .await
+            .unwrap()
+            .into_items();
+        assert_eq!(streamed, queried);
+        assert_eq!(streamed.len(), 4);
+        assert!(
+            streamed
+                .windows(2)
+                .all(|w| w[0].id().value() < w[1].id().value())
+        );
+    }
+
+    #[tokio::test]
+    async fn orders_and_paginates() {
+        let repo = InMemoryBizMetadataRepository::new();
+        for code in ["b", "c", "a"] {
+            repo.insert(node(code)).await.unwrap();
+        }
+
+        let page = repo
+            .query(
+                Expression::True,
+                QueryOptions::new(Some(2), Some(0)).with_order_by(OrderBy::desc("code")),
+            )
+            .await
+            .unwrap();
+        let codes: Vec<_> = page.items().iter().map(|m| m.code().as_str()).collect();
+        assert_eq!(codes, vec!["c", "b"]);
+        assert_eq!(page.total_count(), 3);
+        assert!(page.has_next_page());
+
+        let last = repo
+            .query(
+                Expression::True,
+                QueryOptions::new(Some(2), Some(2)).with_order_by(OrderBy::desc("code")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(last.items()[0].code().as_str(), "a");
+    }
+
+    #[tokio::test]
+    async fn enforces_optimistic_version() {
+        let repo = InMemoryBizMetadataRepository::new();
+        let created = repo.insert(node("company")).await.unwrap();
+
+        let mut first = created.clone();
+        first
+            .change_status(BizMetadataStatus::Deprecated, Utc::now())
+            .unwrap();
+        let updated = repo.update(first).await.unwrap();
+        assert_eq!(i32::from(updated.version()), 2);
+
+        let err = repo.update(created).await.unwrap_err();
+        assert!(matches!(err, DomainError::Validation { .. }));
+    }
+
+    #[tokio::test]
+    async fn enforces_unique_alive_code() {
+        let repo = InMemoryBizMetadataRepository::new();
+        let created = repo.insert(node("company")).await.unwrap();
+        let err = repo.insert(node("company")).await.unwrap_err();
+        assert!(matches!(err, DomainError::Persistence { .. }));
+
+        let mut deleted = created.clone();
+        deleted.mark_deleted(Utc::now()).unwrap();
+        repo.update(deleted).await.unwrap();
+        assert!(repo.find_by_id(created.id()).await.unwrap().is_none());
+        assert!(repo.insert(node("company")).await.is_ok());
+    }
+}
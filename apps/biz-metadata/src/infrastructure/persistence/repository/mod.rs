@@ -1,3 +1,9 @@
 pub mod biz_metadata_alias_repository_impl;
 pub mod biz_metadata_repository_impl;
+#[cfg(feature = "cache")]
+pub mod caching_biz_metadata_repository;
 pub mod future;
+#[cfg(any(test, feature = "test-util"))]
+pub mod in_memory_biz_metadata_alias_repository;
+#[cfg(any(test, feature = "test-util"))]
+pub mod in_memory_biz_metadata_repository;
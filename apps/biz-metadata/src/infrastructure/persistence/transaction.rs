@@ -0,0 +1,41 @@
+//! 事务辅助：按操作选择隔离级别开启事务。
+//!
+//! 隔离级别只作用于仓储事务内的语句。合并、批量改父节点的环、深度与编码校验读取发生在事务之外，
+//! 提高隔离级别并不能覆盖这些读取；事务内每行仍按版本号提交，被并发改写的行会导致整批失败。
+//! 在 `REPEATABLE READ`/`SERIALIZABLE` 下，并发改写同一行时返回序列化失败（SQLSTATE 40001），
+//! 翻译为可重试的 `persistence.serialization_failure`；未配置的操作沿用数据库默认隔离级别。
+
+use sea_orm::{DatabaseTransaction, DbErr, IsolationLevel, TransactionTrait};
+
+/// 仓储各类写事务的隔离级别，`None` 表示沿用数据库默认值。
+///
+/// ```
+/// use biz_metadata::infrastructure::persistence::transaction::TransactionIsolation;
+/// use sea_orm::IsolationLevel;
+///
+/// let isolation = TransactionIsolation {
+///     batch_update: Some(IsolationLevel::Serializable),
+///     ..Default::default()
+/// };
+/// assert_eq!(isolation.locked_update, None);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransactionIsolation {
+    /// 单条 `SELECT ... FOR UPDATE` 后更新，如 `update_biz_metadata_locked`。
+    pub locked_update: Option<IsolationLevel>,
+    /// 多行版本化更新，如合并与批量改父节点提交时使用的 `update_biz_metadata_many`，以及随元数据级联写入别名的删除与恢复。
+    pub batch_update: Option<IsolationLevel>,
+    /// 多行软删除，如 `soft_delete_biz_metadata_many`。
+    pub batch_soft_delete: Option<IsolationLevel>,
+}
+
+/// 以 `isolation` 开启事务，`None` 时等同于 `begin()`。
+pub async fn begin_with_isolation<C>(
+    db: &C,
+    isolation: Option<IsolationLevel>,
+) -> Result<DatabaseTransaction, DbErr>
+where
+    C: TransactionTrait<Transaction = DatabaseTransaction>,
+{
+    db.begin_with_config(isolation, None).await
+}
@@ -0,0 +1,289 @@
+//! Bearer Token 鉴权中间件：校验 HS256 JWT 及其 `tenant_id` 声明。
+//!
+//! 校验密钥通过环境变量 `BIZ_METADATA_JWT_SECRET` 配置（放在 .env 中）；
+//! 本地开发可设置 `BIZ_METADATA_AUTH_DEV_BYPASS_TENANT` 跳过校验，所有请求视为该租户。
+//! 两者不可同时设置，避免生产环境因遗留的旁路变量静默关闭鉴权。
+//!
+//! 当前为单租户部署：服务与仓储固定读写 [`SERVED_TENANT_ID`]，`tenant_id` 声明与之不符的 Token
+//! 返回 403，校验通过后不向 handler 传递租户。按 Token 租户构造服务需要先让仓储支持按租户隔离。
+//! Token 须携带 `exp` 声明。
+
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use hmac::{Hmac, Mac};
+use serde_json::Value;
+use sha2::Sha256;
+
+use crate::domain::biz_metadata::value_object::TenantId;
+use crate::interface::http::mapper::HttpError;
+
+/// 校验密钥的环境变量名。
+pub const JWT_SECRET_ENV: &str = "BIZ_METADATA_JWT_SECRET";
+/// 开发旁路租户的环境变量名。
+pub const DEV_BYPASS_TENANT_ENV: &str = "BIZ_METADATA_AUTH_DEV_BYPASS_TENANT";
+/// 本服务所服务的租户，与仓储写入与查询使用的租户一致。
+pub const SERVED_TENANT_ID: &str = "default";
+
+/// 鉴权配置。
+///
+/// ```
+/// use biz_metadata::interface::http::auth::AuthConfig;
+///
+/// let config = AuthConfig::hs256("secret");
+/// assert!(matches!(config, AuthConfig::Hs256 { .. }));
+/// ```
+#[derive(Clone)]
+pub enum AuthConfig {
+    /// 使用 HMAC-SHA256 共享密钥校验 JWT 签名。
+    Hs256 { secret: Arc<[u8]> },
+    /// 本地开发旁路：不校验 Token，固定注入给定租户。
+    DevBypass { tenant_id: TenantId },
+}
+
+impl AuthConfig {
+    /// 以共享密钥构造 HS256 校验配置。
+    pub fn hs256(secret: impl AsRef<[u8]>) -> Self {
+        Self::Hs256 {
+            secret: Arc::from(secret.as_ref()),
+        }
+    }
+
+    /// 从环境变量读取配置。
+    ///
+    /// 两者同时设置、均未设置、密钥为空或旁路租户不是 [`SERVED_TENANT_ID`] 时返回错误。
+    pub fn from_env() -> Result<Self, String> {
+        Self::from_values(
+            std::env::var(DEV_BYPASS_TENANT_ENV).ok(),
+            std::env::var(JWT_SECRET_ENV).ok(),
+        )
+    }
+
+    fn from_values(bypass: Option<String>, secret: Option<String>) -> Result<Self, String> {
+        match (bypass, secret) {
+            (Some(_), Some(_)) => Err(format!(
+                "{DEV_BYPASS_TENANT_ENV} 与 {JWT_SECRET_ENV} 不能同时设置"
+            )),
+            (Some(tenant), None) => {
+                let tenant_id = TenantId::new(tenant).map_err(|err| err.to_string())?;
+                if tenant_id.as_str() != SERVED_TENANT_ID {
+                    return Err(format!(
+                        "{DEV_BYPASS_TENANT_ENV} 须为 {SERVED_TENANT_ID}，当前为 {}",
+                        tenant_id.as_str()
+                    ));
+                }
+                Ok(Self::DevBypass { tenant_id })
+            }
+            (None, Some(secret)) if secret.trim().is_empty() => {
+                Err(format!("{JWT_SECRET_ENV} 不能为空"))
+            }
+            (None, Some(secret)) => Ok(Self::hs256(secret)),
+            (None, None) => Err(format!(
+                "请设置 {JWT_SECRET_ENV}，或在本地设置 {DEV_BYPASS_TENANT_ENV}"
+            )),
+        }
+    }
+
+    /// 校验 `Authorization` 头并返回 Token 中的租户。
+    fn authenticate(&self, authorization: Option<&str>) -> Result<TenantId, HttpError> {
+        let secret = match self {
+            Self::DevBypass { tenant_id } => return Ok(tenant_id.clone()),
+            Self::Hs256 { secret } => secret,
+        };
+        let token = authorization
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .ok_or_else(|| HttpError::unauthorized("missing bearer token"))?;
+        let claims = verify_hs256(token, secret)?;
+
+        let exp = claims
+            .get("exp")
+            .ok_or_else(|| HttpError::unauthorized("missing exp claim"))?
+            .as_i64()
+            .ok_or_else(|| HttpError::unauthorized("invalid exp claim"))?;
+        if exp <= chrono::Utc::now().timestamp() {
+            return Err(HttpError::unauthorized("token expired"));
+        }
+        let tenant = claims
+            .get("tenant_id")
+            .and_then(Value::as_str)
+            .ok_or_else(|| HttpError::unauthorized("missing tenant_id claim"))?;
+        let tenant_id = TenantId::new(tenant)
+            .map_err(|_| HttpError::unauthorized("invalid tenant_id claim"))?;
+        if tenant_id.as_str() != SERVED_TENANT_ID {
+            return Err(HttpError::tenant_forbidden(format!(
+                "tenant {} is not served by this instance",
+                tenant_id.as_str()
+            )));
+        }
+        Ok(tenant_id)
+    }
+}
+
+/// 鉴权中间件，配合 `axum::middleware::from_fn_with_state` 使用；
+/// 认证失败返回 401、租户不匹配返回 403 problem-details。
+pub async fn require_bearer(
+    State(config): State<AuthConfig>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    match config.authenticate(authorization) {
+        Ok(_) => next.run(request).await,
+        Err(err) => err.into_problem().into_response(),
+    }
+}
+
+/// 校验 `header.payload.signature` 结构、算法与签名，返回载荷声明。
+fn verify_hs256(token: &str, secret: &[u8]) -> Result<Value, HttpError> {
+    let invalid = || HttpError::unauthorized("invalid bearer token");
+    let mut parts = token.split('.');
+    let (Some(header), Some(payload), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+
+    let decode_json = |segment: &str| -> Result<Value, HttpError> {
+        let bytes = URL_SAFE_NO_PAD.decode(segment).map_err(|_| invalid())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid())
+    };
+    if decode_json(header)?.get("alg").and_then(Value::as_str) != Some("HS256") {
+        return Err(invalid());
+    }
+
+    let signature = URL_SAFE_NO_PAD.decode(signature).map_err(|_| invalid())?;
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).map_err(|_| invalid())?;
+    mac.update(header.as_bytes());
+    mac.update(b".");
+    mac.update(payload.as_bytes());
+    mac.verify_slice(&signature).map_err(|_| invalid())?;
+
+    decode_json(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, http::StatusCode, routing::get};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    const SECRET: &str = "test-secret";
+
+    fn sign(claims: Value, secret: &str) -> String {
+        let header = URL_SAFE_NO_PAD.encode(json!({"alg": "HS256", "typ": "JWT"}).to_string());
+        let payload = URL_SAFE_NO_PAD.encode(claims.to_string());
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(format!("{header}.{payload}").as_bytes());
+        let signature = URL_SAFE_NO_PAD.encode(mac.finalize().into_bytes());
+        format!("{header}.{payload}.{signature}")
+    }
+
+    fn app(config: AuthConfig) -> Router {
+        Router::new()
+            .route("/ping", get(|| async { "pong" }))
+            .layer(axum::middleware::from_fn_with_state(config, require_bearer))
+    }
+
+    async fn call(config: AuthConfig, authorization: Option<String>) -> (StatusCode, String) {
+        let mut request = Request::builder().uri("/ping");
+        if let Some(value) = authorization {
+            request = request.header(header::AUTHORIZATION, value);
+        }
+        let response = app(config)
+            .oneshot(request.body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn valid_token_extracts_tenant_and_passes() {
+        let exp = chrono::Utc::now().timestamp() + 60;
+        let token = sign(json!({"tenant_id": SERVED_TENANT_ID, "exp": exp}), SECRET);
+        let authorization = format!("Bearer {token}");
+        let tenant = AuthConfig::hs256(SECRET)
+            .authenticate(Some(&authorization))
+            .unwrap();
+        assert_eq!(tenant.as_str(), SERVED_TENANT_ID);
+        let (status, body) = call(AuthConfig::hs256(SECRET), Some(authorization)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "pong");
+    }
+
+    #[tokio::test]
+    async fn missing_or_invalid_token_is_unauthorized() {
+        let exp = chrono::Utc::now().timestamp() + 60;
+        let expired = sign(json!({"tenant_id": SERVED_TENANT_ID, "exp": 1}), SECRET);
+        let forged = sign(
+            json!({"tenant_id": SERVED_TENANT_ID, "exp": exp}),
+            "other-secret",
+        );
+        let no_tenant = sign(json!({"sub": "u1", "exp": exp}), SECRET);
+        let no_exp = sign(json!({"tenant_id": SERVED_TENANT_ID}), SECRET);
+        for authorization in [
+            None,
+            Some("Basic abc".to_string()),
+            Some("Bearer not-a-jwt".to_string()),
+            Some(format!("Bearer {expired}")),
+            Some(format!("Bearer {forged}")),
+            Some(format!("Bearer {no_tenant}")),
+            Some(format!("Bearer {no_exp}")),
+        ] {
+            let (status, body) = call(AuthConfig::hs256(SECRET), authorization.clone()).await;
+            assert_eq!(status, StatusCode::UNAUTHORIZED, "{authorization:?}");
+            assert!(body.contains("auth.unauthorized"));
+        }
+    }
+
+    #[tokio::test]
+    async fn token_for_other_tenant_is_forbidden() {
+        let exp = chrono::Utc::now().timestamp() + 60;
+        let token = sign(json!({"tenant_id": "acme", "exp": exp}), SECRET);
+        let (status, body) = call(AuthConfig::hs256(SECRET), Some(format!("Bearer {token}"))).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(body.contains("auth.tenant_forbidden"));
+    }
+
+    #[tokio::test]
+    async fn dev_bypass_skips_verification() {
+        let config = AuthConfig::DevBypass {
+            tenant_id: TenantId::new(SERVED_TENANT_ID).unwrap(),
+        };
+        let (status, body) = call(config, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "pong");
+    }
+
+    #[test]
+    fn config_rejects_ambiguous_or_empty_settings() {
+        let some = |value: &str| Some(value.to_string());
+        assert!(AuthConfig::from_values(some(SERVED_TENANT_ID), some(SECRET)).is_err());
+        assert!(AuthConfig::from_values(None, some("  ")).is_err());
+        assert!(AuthConfig::from_values(some("acme"), None).is_err());
+        assert!(AuthConfig::from_values(None, None).is_err());
+        assert!(matches!(
+            AuthConfig::from_values(None, some(SECRET)),
+            Ok(AuthConfig::Hs256 { .. })
+        ));
+        assert!(matches!(
+            AuthConfig::from_values(some(SERVED_TENANT_ID), None),
+            Ok(AuthConfig::DevBypass { .. })
+        ));
+    }
+}
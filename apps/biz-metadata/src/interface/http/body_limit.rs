@@ -0,0 +1,231 @@
+//! 请求体大小限制：写操作默认 1 MiB，批量接口默认 16 MiB，可按路由覆盖；超限返回 413 problem-details。
+
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    extract::{MatchedPath, Request, State},
+    http::{Method, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::interface::http::mapper::HttpError;
+
+/// 默认请求体上限（字节）。
+pub const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
+/// 覆盖默认上限的环境变量名。
+pub const MAX_BODY_BYTES_ENV: &str = "BIZ_METADATA_MAX_BODY_BYTES";
+/// 批量接口的默认请求体上限（字节）。
+pub const DEFAULT_MAX_BULK_BODY_BYTES: usize = 16 * 1024 * 1024;
+/// 覆盖批量接口上限的环境变量名。
+pub const MAX_BULK_BODY_BYTES_ENV: &str = "BIZ_METADATA_MAX_BULK_BODY_BYTES";
+
+/// 请求体大小限制配置。
+///
+/// 路由覆盖按 `(方法, 路由模板)` 匹配，模板与注册路由一致（如 `/biz_metadata/{id}`）。
+///
+/// ```
+/// use axum::http::Method;
+/// use biz_metadata::interface::http::body_limit::BodyLimitConfig;
+///
+/// let config = BodyLimitConfig::default().with_route_limit(Method::POST, "/biz_metadata/import", 16 << 20);
+/// assert_eq!(config.limit_for(&Method::POST, "/biz_metadata/import"), 16 << 20);
+/// assert_eq!(config.limit_for(&Method::POST, "/biz_metadata"), 1 << 20);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BodyLimitConfig {
+    default_limit: usize,
+    bulk_limit: usize,
+    route_limits: Arc<Vec<(Method, String, usize)>>,
+}
+
+impl Default for BodyLimitConfig {
+    fn default() -> Self {
+        Self {
+            default_limit: DEFAULT_MAX_BODY_BYTES,
+            bulk_limit: DEFAULT_MAX_BULK_BODY_BYTES,
+            route_limits: Arc::default(),
+        }
+    }
+}
+
+impl BodyLimitConfig {
+    /// 从环境变量读取默认上限与批量接口上限，未设置时分别使用
+    /// [`DEFAULT_MAX_BODY_BYTES`] 与 [`DEFAULT_MAX_BULK_BODY_BYTES`]。
+    pub fn from_env() -> Result<Self, String> {
+        let read = |name: &str, default: usize| match std::env::var(name) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map_err(|_| format!("{name} 必须是正整数字节数")),
+            Err(_) => Ok(default),
+        };
+        Ok(Self::default()
+            .with_default_limit(read(MAX_BODY_BYTES_ENV, DEFAULT_MAX_BODY_BYTES)?)
+            .with_bulk_limit(read(MAX_BULK_BODY_BYTES_ENV, DEFAULT_MAX_BULK_BODY_BYTES)?))
+    }
+
+    /// 设置默认上限。
+    pub fn with_default_limit(mut self, limit: usize) -> Self {
+        self.default_limit = limit;
+        self
+    }
+
+    /// 设置批量接口的上限，由 [`Self::with_bulk_route`] 注册的路由使用。
+    pub fn with_bulk_limit(mut self, limit: usize) -> Self {
+        self.bulk_limit = limit;
+        self
+    }
+
+    /// 将路由登记为批量接口，使用批量上限；已通过 [`Self::with_route_limit`] 覆盖的路由不受影响。
+    pub fn with_bulk_route(self, method: Method, path: impl Into<String>) -> Self {
+        let limit = self.bulk_limit;
+        self.with_route_limit(method, path, limit)
+    }
+
+    /// 为单个路由设置上限，如批量导入接口。
+    pub fn with_route_limit(
+        mut self,
+        method: Method,
+        path: impl Into<String>,
+        limit: usize,
+    ) -> Self {
+        Arc::make_mut(&mut self.route_limits).push((method, path.into(), limit));
+        self
+    }
+
+    /// 返回给定路由生效的上限。
+    pub fn limit_for(&self, method: &Method, path: &str) -> usize {
+        self.route_limits
+            .iter()
+            .find(|(m, p, _)| m == method && p == path)
+            .map_or(self.default_limit, |(_, _, limit)| *limit)
+    }
+}
+
+/// 请求体限制中间件，需以 `route_layer` 挂载以读取 [`MatchedPath`]。
+///
+/// 仅作用于写操作（POST/PUT/PATCH/DELETE）：先按 `Content-Length` 快速拒绝，
+/// 再以上限读取实际请求体，防止分块传输绕过声明长度。
+pub async fn limit_body(
+    State(config): State<BodyLimitConfig>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    ) {
+        return next.run(request).await;
+    }
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| request.uri().path(), MatchedPath::as_str);
+    let limit = config.limit_for(request.method(), path);
+
+    let declared = request
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if declared.is_some_and(|len| len > limit) {
+        return too_large(limit);
+    }
+
+    let (parts, body) = request.into_parts();
+    match axum::body::to_bytes(body, limit).await {
+        Ok(bytes) => {
+            next.run(Request::from_parts(parts, Body::from(bytes)))
+                .await
+        }
+        Err(_) => too_large(limit),
+    }
+}
+
+fn too_large(limit: usize) -> Response {
+    HttpError::payload_too_large(format!("request body exceeds {limit} bytes"))
+        .into_problem()
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, http::StatusCode, routing::post};
+    use tower::ServiceExt;
+
+    fn app(config: BodyLimitConfig) -> Router {
+        Router::new()
+            .route(
+                "/items",
+                post(|body: String| async move { body.len().to_string() }),
+            )
+            .route(
+                "/items/import",
+                post(|body: String| async move { body.len().to_string() }),
+            )
+            .route_layer(axum::middleware::from_fn_with_state(config, limit_body))
+    }
+
+    async fn send(config: BodyLimitConfig, path: &str, body: Body) -> Response {
+        app(config)
+            .oneshot(
+                Request::builder()
+                    .method(Method::POST)
+                    .uri(path)
+                    .body(body)
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn oversized_body_is_rejected_with_413() {
+        let config = BodyLimitConfig::default().with_default_limit(16);
+
+        let response = send(config.clone(), "/items", Body::from("x".repeat(17))).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            crate::interface::http::mapper::error_mapper::PROBLEM_JSON_CONTENT_TYPE
+        );
+
+        // 无 Content-Length 的流式请求体同样受限。
+        let chunks = futures_util::stream::iter(
+            ["x".repeat(10), "x".repeat(10)].map(Ok::<_, std::io::Error>),
+        );
+        let response = send(config.clone(), "/items", Body::from_stream(chunks)).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+
+        let response = send(config, "/items", Body::from("x".repeat(16))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn route_override_raises_the_limit() {
+        let config = BodyLimitConfig::default()
+            .with_default_limit(16)
+            .with_route_limit(Method::POST, "/items/import", 64);
+
+        let response = send(config.clone(), "/items/import", Body::from("x".repeat(40))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send(config, "/items", Body::from("x".repeat(40))).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn bulk_route_uses_bulk_limit() {
+        let config = BodyLimitConfig::default()
+            .with_default_limit(16)
+            .with_bulk_limit(64)
+            .with_bulk_route(Method::POST, "/items/import");
+
+        let response = send(config.clone(), "/items/import", Body::from("x".repeat(40))).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = send(config, "/items/import", Body::from("x".repeat(65))).await;
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
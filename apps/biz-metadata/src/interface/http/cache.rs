@@ -0,0 +1,219 @@
+//! 详情接口的缓存头策略：生效定义允许客户端私有缓存并支持 `If-Modified-Since` 协商，
+//! 已弃用或已删除的定义一律 `no-store`。
+//!
+//! 响应内容依赖 Bearer Token 所属租户，因此只发 `private` 并声明 `Vary: Authorization`，
+//! 共享代理不会把一个租户的响应返回给另一个租户。
+
+use axum::{
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+
+use crate::domain::biz_metadata::BizMetadata;
+use crate::domain::biz_metadata::value_object::BizMetadataStatus;
+
+/// 覆盖 `max-age`（秒）的环境变量名。
+pub const CACHE_MAX_AGE_ENV: &str = "BIZ_METADATA_CACHE_MAX_AGE_SECONDS";
+/// 默认 `max-age`（秒）。
+pub const DEFAULT_CACHE_MAX_AGE_SECONDS: u64 = 60;
+
+/// HTTP-date（IMF-fixdate）格式。
+const HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+const NO_STORE: HeaderValue = HeaderValue::from_static("no-store");
+const VARY_AUTHORIZATION: HeaderValue = HeaderValue::from_static("authorization");
+
+/// 详情接口缓存配置。
+///
+/// ```
+/// use axum::http::{HeaderMap, StatusCode, header};
+/// use biz_metadata::interface::http::cache::CacheConfig;
+/// use biz_metadata::{BizMetadata, ObjectType, TenantId};
+///
+/// let item = BizMetadata::new_node(
+///     TenantId::new("default").unwrap(),
+///     "company",
+///     "公司",
+///     ObjectType::Entity,
+///     chrono::Utc::now(),
+/// )
+/// .unwrap();
+/// let config = CacheConfig::default().with_max_age_seconds(300);
+/// let response = config.respond(&HeaderMap::new(), &item, || "body");
+/// assert_eq!(response.status(), StatusCode::OK);
+/// assert_eq!(response.headers()[header::CACHE_CONTROL], "private, max-age=300");
+/// assert_eq!(response.headers()[header::VARY], "authorization");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CacheConfig {
+    max_age_seconds: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_age_seconds: DEFAULT_CACHE_MAX_AGE_SECONDS,
+        }
+    }
+}
+
+impl CacheConfig {
+    /// 从环境变量读取 `max-age`，未设置时使用 [`DEFAULT_CACHE_MAX_AGE_SECONDS`]。
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(CACHE_MAX_AGE_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse()
+                .map(|seconds| Self::default().with_max_age_seconds(seconds))
+                .map_err(|_| format!("{CACHE_MAX_AGE_ENV} 必须是非负整数秒数")),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// 设置 `max-age`（秒）。
+    pub fn with_max_age_seconds(mut self, seconds: u64) -> Self {
+        self.max_age_seconds = seconds;
+        self
+    }
+
+    /// 当前 `max-age`（秒）。
+    pub fn max_age_seconds(&self) -> u64 {
+        self.max_age_seconds
+    }
+
+    /// 为详情响应附加缓存头。
+    ///
+    /// 生效定义带 `Cache-Control: private, max-age`、`Vary: Authorization` 与取自 `updated_at` 的 `Last-Modified`，
+    /// 请求的 `If-Modified-Since` 不早于该时间时直接返回 304，不再构造响应体；
+    /// 已弃用或已删除的定义返回 `no-store`，且不参与协商。
+    pub fn respond<T: IntoResponse>(
+        &self,
+        request_headers: &HeaderMap,
+        item: &BizMetadata,
+        body: impl FnOnce() -> T,
+    ) -> Response {
+        if item.status() != BizMetadataStatus::Active || item.delete_at().is_some() {
+            return no_store(body());
+        }
+
+        let last_modified = item.updated_at();
+        let mut response = if not_modified(request_headers, last_modified) {
+            StatusCode::NOT_MODIFIED.into_response()
+        } else {
+            body().into_response()
+        };
+        let headers = response.headers_mut();
+        if let Ok(value) =
+            HeaderValue::from_str(&format!("private, max-age={}", self.max_age_seconds))
+        {
+            headers.insert(header::CACHE_CONTROL, value);
+        }
+        headers.insert(header::VARY, VARY_AUTHORIZATION);
+        if let Ok(value) = HeaderValue::from_str(&http_date(last_modified)) {
+            headers.insert(header::LAST_MODIFIED, value);
+        }
+        response
+    }
+}
+
+/// 为响应附加 `Cache-Control: no-store`，用于弃用、已删除或不存在的定义。
+pub fn no_store(response: impl IntoResponse) -> Response {
+    let mut response = response.into_response();
+    response
+        .headers_mut()
+        .insert(header::CACHE_CONTROL, NO_STORE);
+    response
+}
+
+/// 格式化为 HTTP-date，精度为秒。
+pub fn http_date(at: DateTime<Utc>) -> String {
+    at.format(HTTP_DATE_FORMAT).to_string()
+}
+
+/// `If-Modified-Since` 是否不早于 `last_modified`；HTTP-date 只到秒，比较前截断亚秒部分。
+fn not_modified(request_headers: &HeaderMap, last_modified: DateTime<Utc>) -> bool {
+    let Some(since) = request_headers
+        .get(header::IF_MODIFIED_SINCE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|raw| DateTime::parse_from_rfc2822(raw.trim()).ok())
+    else {
+        return false;
+    };
+    last_modified.timestamp() <= since.timestamp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::value_object::{ObjectType, TenantId};
+    use chrono::{Duration, TimeZone};
+
+    fn node(updated_at: DateTime<Utc>) -> BizMetadata {
+        BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "公司",
+            ObjectType::Entity,
+            updated_at,
+        )
+        .unwrap()
+    }
+
+    fn if_modified_since(at: DateTime<Utc>) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_MODIFIED_SINCE,
+            HeaderValue::from_str(&http_date(at)).unwrap(),
+        );
+        headers
+    }
+
+    #[test]
+    fn matching_if_modified_since_yields_304() {
+        let updated_at =
+            Utc.with_ymd_and_hms(2024, 5, 1, 8, 30, 0).unwrap() + Duration::milliseconds(250);
+        let item = node(updated_at);
+
+        let response =
+            CacheConfig::default().respond(&if_modified_since(updated_at), &item, || -> &str {
+                panic!("body must not be built for 304")
+            });
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(
+            response.headers()[header::LAST_MODIFIED],
+            "Wed, 01 May 2024 08:30:00 GMT"
+        );
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            "private, max-age=60"
+        );
+        assert_eq!(response.headers()[header::VARY], "authorization");
+    }
+
+    #[test]
+    fn newer_updated_at_yields_200() {
+        let since = Utc.with_ymd_and_hms(2024, 5, 1, 8, 30, 0).unwrap();
+        let item = node(since + Duration::seconds(1));
+
+        let response = CacheConfig::default().respond(&if_modified_since(since), &item, || "body");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[header::LAST_MODIFIED],
+            "Wed, 01 May 2024 08:30:01 GMT"
+        );
+    }
+
+    #[test]
+    fn deprecated_definition_is_no_store() {
+        let updated_at = Utc.with_ymd_and_hms(2024, 5, 1, 8, 30, 0).unwrap();
+        let mut item = node(updated_at);
+        item.change_status(BizMetadataStatus::Deprecated, updated_at)
+            .unwrap();
+
+        let response =
+            CacheConfig::default().respond(&if_modified_since(updated_at), &item, || "body");
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CACHE_CONTROL], "no-store");
+        assert!(!response.headers().contains_key(header::LAST_MODIFIED));
+    }
+}
@@ -0,0 +1,211 @@
+//! 可配置的 CORS 策略：默认不放行任何跨域来源，按环境变量显式开放。
+
+use axum::http::{HeaderName, HeaderValue, Method, header};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+/// 允许的来源列表（逗号分隔，如 `https://a.example.com,https://b.example.com`）。
+pub const CORS_ALLOWED_ORIGINS_ENV: &str = "BIZ_METADATA_CORS_ALLOWED_ORIGINS";
+/// 允许的方法列表（逗号分隔），未设置时使用 [`CorsConfig::default`] 的方法集合。
+pub const CORS_ALLOWED_METHODS_ENV: &str = "BIZ_METADATA_CORS_ALLOWED_METHODS";
+/// 允许的请求头列表（逗号分隔），未设置时使用 [`CorsConfig::default`] 的请求头集合。
+pub const CORS_ALLOWED_HEADERS_ENV: &str = "BIZ_METADATA_CORS_ALLOWED_HEADERS";
+
+/// CORS 配置。
+///
+/// 默认来源列表为空，即拒绝所有跨域请求；方法与请求头覆盖现有接口所需的最小集合。
+///
+/// ```
+/// use biz_metadata::interface::http::cors::CorsConfig;
+///
+/// let config = CorsConfig::default().with_allowed_origins(["https://app.example.com"]).unwrap();
+/// assert_eq!(config.allowed_origins().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    allowed_origins: Vec<HeaderValue>,
+    allowed_methods: Vec<Method>,
+    allowed_headers: Vec<HeaderName>,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            allowed_origins: Vec::new(),
+            allowed_methods: vec![
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+            ],
+            allowed_headers: vec![
+                header::AUTHORIZATION,
+                header::CONTENT_TYPE,
+                header::IF_MATCH,
+                header::IF_MODIFIED_SINCE,
+            ],
+        }
+    }
+}
+
+impl CorsConfig {
+    /// 从环境变量读取配置，未设置的项保持默认值。
+    pub fn from_env() -> Result<Self, String> {
+        let mut config = Self::default();
+        if let Ok(raw) = std::env::var(CORS_ALLOWED_ORIGINS_ENV) {
+            config = config.with_allowed_origins(split_list(&raw))?;
+        }
+        if let Ok(raw) = std::env::var(CORS_ALLOWED_METHODS_ENV) {
+            config = config.with_allowed_methods(split_list(&raw))?;
+        }
+        if let Ok(raw) = std::env::var(CORS_ALLOWED_HEADERS_ENV) {
+            config = config.with_allowed_headers(split_list(&raw))?;
+        }
+        Ok(config)
+    }
+
+    /// 设置允许的来源。
+    pub fn with_allowed_origins<I, S>(mut self, origins: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_origins = parse_all(origins, |raw| HeaderValue::from_str(raw).ok())?;
+        Ok(self)
+    }
+
+    /// 设置允许的方法。
+    pub fn with_allowed_methods<I, S>(mut self, methods: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_methods = parse_all(methods, |raw| {
+            Method::from_bytes(raw.to_ascii_uppercase().as_bytes()).ok()
+        })?;
+        Ok(self)
+    }
+
+    /// 设置允许的请求头。
+    pub fn with_allowed_headers<I, S>(mut self, headers: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_headers =
+            parse_all(headers, |raw| HeaderName::from_bytes(raw.as_bytes()).ok())?;
+        Ok(self)
+    }
+
+    /// 当前允许的来源。
+    pub fn allowed_origins(&self) -> &[HeaderValue] {
+        &self.allowed_origins
+    }
+
+    /// 构建 `CorsLayer`；预检请求由该层直接应答，不进入鉴权与业务路由。
+    pub fn layer(&self) -> CorsLayer {
+        CorsLayer::new()
+            .allow_origin(AllowOrigin::list(self.allowed_origins.clone()))
+            .allow_methods(self.allowed_methods.clone())
+            .allow_headers(self.allowed_headers.clone())
+    }
+}
+
+fn split_list(raw: &str) -> Vec<&str> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn parse_all<I, S, T>(items: I, parse: impl Fn(&str) -> Option<T>) -> Result<Vec<T>, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    items
+        .into_iter()
+        .map(|item| {
+            let raw = item.as_ref();
+            parse(raw).ok_or_else(|| format!("invalid CORS entry: {raw}"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, extract::Request, http::StatusCode, routing::get};
+    use tower::ServiceExt;
+
+    const ALLOWED: &str = "https://app.example.com";
+
+    async fn preflight(config: &CorsConfig, origin: &str, path: &str) -> axum::response::Response {
+        let app = Router::new()
+            .route("/biz_metadata", get(|| async { "list" }))
+            .route("/biz_metadata/{id}", get(|| async { "detail" }))
+            .layer(config.layer());
+        app.oneshot(
+            Request::builder()
+                .method(Method::OPTIONS)
+                .uri(path)
+                .header(header::ORIGIN, origin)
+                .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+                .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "authorization")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn preflight_from_allowed_origin_succeeds() {
+        let config = CorsConfig::default()
+            .with_allowed_origins([ALLOWED])
+            .unwrap();
+        for path in ["/biz_metadata", "/biz_metadata/1"] {
+            let response = preflight(&config, ALLOWED, path).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            let headers = response.headers();
+            assert_eq!(headers[header::ACCESS_CONTROL_ALLOW_ORIGIN], ALLOWED);
+            assert!(
+                headers[header::ACCESS_CONTROL_ALLOW_METHODS]
+                    .to_str()
+                    .unwrap()
+                    .contains("GET")
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn preflight_from_other_origin_is_rejected() {
+        for config in [
+            CorsConfig::default(),
+            CorsConfig::default()
+                .with_allowed_origins([ALLOWED])
+                .unwrap(),
+        ] {
+            let response = preflight(&config, "https://evil.example.com", "/biz_metadata").await;
+            assert!(
+                !response
+                    .headers()
+                    .contains_key(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_entries() {
+        assert!(
+            CorsConfig::default()
+                .with_allowed_methods(["GE T"])
+                .is_err()
+        );
+        assert!(
+            CorsConfig::default()
+                .with_allowed_headers(["bad header"])
+                .is_err()
+        );
+    }
+}
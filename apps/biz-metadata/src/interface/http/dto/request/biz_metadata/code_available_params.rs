@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 检查 code 是否可用的查询参数。
+#[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct CodeAvailableParams {
+    /// 待检查的 code。
+    pub code: String,
+    /// 拟挂载的父节点 id；按父节点唯一时只与该父节点下的兄弟节点比较末段。
+    pub parent_id: Option<i64>,
+}
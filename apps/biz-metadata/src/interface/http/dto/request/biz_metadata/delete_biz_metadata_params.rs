@@ -6,4 +6,16 @@ use utoipa::IntoParams;
 pub struct DeleteBizMetadataParams {
     /// 版本号，必须与服务端当前版本一致。
     pub version: i32,
+    /// 存在子节点或 `ref:<code>` 类型引用时的处理方式：`restrict`（默认，拒绝删除）/`deprecate`/`detach`。
+    #[serde(default)]
+    pub dependents: Option<DependentActionParam>,
+}
+
+/// 删除时对依赖方（子节点与类型引用）的处理方式。
+#[derive(Debug, Clone, Copy, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DependentActionParam {
+    Restrict,
+    Deprecate,
+    Detach,
 }
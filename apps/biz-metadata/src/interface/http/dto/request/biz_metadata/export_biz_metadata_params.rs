@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+use super::list_biz_metadata_params::{object_type, status};
+use crate::domain::biz_metadata::value_object::{BizMetadataStatus, ObjectType};
+
+/// BizMetadata 导出的可选过滤参数，均为精确匹配。
+#[derive(Debug, Default, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct ExportBizMetadataParams {
+    /// 可选 code 过滤。
+    pub code: Option<String>,
+    /// 可选对象类型过滤，取值与列表查询相同（大小写不敏感）。
+    #[serde(default, deserialize_with = "object_type")]
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub object_type: Option<ObjectType>,
+    /// 可选 parent_id 过滤。
+    pub parent_id: Option<i64>,
+    /// 可选状态过滤，取值与列表查询相同（大小写不敏感）。
+    #[serde(default, deserialize_with = "status")]
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub status: Option<BizMetadataStatus>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 查询单条 BizMetadata 时的可选参数。
+#[derive(Debug, Default, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct GetBizMetadataParams {
+    /// 逗号分隔的内联展开项，目前仅支持 `parent`：附带父节点的 `id`/`code`/`name`。
+    pub expand: Option<String>,
+}
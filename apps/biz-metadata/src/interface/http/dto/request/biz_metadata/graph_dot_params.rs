@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 层级图 DOT 导出的查询参数。
+#[derive(Debug, Default, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct GraphDotParams {
+    /// 可选根节点 ID，缺省时从所有顶层节点导出。
+    pub root_id: Option<i64>,
+    /// 最大展开深度（根为 0），缺省为 64。
+    pub max_depth: Option<usize>,
+}
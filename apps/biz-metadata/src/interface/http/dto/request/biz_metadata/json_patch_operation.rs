@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde_json::Value;
+use utoipa::ToSchema;
+
+/// RFC 6902 JSON Patch 的单个操作，`path`/`from` 为 JSON Pointer（如 `/name`）。
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum JsonPatchOperation {
+    Add {
+        path: String,
+        #[schema(value_type = Object)]
+        value: Value,
+    },
+    Remove {
+        path: String,
+    },
+    Replace {
+        path: String,
+        #[schema(value_type = Object)]
+        value: Value,
+    },
+    Move {
+        from: String,
+        path: String,
+    },
+    Copy {
+        from: String,
+        path: String,
+    },
+    Test {
+        path: String,
+        #[schema(value_type = Object)]
+        value: Value,
+    },
+}
@@ -1,6 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer, de::Error};
 use utoipa::IntoParams;
 
+use crate::domain::biz_metadata::value_object::{BizMetadataStatus, DataClass, ObjectType};
+
 /// BizMetadata 列表查询的分页与过滤参数。
 #[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
 pub struct BizMetadataListParams {
@@ -12,4 +14,85 @@ pub struct BizMetadataListParams {
     pub code: Option<String>,
     /// 可选 name 过滤。
     pub name: Option<String>,
+    /// 可选对象类型过滤：`entity`/`event`/`relation`/`document`/`feature`。
+    #[serde(default, deserialize_with = "object_type")]
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub object_type: Option<ObjectType>,
+    /// 可选数据分类过滤：`attribute`/`metric`/`text`/`object`/`array`/`identifier`。
+    #[serde(default, deserialize_with = "data_class")]
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub data_class: Option<DataClass>,
+    /// 可选状态过滤：`active`/`deprecated`。
+    #[serde(default, deserialize_with = "status")]
+    #[param(value_type = Option<String>)]
+    #[schema(value_type = Option<String>)]
+    pub status: Option<BizMetadataStatus>,
+    /// 可选字段投影，逗号分隔，例如 `id,code,name`。
+    pub fields: Option<String>,
+    /// 排序字段，逗号分隔，方向与 NULL 位置可选，例如 `name:asc,parent_id:nulls_last`。
+    pub sort: Option<String>,
+    /// `name` 排序的排序规则提示，例如 `pinyin`；数据库不支持时按默认规则排序。
+    pub collation: Option<String>,
+}
+
+pub(crate) fn object_type<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<ObjectType>, D::Error> {
+    parse_enum(
+        deserializer,
+        "object_type",
+        &ObjectType::ALL,
+        ObjectType::as_str,
+    )
+}
+
+fn data_class<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<DataClass>, D::Error> {
+    parse_enum(
+        deserializer,
+        "data_class",
+        &DataClass::ALL,
+        DataClass::as_str,
+    )
+}
+
+pub(crate) fn status<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<BizMetadataStatus>, D::Error> {
+    parse_enum(
+        deserializer,
+        "status",
+        &BizMetadataStatus::ALL,
+        BizMetadataStatus::as_str,
+    )
+}
+
+/// 大小写不敏感地匹配枚举取值，空串视为未提供；非法值的错误信息列出全部允许值。
+fn parse_enum<'de, D, T>(
+    deserializer: D,
+    field: &str,
+    all: &[T],
+    as_str: fn(&T) -> &'static str,
+) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Copy,
+{
+    let Some(raw) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    let raw = raw.trim();
+    if raw.is_empty() {
+        return Ok(None);
+    }
+    all.iter()
+        .find(|value| as_str(value).eq_ignore_ascii_case(raw))
+        .map(|value| Some(*value))
+        .ok_or_else(|| {
+            let allowed = all.iter().map(as_str).collect::<Vec<_>>().join(", ");
+            D::Error::custom(format!(
+                "invalid {field}: {raw}, expected one of: {allowed}"
+            ))
+        })
 }
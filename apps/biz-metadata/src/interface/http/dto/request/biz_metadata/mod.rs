@@ -1,9 +1,23 @@
+pub mod code_available_params;
 pub mod create_biz_metadata_request;
 pub mod delete_biz_metadata_params;
+pub mod export_biz_metadata_params;
+pub mod get_biz_metadata_params;
+pub mod graph_dot_params;
+pub mod json_patch_operation;
 pub mod list_biz_metadata_params;
+pub mod suggest_code_params;
+pub mod touch_biz_metadata_params;
 pub mod update_biz_metadata_request;
 
+pub use code_available_params::CodeAvailableParams;
 pub use create_biz_metadata_request::CreateBizMetadataRequest;
-pub use delete_biz_metadata_params::DeleteBizMetadataParams;
+pub use delete_biz_metadata_params::{DeleteBizMetadataParams, DependentActionParam};
+pub use export_biz_metadata_params::ExportBizMetadataParams;
+pub use get_biz_metadata_params::GetBizMetadataParams;
+pub use graph_dot_params::GraphDotParams;
+pub use json_patch_operation::JsonPatchOperation;
 pub use list_biz_metadata_params::BizMetadataListParams;
+pub use suggest_code_params::SuggestCodeParams;
+pub use touch_biz_metadata_params::TouchBizMetadataParams;
 pub use update_biz_metadata_request::UpdateBizMetadataRequest;
@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 根据名称生成建议 code 的查询参数。
+#[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct SuggestCodeParams {
+    /// 父节点 code，存在时规范化为小写后作为前缀；格式非法时返回 400。
+    pub parent_code: Option<String>,
+    /// 显示名称。
+    pub name: String,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+/// 刷新 BizMetadata 时间戳时需要的参数（乐观锁）。
+#[derive(Debug, Deserialize, IntoParams, utoipa::ToSchema)]
+pub struct TouchBizMetadataParams {
+    /// 版本号，必须与服务端当前版本一致。
+    pub version: i32,
+}
@@ -10,10 +10,10 @@ pub struct UpdateBizMetadataRequest {
     pub name: Option<String>,
     /// 可选描述。
     pub description: Option<Option<String>>,
-    /// `object_type=feature` 时可更新：attribute/metric/text/object/array/identifier。
-    pub data_class: Option<String>,
-    /// 可选值类型。
-    pub value_type: Option<String>,
+    /// `object_type=feature` 时可更新：attribute/metric/text/object/array/identifier；feature 必填，不可置为 `null`。
+    pub data_class: Option<Option<String>>,
+    /// 可选值类型；feature 必填，不可置为 `null`。
+    pub value_type: Option<Option<String>>,
     /// 可选单位。
     pub unit: Option<Option<String>>,
     /// 可选父节点。
@@ -22,4 +22,7 @@ pub struct UpdateBizMetadataRequest {
     pub status: Option<String>,
     /// 可选来源：manual/auto_mine/api_sync。
     pub source: Option<String>,
+    /// 为 `true` 时即使字段均未变化也写入并递增版本号，默认跳过无变化的更新。
+    #[serde(default)]
+    pub force: bool,
 }
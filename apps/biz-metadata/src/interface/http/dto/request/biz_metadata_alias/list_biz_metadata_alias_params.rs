@@ -12,6 +12,6 @@ pub struct BizMetadataAliasListParams {
     pub metadata_id: Option<i64>,
     /// 按别名模糊过滤。
     pub alias: Option<String>,
-    /// 按语言过滤。
+    /// 按语言过滤，`all` 表示全部语言；缺省时使用服务配置的默认语言。
     pub language: Option<String>,
 }
@@ -1,7 +1,9 @@
 pub mod create_biz_metadata_alias_request;
 pub mod list_biz_metadata_alias_params;
+pub mod set_alias_weight_request;
 pub mod update_biz_metadata_alias_request;
 
 pub use create_biz_metadata_alias_request::CreateBizMetadataAliasRequest;
 pub use list_biz_metadata_alias_params::BizMetadataAliasListParams;
+pub use set_alias_weight_request::SetAliasWeightRequest;
 pub use update_biz_metadata_alias_request::UpdateBizMetadataAliasRequest;
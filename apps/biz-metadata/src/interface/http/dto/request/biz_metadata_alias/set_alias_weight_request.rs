@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// 批量设置别名权重请求中的单项。
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct SetAliasWeightRequest {
+    /// 别名 ID。
+    pub id: i64,
+    /// 新的匹配权重，取值范围 0~100。
+    pub weight: i32,
+}
@@ -2,13 +2,18 @@ pub mod biz_metadata;
 pub mod biz_metadata_alias;
 
 pub use biz_metadata::{
+    code_available_params::CodeAvailableParams,
     create_biz_metadata_request::CreateBizMetadataRequest,
     delete_biz_metadata_params::DeleteBizMetadataParams,
-    list_biz_metadata_params::BizMetadataListParams,
+    export_biz_metadata_params::ExportBizMetadataParams,
+    get_biz_metadata_params::GetBizMetadataParams, graph_dot_params::GraphDotParams,
+    json_patch_operation::JsonPatchOperation, list_biz_metadata_params::BizMetadataListParams,
+    suggest_code_params::SuggestCodeParams, touch_biz_metadata_params::TouchBizMetadataParams,
     update_biz_metadata_request::UpdateBizMetadataRequest,
 };
 pub use biz_metadata_alias::{
     create_biz_metadata_alias_request::CreateBizMetadataAliasRequest,
     list_biz_metadata_alias_params::BizMetadataAliasListParams,
+    set_alias_weight_request::SetAliasWeightRequest,
     update_biz_metadata_alias_request::UpdateBizMetadataAliasRequest,
 };
@@ -0,0 +1,39 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::domain::biz_metadata::value_object::{BizMetadataStatus, DataClass, ObjectType, Source};
+
+/// 单个枚举取值。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EnumValueResponse {
+    /// 规范字符串，即请求中应提交的取值。
+    pub value: String,
+}
+
+/// 服务端当前接受的各枚举字段取值，按领域枚举的声明顺序排列。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BizMetadataEnumsResponse {
+    pub object_type: Vec<EnumValueResponse>,
+    pub data_class: Vec<EnumValueResponse>,
+    pub status: Vec<EnumValueResponse>,
+    pub source: Vec<EnumValueResponse>,
+}
+
+impl BizMetadataEnumsResponse {
+    /// 由领域枚举的 `ALL` 常量生成，新增取值无需改动此处。
+    pub fn current() -> Self {
+        Self {
+            object_type: values(ObjectType::ALL.iter().map(ObjectType::as_str)),
+            data_class: values(DataClass::ALL.iter().map(DataClass::as_str)),
+            status: values(BizMetadataStatus::ALL.iter().map(BizMetadataStatus::as_str)),
+            source: values(Source::ALL.iter().map(Source::as_str)),
+        }
+    }
+}
+
+fn values(raw: impl Iterator<Item = &'static str>) -> Vec<EnumValueResponse> {
+    raw.map(|value| EnumValueResponse {
+        value: value.to_string(),
+    })
+    .collect()
+}
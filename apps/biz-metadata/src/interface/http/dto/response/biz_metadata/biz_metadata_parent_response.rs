@@ -0,0 +1,21 @@
+use crate::domain::biz_metadata::BizMetadata;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// `expand=parent` 时内联返回的父节点摘要。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BizMetadataParentResponse {
+    pub id: i64,
+    pub code: String,
+    pub name: String,
+}
+
+impl From<&BizMetadata> for BizMetadataParentResponse {
+    fn from(src: &BizMetadata) -> Self {
+        Self {
+            id: src.id().value(),
+            code: src.code().as_str().to_string(),
+            name: src.name().as_str().to_string(),
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+use utoipa::ToSchema;
+
+/// 按 `fields` 参数裁剪后的 BizMetadata 响应项，仅包含请求的字段。
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(transparent)]
+#[schema(value_type = Object)]
+pub struct BizMetadataProjection(pub Map<String, Value>);
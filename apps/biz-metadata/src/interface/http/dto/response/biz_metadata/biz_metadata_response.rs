@@ -1,3 +1,4 @@
+use super::BizMetadataParentResponse;
 use crate::domain::biz_metadata::BizMetadata;
 use serde::Serialize;
 use utoipa::ToSchema;
@@ -25,6 +26,9 @@ pub struct BizMetadataResponse {
     pub created_at: String,
     pub updated_at: String,
     pub deleted_at: Option<String>,
+    /// 父节点摘要，仅在详情接口指定 `expand=parent` 且父节点存活时返回。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<BizMetadataParentResponse>,
 }
 
 impl From<BizMetadata> for BizMetadataResponse {
@@ -45,6 +49,7 @@ impl From<BizMetadata> for BizMetadataResponse {
             created_at: src.created_at().to_rfc3339(),
             updated_at: src.updated_at().to_rfc3339(),
             deleted_at: src.delete_at().map(|d| d.to_rfc3339()),
+            parent: None,
         }
     }
 }
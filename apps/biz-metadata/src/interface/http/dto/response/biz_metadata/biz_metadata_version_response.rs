@@ -0,0 +1,11 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 当前版本号的响应载荷，供读-改-写前获取乐观锁版本。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BizMetadataVersionResponse {
+    /// BizMetadata ID。
+    pub id: i64,
+    /// 当前版本号（乐观锁）。
+    pub version: i32,
+}
@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// code 可用性检查的响应载荷。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CodeAvailableResponse {
+    /// 同租户下没有存活记录占用该 code 时为 `true`；仅被已软删除记录使用的 code 视为可用。
+    pub available: bool,
+}
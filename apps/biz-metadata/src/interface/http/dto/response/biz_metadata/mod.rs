@@ -1,3 +1,15 @@
+pub mod biz_metadata_enums_response;
+pub mod biz_metadata_parent_response;
+pub mod biz_metadata_projection;
 pub mod biz_metadata_response;
+pub mod biz_metadata_version_response;
+pub mod code_available_response;
+pub mod suggest_code_response;
 
+pub use biz_metadata_enums_response::{BizMetadataEnumsResponse, EnumValueResponse};
+pub use biz_metadata_parent_response::BizMetadataParentResponse;
+pub use biz_metadata_projection::BizMetadataProjection;
 pub use biz_metadata_response::BizMetadataResponse;
+pub use biz_metadata_version_response::BizMetadataVersionResponse;
+pub use code_available_response::CodeAvailableResponse;
+pub use suggest_code_response::SuggestCodeResponse;
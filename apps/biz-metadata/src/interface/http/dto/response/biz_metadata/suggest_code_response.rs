@@ -0,0 +1,9 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 建议 code 的响应载荷。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SuggestCodeResponse {
+    /// 满足编码格式的建议 code。
+    pub code: String,
+}
@@ -2,12 +2,18 @@ pub mod biz_metadata;
 pub mod biz_metadata_alias;
 pub mod empty_payload;
 pub mod page_result_response;
+pub mod problem_details;
 pub mod result_response;
 
-pub use biz_metadata::BizMetadataResponse;
+pub use biz_metadata::{
+    BizMetadataEnumsResponse, BizMetadataParentResponse, BizMetadataProjection,
+    BizMetadataResponse, BizMetadataVersionResponse, CodeAvailableResponse, EnumValueResponse,
+    SuggestCodeResponse,
+};
 pub use biz_metadata_alias::BizMetadataAliasResponse;
 pub use empty_payload::EmptyPayload;
-pub use page_result_response::PageResultResponse;
+pub use page_result_response::{PageLinks, PageResultResponse};
+pub use problem_details::ProblemDetails;
 pub use result_response::ResultResponse;
 
 /// 统一响应类型别名，便于 OpenAPI 声明。
@@ -19,6 +19,67 @@ where
     pub page_size: u64,
     /// 页索引起始值。
     pub index_from: u64,
+    /// 总页数，总数未统计时为空。
+    pub total_pages: Option<u64>,
+    /// 是否存在下一页。
+    pub has_next: bool,
+    /// 是否存在上一页。
+    pub has_prev: bool,
+    /// 翻页导航链接，由列表接口按当前请求生成。
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links: Option<PageLinks>,
+}
+
+/// 分页导航链接，保留原请求中的过滤与排序参数，仅替换 `limit/offset`。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+pub struct PageLinks {
+    /// 第一页。
+    pub first: String,
+    /// 上一页，当前为第一页时为空。
+    pub prev: Option<String>,
+    /// 下一页，当前为最后一页时为空。
+    pub next: Option<String>,
+    /// 最后一页。
+    pub last: String,
+}
+
+impl PageLinks {
+    /// 基于请求路径、原始查询串与分页信息生成导航链接。
+    pub fn build(
+        path: &str,
+        query: Option<&str>,
+        page_index: u64,
+        page_size: u64,
+        index_from: u64,
+        total_count: u64,
+    ) -> Self {
+        let page_size = page_size.max(1);
+        let current = page_index.saturating_sub(index_from);
+        let last_page = total_count.div_ceil(page_size).saturating_sub(1);
+        let link = |page: u64| Self::link(path, query, page_size, page * page_size);
+
+        Self {
+            first: link(0),
+            prev: (current > 0).then(|| link((current - 1).min(last_page))),
+            next: (current < last_page).then(|| link(current + 1)),
+            last: link(last_page),
+        }
+    }
+
+    fn link(path: &str, query: Option<&str>, limit: u64, offset: u64) -> String {
+        let mut pairs: Vec<String> = query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| {
+                let key = pair.split('=').next().unwrap_or_default();
+                !pair.is_empty() && key != "limit" && key != "offset"
+            })
+            .map(str::to_string)
+            .collect();
+        pairs.push(format!("limit={limit}"));
+        pairs.push(format!("offset={offset}"));
+        format!("{path}?{}", pairs.join("&"))
+    }
 }
 
 impl<T> PageResultResponse<T>
@@ -32,7 +93,94 @@ where
             page_index: page.page_index(),
             page_size: page.page_size(),
             index_from: page.index_from(),
+            total_pages: page.total().map(|_| page.total_pages()),
+            has_next: page.has_next(),
+            has_prev: page.has_prev(),
             items: page.into_items(),
+            links: None,
         }
     }
+
+    /// 按请求路径与查询串附加翻页链接。
+    pub fn with_links(mut self, path: &str, query: Option<&str>) -> Self {
+        self.links = Some(PageLinks::build(
+            path,
+            query,
+            self.page_index,
+            self.page_size,
+            self.index_from,
+            self.total_count,
+        ));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PATH: &str = "/biz_metadata";
+
+    #[test]
+    fn middle_page_links_keep_filters() {
+        let links = PageLinks::build(PATH, Some("code=company&limit=10&offset=10"), 1, 10, 0, 35);
+        assert_eq!(links.first, "/biz_metadata?code=company&limit=10&offset=0");
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/biz_metadata?code=company&limit=10&offset=0")
+        );
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/biz_metadata?code=company&limit=10&offset=20")
+        );
+        assert_eq!(links.last, "/biz_metadata?code=company&limit=10&offset=30");
+    }
+
+    #[test]
+    fn first_page_has_no_prev() {
+        let links = PageLinks::build(PATH, None, 0, 10, 0, 35);
+        assert!(links.prev.is_none());
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/biz_metadata?limit=10&offset=10")
+        );
+    }
+
+    #[test]
+    fn last_page_has_no_next() {
+        let links = PageLinks::build(PATH, Some("name=x"), 3, 10, 0, 35);
+        assert!(links.next.is_none());
+        assert_eq!(links.last, "/biz_metadata?name=x&limit=10&offset=30");
+    }
+
+    #[test]
+    fn from_page_surfaces_navigation_flags() {
+        let first = PageResultResponse::from_page(PageResult::new(vec![1, 2], 5, 0, Some(2), None));
+        assert_eq!(
+            (first.has_prev, first.has_next, first.total_pages),
+            (false, true, Some(3))
+        );
+
+        let middle =
+            PageResultResponse::from_page(PageResult::new(vec![3, 4], 5, 1, Some(2), None));
+        assert_eq!((middle.has_prev, middle.has_next), (true, true));
+
+        let last = PageResultResponse::from_page(PageResult::new(vec![5], 5, 2, Some(2), None));
+        assert_eq!((last.has_prev, last.has_next), (true, false));
+
+        let unknown =
+            PageResultResponse::from_page(PageResult::without_total(vec![1, 2], 0, Some(2), None));
+        assert_eq!(
+            (unknown.has_prev, unknown.has_next, unknown.total_pages),
+            (false, true, None)
+        );
+    }
+
+    #[test]
+    fn empty_result_points_to_first_page() {
+        let links = PageLinks::build(PATH, None, 0, 20, 0, 0);
+        assert!(links.prev.is_none());
+        assert!(links.next.is_none());
+        assert_eq!(links.first, links.last);
+    }
 }
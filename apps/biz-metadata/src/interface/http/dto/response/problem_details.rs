@@ -0,0 +1,18 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// RFC 7807 problem-details 错误响应体，以 `application/problem+json` 返回。
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProblemDetails {
+    /// 标识错误类别的稳定 URI。
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    /// 错误类别的简短描述。
+    pub title: String,
+    /// HTTP 状态码。
+    pub status: u16,
+    /// 具体错误信息，措辞可能调整。
+    pub detail: String,
+    /// 稳定的机器可读错误码，取值见 `error_code` 模块。
+    pub code: String,
+}
@@ -0,0 +1,213 @@
+//! 响应 JSON 字段命名策略：默认保持 DTO 的蛇形命名，按部署可切换为驼峰命名。
+//!
+//! DTO 仍以蛇形命名派生 `Serialize`，驼峰命名由路由层中间件在响应写出前统一改写对象键，
+//! 使同一套 DTO 同时服务两类客户端。NDJSON 等流式响应不经改写。
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{Request, State},
+    http::{StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/// 选择字段命名策略的环境变量名，取值 `snake_case`/`camel_case`。
+pub const FIELD_NAMING_ENV: &str = "BIZ_METADATA_FIELD_NAMING";
+
+/// 响应 JSON 对象键的命名策略。
+///
+/// ```
+/// use biz_metadata::interface::http::field_naming::FieldNaming;
+///
+/// let value = serde_json::json!({ "object_type": "entity", "items": [{ "parent_id": 1 }] });
+/// assert_eq!(FieldNaming::default().rename(value.clone()), value);
+/// assert_eq!(
+///     FieldNaming::CamelCase.rename(value),
+///     serde_json::json!({ "objectType": "entity", "items": [{ "parentId": 1 }] })
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FieldNaming {
+    /// 与 DTO 字段名一致的蛇形命名，如 `object_type`。
+    #[default]
+    SnakeCase,
+    /// 首段小写、其余段首字母大写的驼峰命名，如 `objectType`。
+    CamelCase,
+}
+
+impl FieldNaming {
+    /// 从环境变量读取策略，未设置时为 [`FieldNaming::SnakeCase`]。
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(FIELD_NAMING_ENV) {
+            Ok(raw) => Self::parse(&raw)
+                .ok_or_else(|| format!("{FIELD_NAMING_ENV} 必须是 snake_case/camel_case 之一")),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// 解析策略名称，大小写不敏感。
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "snake_case" | "snake" => Some(Self::SnakeCase),
+            "camel_case" | "camelcase" | "camel" => Some(Self::CamelCase),
+            _ => None,
+        }
+    }
+
+    /// 按策略序列化 `value`。
+    pub fn to_value<T: Serialize>(&self, value: &T) -> serde_json::Result<Value> {
+        serde_json::to_value(value).map(|value| self.rename(value))
+    }
+
+    /// 按策略递归改写 JSON 中全部对象的键，取值保持不变。
+    pub fn rename(&self, value: Value) -> Value {
+        match (self, value) {
+            (Self::SnakeCase, value) => value,
+            (Self::CamelCase, Value::Object(map)) => Value::Object(
+                map.into_iter()
+                    .map(|(key, value)| (camel_case(&key), self.rename(value)))
+                    .collect::<Map<_, _>>(),
+            ),
+            (Self::CamelCase, Value::Array(items)) => {
+                Value::Array(items.into_iter().map(|item| self.rename(item)).collect())
+            }
+            (Self::CamelCase, value) => value,
+        }
+    }
+}
+
+/// 路由中间件：按 [`FieldNaming`] 改写 JSON 与 problem-details 响应体的对象键。
+pub async fn apply_field_naming(
+    State(naming): State<FieldNaming>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let response = next.run(request).await;
+    if naming == FieldNaming::SnakeCase || !is_json(&response) {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    // 无法解析的响应体原样返回。
+    let body = match serde_json::from_slice::<Value>(&bytes) {
+        Ok(value) => serde_json::to_vec(&naming.rename(value)).map_or(bytes, Bytes::from),
+        Err(_) => bytes,
+    };
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
+
+fn is_json(response: &Response) -> bool {
+    response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| {
+            value.starts_with("application/json") || value.starts_with("application/problem+json")
+        })
+}
+
+fn camel_case(key: &str) -> String {
+    let mut out = String::with_capacity(key.len());
+    for (index, segment) in key.split('_').enumerate() {
+        let mut chars = segment.chars();
+        match chars.next() {
+            Some(first) if index > 0 => {
+                out.push(first.to_ascii_uppercase());
+                out.push_str(chars.as_str());
+            }
+            _ => out.push_str(segment),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::BizMetadata;
+    use crate::domain::biz_metadata::value_object::{DataClass, TenantId, ValueType};
+    use crate::interface::http::dto::response::BizMetadataResponse;
+    use axum::{Json, Router, routing::get};
+    use tower::ServiceExt;
+
+    fn response() -> BizMetadataResponse {
+        BizMetadataResponse::from(
+            BizMetadata::new_feature(
+                TenantId::new("default").unwrap(),
+                "company.revenue",
+                "营收",
+                DataClass::Metric,
+                ValueType::new("decimal").unwrap(),
+                chrono::Utc::now(),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[test]
+    fn biz_metadata_response_serializes_in_both_namings() {
+        let snake = FieldNaming::SnakeCase.to_value(&response()).unwrap();
+        assert_eq!(snake["object_type"], "feature");
+        assert_eq!(snake["value_type"], "decimal");
+        assert!(snake.get("objectType").is_none());
+
+        let camel = FieldNaming::CamelCase.to_value(&response()).unwrap();
+        assert_eq!(camel["objectType"], "feature");
+        assert_eq!(camel["valueType"], "decimal");
+        assert_eq!(camel["dataClass"], "metric");
+        assert!(camel.get("object_type").is_none());
+        assert!(camel.get("createdAt").is_some());
+    }
+
+    #[test]
+    fn parse_accepts_known_names_only() {
+        assert_eq!(
+            FieldNaming::parse("camelCase"),
+            Some(FieldNaming::CamelCase)
+        );
+        assert_eq!(
+            FieldNaming::parse(" snake_case "),
+            Some(FieldNaming::SnakeCase)
+        );
+        assert_eq!(FieldNaming::parse("kebab"), None);
+    }
+
+    #[tokio::test]
+    async fn middleware_rewrites_json_bodies_only() {
+        let app = |naming| {
+            Router::new()
+                .route("/item", get(|| async { Json(response()) }))
+                .route("/text", get(|| async { "plain_text" }))
+                .route_layer(axum::middleware::from_fn_with_state(
+                    naming,
+                    apply_field_naming,
+                ))
+        };
+        let fetch = |naming, path: &'static str| async move {
+            let response = app(naming)
+                .oneshot(Request::get(path).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap()
+        };
+
+        let body: Value =
+            serde_json::from_slice(&fetch(FieldNaming::CamelCase, "/item").await).unwrap();
+        assert_eq!(body["objectType"], "feature");
+        let body: Value =
+            serde_json::from_slice(&fetch(FieldNaming::SnakeCase, "/item").await).unwrap();
+        assert_eq!(body["object_type"], "feature");
+        assert_eq!(
+            &fetch(FieldNaming::CamelCase, "/text").await[..],
+            b"plain_text"
+        );
+    }
+}
@@ -1,25 +1,41 @@
 use axum::{
     Json,
-    extract::{Path, Query, State},
-    http::{HeaderValue, StatusCode},
+    body::{Body, Bytes},
+    extract::{OriginalUri, Path, Query, State, rejection::QueryRejection},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
 };
 
+use crate::domain::biz_metadata::code::{is_valid_segment, suggest_from_name};
 use crate::domain::biz_metadata::value_object::BizMetadataId;
+use crate::domain::error_code;
 use crate::interface::http::{
+    cache::no_store,
     dto::{
         request::{
-            BizMetadataListParams, CreateBizMetadataRequest, DeleteBizMetadataParams,
+            BizMetadataListParams, CodeAvailableParams, CreateBizMetadataRequest,
+            DeleteBizMetadataParams, ExportBizMetadataParams, GetBizMetadataParams, GraphDotParams,
+            JsonPatchOperation, SuggestCodeParams, TouchBizMetadataParams,
             UpdateBizMetadataRequest,
         },
-        response::{BizMetadataResponse, EmptyPayload, PageResultResponse, ResultResponse},
+        response::{
+            BizMetadataEnumsResponse, BizMetadataResponse, BizMetadataVersionResponse,
+            CodeAvailableResponse, PageResultResponse, ProblemDetails, ResultResponse,
+            SuggestCodeResponse,
+        },
     },
-    error::{ApiError, from_domain_err, not_found, to_api_error},
+    error::{ApiError, from_domain_err, gone, not_found, to_api_error},
     mapper::{BizMetadataDtoMapper, HttpError},
+    ndjson::{NDJSON_CONTENT_TYPE, NDJSON_EXPORT_BATCH_SIZE, biz_metadata_ndjson_stream},
 };
 
 use crate::interface::http::state::AppState;
 
 pub(crate) const BIZ_METADATA_CONTEXT: &str = "/biz_metadata";
+/// GraphViz DOT 的 `Content-Type`。
+const DOT_CONTENT_TYPE: &str = "text/vnd.graphviz; charset=utf-8";
+/// 层级图导出的默认最大深度。
+const GRAPH_DOT_DEFAULT_MAX_DEPTH: usize = 64;
 
 #[utoipa::path(
     post,
@@ -28,8 +44,9 @@ pub(crate) const BIZ_METADATA_CONTEXT: &str = "/biz_metadata";
     request_body = CreateBizMetadataRequest,
     responses(
         (status = 201, body = ResultResponse<BizMetadataResponse>, description = "Created, Location header set"),
-        (status = 400, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json", description = "编码或 ID 与存活记录冲突"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata"
 )]
@@ -61,6 +78,7 @@ pub async fn create_biz_metadata(
         [(axum::http::header::LOCATION, location_header)],
         Json(ResultResponse::ok(BizMetadataDtoMapper::map_to_response(
             created,
+            state.enum_casing,
         ))),
     ))
 }
@@ -75,9 +93,10 @@ pub async fn create_biz_metadata(
     ),
     responses(
         (status = 200, body = ResultResponse<BizMetadataResponse>, description = "Updated"),
-        (status = 400, body = ResultResponse<EmptyPayload>),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json", description = "编码或 ID 与存活记录冲突"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata"
 )]
@@ -95,40 +114,142 @@ pub async fn update_biz_metadata(
         .map_err(from_domain_err)?;
 
     Ok(Json(ResultResponse::ok(
-        BizMetadataDtoMapper::map_to_response(updated),
+        BizMetadataDtoMapper::map_to_response(updated, state.enum_casing),
     )))
 }
 
+/// JSON Patch 请求体的 `Content-Type`。
+const JSON_PATCH_CONTENT_TYPE: &str = "application/json-patch+json";
+
 #[utoipa::path(
-    get,
+    patch,
     context_path = BIZ_METADATA_CONTEXT,
     path = "/{id}",
+    request_body(content = Vec<JsonPatchOperation>, content_type = "application/json-patch+json"),
     params(
-        ("id" = i64, Path, description = "BizMetadata ID")
+        ("id" = i64, Path, description = "BizMetadata ID"),
+        ("If-Match" = Option<i32>, Header, description = "当前版本号；缺省时需通过 /version 操作提供")
     ),
     responses(
-        (status = 200, body = ResultResponse<BizMetadataResponse>),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 200, body = ResultResponse<BizMetadataResponse>, description = "Patched"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata"
 )]
-/// 按 ID 查询单条业务元数据定义。
-pub async fn get_biz_metadata(
+/// 以 JSON Patch（RFC 6902）局部更新指定业务元数据（乐观锁）。
+pub async fn patch_biz_metadata(
     State(state): State<AppState>,
     Path(id): Path<i64>,
+    headers: HeaderMap,
+    body: Bytes,
 ) -> Result<Json<ResultResponse<BizMetadataResponse>>, ApiError> {
+    let content_type = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    if !content_type.starts_with(JSON_PATCH_CONTENT_TYPE) {
+        return Err(to_api_error(HttpError::bad_request(format!(
+            "content-type must be {JSON_PATCH_CONTENT_TYPE}"
+        ))));
+    }
+    let ops: Vec<JsonPatchOperation> = serde_json::from_slice(&body)
+        .map_err(|err| to_api_error(HttpError::bad_request(err.to_string())))?;
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .map(|raw| raw.trim().trim_start_matches("W/").trim_matches('"'))
+                .and_then(|raw| raw.parse::<i32>().ok())
+                .ok_or_else(|| to_api_error(HttpError::bad_request("invalid If-Match header")))
+        })
+        .transpose()?;
+
     let service = &state.biz_metadata_service;
-    let found = service
+    let current = service
         .find_biz_metadata_by_id(BizMetadataId::new(id))
         .await
         .map_err(from_domain_err)?
         .ok_or_else(|| not_found("biz_metadata not found"))?;
+    let cmd = BizMetadataDtoMapper::map_patch_to_update_command(current, if_match, &ops)
+        .map_err(to_api_error)?;
+    let updated = service
+        .update_biz_metadata(cmd)
+        .await
+        .map_err(from_domain_err)?;
+
     Ok(Json(ResultResponse::ok(
-        BizMetadataDtoMapper::map_to_response(found),
+        BizMetadataDtoMapper::map_to_response(updated, state.enum_casing),
     )))
 }
 
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/{id}",
+    params(
+        ("id" = i64, Path, description = "BizMetadata ID"),
+        ("If-Modified-Since" = Option<String>, Header, description = "HTTP-date；定义自该时间起未修改时返回 304"),
+        GetBizMetadataParams
+    ),
+    responses(
+        (status = 200, body = ResultResponse<BizMetadataResponse>, description = "生效定义带 Cache-Control private, max-age、Vary: Authorization 与 Last-Modified，弃用定义或展开父节点的响应为 no-store"),
+        (status = 304, description = "Not Modified"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json", description = "未知的 expand 项"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json", description = "ID 从未存在"),
+        (status = 410, body = ProblemDetails, content_type = "application/problem+json", description = "定义已被软删除"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 按 ID 查询单条业务元数据定义，支持基于 `Last-Modified` 的条件请求；已软删除的定义返回 410。
+///
+/// `expand=parent` 时内联父节点的 `id`/`code`/`name`；`Last-Modified` 无法反映父节点变化，
+/// 展开后的响应不做条件请求与缓存。
+pub async fn get_biz_metadata(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(params): Query<GetBizMetadataParams>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let expand_parent = params
+        .expand
+        .as_deref()
+        .map(BizMetadataDtoMapper::parse_expand_parent)
+        .transpose()
+        .map_err(to_api_error)?
+        .unwrap_or(false);
+    let service = &state.biz_metadata_service;
+    let Some(found) = service
+        .find_by_id_including_deleted(BizMetadataId::new(id))
+        .await
+        .map_err(from_domain_err)?
+    else {
+        return Ok(no_store(not_found("biz_metadata not found")));
+    };
+    if found.is_deleted() {
+        return Ok(no_store(gone("biz_metadata has been deleted")));
+    }
+    if expand_parent {
+        let parent = service.find_parent(&found).await.map_err(from_domain_err)?;
+        let response = BizMetadataDtoMapper::map_to_response(found, state.enum_casing);
+        let response = match parent {
+            Some(parent) => BizMetadataDtoMapper::with_parent(response, &parent),
+            None => response,
+        };
+        return Ok(no_store(Json(ResultResponse::ok(response))));
+    }
+    Ok(state.cache.respond(&headers, &found, || {
+        Json(ResultResponse::ok(BizMetadataDtoMapper::map_to_response(
+            found.clone(),
+            state.enum_casing,
+        )))
+    }))
+}
+
 #[utoipa::path(
     delete,
     context_path = BIZ_METADATA_CONTEXT,
@@ -139,12 +260,13 @@ pub async fn get_biz_metadata(
     ),
     responses(
         (status = 204, description = "Deleted"),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json", description = "版本非法，或仍有子节点且 dependents=restrict"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata"
 )]
-/// 基于版本号删除（软删）指定业务元数据定义。
+/// 基于版本号删除（软删）指定业务元数据定义并级联软删其别名，`dependents` 控制存在子节点时的处理方式。
 pub async fn delete_biz_metadata(
     State(state): State<AppState>,
     Path(id): Path<i64>,
@@ -152,14 +274,118 @@ pub async fn delete_biz_metadata(
 ) -> Result<StatusCode, ApiError> {
     let version = crate::domain::biz_metadata::value_object::Version::new(params.version)
         .map_err(|e| to_api_error(HttpError::bad_request(e.to_string())))?;
+    let dependents = BizMetadataDtoMapper::map_to_dependent_action(params.dependents);
     state
         .biz_metadata_service
-        .delete_biz_metadata(BizMetadataId::new(id), version)
+        .delete_biz_metadata(BizMetadataId::new(id), version, dependents)
         .await
         .map_err(from_domain_err)?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/{id}/touch",
+    params(
+        ("id" = i64, Path, description = "BizMetadata ID"),
+        TouchBizMetadataParams
+    ),
+    responses(
+        (status = 200, body = ResultResponse<BizMetadataResponse>, description = "Touched"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json", description = "版本非法或冲突"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 不改动数据，仅基于版本号推进 `updated_at` 与版本，用于触发下游按时间戳重新同步或索引。
+pub async fn touch_biz_metadata(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Query(params): Query<TouchBizMetadataParams>,
+) -> Result<Json<ResultResponse<BizMetadataResponse>>, ApiError> {
+    let version = crate::domain::biz_metadata::value_object::Version::new(params.version)
+        .map_err(|e| to_api_error(HttpError::bad_request(e.to_string())))?;
+    let touched = state
+        .biz_metadata_service
+        .touch_biz_metadata(BizMetadataId::new(id), version)
+        .await
+        .map_err(from_domain_err)?;
+    Ok(Json(ResultResponse::ok(
+        BizMetadataDtoMapper::map_to_response(touched, state.enum_casing),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/{id}/version",
+    params(
+        ("id" = i64, Path, description = "BizMetadata ID")
+    ),
+    responses(
+        (status = 200, body = ResultResponse<BizMetadataVersionResponse>),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json", description = "不存在或已软删除"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 只返回当前版本号，供读-改-写前获取乐观锁版本，避免加载完整定义。
+pub async fn get_biz_metadata_version(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ResultResponse<BizMetadataVersionResponse>>, ApiError> {
+    let version = state
+        .biz_metadata_service
+        .find_version(BizMetadataId::new(id))
+        .await
+        .map_err(from_domain_err)?
+        .ok_or_else(|| not_found("biz_metadata not found"))?;
+    Ok(Json(ResultResponse::ok(BizMetadataVersionResponse {
+        id,
+        version: version.into(),
+    })))
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/{id}/path",
+    params(
+        ("id" = i64, Path, description = "BizMetadata ID")
+    ),
+    responses(
+        (status = 200, body = ResultResponse<Vec<BizMetadataResponse>>, description = "根节点在前，节点自身在末尾"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json", description = "父节点链成环或过深"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 返回从根节点到指定节点的祖先路径，供面包屑展示。
+pub async fn get_biz_metadata_path(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+) -> Result<Json<ResultResponse<Vec<BizMetadataResponse>>>, ApiError> {
+    let path = match state
+        .biz_metadata_service
+        .ancestor_path(BizMetadataId::new(id))
+        .await
+    {
+        Ok(path) => path,
+        Err(err) if err.code() == error_code::BIZ_METADATA_NOT_FOUND => {
+            return Err(not_found("biz_metadata not found"));
+        }
+        Err(err) => return Err(from_domain_err(err)),
+    };
+    Ok(Json(ResultResponse::ok(
+        path.into_iter()
+            .map(|node| BizMetadataDtoMapper::map_to_response(node, state.enum_casing))
+            .collect(),
+    )))
+}
+
 #[utoipa::path(
     get,
     context_path = BIZ_METADATA_CONTEXT,
@@ -168,17 +394,27 @@ pub async fn delete_biz_metadata(
         BizMetadataListParams
     ),
     responses(
-        (status = 200, body = ResultResponse<PageResultResponse<BizMetadataResponse>>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 200, body = ResultResponse<PageResultResponse<BizMetadataResponse>>, description = "指定 fields 时 items 仅包含所选字段"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata"
 )]
-/// 分页查询业务元数据定义列表。
+/// 分页查询业务元数据定义列表，支持通过 `fields` 投影返回字段、`sort`/`collation` 指定排序。
 pub async fn list_biz_metadata(
     State(state): State<AppState>,
-    Query(params): Query<BizMetadataListParams>,
-) -> Result<Json<ResultResponse<PageResultResponse<BizMetadataResponse>>>, ApiError> {
-    let query = BizMetadataDtoMapper::map_to_query_request(params);
+    OriginalUri(uri): OriginalUri,
+    params: Result<Query<BizMetadataListParams>, QueryRejection>,
+) -> Result<Response, ApiError> {
+    let Query(params) =
+        params.map_err(|rejection| to_api_error(HttpError::bad_request(rejection.body_text())))?;
+    let fields = params
+        .fields
+        .as_deref()
+        .map(BizMetadataDtoMapper::parse_fields)
+        .transpose()
+        .map_err(to_api_error)?;
+    let query = BizMetadataDtoMapper::map_to_query_request(params).map_err(to_api_error)?;
 
     let page = state
         .biz_metadata_service
@@ -186,6 +422,382 @@ pub async fn list_biz_metadata(
         .await
         .map_err(from_domain_err)?;
 
-    let resp_page = BizMetadataDtoMapper::map_to_page_response(page);
-    Ok(Json(ResultResponse::ok(resp_page)))
+    let resp_page = BizMetadataDtoMapper::map_to_page_response(page, state.enum_casing)
+        .with_links(uri.path(), uri.query());
+    match fields {
+        Some(fields) => {
+            let projected =
+                BizMetadataDtoMapper::map_to_projected_page_response(resp_page, &fields)
+                    .map_err(to_api_error)?;
+            Ok(Json(ResultResponse::ok(projected)).into_response())
+        }
+        None => Ok(Json(ResultResponse::ok(resp_page)).into_response()),
+    }
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/suggest_code",
+    params(
+        SuggestCodeParams
+    ),
+    responses(
+        (status = 200, body = ResultResponse<SuggestCodeResponse>),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 根据显示名称（可选父 code）生成满足编码格式的建议 code。
+pub async fn suggest_biz_metadata_code(
+    Query(params): Query<SuggestCodeParams>,
+) -> Result<Json<ResultResponse<SuggestCodeResponse>>, ApiError> {
+    if params.name.trim().is_empty() {
+        return Err(to_api_error(HttpError::bad_request(
+            "name must not be empty",
+        )));
+    }
+    if let Some(parent) = params
+        .parent_code
+        .as_deref()
+        .map(str::trim)
+        .filter(|parent| !parent.is_empty())
+        && !parent.to_lowercase().split('.').all(is_valid_segment)
+    {
+        return Err(to_api_error(HttpError::bad_request(
+            "parent_code must be dot-separated [a-z][a-z0-9_]* segments",
+        )));
+    }
+    let code = suggest_from_name(params.parent_code.as_deref(), &params.name);
+    Ok(Json(ResultResponse::ok(SuggestCodeResponse { code })))
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/code_available",
+    params(
+        CodeAvailableParams
+    ),
+    responses(
+        (status = 200, body = ResultResponse<CodeAvailableResponse>),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 检查 code 能否用于新建：仅被已软删除记录使用的 code 视为可用，与插入时的唯一约束一致；
+/// 传入 `parent_id` 时按创建该父节点下子节点的唯一性规则判断。
+pub async fn check_biz_metadata_code_available(
+    State(state): State<AppState>,
+    Query(params): Query<CodeAvailableParams>,
+) -> Result<Json<ResultResponse<CodeAvailableResponse>>, ApiError> {
+    let code = params.code.trim().to_lowercase();
+    if code.is_empty() {
+        return Err(to_api_error(HttpError::bad_request(
+            "code must not be empty",
+        )));
+    }
+    if !code.split('.').all(is_valid_segment) {
+        return Err(to_api_error(HttpError::bad_request(
+            "code must be dot-separated [a-z][a-z0-9_]* segments",
+        )));
+    }
+    let available = state
+        .biz_metadata_service
+        .code_available(&code, params.parent_id.map(BizMetadataId::new))
+        .await
+        .map_err(from_domain_err)?;
+    Ok(Json(ResultResponse::ok(CodeAvailableResponse {
+        available,
+    })))
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/enums",
+    responses(
+        (status = 200, body = ResultResponse<BizMetadataEnumsResponse>)
+    ),
+    tag = "biz_metadata"
+)]
+/// 列出 `object_type`/`data_class`/`status`/`source` 当前接受的规范取值，由领域枚举定义生成。
+pub async fn list_biz_metadata_enums() -> Json<ResultResponse<BizMetadataEnumsResponse>> {
+    Json(ResultResponse::ok(BizMetadataEnumsResponse::current()))
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/export.ndjson",
+    params(
+        ExportBizMetadataParams
+    ),
+    responses(
+        (status = 200, body = BizMetadataResponse, content_type = "application/x-ndjson", description = "每行一个 JSON 对象，按 id 升序分批流式输出")
+    ),
+    tag = "biz_metadata"
+)]
+/// 以 NDJSON 流式导出匹配过滤条件的业务元数据定义，内存占用与目录规模无关。
+pub async fn export_biz_metadata_ndjson(
+    State(state): State<AppState>,
+    Query(params): Query<ExportBizMetadataParams>,
+) -> Response {
+    let expression = BizMetadataDtoMapper::map_to_export_expression(params);
+    let stream = biz_metadata_ndjson_stream(
+        state.biz_metadata_service,
+        expression,
+        NDJSON_EXPORT_BATCH_SIZE,
+        state.enum_casing,
+    );
+    (
+        [(header::CONTENT_TYPE, NDJSON_CONTENT_TYPE)],
+        Body::from_stream(stream),
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    context_path = BIZ_METADATA_CONTEXT,
+    path = "/graph.dot",
+    params(
+        GraphDotParams
+    ),
+    responses(
+        (status = 200, body = String, content_type = "text/vnd.graphviz", description = "GraphViz DOT，边由父节点指向子节点"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata"
+)]
+/// 以 GraphViz DOT 导出元数据父子层级，可指定根节点与最大深度。
+pub async fn export_biz_metadata_graph_dot(
+    State(state): State<AppState>,
+    Query(params): Query<GraphDotParams>,
+) -> Result<Response, ApiError> {
+    let dot = state
+        .biz_metadata_service
+        .export_dot(
+            params.root_id.map(BizMetadataId::new),
+            params.max_depth.unwrap_or(GRAPH_DOT_DEFAULT_MAX_DEPTH),
+        )
+        .await
+        .map_err(from_domain_err)?;
+    Ok(([(header::CONTENT_TYPE, DOT_CONTENT_TYPE)], dot).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::service::biz_metadata::{CreateBizMetadataCommand, DependentAction};
+    use crate::domain::biz_metadata::value_object::{ObjectType, Version};
+    use crate::interface::http::cache::CacheConfig;
+    use crate::interface::http::dto::response::EnumValueResponse;
+    use crate::interface::http::mapper::EnumCasing;
+    use crate::{build_alias_service, build_service};
+    use biz_metadata_migration::{Migrator, MigratorTrait};
+
+    /// 仅在设置 `TEST_DATABASE_URL` 时连接 PostgreSQL 并执行迁移，否则返回 `None` 跳过测试。
+    async fn pg_state() -> Option<AppState> {
+        let url = std::env::var("TEST_DATABASE_URL").ok()?;
+        let db = sea_orm::Database::connect(url).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+        Some(AppState {
+            biz_metadata_service: Arc::new(build_service(db.clone())),
+            biz_metadata_alias_service: Arc::new(build_alias_service(db)),
+            cache: CacheConfig::default(),
+            enum_casing: EnumCasing::default(),
+        })
+    }
+
+    #[tokio::test]
+    async fn suggest_code_normalises_or_rejects_parent_code() {
+        let suggest = |parent_code: &str| {
+            suggest_biz_metadata_code(Query(SuggestCodeParams {
+                parent_code: Some(parent_code.into()),
+                name: "Revenue".into(),
+            }))
+        };
+        let Json(body) = suggest(" Company.Finance ").await.unwrap();
+        assert_eq!(body.data.unwrap().code, "company.finance.revenue");
+        for invalid in ["company..finance", "1company", "company.fin-ance"] {
+            let err = suggest(invalid).await.unwrap_err();
+            assert_eq!(err.status, 400, "{invalid}");
+        }
+    }
+
+    #[tokio::test]
+    async fn enums_list_values_derived_from_domain() {
+        let Json(body) = list_biz_metadata_enums().await;
+        let enums = body.data.unwrap();
+        let values = |items: &[EnumValueResponse]| {
+            items
+                .iter()
+                .map(|item| item.value.clone())
+                .collect::<Vec<_>>()
+        };
+        assert!(values(&enums.object_type).contains(&"feature".to_string()));
+        assert!(values(&enums.data_class).contains(&"attribute".to_string()));
+        assert_eq!(values(&enums.status), vec!["active", "deprecated"]);
+        assert_eq!(
+            values(&enums.source),
+            vec!["manual", "auto_mine", "api_sync"]
+        );
+    }
+
+    #[tokio::test]
+    async fn detail_distinguishes_deleted_from_missing() {
+        let Some(state) = pg_state().await else {
+            return;
+        };
+        let code = format!("gone_{}", chrono::Utc::now().timestamp_micros());
+        let created = state
+            .biz_metadata_service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: code.clone(),
+                name: code,
+                description: None,
+                object_type: ObjectType::Entity,
+                parent_id: None,
+                data_class: None,
+                value_type: None,
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+        let id = created.id().value();
+        let detail = |id| {
+            get_biz_metadata(
+                State(state.clone()),
+                Path(id),
+                Query(GetBizMetadataParams::default()),
+                HeaderMap::new(),
+            )
+        };
+
+        assert_eq!(detail(id).await.unwrap().status(), StatusCode::OK);
+
+        state
+            .biz_metadata_service
+            .delete_biz_metadata(
+                created.id(),
+                Version::new(1).unwrap(),
+                DependentAction::Restrict,
+            )
+            .await
+            .unwrap();
+        let deleted = detail(id).await.unwrap();
+        assert_eq!(deleted.status(), StatusCode::GONE);
+
+        let missing = detail(i64::MAX).await.unwrap();
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn version_endpoint_returns_current_version_or_404() {
+        let Some(state) = pg_state().await else {
+            return;
+        };
+        let code = format!("versioned_{}", chrono::Utc::now().timestamp_micros());
+        let created = state
+            .biz_metadata_service
+            .create_biz_metadata(CreateBizMetadataCommand {
+                code: code.clone(),
+                name: code,
+                description: None,
+                object_type: ObjectType::Entity,
+                parent_id: None,
+                data_class: None,
+                value_type: None,
+                unit: None,
+                status: None,
+                source: None,
+            })
+            .await
+            .unwrap();
+        let touched = state
+            .biz_metadata_service
+            .touch_biz_metadata(created.id(), created.version())
+            .await
+            .unwrap();
+
+        let Json(body) = get_biz_metadata_version(State(state.clone()), Path(created.id().value()))
+            .await
+            .unwrap();
+        let data = body.data.unwrap();
+        assert_eq!(data.id, created.id().value());
+        assert_eq!(data.version, i32::from(touched.version()));
+        assert_eq!(data.version, 2);
+
+        let missing = get_biz_metadata_version(State(state), Path(i64::MAX))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.status, 404);
+    }
+
+    #[tokio::test]
+    async fn detail_expands_parent_only_when_requested() {
+        let Some(state) = pg_state().await else {
+            return;
+        };
+        let suffix = chrono::Utc::now().timestamp_micros();
+        let create = |code: String, parent_id| CreateBizMetadataCommand {
+            name: code.clone(),
+            code,
+            description: None,
+            object_type: ObjectType::Entity,
+            parent_id,
+            data_class: None,
+            value_type: None,
+            unit: None,
+            status: None,
+            source: None,
+        };
+        let service = &state.biz_metadata_service;
+        let parent = service
+            .create_biz_metadata(create(format!("parent_{suffix}"), None))
+            .await
+            .unwrap();
+        let child = service
+            .create_biz_metadata(create(format!("child_{suffix}"), Some(parent.id())))
+            .await
+            .unwrap();
+        let detail = |expand: Option<&str>| {
+            get_biz_metadata(
+                State(state.clone()),
+                Path(child.id().value()),
+                Query(GetBizMetadataParams {
+                    expand: expand.map(str::to_string),
+                }),
+                HeaderMap::new(),
+            )
+        };
+        let body = |response: Response| async move {
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .unwrap();
+            serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+        };
+
+        let expanded = detail(Some("parent")).await.unwrap();
+        assert_eq!(expanded.headers()[header::CACHE_CONTROL], "no-store");
+        let expanded = body(expanded).await;
+        assert_eq!(expanded["data"]["parent"]["id"], parent.id().value());
+        assert_eq!(expanded["data"]["parent"]["code"], parent.code().as_str());
+        assert_eq!(expanded["data"]["parent"]["name"], parent.name().as_str());
+
+        let plain = body(detail(None).await.unwrap()).await;
+        assert!(plain["data"].get("parent").is_none());
+        assert_eq!(plain["data"]["parent_id"], parent.id().value());
+
+        let Err(err) = detail(Some("children")).await else {
+            panic!("unknown expand should be rejected");
+        };
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -1,17 +1,18 @@
 use axum::{
     Json,
-    extract::{Path, Query, State},
+    extract::{OriginalUri, Path, Query, State},
     http::{HeaderValue, StatusCode},
 };
 
 use crate::domain::biz_metadata_alias::value_object::BizMetadataAliasId;
 use crate::interface::http::dto::{
     request::{
-        BizMetadataAliasListParams, CreateBizMetadataAliasRequest, UpdateBizMetadataAliasRequest,
+        BizMetadataAliasListParams, CreateBizMetadataAliasRequest, SetAliasWeightRequest,
+        UpdateBizMetadataAliasRequest,
     },
     response::{
         BizMetadataAliasPageResponseBody, BizMetadataAliasResponse, BizMetadataAliasResponseBody,
-        EmptyPayload, PageResultResponse, ResultResponse,
+        PageResultResponse, ProblemDetails, ResultResponse,
     },
 };
 use crate::interface::http::error::{ApiError, from_domain_err, not_found, to_api_error};
@@ -27,8 +28,9 @@ pub(crate) const BIZ_METADATA_ALIAS_CONTEXT: &str = "/biz_metadata_alias";
     request_body = CreateBizMetadataAliasRequest,
     responses(
         (status = 201, body = ResultResponse<BizMetadataAliasResponse>, description = "Created, Location header set"),
-        (status = 400, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json", description = "同一元数据下已存在相同文本与语言的存活别名"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata_alias"
 )]
@@ -73,9 +75,10 @@ pub async fn create_biz_metadata_alias(
     ),
     responses(
         (status = 200, body = ResultResponse<BizMetadataAliasResponse>, description = "Updated"),
-        (status = 400, body = ResultResponse<EmptyPayload>),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, body = ProblemDetails, content_type = "application/problem+json", description = "同一元数据下已存在相同文本与语言的存活别名"),
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata_alias"
 )]
@@ -97,6 +100,36 @@ pub async fn update_biz_metadata_alias(
     )))
 }
 
+#[utoipa::path(
+    post,
+    context_path = BIZ_METADATA_ALIAS_CONTEXT,
+    path = "/weights",
+    request_body = Vec<SetAliasWeightRequest>,
+    responses(
+        (status = 200, body = ResultResponse<Vec<BizMetadataAliasResponse>>, description = "按请求顺序返回更新后的别名，重复 id 只返回一次"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json", description = "任一权重越界或别名不存在，整批不写入"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
+    ),
+    tag = "biz_metadata_alias"
+)]
+/// 在单个事务内批量设置别名权重，供检索相关性调优使用。
+pub async fn set_biz_metadata_alias_weights(
+    State(state): State<AppState>,
+    Json(payload): Json<Vec<SetAliasWeightRequest>>,
+) -> Result<Json<ResultResponse<Vec<BizMetadataAliasResponse>>>, ApiError> {
+    let updated = state
+        .biz_metadata_alias_service
+        .bulk_set_weights(BizMetadataAliasDtoMapper::map_to_weight_updates(payload))
+        .await
+        .map_err(from_domain_err)?;
+    Ok(Json(ResultResponse::ok(
+        updated
+            .into_iter()
+            .map(BizMetadataAliasDtoMapper::map_to_response)
+            .collect(),
+    )))
+}
+
 #[utoipa::path(
     get,
     context_path = BIZ_METADATA_ALIAS_CONTEXT,
@@ -106,8 +139,8 @@ pub async fn update_biz_metadata_alias(
     ),
     responses(
         (status = 200, body = ResultResponse<BizMetadataAliasResponse>),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata_alias"
 )]
@@ -136,8 +169,8 @@ pub async fn get_biz_metadata_alias(
     ),
     responses(
         (status = 204, description = "Deleted"),
-        (status = 404, body = ResultResponse<EmptyPayload>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 404, body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata_alias"
 )]
@@ -163,21 +196,23 @@ pub async fn delete_biz_metadata_alias(
     ),
     responses(
         (status = 200, body = ResultResponse<PageResultResponse<BizMetadataAliasResponse>>),
-        (status = 500, body = ResultResponse<EmptyPayload>)
+        (status = 500, body = ProblemDetails, content_type = "application/problem+json")
     ),
     tag = "biz_metadata_alias"
 )]
 /// 分页查询业务元数据别名列表。
 pub async fn list_biz_metadata_alias(
     State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
     Query(params): Query<BizMetadataAliasListParams>,
 ) -> Result<Json<BizMetadataAliasPageResponseBody>, ApiError> {
-    let query = BizMetadataAliasDtoMapper::map_to_query_request(params);
+    let query = BizMetadataAliasDtoMapper::map_to_query_request(params).map_err(to_api_error)?;
     let page = state
         .biz_metadata_alias_service
         .query_alias(query)
         .await
         .map_err(from_domain_err)?;
-    let resp_page = BizMetadataAliasDtoMapper::map_to_page_response(page);
+    let resp_page =
+        BizMetadataAliasDtoMapper::map_to_page_response(page).with_links(uri.path(), uri.query());
     Ok(Json(ResultResponse::ok(resp_page)))
 }
@@ -1,5 +1,5 @@
 use crate::application::service::biz_metadata_alias::{
-    AliasFieldUpdate, BizMetadataAliasQueryRequest, CreateBizMetadataAliasCommand,
+    AliasFieldUpdate, BizMetadataAliasQueryRequest, CreateBizMetadataAliasCommand, LanguageScope,
     UpdateBizMetadataAliasCommand,
 };
 use crate::domain::biz_metadata::value_object::BizMetadataId;
@@ -8,12 +8,13 @@ use crate::domain::biz_metadata_alias::value_object::{
     AliasSource, AliasWeight, BizMetadataAliasId, LanguageCode,
 };
 use crate::interface::http::dto::request::{
-    BizMetadataAliasListParams, CreateBizMetadataAliasRequest, UpdateBizMetadataAliasRequest,
+    BizMetadataAliasListParams, CreateBizMetadataAliasRequest, SetAliasWeightRequest,
+    UpdateBizMetadataAliasRequest,
 };
 use crate::interface::http::dto::response::{BizMetadataAliasResponse, PageResultResponse};
 use crate::interface::http::mapper::error_mapper::HttpError;
 use domain_core::expression::{Expression, QueryOptions};
-use domain_core::pagination::{Page, PageResult};
+use domain_core::pagination::PageResult;
 
 /// BizMetadataAlias 相关 DTO 与领域模型的转换器。
 pub struct BizMetadataAliasDtoMapper;
@@ -81,18 +82,37 @@ impl BizMetadataAliasDtoMapper {
         })
     }
 
+    /// 转换批量权重请求，权重范围由服务统一校验以便整批报告越界项。
+    pub fn map_to_weight_updates(
+        payload: Vec<SetAliasWeightRequest>,
+    ) -> Vec<(BizMetadataAliasId, i32)> {
+        payload
+            .into_iter()
+            .map(|item| (BizMetadataAliasId::new(item.id), item.weight))
+            .collect()
+    }
+
     pub fn map_to_query_request(
         params: BizMetadataAliasListParams,
-    ) -> BizMetadataAliasQueryRequest {
+    ) -> Result<BizMetadataAliasQueryRequest, HttpError> {
+        let language = params
+            .language
+            .as_deref()
+            .map(LanguageScope::parse)
+            .transpose()
+            .map_err(|e| HttpError::bad_request(e.to_string()))?
+            .unwrap_or_default();
+
         // TODO: expression building when filters used; keep Expression::True for now.
-        BizMetadataAliasQueryRequest {
+        Ok(BizMetadataAliasQueryRequest {
             expression: Expression::True,
+            language,
             options: QueryOptions {
                 limit: params.limit,
                 offset: params.offset,
                 order_bys: vec![],
             },
-        }
+        })
     }
 
     pub fn map_to_response(domain: BizMetadataAlias) -> BizMetadataAliasResponse {
@@ -102,22 +122,6 @@ impl BizMetadataAliasDtoMapper {
     pub fn map_to_page_response(
         page: PageResult<BizMetadataAlias>,
     ) -> PageResultResponse<BizMetadataAliasResponse> {
-        let total_count = page.total_count();
-        let page_index = page.page_index();
-        let page_size = page.page_size();
-        let index_from = page.index_from();
-        let items = page
-            .into_items()
-            .into_iter()
-            .map(BizMetadataAliasResponse::from)
-            .collect::<Vec<_>>();
-        let mapped_page = PageResult::new(
-            items,
-            total_count,
-            page_index,
-            Some(page_size),
-            Some(index_from),
-        );
-        PageResultResponse::from_page(mapped_page)
+        PageResultResponse::from_page(page.map(BizMetadataAliasResponse::from))
     }
 }
@@ -1,18 +1,59 @@
 use crate::application::service::biz_metadata::{
     BizMetadataQueryRequest,
-    command::{CreateBizMetadataCommand, FieldUpdate, UpdateBizMetadataCommand},
+    command::{CreateBizMetadataCommand, DependentAction, FieldUpdate, UpdateBizMetadataCommand},
 };
 use crate::domain::biz_metadata::BizMetadata;
 use crate::domain::biz_metadata::value_object::{
     BizMetadataId, BizMetadataStatus, DataClass, ObjectType, Source, Version,
 };
+use crate::interface::http::dto::request::biz_metadata::DependentActionParam;
 use crate::interface::http::dto::request::{
-    BizMetadataListParams, CreateBizMetadataRequest, UpdateBizMetadataRequest,
+    BizMetadataListParams, CreateBizMetadataRequest, ExportBizMetadataParams, JsonPatchOperation,
+    UpdateBizMetadataRequest,
 };
-use crate::interface::http::dto::response::{BizMetadataResponse, PageResultResponse};
+use crate::interface::http::dto::response::{
+    BizMetadataParentResponse, BizMetadataProjection, BizMetadataResponse, PageResultResponse,
+};
+use crate::interface::http::mapper::enum_casing::EnumCasing;
 use crate::interface::http::mapper::error_mapper::HttpError;
-use domain_core::expression::{Expression, QueryOptions};
-use domain_core::pagination::{Page, PageResult};
+use crate::interface::http::mapper::json_patch::{apply_patch, touches};
+use domain_core::expression::{
+    Expression, NullsOrder, OrderBy, QueryOptions, SortDirection, contains, eq,
+};
+use domain_core::pagination::PageResult;
+use serde_json::Value;
+
+/// 列表接口允许通过 `fields` 投影的字段集合，与 [`BizMetadataResponse`] 字段一一对应。
+pub const BIZ_METADATA_FIELDS: &[&str] = &[
+    "id",
+    "version",
+    "code",
+    "name",
+    "description",
+    "object_type",
+    "parent_id",
+    "data_class",
+    "value_type",
+    "unit",
+    "status",
+    "source",
+    "created_at",
+    "updated_at",
+    "deleted_at",
+];
+
+/// JSON Patch 可写入的字段，其余字段仅可用于 `test`/`copy` 来源。
+pub const BIZ_METADATA_PATCHABLE_FIELDS: &[&str] = &[
+    "version",
+    "name",
+    "description",
+    "data_class",
+    "value_type",
+    "unit",
+    "parent_id",
+    "status",
+    "source",
+];
 
 /// BizMetadata 相关 DTO 与领域模型的转换器。
 pub struct BizMetadataDtoMapper;
@@ -78,11 +119,11 @@ impl BizMetadataDtoMapper {
     ) -> Result<UpdateBizMetadataCommand, HttpError> {
         let version =
             Version::new(payload.version).map_err(|e| HttpError::bad_request(e.to_string()))?;
-        let data_class = payload
-            .data_class
-            .as_deref()
-            .map(Self::map_data_class)
-            .transpose()?;
+        let data_class = match payload.data_class {
+            Some(Some(value)) => FieldUpdate::Set(Self::map_data_class(&value)?),
+            Some(None) => FieldUpdate::Clear,
+            None => FieldUpdate::Keep,
+        };
         let status = payload
             .status
             .as_deref()
@@ -104,7 +145,11 @@ impl BizMetadataDtoMapper {
                 None => FieldUpdate::Keep,
             },
             data_class,
-            value_type: payload.value_type,
+            value_type: match payload.value_type {
+                Some(Some(value)) => FieldUpdate::Set(value),
+                Some(None) => FieldUpdate::Clear,
+                None => FieldUpdate::Keep,
+            },
             unit: match payload.unit {
                 Some(Some(val)) => FieldUpdate::Set(val),
                 Some(None) => FieldUpdate::Clear,
@@ -117,47 +162,319 @@ impl BizMetadataDtoMapper {
             },
             status,
             source,
+            force: payload.force,
+        })
+    }
+
+    /// 将 JSON Patch 应用于当前元数据的响应表示，并与原表示比对生成更新命令。
+    ///
+    /// 版本号取自 `if_match`（优先）或补丁中针对 `/version` 的操作，二者皆无时返回 400；
+    /// 可空字段（description/unit/parent_id）与 feature 字段（data_class/value_type）被移除或置为
+    /// `null` 时映射为 [`FieldUpdate::Clear`]；清空 feature 必填字段由服务拒绝。
+    pub fn map_patch_to_update_command(
+        current: BizMetadata,
+        if_match: Option<i32>,
+        ops: &[JsonPatchOperation],
+    ) -> Result<UpdateBizMetadataCommand, HttpError> {
+        let id = current.id();
+        let Value::Object(before) = serde_json::to_value(BizMetadataResponse::from(current))
+            .map_err(|err| HttpError::bad_request(err.to_string()))?
+        else {
+            return Err(HttpError::bad_request(
+                "biz_metadata representation is not an object",
+            ));
+        };
+        let mut after = before.clone();
+        apply_patch(&mut after, ops, BIZ_METADATA_PATCHABLE_FIELDS)?;
+
+        let version = match if_match {
+            Some(version) => version,
+            None if touches(ops, "/version") => after
+                .get("version")
+                .and_then(Value::as_i64)
+                .and_then(|v| i32::try_from(v).ok())
+                .ok_or_else(|| HttpError::bad_request("version must be an integer"))?,
+            None => {
+                return Err(HttpError::bad_request(
+                    "version is required via If-Match header or /version op",
+                ));
+            }
+        };
+        let version = Version::new(version).map_err(|e| HttpError::bad_request(e.to_string()))?;
+
+        let changed = |field: &str| -> Option<&Value> {
+            let value = after.get(field).unwrap_or(&Value::Null);
+            (value != before.get(field).unwrap_or(&Value::Null)).then_some(value)
+        };
+        let required_str = |field: &str| -> Result<Option<String>, HttpError> {
+            changed(field)
+                .map(|value| {
+                    value
+                        .as_str()
+                        .map(str::to_string)
+                        .ok_or_else(|| HttpError::bad_request(format!("{field} must be a string")))
+                })
+                .transpose()
+        };
+        let nullable_str = |field: &str| -> Result<FieldUpdate<String>, HttpError> {
+            match changed(field) {
+                None => Ok(FieldUpdate::Keep),
+                Some(Value::Null) => Ok(FieldUpdate::Clear),
+                Some(Value::String(value)) => Ok(FieldUpdate::Set(value.clone())),
+                Some(_) => Err(HttpError::bad_request(format!(
+                    "{field} must be a string or null"
+                ))),
+            }
+        };
+
+        let parent_id = match changed("parent_id") {
+            None => FieldUpdate::Keep,
+            Some(Value::Null) => FieldUpdate::Clear,
+            Some(value) => {
+                FieldUpdate::Set(BizMetadataId::new(value.as_i64().ok_or_else(|| {
+                    HttpError::bad_request("parent_id must be an integer or null")
+                })?))
+            }
+        };
+
+        Ok(UpdateBizMetadataCommand {
+            id,
+            version,
+            name: required_str("name")?,
+            description: nullable_str("description")?,
+            data_class: match nullable_str("data_class")? {
+                FieldUpdate::Set(value) => FieldUpdate::Set(Self::map_data_class(&value)?),
+                FieldUpdate::Clear => FieldUpdate::Clear,
+                FieldUpdate::Keep => FieldUpdate::Keep,
+            },
+            value_type: nullable_str("value_type")?,
+            unit: nullable_str("unit")?,
+            parent_id,
+            status: required_str("status")?
+                .as_deref()
+                .map(Self::map_status)
+                .transpose()?,
+            source: required_str("source")?
+                .as_deref()
+                .map(Self::map_source)
+                .transpose()?,
+            force: false,
         })
     }
 
-    /// 列表查询参数转查询请求。
-    pub fn map_to_query_request(params: BizMetadataListParams) -> BizMetadataQueryRequest {
-        BizMetadataQueryRequest {
-            expression: Expression::True,
+    /// 删除参数中的子节点处理方式转换为领域枚举，缺省为 `Restrict`。
+    pub fn map_to_dependent_action(param: Option<DependentActionParam>) -> DependentAction {
+        match param {
+            None | Some(DependentActionParam::Restrict) => DependentAction::Restrict,
+            Some(DependentActionParam::Deprecate) => DependentAction::Deprecate,
+            Some(DependentActionParam::Detach) => DependentAction::Detach,
+        }
+    }
+
+    /// 导出过滤参数转换为查询表达式，未提供任何过滤时为恒真。
+    pub fn map_to_export_expression(params: ExportBizMetadataParams) -> Expression {
+        let mut filters = Vec::new();
+        if let Some(code) = params.code {
+            filters.push(Expression::cmp(eq("code", code.to_lowercase())));
+        }
+        if let Some(object_type) = params.object_type {
+            filters.push(Expression::cmp(eq("object_type", object_type.as_str())));
+        }
+        if let Some(parent_id) = params.parent_id {
+            filters.push(Expression::cmp(eq("parent_id", parent_id)));
+        }
+        if let Some(status) = params.status {
+            filters.push(Expression::cmp(eq("status", status.as_str())));
+        }
+        Expression::and(filters)
+    }
+
+    /// 列表查询参数转查询请求，过滤条件按 AND 组合，未提供任何过滤时为恒真。
+    pub fn map_to_query_request(
+        params: BizMetadataListParams,
+    ) -> Result<BizMetadataQueryRequest, HttpError> {
+        let mut filters = Vec::new();
+        if let Some(code) = params.code.as_deref().map(str::trim)
+            && !code.is_empty()
+        {
+            filters.push(Expression::cmp(eq("code", code.to_lowercase())));
+        }
+        if let Some(name) = params.name.as_deref().map(str::trim)
+            && !name.is_empty()
+        {
+            filters.push(Expression::cmp(contains("name", name)));
+        }
+        if let Some(object_type) = params.object_type {
+            filters.push(Expression::cmp(eq("object_type", object_type.as_str())));
+        }
+        if let Some(data_class) = params.data_class {
+            filters.push(Expression::cmp(eq("data_class", data_class.as_str())));
+        }
+        if let Some(status) = params.status {
+            filters.push(Expression::cmp(eq("status", status.as_str())));
+        }
+        let mut order_bys = params
+            .sort
+            .as_deref()
+            .map(Self::parse_sort)
+            .transpose()?
+            .unwrap_or_default();
+        if let Some(collation) = params.collation.as_deref().map(str::trim)
+            && !collation.is_empty()
+        {
+            for order in order_bys.iter_mut().filter(|order| order.field == "name") {
+                order.collation = Some(collation.to_string());
+            }
+        }
+        Ok(BizMetadataQueryRequest {
+            expression: Expression::and(filters),
             options: QueryOptions {
                 limit: params.limit,
                 offset: params.offset,
-                order_bys: vec![],
+                order_bys,
             },
+        })
+    }
+
+    /// 解析 `sort` 参数（`field[:asc|:desc][:nulls_first|:nulls_last]`，逗号分隔），
+    /// 未知字段、方向或 NULL 位置返回 400。
+    pub fn parse_sort(raw: &str) -> Result<Vec<OrderBy>, HttpError> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| {
+                let mut parts = item.split(':').map(str::trim);
+                let field = parts.next().unwrap_or_default();
+                if !BIZ_METADATA_FIELDS.contains(&field) {
+                    return Err(HttpError::bad_request(format!(
+                        "unknown sort field: {field}"
+                    )));
+                }
+                let mut order = OrderBy::asc(field);
+                for modifier in parts {
+                    match modifier {
+                        "asc" => order.direction = SortDirection::Asc,
+                        "desc" => order.direction = SortDirection::Desc,
+                        "nulls_first" => order.nulls = NullsOrder::First,
+                        "nulls_last" => order.nulls = NullsOrder::Last,
+                        other => {
+                            return Err(HttpError::bad_request(format!(
+                                "unknown sort modifier: {other}, expected asc, desc, nulls_first or nulls_last"
+                            )));
+                        }
+                    }
+                }
+                Ok(order)
+            })
+            .collect()
+    }
+
+    /// 解析 `expand` 参数（逗号分隔），返回是否展开父节点；未知展开项返回 400。
+    pub fn parse_expand_parent(raw: &str) -> Result<bool, HttpError> {
+        let mut parent = false;
+        for item in raw
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+        {
+            match item {
+                "parent" => parent = true,
+                other => {
+                    return Err(HttpError::bad_request(format!(
+                        "unknown expand: {other}, expected parent"
+                    )));
+                }
+            }
         }
+        Ok(parent)
     }
 
-    /// 领域对象转响应 DTO。
-    pub fn map_to_response(entity: BizMetadata) -> BizMetadataResponse {
-        BizMetadataResponse::from(entity)
+    /// 在响应中内联父节点摘要。
+    pub fn with_parent(
+        mut response: BizMetadataResponse,
+        parent: &BizMetadata,
+    ) -> BizMetadataResponse {
+        response.parent = Some(BizMetadataParentResponse::from(parent));
+        response
     }
 
-    /// 分页结果转响应 DTO。
+    /// 领域对象转响应 DTO，枚举字段按 `casing` 输出。
+    pub fn map_to_response(entity: BizMetadata, casing: EnumCasing) -> BizMetadataResponse {
+        let mut response = BizMetadataResponse::from(entity);
+        if casing != EnumCasing::Lower {
+            response.object_type = casing.apply(&response.object_type);
+            response.data_class = response.data_class.map(|v| casing.apply(&v));
+            response.status = casing.apply(&response.status);
+            response.source = casing.apply(&response.source);
+        }
+        response
+    }
+
+    /// 分页结果转响应 DTO，枚举字段按 `casing` 输出。
     pub fn map_to_page_response(
         page: PageResult<BizMetadata>,
+        casing: EnumCasing,
     ) -> PageResultResponse<BizMetadataResponse> {
-        let total_count = page.total_count();
-        let page_index = page.page_index();
-        let page_size = page.page_size();
-        let index_from = page.index_from();
+        PageResultResponse::from_page(page.map(|item| Self::map_to_response(item, casing)))
+    }
+
+    /// 解析 `fields` 参数，去除空白与重复项，未知字段返回 400。
+    pub fn parse_fields(raw: &str) -> Result<Vec<String>, HttpError> {
+        let mut fields: Vec<String> = Vec::new();
+        for field in raw.split(',').map(str::trim).filter(|f| !f.is_empty()) {
+            if !BIZ_METADATA_FIELDS.contains(&field) {
+                return Err(HttpError::bad_request(format!("unknown field: {field}")));
+            }
+            if !fields.iter().any(|f| f == field) {
+                fields.push(field.to_string());
+            }
+        }
+        if fields.is_empty() {
+            return Err(HttpError::bad_request("fields must not be empty"));
+        }
+        Ok(fields)
+    }
+
+    /// 将响应 DTO 裁剪为仅包含 `fields` 的投影。
+    pub fn project(
+        response: BizMetadataResponse,
+        fields: &[String],
+    ) -> Result<BizMetadataProjection, HttpError> {
+        let serde_json::Value::Object(mut all) = serde_json::to_value(response)
+            .map_err(|err| HttpError::bad_request(err.to_string()))?
+        else {
+            return Err(HttpError::bad_request(
+                "biz_metadata response is not an object",
+            ));
+        };
+        let projected = fields
+            .iter()
+            .filter_map(|field| all.remove(field).map(|value| (field.clone(), value)))
+            .collect();
+        Ok(BizMetadataProjection(projected))
+    }
+
+    /// 分页结果转投影后的响应 DTO。
+    pub fn map_to_projected_page_response(
+        page: PageResultResponse<BizMetadataResponse>,
+        fields: &[String],
+    ) -> Result<PageResultResponse<BizMetadataProjection>, HttpError> {
         let items = page
-            .into_items()
+            .items
             .into_iter()
-            .map(BizMetadataResponse::from)
-            .collect::<Vec<_>>();
-        let mapped_page = PageResult::new(
+            .map(|item| Self::project(item, fields))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(PageResultResponse {
             items,
-            total_count,
-            page_index,
-            Some(page_size),
-            Some(index_from),
-        );
-        PageResultResponse::from_page(mapped_page)
+            total_count: page.total_count,
+            page_index: page.page_index,
+            page_size: page.page_size,
+            index_from: page.index_from,
+            total_pages: page.total_pages,
+            has_next: page.has_next,
+            has_prev: page.has_prev,
+            links: page.links,
+        })
     }
 
     fn map_data_class(raw: &str) -> Result<DataClass, HttpError> {
@@ -172,3 +489,244 @@ impl BizMetadataDtoMapper {
         Source::new(raw).map_err(|err| HttpError::bad_request(err.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::biz_metadata::value_object::{DataClass, TenantId, ValueType};
+
+    fn response() -> BizMetadataResponse {
+        let entity = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "公司",
+            ObjectType::Entity,
+            chrono::Utc::now(),
+        )
+        .unwrap();
+        BizMetadataResponse::from(entity)
+    }
+
+    #[test]
+    fn projects_requested_fields_only() {
+        let fields = BizMetadataDtoMapper::parse_fields("code, name").unwrap();
+        let projected = BizMetadataDtoMapper::project(response(), &fields).unwrap();
+
+        let keys: Vec<_> = projected.0.keys().map(String::as_str).collect();
+        assert_eq!(keys.len(), 2);
+        assert!(keys.contains(&"code") && keys.contains(&"name"));
+        assert_eq!(projected.0["code"], "company");
+    }
+
+    fn feature() -> BizMetadata {
+        BizMetadata::new_feature(
+            TenantId::new("default").unwrap(),
+            "company_amount",
+            "金额",
+            DataClass::Attribute,
+            ValueType::new("decimal").unwrap(),
+            chrono::Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn parent_is_serialized_only_when_expanded() {
+        let plain = serde_json::to_value(response()).unwrap();
+        assert!(plain.get("parent").is_none());
+
+        let parent = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "group",
+            "集团",
+            ObjectType::Entity,
+            chrono::Utc::now(),
+        )
+        .unwrap();
+        let expanded =
+            serde_json::to_value(BizMetadataDtoMapper::with_parent(response(), &parent)).unwrap();
+        assert_eq!(expanded["parent"]["code"], "group");
+        assert_eq!(expanded["parent"]["name"], "集团");
+        assert!(expanded["parent"].get("id").is_some());
+
+        assert!(BizMetadataDtoMapper::parse_expand_parent("parent, ").unwrap());
+        assert!(!BizMetadataDtoMapper::parse_expand_parent("").unwrap());
+        assert!(BizMetadataDtoMapper::parse_expand_parent("children").is_err());
+    }
+
+    #[test]
+    fn default_casing_keeps_lowercase_enums() {
+        let response = BizMetadataDtoMapper::map_to_response(feature(), EnumCasing::default());
+        assert_eq!(response.object_type, "feature");
+        assert_eq!(response.data_class.as_deref(), Some("attribute"));
+        assert_eq!(response.status, "active");
+        assert_eq!(response.source, "manual");
+    }
+
+    #[test]
+    fn uppercase_casing_applies_to_every_enum_field() {
+        let response = BizMetadataDtoMapper::map_to_response(feature(), EnumCasing::ScreamingSnake);
+        assert_eq!(response.object_type, "FEATURE");
+        assert_eq!(response.data_class.as_deref(), Some("ATTRIBUTE"));
+        assert_eq!(response.status, "ACTIVE");
+        assert_eq!(response.source, "MANUAL");
+        assert_eq!(response.code, "company_amount");
+    }
+
+    fn list_params(
+        query: &str,
+    ) -> Result<BizMetadataListParams, axum::extract::rejection::QueryRejection> {
+        let uri: axum::http::Uri = format!("/biz_metadata?{query}").parse().unwrap();
+        axum::extract::Query::try_from_uri(&uri).map(|axum::extract::Query(params)| params)
+    }
+
+    #[test]
+    fn enum_filters_deserialize_into_domain_types() {
+        let params = list_params("object_type=feature&status=Active").unwrap();
+        assert_eq!(params.object_type, Some(ObjectType::Feature));
+        assert_eq!(params.status, Some(BizMetadataStatus::Active));
+        assert_eq!(params.data_class, None);
+
+        let query = BizMetadataDtoMapper::map_to_query_request(params).unwrap();
+        assert_eq!(
+            query.expression,
+            Expression::and(vec![
+                Expression::cmp(eq("object_type", "feature")),
+                Expression::cmp(eq("status", "active")),
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_enum_filter_is_rejected_with_allowed_values() {
+        let rejection = list_params("status=foo").unwrap_err();
+        assert_eq!(rejection.status(), axum::http::StatusCode::BAD_REQUEST);
+        let message = rejection.body_text();
+        assert!(message.contains("invalid status: foo"), "{message}");
+        assert!(message.contains("active, deprecated"), "{message}");
+    }
+
+    #[test]
+    fn export_enum_filters_parse_like_list_filters() {
+        let uri: axum::http::Uri = "/export.ndjson?object_type=Event&status=ACTIVE"
+            .parse()
+            .unwrap();
+        let axum::extract::Query(params) =
+            axum::extract::Query::<ExportBizMetadataParams>::try_from_uri(&uri).unwrap();
+        assert_eq!(
+            BizMetadataDtoMapper::map_to_export_expression(params),
+            Expression::and(vec![
+                Expression::cmp(eq("object_type", "event")),
+                Expression::cmp(eq("status", "active")),
+            ])
+        );
+
+        let uri: axum::http::Uri = "/export.ndjson?object_type=table".parse().unwrap();
+        let rejection =
+            axum::extract::Query::<ExportBizMetadataParams>::try_from_uri(&uri).unwrap_err();
+        assert!(rejection.body_text().contains("invalid object_type: table"));
+    }
+
+    #[test]
+    fn sort_param_attaches_collation_to_name_only() {
+        let params = list_params("sort=name:desc,%20code&collation=pinyin").unwrap();
+        let query = BizMetadataDtoMapper::map_to_query_request(params).unwrap();
+        assert_eq!(
+            query.options.order_bys,
+            vec![
+                OrderBy::desc("name").with_collation("pinyin"),
+                OrderBy::asc("code")
+            ]
+        );
+
+        assert!(BizMetadataDtoMapper::parse_sort("secret").is_err());
+        assert!(BizMetadataDtoMapper::parse_sort("name:sideways").is_err());
+    }
+
+    #[test]
+    fn sort_param_accepts_nulls_placement() {
+        assert_eq!(
+            BizMetadataDtoMapper::parse_sort("parent_id:nulls_last, data_class:desc:nulls_first")
+                .unwrap(),
+            vec![
+                OrderBy::asc("parent_id").with_nulls(NullsOrder::Last),
+                OrderBy::desc("data_class").with_nulls(NullsOrder::First),
+            ]
+        );
+        assert!(BizMetadataDtoMapper::parse_sort("parent_id:nulls_middle").is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_field() {
+        let err = BizMetadataDtoMapper::parse_fields("id,secret").unwrap_err();
+        assert_eq!(err.status, axum::http::StatusCode::BAD_REQUEST);
+        assert!(err.message.contains("secret"));
+    }
+
+    fn patch(raw: Value) -> Vec<JsonPatchOperation> {
+        serde_json::from_value(raw).unwrap()
+    }
+
+    fn described() -> BizMetadata {
+        let mut entity = BizMetadata::new_node(
+            TenantId::new("default").unwrap(),
+            "company",
+            "公司",
+            ObjectType::Entity,
+            chrono::Utc::now(),
+        )
+        .unwrap();
+        entity
+            .set_description(Some("desc".into()), chrono::Utc::now())
+            .unwrap();
+        entity
+    }
+
+    #[test]
+    fn patch_replaces_name() {
+        let ops = patch(serde_json::json!([
+            {"op": "replace", "path": "/name", "value": "Company"}
+        ]));
+        let cmd =
+            BizMetadataDtoMapper::map_patch_to_update_command(described(), Some(1), &ops).unwrap();
+        assert_eq!(cmd.name.as_deref(), Some("Company"));
+        assert_eq!(cmd.version, Version::new(1).unwrap());
+        assert!(matches!(cmd.description, FieldUpdate::Keep));
+        assert!(cmd.status.is_none());
+    }
+
+    #[test]
+    fn patch_remove_description_clears_it() {
+        let ops = patch(serde_json::json!([
+            {"op": "test", "path": "/version", "value": 1},
+            {"op": "remove", "path": "/description"}
+        ]));
+        let cmd =
+            BizMetadataDtoMapper::map_patch_to_update_command(described(), None, &ops).unwrap();
+        assert!(matches!(cmd.description, FieldUpdate::Clear));
+        assert!(cmd.name.is_none());
+    }
+
+    #[test]
+    fn patch_requires_version_and_valid_paths() {
+        let rename = patch(serde_json::json!([
+            {"op": "replace", "path": "/name", "value": "Company"}
+        ]));
+        let Err(err) =
+            BizMetadataDtoMapper::map_patch_to_update_command(described(), None, &rename)
+        else {
+            panic!("patch should be rejected");
+        };
+        assert!(err.message.contains("version"));
+
+        let read_only = patch(serde_json::json!([
+            {"op": "replace", "path": "/code", "value": "other"}
+        ]));
+        let Err(err) =
+            BizMetadataDtoMapper::map_patch_to_update_command(described(), Some(1), &read_only)
+        else {
+            panic!("read-only path should be rejected");
+        };
+        assert_eq!(err.status, axum::http::StatusCode::BAD_REQUEST);
+    }
+}
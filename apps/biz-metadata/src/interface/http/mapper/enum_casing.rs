@@ -0,0 +1,64 @@
+//! 响应中枚举取值的大小写策略，按部署选择，默认保持领域层的小写形式。
+
+/// 选择枚举大小写策略的环境变量名，取值 `lower`/`screaming_snake`/`title`。
+pub const ENUM_CASING_ENV: &str = "BIZ_METADATA_ENUM_CASING";
+
+/// 枚举取值（`object_type`/`data_class`/`status`/`source`）在响应中的大小写。
+///
+/// ```
+/// use biz_metadata::interface::http::mapper::EnumCasing;
+///
+/// assert_eq!(EnumCasing::default().apply("auto_mine"), "auto_mine");
+/// assert_eq!(EnumCasing::ScreamingSnake.apply("auto_mine"), "AUTO_MINE");
+/// assert_eq!(EnumCasing::Title.apply("auto_mine"), "Auto Mine");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnumCasing {
+    /// 与 `as_str()` 一致的小写蛇形，如 `auto_mine`。
+    #[default]
+    Lower,
+    /// 全大写蛇形，如 `AUTO_MINE`。
+    ScreamingSnake,
+    /// 首字母大写、以空格分词，如 `Auto Mine`。
+    Title,
+}
+
+impl EnumCasing {
+    /// 从环境变量读取策略，未设置时为 [`EnumCasing::Lower`]。
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var(ENUM_CASING_ENV) {
+            Ok(raw) => Self::parse(&raw).ok_or_else(|| {
+                format!("{ENUM_CASING_ENV} 必须是 lower/screaming_snake/title 之一")
+            }),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// 解析策略名称，大小写不敏感。
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "lower" | "snake_case" => Some(Self::Lower),
+            "screaming_snake" | "upper" => Some(Self::ScreamingSnake),
+            "title" => Some(Self::Title),
+            _ => None,
+        }
+    }
+
+    /// 按策略转换 `as_str()` 形式的枚举取值。
+    pub fn apply(&self, raw: &str) -> String {
+        match self {
+            Self::Lower => raw.to_string(),
+            Self::ScreamingSnake => raw.to_ascii_uppercase(),
+            Self::Title => raw
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    chars.next().map_or_else(String::new, |first| {
+                        first.to_ascii_uppercase().to_string() + chars.as_str()
+                    })
+                })
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
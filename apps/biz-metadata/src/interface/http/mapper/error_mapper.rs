@@ -1,13 +1,34 @@
-use axum::{Json, http::StatusCode};
+use axum::{
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
 
-use crate::interface::http::dto::response::{EmptyPayload, ResultResponse};
+use crate::domain::error_code;
+use crate::interface::http::dto::response::ProblemDetails;
 use domain_core::domain_error::DomainError;
 
+/// problem-details 的 `Content-Type`。
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// 各类错误对应的 problem `type` URI，保持稳定以便网关与客户端按类别处理。
+pub const PROBLEM_TYPE_BAD_REQUEST: &str = "/problems/bad-request";
+pub const PROBLEM_TYPE_VALIDATION: &str = "/problems/validation";
+pub const PROBLEM_TYPE_INVARIANT_VIOLATION: &str = "/problems/invariant-violation";
+pub const PROBLEM_TYPE_PERSISTENCE: &str = "/problems/persistence";
+pub const PROBLEM_TYPE_NOT_FOUND: &str = "/problems/not-found";
+pub const PROBLEM_TYPE_GONE: &str = "/problems/gone";
+pub const PROBLEM_TYPE_CONFLICT: &str = "/problems/conflict";
+pub const PROBLEM_TYPE_UNAUTHORIZED: &str = "/problems/unauthorized";
+pub const PROBLEM_TYPE_FORBIDDEN: &str = "/problems/forbidden";
+pub const PROBLEM_TYPE_PAYLOAD_TOO_LARGE: &str = "/problems/payload-too-large";
+pub const PROBLEM_TYPE_RATE_LIMITED: &str = "/problems/rate-limited";
+
 /// HTTP 层标准化错误，便于转换为响应体。
 #[derive(Debug)]
 pub struct HttpError {
     pub status: StatusCode,
-    pub code: i32,
+    pub type_uri: &'static str,
+    pub code: &'static str,
     pub message: String,
 }
 
@@ -16,30 +37,255 @@ impl HttpError {
     pub fn bad_request(message: impl Into<String>) -> Self {
         Self {
             status: StatusCode::BAD_REQUEST,
-            code: StatusCode::BAD_REQUEST.as_u16() as i32,
+            type_uri: PROBLEM_TYPE_BAD_REQUEST,
+            code: error_code::REQUEST_INVALID,
             message: message.into(),
         }
     }
 
-    /// 转换为统一响应格式。
-    pub fn into_response(self) -> (StatusCode, Json<ResultResponse<EmptyPayload>>) {
-        (
-            self.status,
-            Json(ResultResponse::error(self.code, self.message)),
-        )
+    /// 404 错误。
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::NOT_FOUND,
+            type_uri: PROBLEM_TYPE_NOT_FOUND,
+            code: error_code::RESOURCE_NOT_FOUND,
+            message: message.into(),
+        }
+    }
+
+    /// 410 错误。
+    pub fn gone(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::GONE,
+            type_uri: PROBLEM_TYPE_GONE,
+            code: error_code::RESOURCE_GONE,
+            message: message.into(),
+        }
+    }
+
+    /// 401 错误。
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::UNAUTHORIZED,
+            type_uri: PROBLEM_TYPE_UNAUTHORIZED,
+            code: error_code::AUTH_UNAUTHORIZED,
+            message: message.into(),
+        }
+    }
+
+    /// 403 错误：已认证但无权访问（如租户不匹配）。
+    pub fn tenant_forbidden(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::FORBIDDEN,
+            type_uri: PROBLEM_TYPE_FORBIDDEN,
+            code: error_code::AUTH_TENANT_FORBIDDEN,
+            message: message.into(),
+        }
+    }
+
+    /// 413 错误。
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::PAYLOAD_TOO_LARGE,
+            type_uri: PROBLEM_TYPE_PAYLOAD_TOO_LARGE,
+            code: error_code::REQUEST_TOO_LARGE,
+            message: message.into(),
+        }
+    }
+
+    /// 429 错误。
+    pub fn too_many_requests(message: impl Into<String>) -> Self {
+        Self {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            type_uri: PROBLEM_TYPE_RATE_LIMITED,
+            code: error_code::REQUEST_RATE_LIMITED,
+            message: message.into(),
+        }
+    }
+
+    /// 转换为 RFC 7807 problem-details 响应体。
+    pub fn into_problem(self) -> ProblemDetails {
+        ProblemDetails {
+            type_uri: self.type_uri.to_string(),
+            title: self
+                .status
+                .canonical_reason()
+                .unwrap_or("Error")
+                .to_string(),
+            status: self.status.as_u16(),
+            detail: self.message,
+            code: self.code.to_string(),
+        }
     }
 }
 
-/// 将领域错误映射为 HTTP 错误。
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let mut response = (status, axum::Json(self)).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+        );
+        response
+    }
+}
+
+/// 表示与已有数据唯一性冲突或与并发事务冲突（可重试）的错误码，统一映射为 409。
+const CONFLICT_CODES: &[&str] = &[
+    error_code::BIZ_METADATA_CODE_CONFLICT,
+    error_code::BIZ_METADATA_DUPLICATE_CODE,
+    error_code::BIZ_METADATA_ID_CONFLICT,
+    error_code::BIZ_METADATA_CODE_AMBIGUOUS,
+    error_code::ALIAS_DUPLICATE,
+    error_code::PERSISTENCE_UNIQUE_VIOLATION,
+    error_code::PERSISTENCE_SERIALIZATION_FAILURE,
+];
+
+/// 将领域错误映射为 HTTP 错误，唯一性冲突与事务序列化失败映射为 409，其余按错误变体决定状态码。
 pub fn map_domain_error(err: DomainError) -> HttpError {
+    let code = err.code();
+    if CONFLICT_CODES.contains(&code) {
+        return HttpError {
+            status: StatusCode::CONFLICT,
+            type_uri: PROBLEM_TYPE_CONFLICT,
+            code,
+            message: err.message().to_string(),
+        };
+    }
     match err {
-        DomainError::Validation { message } | DomainError::InvariantViolation { message } => {
-            HttpError::bad_request(message)
-        }
-        DomainError::Persistence { message } => HttpError {
+        DomainError::Validation { message, .. } => HttpError {
+            status: StatusCode::BAD_REQUEST,
+            type_uri: PROBLEM_TYPE_VALIDATION,
+            code,
+            message,
+        },
+        DomainError::InvariantViolation { message, .. } => HttpError {
+            status: StatusCode::BAD_REQUEST,
+            type_uri: PROBLEM_TYPE_INVARIANT_VIOLATION,
+            code,
+            message,
+        },
+        DomainError::Persistence { message, .. } => HttpError {
             status: StatusCode::INTERNAL_SERVER_ERROR,
-            code: StatusCode::INTERNAL_SERVER_ERROR.as_u16() as i32,
+            type_uri: PROBLEM_TYPE_PERSISTENCE,
+            code,
             message,
         },
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::{Value, json};
+
+    async fn problem_of(err: HttpError) -> (StatusCode, String, Value) {
+        let response = err.into_problem().into_response();
+        let status = response.status();
+        let content_type = response.headers()[header::CONTENT_TYPE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (
+            status,
+            content_type,
+            serde_json::from_slice(&bytes).unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn validation_error_as_problem_details() {
+        let err = map_domain_error(DomainError::Validation {
+            code: domain_core::error_code::VALIDATION_FAILED,
+            message: "code is required".into(),
+        });
+        let (status, content_type, body) = problem_of(err).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(content_type, PROBLEM_JSON_CONTENT_TYPE);
+        assert_eq!(
+            body,
+            json!({
+                "type": PROBLEM_TYPE_VALIDATION,
+                "title": "Bad Request",
+                "status": 400,
+                "detail": "code is required",
+                "code": "validation.failed",
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn not_found_as_problem_details() {
+        let (status, content_type, body) =
+            problem_of(HttpError::not_found("biz_metadata not found")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(content_type, PROBLEM_JSON_CONTENT_TYPE);
+        assert_eq!(
+            body,
+            json!({
+                "type": PROBLEM_TYPE_NOT_FOUND,
+                "title": "Not Found",
+                "status": 404,
+                "detail": "biz_metadata not found",
+                "code": error_code::RESOURCE_NOT_FOUND,
+            })
+        );
+    }
+
+    #[test]
+    fn each_domain_error_has_distinct_type() {
+        let types = [
+            DomainError::Validation {
+                code: domain_core::error_code::VALIDATION_FAILED,
+                message: "v".into(),
+            },
+            DomainError::InvariantViolation {
+                code: domain_core::error_code::INVARIANT_VIOLATED,
+                message: "i".into(),
+            },
+            DomainError::Persistence {
+                code: domain_core::error_code::PERSISTENCE_FAILED,
+                message: "p".into(),
+            },
+        ]
+        .map(|err| map_domain_error(err).type_uri);
+        assert_eq!(
+            types,
+            [
+                PROBLEM_TYPE_VALIDATION,
+                PROBLEM_TYPE_INVARIANT_VIOLATION,
+                PROBLEM_TYPE_PERSISTENCE
+            ]
+        );
+    }
+
+    #[test]
+    fn uniqueness_conflicts_map_to_409_regardless_of_variant() {
+        for err in [
+            DomainError::Validation {
+                code: error_code::ALIAS_DUPLICATE,
+                message: "duplicate".into(),
+            },
+            DomainError::Persistence {
+                code: error_code::BIZ_METADATA_CODE_CONFLICT,
+                message: "duplicate".into(),
+            },
+            DomainError::Persistence {
+                code: error_code::PERSISTENCE_SERIALIZATION_FAILURE,
+                message: "could not serialize access due to concurrent update".into(),
+            },
+        ] {
+            let mapped = map_domain_error(err);
+            assert_eq!(mapped.status, StatusCode::CONFLICT);
+            assert_eq!(mapped.type_uri, PROBLEM_TYPE_CONFLICT);
+        }
+        let missing = map_domain_error(DomainError::Validation {
+            code: error_code::PERSISTENCE_REFERENCE_MISSING,
+            message: "parent".into(),
+        });
+        assert_eq!(missing.status, StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,124 @@
+use serde_json::{Map, Value};
+
+use crate::interface::http::dto::request::JsonPatchOperation;
+use crate::interface::http::mapper::error_mapper::HttpError;
+
+/// 在扁平 JSON 对象上按顺序应用 RFC 6902 操作。
+///
+/// 仅支持指向顶层成员的路径（`/field`），`field` 必须是文档已有成员；
+/// 写操作（add/remove/replace/move/copy 的目标，以及 move 的来源）仅允许 `writable` 中的成员。
+/// `remove` 后成员缺失，调用方应将缺失视为 `null`。
+pub fn apply_patch(
+    doc: &mut Map<String, Value>,
+    ops: &[JsonPatchOperation],
+    writable: &[&str],
+) -> Result<(), HttpError> {
+    let known: Vec<String> = doc.keys().cloned().collect();
+    let member = |path: &str| -> Result<String, HttpError> {
+        let field = path
+            .strip_prefix('/')
+            .filter(|field| !field.contains('/'))
+            .map(|field| field.replace("~1", "/").replace("~0", "~"))
+            .ok_or_else(|| HttpError::bad_request(format!("invalid patch path: {path}")))?;
+        if !known.contains(&field) {
+            return Err(HttpError::bad_request(format!(
+                "unknown patch path: {path}"
+            )));
+        }
+        Ok(field)
+    };
+    let writable_member = |path: &str| -> Result<String, HttpError> {
+        let field = member(path)?;
+        if !writable.contains(&field.as_str()) {
+            return Err(HttpError::bad_request(format!(
+                "read-only patch path: {path}"
+            )));
+        }
+        Ok(field)
+    };
+
+    for op in ops {
+        match op {
+            JsonPatchOperation::Add { path, value }
+            | JsonPatchOperation::Replace { path, value } => {
+                doc.insert(writable_member(path)?, value.clone());
+            }
+            JsonPatchOperation::Remove { path } => {
+                doc.remove(&writable_member(path)?);
+            }
+            JsonPatchOperation::Move { from, path } => {
+                let value = doc.remove(&writable_member(from)?).unwrap_or(Value::Null);
+                doc.insert(writable_member(path)?, value);
+            }
+            JsonPatchOperation::Copy { from, path } => {
+                let value = doc.get(&member(from)?).cloned().unwrap_or(Value::Null);
+                doc.insert(writable_member(path)?, value);
+            }
+            JsonPatchOperation::Test { path, value } => {
+                let actual = doc.get(&member(path)?).unwrap_or(&Value::Null);
+                if actual != value {
+                    return Err(HttpError::bad_request(format!("patch test failed: {path}")));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 判断补丁是否包含指向 `path` 的写操作或 test 操作。
+pub fn touches(ops: &[JsonPatchOperation], path: &str) -> bool {
+    ops.iter().any(|op| match op {
+        JsonPatchOperation::Add { path: p, .. }
+        | JsonPatchOperation::Remove { path: p }
+        | JsonPatchOperation::Replace { path: p, .. }
+        | JsonPatchOperation::Test { path: p, .. } => p == path,
+        JsonPatchOperation::Move { from, path: p } | JsonPatchOperation::Copy { from, path: p } => {
+            p == path || from == path
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn doc() -> Map<String, Value> {
+        json!({"id": 1, "name": "a", "description": "d"})
+            .as_object()
+            .cloned()
+            .unwrap()
+    }
+
+    fn ops(raw: Value) -> Vec<JsonPatchOperation> {
+        serde_json::from_value(raw).unwrap()
+    }
+
+    #[test]
+    fn applies_operations_in_order() {
+        let mut doc = doc();
+        let patch = ops(json!([
+            {"op": "test", "path": "/name", "value": "a"},
+            {"op": "replace", "path": "/name", "value": "b"},
+            {"op": "copy", "from": "/name", "path": "/description"},
+        ]));
+        apply_patch(&mut doc, &patch, &["name", "description"]).unwrap();
+        assert_eq!(doc["name"], "b");
+        assert_eq!(doc["description"], "b");
+    }
+
+    #[test]
+    fn rejects_invalid_paths_and_failed_test() {
+        let writable = ["name", "description"];
+        for raw in [
+            json!([{"op": "replace", "path": "/missing", "value": 1}]),
+            json!([{"op": "replace", "path": "/id", "value": 2}]),
+            json!([{"op": "remove", "path": "name"}]),
+            json!([{"op": "add", "path": "/name/0", "value": "x"}]),
+            json!([{"op": "test", "path": "/name", "value": "z"}]),
+        ] {
+            let err = apply_patch(&mut doc(), &ops(raw), &writable).unwrap_err();
+            assert_eq!(err.status, axum::http::StatusCode::BAD_REQUEST);
+        }
+    }
+}
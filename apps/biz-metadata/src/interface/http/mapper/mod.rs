@@ -1,7 +1,10 @@
 pub mod biz_metadata_alias_mapper;
 pub mod biz_metadata_mapper;
+pub mod enum_casing;
 pub mod error_mapper;
+pub mod json_patch;
 
 pub use biz_metadata_alias_mapper::BizMetadataAliasDtoMapper;
 pub use biz_metadata_mapper::BizMetadataDtoMapper;
+pub use enum_casing::EnumCasing;
 pub use error_mapper::{HttpError, map_domain_error};
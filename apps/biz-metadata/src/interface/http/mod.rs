@@ -1,6 +1,13 @@
+pub mod auth;
+pub mod body_limit;
+pub mod cache;
+pub mod cors;
 pub mod dto;
 pub mod error;
+pub mod field_naming;
 pub mod handler;
 pub mod mapper;
+pub mod ndjson;
+pub mod rate_limit;
 pub mod router;
 pub mod state;
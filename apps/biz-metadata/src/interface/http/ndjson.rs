@@ -0,0 +1,198 @@
+//! NDJSON（每行一个 JSON 对象）流式导出。
+//!
+//! 按 `id` 升序分批读取仓储，每批以上一批末尾的 `id` 为游标（`id > last`）继续读取，
+//! 不使用偏移分页，导出期间的插入与删除不会导致行被跳过或重复，大表上也不会随页数变慢。
+//! 每批序列化为一个数据块立即写出，内存占用与目录规模无关，客户端也能按批看到进度。
+
+use std::sync::Arc;
+
+use axum::BoxError;
+use axum::body::Bytes;
+use domain_core::expression::{Expression, OrderBy, QueryOptions, gt};
+use futures_util::stream::{self, Stream};
+
+use crate::application::service::biz_metadata::{BizMetadataQueryRequest, BizMetadataService};
+use crate::domain::biz_metadata::repository::BizMetadataRepository;
+use crate::interface::http::mapper::{BizMetadataDtoMapper, EnumCasing};
+
+/// NDJSON 响应的 `Content-Type`。
+pub const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// 单批从仓储读取的记录数，即每次写出给客户端的行数。
+pub const NDJSON_EXPORT_BATCH_SIZE: u64 = 500;
+
+/// 将匹配 `expression` 的元数据逐批转换为 NDJSON 数据块。
+///
+/// 每个数据块包含一批记录，每行一个 [`BizMetadataResponse`](crate::interface::http::dto::response::BizMetadataResponse)；
+/// 枚举取值按 `casing` 输出；中途读取失败时流以错误结束。
+pub fn biz_metadata_ndjson_stream<R>(
+    service: Arc<BizMetadataService<R>>,
+    expression: Expression,
+    batch_size: u64,
+    casing: EnumCasing,
+) -> impl Stream<Item = Result<Bytes, BoxError>> + Send + 'static
+where
+    R: BizMetadataRepository + 'static,
+{
+    let batch_size = batch_size.max(1);
+    stream::unfold(Some(None), move |cursor: Option<Option<i64>>| {
+        let service = Arc::clone(&service);
+        let expression = expression.clone();
+        async move {
+            let after = cursor?;
+            let filter = match after {
+                Some(id) => Expression::and(vec![expression, Expression::cmp(gt("id", id))]),
+                None => expression,
+            };
+            let options =
+                QueryOptions::new(Some(batch_size), Some(0)).with_order_by(OrderBy::asc("id"));
+            let page = match service
+                .query_biz_metadata(BizMetadataQueryRequest::new(filter, options))
+                .await
+            {
+                Ok(page) => page,
+                Err(err) => return Some((Err(err.into()), None)),
+            };
+            let items = page.into_items();
+            let next = (items.len() as u64 == batch_size)
+                .then(|| items.last().map(|item| item.id().value()));
+
+            let mut chunk = Vec::new();
+            for item in items {
+                let response = BizMetadataDtoMapper::map_to_response(item, casing);
+                if let Err(err) = serde_json::to_writer(&mut chunk, &response) {
+                    return Some((Err(err.into()), None));
+                }
+                chunk.push(b'\n');
+            }
+            if chunk.is_empty() {
+                return None;
+            }
+            Some((Ok(Bytes::from(chunk)), next))
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::service::biz_metadata::CreateBizMetadataCommand;
+    use crate::domain::biz_metadata::value_object::ObjectType;
+    use crate::infrastructure::persistence::repository::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository;
+    use axum::body::{Body, to_bytes};
+    use domain_core::expression::eq;
+    use futures_util::StreamExt;
+    use serde_json::Value;
+
+    async fn seeded_service(
+        count: usize,
+    ) -> Arc<BizMetadataService<InMemoryBizMetadataRepository>> {
+        let service = BizMetadataService::new(InMemoryBizMetadataRepository::new());
+        for i in 0..count {
+            let object_type = if i % 2 == 0 {
+                ObjectType::Entity
+            } else {
+                ObjectType::Event
+            };
+            service
+                .create_biz_metadata(CreateBizMetadataCommand {
+                    code: format!("node_{i}"),
+                    name: format!("node {i}"),
+                    description: None,
+                    object_type,
+                    parent_id: None,
+                    data_class: None,
+                    value_type: None,
+                    unit: None,
+                    status: None,
+                    source: None,
+                })
+                .await
+                .unwrap();
+        }
+        Arc::new(service)
+    }
+
+    #[tokio::test]
+    async fn streams_every_row_as_one_line() {
+        let service = seeded_service(5).await;
+        let body = Body::from_stream(biz_metadata_ndjson_stream(
+            service,
+            Expression::True,
+            2,
+            EnumCasing::default(),
+        ));
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 5);
+        let first: Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first["code"], "node_0");
+        assert!(text.ends_with('\n'));
+    }
+
+    #[tokio::test]
+    async fn flushes_one_chunk_per_batch_and_honors_filter() {
+        let service = seeded_service(5).await;
+        let chunks: Vec<_> = biz_metadata_ndjson_stream(
+            Arc::clone(&service),
+            Expression::True,
+            2,
+            EnumCasing::default(),
+        )
+        .collect()
+        .await;
+        assert_eq!(chunks.len(), 3);
+
+        let filtered = Expression::cmp(eq("object_type", "event"));
+        let body = Body::from_stream(biz_metadata_ndjson_stream(
+            service,
+            filtered,
+            2,
+            EnumCasing::default(),
+        ));
+        let bytes = to_bytes(body, usize::MAX).await.unwrap();
+        assert_eq!(bytes.iter().filter(|b| **b == b'\n').count(), 2);
+    }
+
+    #[tokio::test]
+    async fn deletes_during_export_do_not_skip_rows() {
+        let service = seeded_service(5).await;
+        let mut stream = Box::pin(biz_metadata_ndjson_stream(
+            Arc::clone(&service),
+            Expression::True,
+            2,
+            EnumCasing::default(),
+        ));
+        let first = stream.next().await.unwrap().unwrap();
+        let first = String::from_utf8(first.to_vec()).unwrap();
+        assert!(first.contains("node_0") && first.contains("node_1"));
+
+        // 删除已导出的行后，偏移分页会跳过 node_2；游标分页从 node_1 之后继续。
+        let exported = service
+            .query_biz_metadata(BizMetadataQueryRequest::new(
+                Expression::cmp(eq("code", "node_0")),
+                QueryOptions::default(),
+            ))
+            .await
+            .unwrap()
+            .into_items()
+            .remove(0);
+        service
+            .repository()
+            .soft_delete_biz_metadata_many(vec![exported], chrono::Utc::now())
+            .await
+            .unwrap();
+
+        let mut rest = String::new();
+        while let Some(chunk) = stream.next().await {
+            rest.push_str(std::str::from_utf8(&chunk.unwrap()).unwrap());
+        }
+        let codes: Vec<Value> = rest
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap()["code"].clone())
+            .collect();
+        assert_eq!(codes, ["node_2", "node_3", "node_4"]);
+    }
+}
@@ -0,0 +1,241 @@
+//! 写操作限流：按客户端 IP 计算令牌桶，超限返回 429 与 `Retry-After`。
+//!
+//! 服务只服务单一租户（见 [`auth`](super::auth)），按租户计数等同全局限流，因此不以租户作为限流键。
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{HeaderValue, Method, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use futures_util::future::BoxFuture;
+
+use crate::interface::http::mapper::HttpError;
+
+/// 每秒补充令牌数的环境变量名。
+pub const RATE_LIMIT_PER_SECOND_ENV: &str = "BIZ_METADATA_RATE_LIMIT_PER_SECOND";
+/// 桶容量（突发上限）的环境变量名。
+pub const RATE_LIMIT_BURST_ENV: &str = "BIZ_METADATA_RATE_LIMIT_BURST";
+/// 默认每秒补充令牌数。
+pub const DEFAULT_RATE_PER_SECOND: f64 = 20.0;
+/// 默认桶容量。
+pub const DEFAULT_BURST: u32 = 40;
+
+/// 内存桶数量超过该值时清理已回满的桶，避免按 IP 计数时无限增长。
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// 限流状态存储，进程内实现为 [`InMemoryTokenBucket`]，可替换为 Redis 等共享实现。
+pub trait RateLimitStore: Send + Sync + 'static {
+    /// 为 `key` 消耗一个令牌；不足时返回需要等待的时长。
+    fn try_acquire<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), Duration>>;
+}
+
+struct Bucket {
+    tokens: f64,
+    refreshed_at: Instant,
+}
+
+/// 进程内令牌桶。
+///
+/// ```
+/// use biz_metadata::interface::http::rate_limit::InMemoryTokenBucket;
+///
+/// let bucket = InMemoryTokenBucket::new(1.0, 2);
+/// assert!(bucket.acquire_at("t", std::time::Instant::now()).is_ok());
+/// ```
+pub struct InMemoryTokenBucket {
+    rate_per_second: f64,
+    burst: u32,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+impl InMemoryTokenBucket {
+    /// 以补充速率与桶容量构造。
+    pub fn new(rate_per_second: f64, burst: u32) -> Self {
+        Self {
+            rate_per_second,
+            burst,
+            buckets: Mutex::default(),
+        }
+    }
+
+    /// 按给定时间点消耗令牌，便于测试控制时钟。
+    pub fn acquire_at(&self, key: &str, now: Instant) -> Result<(), Duration> {
+        let burst = f64::from(self.burst);
+        let mut buckets = self
+            .buckets
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if buckets.len() > PRUNE_THRESHOLD {
+            let rate = self.rate_per_second;
+            buckets.retain(|_, bucket| {
+                bucket.tokens + now.duration_since(bucket.refreshed_at).as_secs_f64() * rate < burst
+            });
+        }
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: burst,
+            refreshed_at: now,
+        });
+        let elapsed = now.duration_since(bucket.refreshed_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate_per_second).min(burst);
+        bucket.refreshed_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        let missing = 1.0 - bucket.tokens;
+        Err(Duration::from_secs_f64(missing / self.rate_per_second))
+    }
+}
+
+impl RateLimitStore for InMemoryTokenBucket {
+    fn try_acquire<'a>(&'a self, key: &'a str) -> BoxFuture<'a, Result<(), Duration>> {
+        Box::pin(std::future::ready(self.acquire_at(key, Instant::now())))
+    }
+}
+
+/// 限流中间件的共享状态。
+#[derive(Clone)]
+pub struct RateLimiter {
+    store: Arc<dyn RateLimitStore>,
+}
+
+impl RateLimiter {
+    /// 使用自定义存储。
+    pub fn new(store: impl RateLimitStore) -> Self {
+        Self {
+            store: Arc::new(store),
+        }
+    }
+
+    /// 从环境变量读取速率与容量，构造进程内限流器。
+    pub fn from_env() -> Result<Self, String> {
+        let rate = match std::env::var(RATE_LIMIT_PER_SECOND_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|rate| *rate > 0.0)
+                .ok_or_else(|| format!("{RATE_LIMIT_PER_SECOND_ENV} 必须是正数"))?,
+            Err(_) => DEFAULT_RATE_PER_SECOND,
+        };
+        let burst = match std::env::var(RATE_LIMIT_BURST_ENV) {
+            Ok(raw) => raw
+                .trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|burst| *burst > 0)
+                .ok_or_else(|| format!("{RATE_LIMIT_BURST_ENV} 必须是正整数"))?,
+            Err(_) => DEFAULT_BURST,
+        };
+        Ok(Self::new(InMemoryTokenBucket::new(rate, burst)))
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(InMemoryTokenBucket::new(
+            DEFAULT_RATE_PER_SECOND,
+            DEFAULT_BURST,
+        ))
+    }
+}
+
+/// 限流中间件，仅作用于写操作，挂载在鉴权中间件内侧，鉴权失败的请求不消耗令牌。
+///
+/// 限流键取连接的客户端 IP（需以 `into_make_service_with_connect_info` 启动），取不到时共用同一个桶。
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !matches!(
+        *request.method(),
+        Method::POST | Method::PUT | Method::PATCH | Method::DELETE
+    ) {
+        return next.run(request).await;
+    }
+    let key = if let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>()
+    {
+        format!("ip:{}", addr.ip())
+    } else {
+        "anonymous".to_string()
+    };
+
+    match limiter.store.try_acquire(&key).await {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            let retry_after = wait.as_secs_f64().ceil().max(1.0) as u64;
+            let mut response =
+                HttpError::too_many_requests(format!("rate limit exceeded for {key}"))
+                    .into_problem()
+                    .into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, http::StatusCode, routing::post};
+    use tower::ServiceExt;
+
+    fn app(limiter: RateLimiter) -> Router {
+        Router::new()
+            .route(
+                "/items",
+                post(|| async { "created" }).get(|| async { "list" }),
+            )
+            .route_layer(axum::middleware::from_fn_with_state(limiter, rate_limit))
+    }
+
+    async fn send(app: &Router, method: Method) -> Response {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri("/items")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn request_beyond_burst_gets_429() {
+        let app = app(RateLimiter::new(InMemoryTokenBucket::new(0.5, 3)));
+        for _ in 0..3 {
+            assert_eq!(send(&app, Method::POST).await.status(), StatusCode::OK);
+        }
+        let response = send(&app, Method::POST).await;
+        assert_eq!(response.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert_eq!(response.headers()[header::RETRY_AFTER], "2");
+
+        // 读操作默认不限流。
+        assert_eq!(send(&app, Method::GET).await.status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn bucket_refills_over_time_per_key() {
+        let bucket = InMemoryTokenBucket::new(1.0, 1);
+        let start = Instant::now();
+        assert!(bucket.acquire_at("a", start).is_ok());
+        assert!(bucket.acquire_at("a", start).is_err());
+        assert!(bucket.acquire_at("b", start).is_ok());
+        assert!(
+            bucket
+                .acquire_at("a", start + Duration::from_secs(1))
+                .is_ok()
+        );
+    }
+}
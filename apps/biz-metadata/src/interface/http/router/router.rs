@@ -1,28 +1,81 @@
 use axum::Router;
+use axum::http::Method;
 
 use crate::application::service::biz_metadata::BizMetadataService;
 use crate::application::service::biz_metadata_alias::BizMetadataAliasService;
 use crate::infrastructure::persistence::repository::biz_metadata_alias_repository_impl::BizMetadataAliasRepositoryImpl;
 use crate::infrastructure::persistence::repository::biz_metadata_repository_impl::BizMetadataRepositoryImpl;
+use crate::interface::http::auth::{AuthConfig, require_bearer};
+use crate::interface::http::body_limit::{BodyLimitConfig, limit_body};
+use crate::interface::http::cache::CacheConfig;
+use crate::interface::http::cors::CorsConfig;
+use crate::interface::http::field_naming::{FieldNaming, apply_field_naming};
+use crate::interface::http::mapper::EnumCasing;
+use crate::interface::http::rate_limit::{RateLimiter, rate_limit};
 use crate::interface::http::state::AppState;
-use tower_http::cors::CorsLayer;
 use tower_http::normalize_path::NormalizePathLayer;
 
+/// 批量写接口，请求体上限使用 [`BodyLimitConfig`] 的批量上限而非默认上限。
+pub const BULK_ROUTES: &[(Method, &str)] = &[(Method::POST, "/biz_metadata_alias/weights")];
+
 // Include build.rs 生成的 OpenAPI 定义。
 include!(concat!(env!("OUT_DIR"), "/api_doc.rs"));
 
+/// HTTP 层配置集合，各项均按部署从环境变量读取。
+#[derive(Clone)]
+pub struct HttpConfig {
+    pub auth: AuthConfig,
+    pub cors: CorsConfig,
+    pub body_limit: BodyLimitConfig,
+    pub rate_limiter: RateLimiter,
+    pub cache: CacheConfig,
+    pub enum_casing: EnumCasing,
+    pub field_naming: FieldNaming,
+}
+
+impl HttpConfig {
+    /// 依次读取各项配置，任一非法时返回对应错误信息。
+    pub fn from_env() -> Result<Self, String> {
+        Ok(Self {
+            auth: AuthConfig::from_env()?,
+            cors: CorsConfig::from_env()?,
+            body_limit: BodyLimitConfig::from_env()?,
+            rate_limiter: RateLimiter::from_env()?,
+            cache: CacheConfig::from_env()?,
+            enum_casing: EnumCasing::from_env()?,
+            field_naming: FieldNaming::from_env()?,
+        })
+    }
+}
+
 /// 构建带 Swagger UI 的路由，包含元数据与别名接口。
+///
+/// 业务接口统一经过 Bearer 鉴权中间件，Swagger UI 与 OpenAPI 文档保持公开；
+/// CORS 层位于最外层，预检请求在鉴权之前应答；鉴权通过后依次执行写操作限流与请求体限制。
+/// 字段命名改写包在鉴权之外，鉴权失败的 problem-details 同样按配置命名。
 pub fn build_router(
     biz_metadata_service: BizMetadataService<BizMetadataRepositoryImpl>,
     biz_metadata_alias_service: BizMetadataAliasService<BizMetadataAliasRepositoryImpl>,
+    config: HttpConfig,
 ) -> Router<()> {
     use std::sync::Arc;
     use utoipa::openapi::server::ServerBuilder;
     use utoipa_swagger_ui::SwaggerUi;
 
+    let HttpConfig {
+        auth,
+        cors,
+        body_limit,
+        rate_limiter,
+        cache,
+        enum_casing,
+        field_naming,
+    } = config;
     let state = AppState {
         biz_metadata_service: Arc::new(biz_metadata_service),
         biz_metadata_alias_service: Arc::new(biz_metadata_alias_service),
+        cache,
+        enum_casing,
     };
 
     let mut openapi = ApiDoc::openapi();
@@ -36,11 +89,52 @@ pub fn build_router(
         .merge(SwaggerUi::new("/docs").url("/openapi.json", openapi))
         .with_state(state.clone());
 
+    let body_limit = BULK_ROUTES
+        .iter()
+        .fold(body_limit, |config, (method, path)| {
+            config.with_bulk_route(method.clone(), *path)
+        });
     let api = generated_routes_biz_metadata(state.clone())
-        .merge(generated_routes_biz_metadata_alias(state));
+        .merge(generated_routes_biz_metadata_alias(state))
+        // 上限统一由 limit_body 按路由判定，关闭 axum 提取器自带的 2 MiB 默认限制。
+        .layer(axum::extract::DefaultBodyLimit::disable())
+        .route_layer(axum::middleware::from_fn_with_state(body_limit, limit_body))
+        .route_layer(axum::middleware::from_fn_with_state(
+            rate_limiter,
+            rate_limit,
+        ))
+        .route_layer(axum::middleware::from_fn_with_state(auth, require_bearer))
+        .route_layer(axum::middleware::from_fn_with_state(
+            field_naming,
+            apply_field_naming,
+        ));
 
     swagger
         .merge(api)
-        .layer(CorsLayer::permissive())
+        .layer(cors.layer())
         .layer(NormalizePathLayer::trim_trailing_slash())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn openapi_declares_bearer_security() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+        let scheme = &doc["components"]["securitySchemes"][BEARER_AUTH];
+        assert_eq!(scheme["type"], "http");
+        assert_eq!(scheme["scheme"], "bearer");
+        assert_eq!(doc["security"], serde_json::json!([{ BEARER_AUTH: [] }]));
+    }
+
+    #[test]
+    fn bulk_routes_are_registered_operations() {
+        let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        for (method, path) in BULK_ROUTES {
+            let operation = &doc["paths"][*path][method.as_str().to_lowercase()];
+            assert!(operation.is_object(), "{method} {path}");
+        }
+    }
+}
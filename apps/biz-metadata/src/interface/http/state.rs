@@ -4,10 +4,14 @@ use crate::application::service::biz_metadata::BizMetadataService;
 use crate::application::service::biz_metadata_alias::BizMetadataAliasService;
 use crate::infrastructure::persistence::repository::biz_metadata_alias_repository_impl::BizMetadataAliasRepositoryImpl;
 use crate::infrastructure::persistence::repository::biz_metadata_repository_impl::BizMetadataRepositoryImpl;
+use crate::interface::http::cache::CacheConfig;
+use crate::interface::http::mapper::EnumCasing;
 
-/// Axum 共享状态，持有应用服务。
+/// Axum 共享状态，持有应用服务与响应相关配置。
 #[derive(Clone)]
 pub struct AppState {
     pub biz_metadata_service: Arc<BizMetadataService<BizMetadataRepositoryImpl>>,
     pub biz_metadata_alias_service: Arc<BizMetadataAliasService<BizMetadataAliasRepositoryImpl>>,
+    pub cache: CacheConfig,
+    pub enum_casing: EnumCasing,
 }
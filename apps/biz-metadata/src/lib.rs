@@ -1,23 +1,42 @@
+use std::time::Duration;
+
 use sea_orm::{Database, DatabaseConnection};
 
 pub use application::service::biz_metadata::{
-    BizMetadataQueryRequest, BizMetadataService, CreateBizMetadataCommand, FieldUpdate,
+    BizMetadataQueryRequest, BizMetadataSearchHit, BizMetadataService, CATALOG_SCHEMA_VERSION,
+    CatalogAlias, CatalogDump, CatalogEntry, CatalogImportSummary, CreateBizMetadataCommand,
+    DEFAULT_MAX_TREE_DEPTH, DependentAction, DeprecateSubtreeReport, FieldUpdate, ImportMode,
     UpdateBizMetadataCommand,
 };
 pub use application::service::biz_metadata_alias::{
-    AliasFieldUpdate, BizMetadataAliasQueryRequest, BizMetadataAliasService,
-    CreateBizMetadataAliasCommand, UpdateBizMetadataAliasCommand,
+    AliasFieldUpdate, AliasResolution, BizMetadataAliasQueryRequest, BizMetadataAliasService,
+    CreateBizMetadataAliasCommand, DEFAULT_MAX_ALIASES_PER_METADATA, LanguageScope,
+    UpdateBizMetadataAliasCommand,
 };
 pub use domain::biz_metadata::BizMetadata;
-pub use domain::biz_metadata::repository::BizMetadataRepository;
+pub use domain::biz_metadata::code;
+pub use domain::biz_metadata::lint::{
+    LintFinding, LintRule, LintSeverity, MAX_TYPE_REF_DEPTH, lint_catalog,
+};
+pub use domain::biz_metadata::repository::{BizMetadataRepository, is_retryable};
 pub use domain::biz_metadata::value_object::{
-    BizMetadataId, BizMetadataStatus, DataClass, ObjectType, Source, TenantId, ValueType, Version,
+    BizMetadataCode, BizMetadataId, BizMetadataStatus, DataClass, ObjectType, Source, TenantId,
+    Unit, UnitRegistry, ValueType, ValueTypeRegistry, Version,
 };
+pub use domain::biz_metadata::{CodePolicy, CodeUniqueness};
 pub use domain::biz_metadata_alias::{
-    AliasSource, AliasText, AliasWeight, BizMetadataAlias, BizMetadataAliasId,
-    BizMetadataAliasRepository, BizMetadataAliasSnapshot, LanguageCode,
+    AliasNormalization, AliasPrimaryChange, AliasSource, AliasText, AliasWeight, BizMetadataAlias,
+    BizMetadataAliasId, BizMetadataAliasRepository, BizMetadataAliasSnapshot, LanguageCode,
+    PrimaryWeightPolicy,
 };
+pub use domain::error_code;
 pub use domain_core::prelude::Audit;
+#[cfg(feature = "cache")]
+pub use infrastructure::persistence::repository::caching_biz_metadata_repository::CachingBizMetadataRepository;
+#[cfg(feature = "test-util")]
+pub use infrastructure::persistence::repository::in_memory_biz_metadata_alias_repository::InMemoryBizMetadataAliasRepository;
+#[cfg(feature = "test-util")]
+pub use infrastructure::persistence::repository::in_memory_biz_metadata_repository::InMemoryBizMetadataRepository;
 
 use infrastructure::persistence::repository::{
     biz_metadata_alias_repository_impl::BizMetadataAliasRepositoryImpl,
@@ -51,6 +70,24 @@ pub fn build_service(db: DatabaseConnection) -> BizMetadataService<BizMetadataRe
     BizMetadataService::new(repository)
 }
 
+/// 与 [`build_service`] 相同，但自定义仓储数据库调用的超时时间（默认 30 秒）。
+pub fn build_service_with_query_timeout(
+    db: DatabaseConnection,
+    timeout: Duration,
+) -> BizMetadataService<BizMetadataRepositoryImpl> {
+    let repository = BizMetadataRepositoryImpl::new(db).with_query_timeout(timeout);
+    BizMetadataService::new(repository)
+}
+
+/// 与 [`build_service`] 相同，但只读方法走 `read_db`（如只读副本），写入走主库 `db`。
+pub fn build_service_with_read_replica(
+    db: DatabaseConnection,
+    read_db: DatabaseConnection,
+) -> BizMetadataService<BizMetadataRepositoryImpl> {
+    let repository = BizMetadataRepositoryImpl::new(db).with_read_replica(read_db);
+    BizMetadataService::new(repository)
+}
+
 /// 根据数据库连接构建 BizMetadataAliasService。
 pub fn build_alias_service(
     db: DatabaseConnection,
@@ -58,3 +95,21 @@ pub fn build_alias_service(
     let repository = BizMetadataAliasRepositoryImpl::new(db);
     BizMetadataAliasService::new(repository)
 }
+
+/// 与 [`build_alias_service`] 相同，但自定义仓储数据库调用的超时时间（默认 30 秒）。
+pub fn build_alias_service_with_query_timeout(
+    db: DatabaseConnection,
+    timeout: Duration,
+) -> BizMetadataAliasService<BizMetadataAliasRepositoryImpl> {
+    let repository = BizMetadataAliasRepositoryImpl::new(db).with_query_timeout(timeout);
+    BizMetadataAliasService::new(repository)
+}
+
+/// 与 [`build_alias_service`] 相同，但只读方法走 `read_db`（如只读副本），写入走主库 `db`。
+pub fn build_alias_service_with_read_replica(
+    db: DatabaseConnection,
+    read_db: DatabaseConnection,
+) -> BizMetadataAliasService<BizMetadataAliasRepositoryImpl> {
+    let repository = BizMetadataAliasRepositoryImpl::new(db).with_read_replica(read_db);
+    BizMetadataAliasService::new(repository)
+}
@@ -0,0 +1,45 @@
+#[path = "../build_support/body_types.rs"]
+mod body_types;
+
+use body_types::parse_body_types;
+
+#[test]
+fn collects_every_declared_body_type() {
+    let attr = r#"(
+    post,
+    path = "/import",
+    request_body(content(
+        (crate::dto::ImportJson = "application/json"),
+        (crate::dto::ImportForm = "application/x-www-form-urlencoded")
+    )),
+    responses(
+        (status = 200, body = ResultResponse<Pair<A, B>>, description = "Imported"),
+        (status = 400, body = ProblemDetails, content_type = "application/problem+json")
+    )
+"#;
+    assert_eq!(
+        parse_body_types(attr),
+        vec![
+            "crate::dto::ImportJson",
+            "crate::dto::ImportForm",
+            "ResultResponse<Pair<A,B>>",
+            "ProblemDetails",
+        ]
+    );
+}
+
+#[test]
+fn keeps_single_body_declarations_working() {
+    let attr = r#"(
+    get,
+    path = "/{id}",
+    request_body = CreateRequest,
+    responses(
+        (status = 200, body = ResultResponse<BizMetadataResponse>, description = "Nobody else"),
+    )
+"#;
+    assert_eq!(
+        parse_body_types(attr),
+        vec!["CreateRequest", "ResultResponse<BizMetadataResponse>"]
+    );
+}
@@ -3,17 +3,63 @@
 use thiserror::Error;
 
 /// 领域层统一错误类型：表达业务规则、不变式等失败
+///
+/// 每个错误都携带稳定的机器可读错误码 `code`（见 [`error_code`](crate::error_code)），
+/// `message` 仅用于展示，措辞可能调整。
+///
+/// ```
+/// use domain_core::domain_error::DomainError;
+/// use domain_core::error_code;
+///
+/// let err = DomainError::Validation {
+///     code: error_code::VALUE_BLANK,
+///     message: "name cannot be blank".into(),
+/// };
+/// assert_eq!(err.code(), "validation.blank");
+/// assert_eq!(err.message(), "name cannot be blank");
+/// assert_eq!(err.with_code("name.invalid").code(), "name.invalid");
+/// ```
 #[derive(Debug, Error)]
 pub enum DomainError {
     /// 通用校验错误（值对象、实体构造失败）
     #[error("validation error: {message}")]
-    Validation { message: String },
+    Validation { code: &'static str, message: String },
 
     /// 不变式 / 状态约束被违反
     #[error("invariant violation: {message}")]
-    InvariantViolation { message: String },
+    InvariantViolation { code: &'static str, message: String },
 
     /// 基础设施或持久化相关错误
     #[error("persistence error: {message}")]
-    Persistence { message: String },
+    Persistence { code: &'static str, message: String },
+}
+
+impl DomainError {
+    /// 稳定的机器可读错误码。
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Validation { code, .. }
+            | Self::InvariantViolation { code, .. }
+            | Self::Persistence { code, .. } => code,
+        }
+    }
+
+    /// 面向人的错误信息。
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Validation { message, .. }
+            | Self::InvariantViolation { message, .. }
+            | Self::Persistence { message, .. } => message,
+        }
+    }
+
+    /// 保留变体与信息，替换为更具体的错误码。
+    pub fn with_code(mut self, new_code: &'static str) -> Self {
+        match &mut self {
+            Self::Validation { code, .. }
+            | Self::InvariantViolation { code, .. }
+            | Self::Persistence { code, .. } => *code = new_code,
+        }
+        self
+    }
 }
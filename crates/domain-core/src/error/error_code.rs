@@ -0,0 +1,29 @@
+//! 领域核心内置的稳定错误码。
+//!
+//! 错误码采用 `<领域>.<失败原因>` 的小写点分格式，一经发布不再修改，客户端应据此而非错误信息做判断。
+//! 业务模块的错误码在各自模块集中定义，此处仅包含通用兜底码与核心组件自身使用的错误码。
+//!
+//! | 错误码 | 变体 | 含义 |
+//! | --- | --- | --- |
+//! | `validation.failed` | `Validation` | 未细分的校验失败 |
+//! | `validation.blank` | `Validation` | 必填字符串为空白 |
+//! | `invariant.violated` | `InvariantViolation` | 未细分的不变式被违反 |
+//! | `audit.timeline_invalid` | `InvariantViolation` | 审计时间线（created/updated/deleted）顺序不合法 |
+//! | `persistence.failed` | `Persistence` | 未细分的持久化失败 |
+//! | `cursor.invalid` | `Validation` | 分页游标格式错误或被篡改 |
+//! | `cursor.mismatch` | `Validation` | 分页游标与当前排序字段或方向不一致 |
+
+/// 未细分的校验失败。
+pub const VALIDATION_FAILED: &str = "validation.failed";
+/// 必填字符串为空白。
+pub const VALUE_BLANK: &str = "validation.blank";
+/// 未细分的不变式被违反。
+pub const INVARIANT_VIOLATED: &str = "invariant.violated";
+/// 审计时间线顺序不合法。
+pub const AUDIT_TIMELINE_INVALID: &str = "audit.timeline_invalid";
+/// 未细分的持久化失败。
+pub const PERSISTENCE_FAILED: &str = "persistence.failed";
+/// 分页游标格式错误或被篡改。
+pub const CURSOR_INVALID: &str = "cursor.invalid";
+/// 分页游标与当前排序不一致。
+pub const CURSOR_MISMATCH: &str = "cursor.mismatch";
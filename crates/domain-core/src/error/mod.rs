@@ -1,3 +1,4 @@
 //! 领域层公共错误定义。
 
 pub mod domain_error;
+pub mod error_code;
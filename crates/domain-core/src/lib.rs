@@ -23,10 +23,22 @@ pub mod domain_error {
     pub use crate::error::domain_error::*;
 }
 
+pub mod error_code {
+    pub use crate::error::error_code::*;
+}
+
 pub mod audit {
     pub use crate::shared::audit::*;
 }
 
+pub mod clock {
+    pub use crate::shared::clock::*;
+}
+
+pub mod id_generator {
+    pub use crate::shared::id_generator::*;
+}
+
 pub mod expression {
     pub use crate::shared::expression::*;
 }
@@ -35,6 +47,10 @@ pub mod pagination {
     pub use crate::shared::pagination::*;
 }
 
+pub mod cursor {
+    pub use crate::shared::cursor::*;
+}
+
 // 兼容别名
 pub mod building_blocks {
     pub use crate::core::*;
@@ -2,8 +2,12 @@ pub use super::core::value_object::ValueObject;
 pub use super::core::{aggregate_root::AggregateRoot, entity::Entity, repository::Repository};
 pub use super::error::domain_error::DomainError;
 pub use super::shared::{
-    audit::Audit,
-    expression::{Comparison, Expression, FilterValue, OrderBy, QueryOptions, SortDirection},
+    audit::{Audit, TimestampPrecision},
+    clock::{Clock, SystemClock},
+    expression::{
+        Comparison, Expression, FilterValue, NullsOrder, OrderBy, QueryOptions, SortDirection,
+    },
+    id_generator::{DatabaseSequence, IdGenerator},
     pagination::Page,
     validation::validate_non_empty,
 };
@@ -1,6 +1,7 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, SubsecRound, Utc};
 
 use crate::error::domain_error::DomainError;
+use crate::error::error_code;
 
 /// 通用的审计时间信息，封装创建、更新时间以及软删除校验。
 ///
@@ -70,6 +71,7 @@ impl Audit {
     pub fn bump_updated(&mut self, updated_at: DateTime<Utc>) -> Result<(), DomainError> {
         if updated_at < self.updated_at {
             return Err(DomainError::InvariantViolation {
+                code: error_code::AUDIT_TIMELINE_INVALID,
                 message: "updated_at cannot move backwards".into(),
             });
         }
@@ -81,6 +83,7 @@ impl Audit {
     pub fn mark_deleted(&mut self, delete_at: DateTime<Utc>) -> Result<(), DomainError> {
         if delete_at < self.updated_at {
             return Err(DomainError::InvariantViolation {
+                code: error_code::AUDIT_TIMELINE_INVALID,
                 message: "delete_at must be greater than or equal to updated_at".into(),
             });
         }
@@ -88,6 +91,35 @@ impl Audit {
         Ok(())
     }
 
+    /// 撤销软删除并以 `restored_at` 作为更新时间，要求不早于删除时间与当前 `updated_at`。
+    ///
+    /// ```
+    /// use chrono::{Duration, Utc};
+    /// use domain_core::audit::Audit;
+    ///
+    /// let now = Utc::now();
+    /// let mut audit = Audit::new(now);
+    /// audit.mark_deleted(now + Duration::seconds(1)).unwrap();
+    /// assert!(audit.restore(now).is_err());
+    /// audit.restore(now + Duration::seconds(2)).unwrap();
+    /// assert!(!audit.is_deleted());
+    /// assert_eq!(audit.updated_at(), now + Duration::seconds(2));
+    /// ```
+    pub fn restore(&mut self, restored_at: DateTime<Utc>) -> Result<(), DomainError> {
+        if self
+            .delete_at
+            .is_some_and(|delete_at| restored_at < delete_at)
+        {
+            return Err(DomainError::InvariantViolation {
+                code: error_code::AUDIT_TIMELINE_INVALID,
+                message: "restored_at must be greater than or equal to delete_at".into(),
+            });
+        }
+        self.bump_updated(restored_at)?;
+        self.delete_at = None;
+        Ok(())
+    }
+
     fn validate(
         created_at: DateTime<Utc>,
         updated_at: DateTime<Utc>,
@@ -95,6 +127,7 @@ impl Audit {
     ) -> Result<(), DomainError> {
         if updated_at < created_at {
             return Err(DomainError::InvariantViolation {
+                code: error_code::AUDIT_TIMELINE_INVALID,
                 message: "updated_at cannot be earlier than created_at".into(),
             });
         }
@@ -102,9 +135,50 @@ impl Audit {
             && delete_at < updated_at
         {
             return Err(DomainError::InvariantViolation {
+                code: error_code::AUDIT_TIMELINE_INVALID,
                 message: "delete_at must be greater than or equal to updated_at".into(),
             });
         }
         Ok(())
     }
 }
+
+/// 审计时间的保留精度，默认保留完整精度。
+///
+/// 截断总是向下取整，`a <= b` 时截断后仍有 `a <= b`，因此不会破坏时间线的先后顺序。
+///
+/// # 示例
+/// ```
+/// use chrono::{TimeZone, Timelike, Utc};
+/// use domain_core::audit::TimestampPrecision;
+///
+/// let at = Utc.timestamp_opt(1_700_000_000, 123_456_789).unwrap();
+/// assert_eq!(TimestampPrecision::Full.truncate(at), at);
+/// assert_eq!(TimestampPrecision::Micros.truncate(at).nanosecond(), 123_456_000);
+/// assert_eq!(TimestampPrecision::Millis.truncate(at).nanosecond(), 123_000_000);
+/// assert_eq!(TimestampPrecision::Seconds.truncate(at).nanosecond(), 0);
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimestampPrecision {
+    /// 不截断。
+    #[default]
+    Full,
+    /// 截断到微秒，与 PostgreSQL `timestamptz` 一致。
+    Micros,
+    /// 截断到毫秒。
+    Millis,
+    /// 截断到秒。
+    Seconds,
+}
+
+impl TimestampPrecision {
+    /// 按精度向下截断时间。
+    pub fn truncate(self, at: DateTime<Utc>) -> DateTime<Utc> {
+        match self {
+            Self::Full => at,
+            Self::Micros => at.trunc_subsecs(6),
+            Self::Millis => at.trunc_subsecs(3),
+            Self::Seconds => at.trunc_subsecs(0),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// 审计时间来源，服务通过注入时钟决定聚合的创建、更新与删除时间。
+pub trait Clock: Send + Sync {
+    /// 当前时间，UTC。
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// 读取系统时间的时钟，生产环境默认使用。
+///
+/// # 示例
+/// ```
+/// use domain_core::clock::{Clock, SystemClock};
+///
+/// let before = chrono::Utc::now();
+/// assert!(SystemClock.now() >= before);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// 可手动设置与推进的固定时钟，用于测试断言精确时间戳。
+///
+/// # 示例
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use domain_core::clock::{Clock, FixedClock};
+///
+/// let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+/// let clock = FixedClock::new(start);
+/// assert_eq!(clock.now(), start);
+/// clock.advance(Duration::seconds(5));
+/// assert_eq!(clock.now(), start + Duration::seconds(5));
+/// ```
+#[derive(Debug)]
+pub struct FixedClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    /// 以给定时间构造。
+    pub fn new(now: DateTime<Utc>) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// 将时间设置为 `now`。
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.lock() = now;
+    }
+
+    /// 将时间向前推进 `by`。
+    pub fn advance(&self, by: Duration) {
+        *self.lock() += by;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DateTime<Utc>> {
+        self.now
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.lock()
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for std::sync::Arc<C> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+}
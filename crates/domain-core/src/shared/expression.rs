@@ -1,6 +1,12 @@
 //! 轻量表达式 DSL，用于跨仓储的筛选、排序和分页需求。
 
-use std::fmt::Debug;
+use std::cmp::Ordering;
+use std::fmt::{self, Debug, Display, Write};
+
+use chrono::{DateTime, Utc};
+
+/// [`Display`] 渲染字符串值时保留的最大字符数，超出部分以 `…` 截断，避免日志泄露完整敏感取值。
+pub const DISPLAY_VALUE_MAX_CHARS: usize = 32;
 
 /// 基础的筛选值类型，覆盖常见标量场景。
 #[derive(Clone, Debug, PartialEq)]
@@ -9,6 +15,8 @@ pub enum FilterValue {
     I64(i64),
     F64(f64),
     Bool(bool),
+    /// UTC 时间戳，用于 `created_at/updated_at` 等时间字段的区间筛选。
+    DateTime(DateTime<Utc>),
 }
 
 impl FilterValue {
@@ -17,7 +25,7 @@ impl FilterValue {
             FilterValue::I64(v) => Some(*v),
             FilterValue::F64(v) => Some(*v as i64),
             FilterValue::String(v) => v.parse().ok(),
-            FilterValue::Bool(_) => None,
+            FilterValue::Bool(_) | FilterValue::DateTime(_) => None,
         }
     }
 
@@ -27,6 +35,7 @@ impl FilterValue {
             FilterValue::I64(v) => Some(v.to_string()),
             FilterValue::F64(v) => Some(v.to_string()),
             FilterValue::Bool(v) => Some(v.to_string()),
+            FilterValue::DateTime(v) => Some(v.to_rfc3339()),
         }
     }
 
@@ -34,7 +43,33 @@ impl FilterValue {
         match self {
             FilterValue::Bool(v) => Some(*v),
             FilterValue::String(v) => v.parse().ok(),
-            FilterValue::I64(_) | FilterValue::F64(_) => None,
+            FilterValue::I64(_) | FilterValue::F64(_) | FilterValue::DateTime(_) => None,
+        }
+    }
+
+    /// 读取时间戳，字符串按 RFC 3339 解析。
+    pub fn as_datetime(&self) -> Option<DateTime<Utc>> {
+        match self {
+            FilterValue::DateTime(v) => Some(*v),
+            FilterValue::String(v) => DateTime::parse_from_rfc3339(v)
+                .ok()
+                .map(|v| v.with_timezone(&Utc)),
+            FilterValue::I64(_) | FilterValue::F64(_) | FilterValue::Bool(_) => None,
+        }
+    }
+}
+
+impl Display for FilterValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FilterValue::String(v) => match v.char_indices().nth(DISPLAY_VALUE_MAX_CHARS) {
+                Some((cut, _)) => write!(f, "{:?}", format!("{}…", &v[..cut])),
+                None => write!(f, "{v:?}"),
+            },
+            FilterValue::I64(v) => write!(f, "{v}"),
+            FilterValue::F64(v) => write!(f, "{v}"),
+            FilterValue::Bool(v) => write!(f, "{v}"),
+            FilterValue::DateTime(v) => write!(f, "{}", v.to_rfc3339()),
         }
     }
 }
@@ -69,6 +104,12 @@ impl From<bool> for FilterValue {
     }
 }
 
+impl From<DateTime<Utc>> for FilterValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self::DateTime(value)
+    }
+}
+
 /// 单字段条件表达式。
 #[derive(Clone, Debug, PartialEq)]
 pub enum Comparison {
@@ -111,6 +152,50 @@ pub enum Comparison {
     },
 }
 
+impl Comparison {
+    /// 比较所作用的字段名。
+    pub fn field(&self) -> &str {
+        match self {
+            Comparison::Eq { field, .. }
+            | Comparison::Ne { field, .. }
+            | Comparison::Gt { field, .. }
+            | Comparison::Ge { field, .. }
+            | Comparison::Lt { field, .. }
+            | Comparison::Le { field, .. }
+            | Comparison::Between { field, .. }
+            | Comparison::In { field, .. }
+            | Comparison::Contains { field, .. } => field,
+        }
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Comparison::Eq { field, value } => write!(f, "{field} = {value}"),
+            Comparison::Ne { field, value } => write!(f, "{field} != {value}"),
+            Comparison::Gt { field, value } => write!(f, "{field} > {value}"),
+            Comparison::Ge { field, value } => write!(f, "{field} >= {value}"),
+            Comparison::Lt { field, value } => write!(f, "{field} < {value}"),
+            Comparison::Le { field, value } => write!(f, "{field} <= {value}"),
+            Comparison::Between { field, start, end } => {
+                write!(f, "{field} BETWEEN {start} AND {end}")
+            }
+            Comparison::In { field, values } => {
+                write!(f, "{field} IN (")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str(")")
+            }
+            Comparison::Contains { field, value } => write!(f, "{field} CONTAINS {value}"),
+        }
+    }
+}
+
 /// 组合表达式，支持 AND / OR / NOT。
 #[derive(Clone, Debug, PartialEq)]
 pub enum Expression {
@@ -150,6 +235,182 @@ impl Expression {
     pub fn cmp(comparison: Comparison) -> Self {
         Expression::Comparison(comparison)
     }
+
+    /// 按出现顺序列出表达式引用的全部字段（可能重复）。
+    ///
+    /// # 示例
+    /// ```
+    /// use domain_core::expression::{Expression, eq, gt};
+    ///
+    /// let expr = Expression::and(vec![
+    ///     Expression::cmp(eq("code", "company")),
+    ///     Expression::negate(Expression::cmp(gt("version", 1_i64))),
+    /// ]);
+    /// assert_eq!(expr.fields(), ["code", "version"]);
+    /// ```
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields = Vec::new();
+        self.collect_fields(&mut fields);
+        fields
+    }
+
+    fn collect_fields<'a>(&'a self, fields: &mut Vec<&'a str>) {
+        match self {
+            Expression::Comparison(cmp) => fields.push(cmp.field()),
+            Expression::And(children) | Expression::Or(children) => {
+                for child in children {
+                    child.collect_fields(fields);
+                }
+            }
+            Expression::Not(child) => child.collect_fields(fields),
+            Expression::True | Expression::False => {}
+        }
+    }
+}
+
+/// 以类 SQL 的形式渲染表达式，供日志与排障使用；嵌套的 AND / OR 加括号，
+/// 字符串值超过 [`DISPLAY_VALUE_MAX_CHARS`] 时截断。
+///
+/// ```
+/// use domain_core::expression::{Expression, eq, gt};
+///
+/// let expr = Expression::and(vec![
+///     Expression::cmp(eq("code", "company")),
+///     Expression::negate(Expression::cmp(gt("version", 1_i64))),
+/// ]);
+/// assert_eq!(expr.to_string(), r#"code = "company" AND NOT version > 1"#);
+/// ```
+impl Display for Expression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_tree(f, self, &mut |f, cmp| write!(f, "{cmp}"))
+    }
+}
+
+/// 字段名到列名的映射，供 [`Expression::to_sql_preview`] 解析字段；
+/// 持久化层应以与仓储过滤相同的字段表实现，保证预览与实际查询一致。
+pub trait FieldColumns {
+    /// 字段对应的列名，未登记时返回 `None`。
+    fn column_name(&self, field: &str) -> Option<&str>;
+}
+
+impl FieldColumns for [(&str, &str)] {
+    fn column_name(&self, field: &str) -> Option<&str> {
+        self.iter()
+            .find(|(name, _)| *name == field)
+            .map(|(_, column)| *column)
+    }
+}
+
+impl<const N: usize> FieldColumns for [(&str, &str); N] {
+    fn column_name(&self, field: &str) -> Option<&str> {
+        self.as_slice().column_name(field)
+    }
+}
+
+/// 未登记字段在 SQL 预览中的占位标记。
+pub const UNKNOWN_FIELD_MARKER: &str = "<unknown>";
+
+impl Expression {
+    /// 渲染不含取值的类 SQL 预览：字段按 `columns` 映射为列名，取值依次替换为 `$1`、`$2`……；
+    /// 未登记的字段渲染为 `<unknown>:字段名`，不会被静默丢弃。
+    ///
+    /// ```
+    /// use domain_core::expression::{Expression, eq, r#in};
+    ///
+    /// let expr = Expression::and(vec![
+    ///     Expression::cmp(eq("status", "active")),
+    ///     Expression::cmp(r#in("object_type", vec!["entity", "feature"])),
+    /// ]);
+    /// let columns = [("status", "status"), ("object_type", "object_type")];
+    /// assert_eq!(
+    ///     expr.to_sql_preview(&columns),
+    ///     "status = $1 AND object_type IN ($2,$3)"
+    /// );
+    /// ```
+    pub fn to_sql_preview(&self, columns: &(impl FieldColumns + ?Sized)) -> String {
+        let mut next = 0;
+        let mut placeholder = move || {
+            next += 1;
+            format!("${next}")
+        };
+        let mut out = String::new();
+        write_tree(&mut out, self, &mut |out, cmp| {
+            let field = cmp.field();
+            match columns.column_name(field) {
+                Some(column) => out.write_str(column)?,
+                None => write!(out, "{UNKNOWN_FIELD_MARKER}:{field}")?,
+            }
+            match cmp {
+                Comparison::Eq { .. } => write!(out, " = {}", placeholder()),
+                Comparison::Ne { .. } => write!(out, " != {}", placeholder()),
+                Comparison::Gt { .. } => write!(out, " > {}", placeholder()),
+                Comparison::Ge { .. } => write!(out, " >= {}", placeholder()),
+                Comparison::Lt { .. } => write!(out, " < {}", placeholder()),
+                Comparison::Le { .. } => write!(out, " <= {}", placeholder()),
+                Comparison::Between { .. } => {
+                    let start = placeholder();
+                    write!(out, " BETWEEN {start} AND {}", placeholder())
+                }
+                Comparison::In { values, .. } => {
+                    let list = values.iter().map(|_| placeholder()).collect::<Vec<_>>();
+                    write!(out, " IN ({})", list.join(","))
+                }
+                Comparison::Contains { .. } => write!(out, " LIKE {}", placeholder()),
+            }
+        })
+        .expect("writing to a String cannot fail");
+        out
+    }
+}
+
+/// 按 AND / OR / NOT 结构输出表达式，比较节点交由 `leaf` 渲染。
+fn write_tree<W: fmt::Write>(
+    out: &mut W,
+    expr: &Expression,
+    leaf: &mut impl FnMut(&mut W, &Comparison) -> fmt::Result,
+) -> fmt::Result {
+    match expr {
+        Expression::Comparison(cmp) => leaf(out, cmp),
+        Expression::And(children) => write_joined(out, children, " AND ", leaf),
+        Expression::Or(children) => write_joined(out, children, " OR ", leaf),
+        Expression::Not(child) => {
+            out.write_str("NOT ")?;
+            write_operand(out, child, leaf)
+        }
+        Expression::True => out.write_str("TRUE"),
+        Expression::False => out.write_str("FALSE"),
+    }
+}
+
+fn write_joined<W: fmt::Write>(
+    out: &mut W,
+    children: &[Expression],
+    separator: &str,
+    leaf: &mut impl FnMut(&mut W, &Comparison) -> fmt::Result,
+) -> fmt::Result {
+    for (i, child) in children.iter().enumerate() {
+        if i > 0 {
+            out.write_str(separator)?;
+        }
+        write_operand(out, child, leaf)?;
+    }
+    Ok(())
+}
+
+/// 作为子项输出时，为多元 AND / OR 加括号以保留优先级。
+fn write_operand<W: fmt::Write>(
+    out: &mut W,
+    expr: &Expression,
+    leaf: &mut impl FnMut(&mut W, &Comparison) -> fmt::Result,
+) -> fmt::Result {
+    match expr {
+        Expression::And(children) | Expression::Or(children) if children.len() > 1 => {
+            out.write_char('(')?;
+            write_tree(out, expr, leaf)?;
+            out.write_char(')')
+        }
+        _ => write_tree(out, expr, leaf),
+    }
 }
 
 /// 排序方向。
@@ -159,11 +420,27 @@ pub enum SortDirection {
     Desc,
 }
 
+/// NULL 值在排序结果中的位置。
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum NullsOrder {
+    /// 沿用 PostgreSQL 默认：升序时排在最后，降序时排在最前。
+    #[default]
+    Default,
+    /// 无论方向均排在最前。
+    First,
+    /// 无论方向均排在最后。
+    Last,
+}
+
 /// 排序字段表达式。
 #[derive(Clone, Debug, PartialEq)]
 pub struct OrderBy {
     pub field: String,
     pub direction: SortDirection,
+    /// 排序规则提示（如 `pinyin`），由持久化实现映射为具体 collation，不支持时按默认规则排序。
+    pub collation: Option<String>,
+    /// NULL 值的位置。
+    pub nulls: NullsOrder,
 }
 
 impl OrderBy {
@@ -171,6 +448,8 @@ impl OrderBy {
         Self {
             field: field.into(),
             direction: SortDirection::Asc,
+            collation: None,
+            nulls: NullsOrder::Default,
         }
     }
 
@@ -178,6 +457,63 @@ impl OrderBy {
         Self {
             field: field.into(),
             direction: SortDirection::Desc,
+            collation: None,
+            nulls: NullsOrder::Default,
+        }
+    }
+
+    /// 附加排序规则提示。
+    ///
+    /// ```
+    /// use domain_core::expression::OrderBy;
+    ///
+    /// let order = OrderBy::asc("name").with_collation("pinyin");
+    /// assert_eq!(order.collation.as_deref(), Some("pinyin"));
+    /// ```
+    pub fn with_collation(mut self, collation: impl Into<String>) -> Self {
+        self.collation = Some(collation.into());
+        self
+    }
+
+    /// 指定 NULL 值的位置。
+    pub fn with_nulls(mut self, nulls: NullsOrder) -> Self {
+        self.nulls = nulls;
+        self
+    }
+
+    /// 按本排序项比较两个可能为空的字段值，供内存实现与数据库排序保持一致。
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use domain_core::expression::{FilterValue, NullsOrder, OrderBy};
+    ///
+    /// let value = Some(FilterValue::from(1_i64));
+    /// assert_eq!(OrderBy::asc("parent_id").compare(value.clone(), None), Ordering::Less);
+    /// let order = OrderBy::asc("parent_id").with_nulls(NullsOrder::First);
+    /// assert_eq!(order.compare(value, None), Ordering::Greater);
+    /// ```
+    pub fn compare(&self, left: Option<FilterValue>, right: Option<FilterValue>) -> Ordering {
+        let nulls_first = match self.nulls {
+            NullsOrder::First => true,
+            NullsOrder::Last => false,
+            NullsOrder::Default => self.direction == SortDirection::Desc,
+        };
+        let null_vs_value = if nulls_first {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        };
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                let ordering = compare_values(&left, &right).unwrap_or(Ordering::Equal);
+                match self.direction {
+                    SortDirection::Asc => ordering,
+                    SortDirection::Desc => ordering.reverse(),
+                }
+            }
+            (None, Some(_)) => null_vs_value,
+            (Some(_), None) => null_vs_value.reverse(),
+            (None, None) => Ordering::Equal,
         }
     }
 }
@@ -291,3 +627,127 @@ impl std::ops::Not for Expression {
         Expression::Not(Box::new(self))
     }
 }
+
+/// 基于字段访问器对表达式求值，用于内存过滤等无需数据库的场景。
+///
+/// - 比较时以字段值的类型为准，目标值按 `FilterValue::as_*` 规则强制转换，转换失败视为不匹配
+/// - 字段缺失（`get_field` 返回 `None`）时比较结果为未知，按 SQL 三值逻辑参与 `And`/`Or`/`Not`：
+///   `Not` 未知仍为未知，最终只有确定为真的记录匹配，与 `NOT (col = x)` 在 `col IS NULL` 时不命中一致
+/// - `Contains` 按字符串子串匹配
+///
+/// ```
+/// use domain_core::expression::{Expression, FilterValue, between, eq, evaluate};
+///
+/// let expr = Expression::and(vec![
+///     Expression::cmp(eq("status", "active")),
+///     Expression::cmp(between("score", 60_i64, 100_i64)),
+/// ]);
+/// let record = |field: &str| match field {
+///     "status" => Some(FilterValue::from("active")),
+///     "score" => Some(FilterValue::from(88_i64)),
+///     _ => None,
+/// };
+/// assert!(evaluate(&expr, record));
+///
+/// // 缺失字段为未知：取反后仍不匹配，但可被 `Or` 中确定为真的分支覆盖。
+/// let missing = Expression::cmp(eq("unit", "CNY"));
+/// assert!(!evaluate(&!missing.clone(), record));
+/// assert!(evaluate(
+///     &Expression::or(vec![missing, Expression::cmp(eq("status", "active"))]),
+///     record
+/// ));
+/// ```
+pub fn evaluate(expr: &Expression, get_field: impl Fn(&str) -> Option<FilterValue>) -> bool {
+    evaluate_with(expr, &get_field) == Some(true)
+}
+
+/// 三值求值：`None` 表示未知（对应 SQL 的 `NULL`）。
+fn evaluate_with(
+    expr: &Expression,
+    get_field: &impl Fn(&str) -> Option<FilterValue>,
+) -> Option<bool> {
+    match expr {
+        Expression::Comparison(cmp) => evaluate_comparison(cmp, get_field),
+        Expression::And(children) => {
+            let mut result = Some(true);
+            for child in children {
+                match evaluate_with(child, get_field) {
+                    Some(false) => return Some(false),
+                    None => result = None,
+                    Some(true) => {}
+                }
+            }
+            result
+        }
+        Expression::Or(children) => {
+            let mut result = Some(false);
+            for child in children {
+                match evaluate_with(child, get_field) {
+                    Some(true) => return Some(true),
+                    None => result = None,
+                    Some(false) => {}
+                }
+            }
+            result
+        }
+        Expression::Not(child) => evaluate_with(child, get_field).map(|value| !value),
+        Expression::True => Some(true),
+        Expression::False => Some(false),
+    }
+}
+
+fn evaluate_comparison(
+    cmp: &Comparison,
+    get_field: &impl Fn(&str) -> Option<FilterValue>,
+) -> Option<bool> {
+    let field = match cmp {
+        Comparison::Eq { field, .. }
+        | Comparison::Ne { field, .. }
+        | Comparison::Gt { field, .. }
+        | Comparison::Ge { field, .. }
+        | Comparison::Lt { field, .. }
+        | Comparison::Le { field, .. }
+        | Comparison::Between { field, .. }
+        | Comparison::In { field, .. }
+        | Comparison::Contains { field, .. } => field,
+    };
+    let actual = get_field(field)?;
+    let ordering = |target: &FilterValue| compare_values(&actual, target);
+    Some(match cmp {
+        Comparison::Eq { value, .. } => ordering(value).is_some_and(Ordering::is_eq),
+        Comparison::Ne { value, .. } => ordering(value).is_some_and(Ordering::is_ne),
+        Comparison::Gt { value, .. } => ordering(value).is_some_and(Ordering::is_gt),
+        Comparison::Ge { value, .. } => ordering(value).is_some_and(Ordering::is_ge),
+        Comparison::Lt { value, .. } => ordering(value).is_some_and(Ordering::is_lt),
+        Comparison::Le { value, .. } => ordering(value).is_some_and(Ordering::is_le),
+        Comparison::Between { start, end, .. } => {
+            ordering(start).is_some_and(Ordering::is_ge)
+                && ordering(end).is_some_and(Ordering::is_le)
+        }
+        Comparison::In { values, .. } => values
+            .iter()
+            .any(|target| ordering(target).is_some_and(Ordering::is_eq)),
+        Comparison::Contains { value, .. } => match (actual.as_string(), value.as_string()) {
+            (Some(haystack), Some(needle)) => haystack.contains(&needle),
+            _ => false,
+        },
+    })
+}
+
+/// 以左值类型为准比较两个筛选值，右值按 `FilterValue::as_*` 转换，无法转换时返回 `None`。
+pub fn compare_values(left: &FilterValue, right: &FilterValue) -> Option<Ordering> {
+    match left {
+        FilterValue::I64(l) => match right {
+            FilterValue::F64(r) => (*l as f64).partial_cmp(r),
+            other => other.as_i64().map(|r| l.cmp(&r)),
+        },
+        FilterValue::F64(l) => match right {
+            FilterValue::F64(r) => l.partial_cmp(r),
+            FilterValue::String(r) => r.parse::<f64>().ok().and_then(|r| l.partial_cmp(&r)),
+            other => other.as_i64().and_then(|r| l.partial_cmp(&(r as f64))),
+        },
+        FilterValue::String(l) => right.as_string().map(|r| l.as_str().cmp(r.as_str())),
+        FilterValue::Bool(l) => right.as_bool().map(|r| l.cmp(&r)),
+        FilterValue::DateTime(l) => right.as_datetime().map(|r| l.cmp(&r)),
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 聚合标识来源，服务在插入前通过注入的生成器决定是否预分配 id。
+pub trait IdGenerator: Send + Sync {
+    /// 为新聚合预分配的正整数 id；返回 `None` 表示交由数据库自增序列生成。
+    fn next_id(&self) -> Option<i64>;
+}
+
+/// 不预分配 id，由数据库自增序列在插入时生成，生产环境默认使用。
+///
+/// # 示例
+/// ```
+/// use domain_core::id_generator::{DatabaseSequence, IdGenerator};
+///
+/// assert_eq!(DatabaseSequence.next_id(), None);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DatabaseSequence;
+
+impl IdGenerator for DatabaseSequence {
+    fn next_id(&self) -> Option<i64> {
+        None
+    }
+}
+
+/// Snowflake 起始纪元：2024-01-01T00:00:00Z 的毫秒时间戳。
+pub const SNOWFLAKE_EPOCH_MILLIS: i64 = 1_704_067_200_000;
+/// 节点号位数，最多 1024 个节点。
+const WORKER_BITS: u32 = 10;
+/// 毫秒内序列号位数，单节点每毫秒最多 4096 个 id。
+const SEQUENCE_BITS: u32 = 12;
+/// 节点号上限（含）。
+pub const MAX_SNOWFLAKE_WORKER_ID: u16 = (1 << WORKER_BITS) - 1;
+const MAX_SEQUENCE: i64 = (1 << SEQUENCE_BITS) - 1;
+
+/// Snowflake 风格的进程内 id 生成器：41 位毫秒时间戳 + 10 位节点号 + 12 位序列号。
+///
+/// 同一节点生成的 id 严格递增；序列号耗尽或系统时钟回拨时沿用上一个毫秒继续递增，
+/// 不等待也不重复。多实例部署须为每个实例分配不同的节点号。
+///
+/// # 示例
+/// ```
+/// use domain_core::id_generator::{IdGenerator, SnowflakeIdGenerator};
+///
+/// let generator = SnowflakeIdGenerator::new(7).unwrap();
+/// let first = generator.next_id().unwrap();
+/// let second = generator.next_id().unwrap();
+/// assert!(first > 0 && second > first);
+/// assert_eq!(SnowflakeIdGenerator::worker_of(first), 7);
+/// assert!(SnowflakeIdGenerator::new(4096).is_none());
+/// ```
+#[derive(Debug)]
+pub struct SnowflakeIdGenerator {
+    worker_id: u16,
+    /// 上一次分配的（毫秒偏移，序列号）。
+    last: Mutex<(i64, i64)>,
+}
+
+impl SnowflakeIdGenerator {
+    /// 以节点号构造，超过 [`MAX_SNOWFLAKE_WORKER_ID`] 时返回 `None`。
+    pub fn new(worker_id: u16) -> Option<Self> {
+        (worker_id <= MAX_SNOWFLAKE_WORKER_ID).then(|| Self {
+            worker_id,
+            last: Mutex::new((-1, MAX_SEQUENCE)),
+        })
+    }
+
+    /// 当前节点号。
+    pub fn worker_id(&self) -> u16 {
+        self.worker_id
+    }
+
+    /// 从 id 中取出生成它的节点号。
+    pub fn worker_of(id: i64) -> u16 {
+        ((id >> SEQUENCE_BITS) & i64::from(MAX_SNOWFLAKE_WORKER_ID)) as u16
+    }
+
+    fn elapsed_millis() -> i64 {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as i64);
+        (now - SNOWFLAKE_EPOCH_MILLIS).max(0)
+    }
+}
+
+impl IdGenerator for SnowflakeIdGenerator {
+    fn next_id(&self) -> Option<i64> {
+        let mut last = self
+            .last
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let (last_millis, last_sequence) = *last;
+        let now = Self::elapsed_millis();
+        let next = if now > last_millis {
+            (now, 0)
+        } else if last_sequence < MAX_SEQUENCE {
+            (last_millis, last_sequence + 1)
+        } else {
+            (last_millis + 1, 0)
+        };
+        *last = next;
+        let (millis, sequence) = next;
+        Some(
+            (millis << (WORKER_BITS + SEQUENCE_BITS))
+                | (i64::from(self.worker_id) << SEQUENCE_BITS)
+                | sequence,
+        )
+    }
+}
+
+impl<G: IdGenerator + ?Sized> IdGenerator for std::sync::Arc<G> {
+    fn next_id(&self) -> Option<i64> {
+        (**self).next_id()
+    }
+}
@@ -1,6 +1,9 @@
-//! 领域通用支持组件，例如审计字段、表达式与分页。
+//! 领域通用支持组件，例如审计字段、时钟、标识生成、表达式、分页与游标。
 
 pub mod audit;
+pub mod clock;
+pub mod cursor;
 pub mod expression;
+pub mod id_generator;
 pub mod pagination;
 pub mod validation;
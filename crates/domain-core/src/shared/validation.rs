@@ -1,10 +1,12 @@
 //! 通用校验工具。
 use crate::error::domain_error::DomainError;
+use crate::error::error_code;
 
 /// 校验字符串是否包含非空白字符。
 pub fn validate_non_empty(value: &str, label: &str) -> Result<(), DomainError> {
     if value.trim().is_empty() {
         Err(DomainError::Validation {
+            code: error_code::VALUE_BLANK,
             message: format!("{label} cannot be blank"),
         })
     } else {
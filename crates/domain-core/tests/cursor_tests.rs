@@ -0,0 +1,76 @@
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use domain_core::cursor::Cursor;
+use domain_core::error_code;
+use domain_core::expression::{OrderBy, SortDirection};
+
+const KEY: &[u8] = b"cursor test key";
+
+fn cursor(last_sort_value: Option<&str>) -> Cursor {
+    Cursor {
+        last_id: 1024,
+        last_sort_value: last_sort_value.map(str::to_string),
+        sort_field: "code".into(),
+        direction: SortDirection::Desc,
+    }
+}
+
+#[test]
+fn round_trips_values_and_nulls() {
+    for value in [Some("company"), Some(""), Some("a\u{1f}b|c"), None] {
+        let original = cursor(value);
+        let token = original.encode(KEY);
+        assert!(
+            token
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        );
+        assert_eq!(Cursor::decode(&token, KEY).unwrap(), original);
+        assert_eq!(
+            Cursor::decode_for(&token, &OrderBy::desc("code"), KEY).unwrap(),
+            original
+        );
+    }
+}
+
+#[test]
+fn rejects_tampered_and_malformed_tokens() {
+    let token = cursor(Some("company")).encode(KEY);
+    let payload = String::from_utf8(URL_SAFE_NO_PAD.decode(&token).unwrap()).unwrap();
+    let tampered = URL_SAFE_NO_PAD.encode(payload.replace("1024", "2048"));
+    let err = Cursor::decode(&tampered, KEY).unwrap_err();
+    assert_eq!(err.code(), error_code::CURSOR_INVALID);
+
+    for malformed in ["", "not base64!", &token[..token.len() / 2]] {
+        let err = Cursor::decode(malformed, KEY).unwrap_err();
+        assert_eq!(err.code(), error_code::CURSOR_INVALID, "{malformed}");
+    }
+}
+
+#[test]
+fn rejects_cursor_issued_for_another_sort() {
+    let token = cursor(Some("company")).encode(KEY);
+    for order in [OrderBy::asc("code"), OrderBy::desc("name")] {
+        let err = Cursor::decode_for(&token, &order, KEY).unwrap_err();
+        assert_eq!(err.code(), error_code::CURSOR_MISMATCH);
+    }
+}
+
+#[test]
+fn rejects_tokens_signed_with_another_key() {
+    let token = cursor(Some("company")).encode(b"another key");
+    let err = Cursor::decode(&token, KEY).unwrap_err();
+    assert_eq!(err.code(), error_code::CURSOR_INVALID);
+
+    // 改动正文后即使重新拼出格式正确的签名段，没有密钥也无法通过校验。
+    let payload = String::from_utf8(URL_SAFE_NO_PAD.decode(&token).unwrap()).unwrap();
+    let (prefix, rest) = payload.split_at(3);
+    let (_, body) = rest.split_once('\u{1f}').unwrap();
+    let forged = format!(
+        "{prefix}{}\u{1f}{}",
+        "0".repeat(64),
+        body.replace("1024", "1")
+    );
+    let err = Cursor::decode(&URL_SAFE_NO_PAD.encode(forged), KEY).unwrap_err();
+    assert_eq!(err.code(), error_code::CURSOR_INVALID);
+}
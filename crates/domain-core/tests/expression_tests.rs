@@ -30,3 +30,189 @@ fn between_and_sort_options() {
     assert_eq!(opts.order_bys.len(), 1);
     assert_eq!(opts.order_bys[0].direction, SortDirection::Desc);
 }
+
+fn record(field: &str) -> Option<FilterValue> {
+    match field {
+        "status" => Some(FilterValue::from("active")),
+        "code" => Some(FilterValue::from("company.base.name_cn")),
+        "score" => Some(FilterValue::from(75_i64)),
+        "ratio" => Some(FilterValue::from(0.5_f64)),
+        "enabled" => Some(FilterValue::from(true)),
+        "updated_at" => "2025-01-02T00:00:00Z"
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .ok()
+            .map(FilterValue::from),
+        _ => None,
+    }
+}
+
+#[test]
+fn evaluate_equality_and_ordering() {
+    assert!(evaluate(&Expression::cmp(eq("status", "active")), record));
+    assert!(!evaluate(&Expression::cmp(ne("status", "active")), record));
+    assert!(evaluate(&Expression::cmp(gt("score", 70_i64)), record));
+    assert!(evaluate(&Expression::cmp(ge("score", 75_i64)), record));
+    assert!(evaluate(&Expression::cmp(lt("ratio", 1_i64)), record));
+    assert!(!evaluate(&Expression::cmp(le("score", 74_i64)), record));
+}
+
+#[test]
+fn evaluate_between_in_and_contains() {
+    assert!(evaluate(
+        &Expression::cmp(between("score", 70_i64, 75_i64)),
+        record
+    ));
+    assert!(!evaluate(
+        &Expression::cmp(between("score", 76_i64, 80_i64)),
+        record
+    ));
+    assert!(evaluate(
+        &Expression::cmp(r#in("status", vec!["deprecated", "active"])),
+        record
+    ));
+    assert!(evaluate(&Expression::cmp(contains("code", "base")), record));
+    assert!(!evaluate(
+        &Expression::cmp(contains("code", "person")),
+        record
+    ));
+}
+
+#[test]
+fn evaluate_coerces_like_filter_value() {
+    assert!(evaluate(&Expression::cmp(eq("score", "75")), record));
+    assert!(evaluate(&Expression::cmp(eq("enabled", "true")), record));
+    assert!(!evaluate(&Expression::cmp(eq("score", true)), record));
+}
+
+#[test]
+fn evaluate_compares_timestamps() {
+    assert!(evaluate(
+        &Expression::cmp(ge("updated_at", "2025-01-01T08:00:00+08:00")),
+        record
+    ));
+    assert!(!evaluate(
+        &Expression::cmp(gt("updated_at", "2025-01-02T08:00:00+08:00")),
+        record
+    ));
+    assert!(!evaluate(
+        &Expression::cmp(ge("updated_at", "not-a-timestamp")),
+        record
+    ));
+}
+
+#[test]
+fn evaluate_missing_field_follows_three_valued_logic() {
+    assert!(!evaluate(&Expression::cmp(eq("missing", "x")), record));
+    assert!(!evaluate(&Expression::cmp(ne("missing", "x")), record));
+    // 与 SQL 三值逻辑一致：NOT (NULL = 'x') 仍为未知，不匹配。
+    assert!(!evaluate(
+        &Expression::negate(Expression::cmp(eq("missing", "x"))),
+        record
+    ));
+    assert!(evaluate(
+        &Expression::or(vec![Expression::cmp(eq("missing", "x")), Expression::True]),
+        record
+    ));
+    assert!(!evaluate(
+        &Expression::negate(Expression::and(vec![
+            Expression::cmp(eq("missing", "x")),
+            Expression::True
+        ])),
+        record
+    ));
+    assert!(evaluate(
+        &Expression::negate(Expression::and(vec![
+            Expression::cmp(eq("missing", "x")),
+            Expression::False
+        ])),
+        record
+    ));
+}
+
+#[test]
+fn evaluate_nested_logical_combinations() {
+    let expr = Expression::and(vec![
+        Expression::cmp(eq("status", "active")),
+        Expression::or(vec![
+            Expression::cmp(gt("score", 90_i64)),
+            Expression::negate(Expression::cmp(le("score", 30_i64))),
+        ]),
+    ]);
+    assert!(evaluate(&expr, record));
+    assert!(evaluate(&Expression::and(Vec::new()), record));
+    assert!(!evaluate(&Expression::or(Vec::new()), record));
+    assert!(!evaluate(&!Expression::True, record));
+}
+
+#[test]
+fn display_renders_nested_and_or_with_parentheses() {
+    let expr = Expression::and(vec![
+        Expression::cmp(eq("status", "active")),
+        Expression::or(vec![
+            Expression::cmp(gt("score", 80_i64)),
+            Expression::negate(Expression::or(vec![
+                Expression::cmp(r#in("code", vec!["a", "b"])),
+                Expression::cmp(contains("name", "营收")),
+            ])),
+        ]),
+        Expression::cmp(between("ratio", 0.5_f64, 1.5_f64)),
+    ]);
+    assert_eq!(
+        expr.to_string(),
+        r#"status = "active" AND (score > 80 OR NOT (code IN ("a", "b") OR name CONTAINS "营收")) AND ratio BETWEEN 0.5 AND 1.5"#
+    );
+    assert_eq!(Expression::and(Vec::new()).to_string(), "TRUE");
+}
+
+#[test]
+fn display_truncates_long_string_values() {
+    let secret = "x".repeat(DISPLAY_VALUE_MAX_CHARS + 8);
+    let rendered = Expression::cmp(eq("token", secret.as_str())).to_string();
+    assert_eq!(
+        rendered,
+        format!("token = \"{}…\"", "x".repeat(DISPLAY_VALUE_MAX_CHARS))
+    );
+}
+
+const PREVIEW_COLUMNS: [(&str, &str); 4] = [
+    ("status", "status"),
+    ("object_type", "object_type"),
+    ("score", "score"),
+    ("name", "display_name"),
+];
+
+#[test]
+fn sql_preview_covers_and_or_not_in_between() {
+    let expr = Expression::and(vec![
+        Expression::cmp(eq("status", "active")),
+        Expression::or(vec![
+            Expression::cmp(r#in("object_type", vec!["entity", "feature"])),
+            Expression::negate(Expression::cmp(between("score", 1_i64, 10_i64))),
+        ]),
+        Expression::negate(Expression::and(vec![
+            Expression::cmp(contains("name", "营收")),
+            Expression::cmp(ne("status", "archived")),
+        ])),
+    ]);
+    assert_eq!(
+        expr.to_sql_preview(&PREVIEW_COLUMNS),
+        "status = $1 AND (object_type IN ($2,$3) OR NOT score BETWEEN $4 AND $5) \
+         AND NOT (display_name LIKE $6 AND status != $7)"
+    );
+    assert_eq!(
+        Expression::or(Vec::new()).to_sql_preview(&PREVIEW_COLUMNS),
+        "FALSE"
+    );
+}
+
+#[test]
+fn sql_preview_marks_unknown_fields() {
+    let expr = Expression::or(vec![
+        Expression::cmp(eq("status", "active")),
+        Expression::cmp(gt("secret", 1_i64)),
+    ]);
+    assert_eq!(
+        expr.to_sql_preview(&PREVIEW_COLUMNS[..]),
+        format!("status = $1 OR {UNKNOWN_FIELD_MARKER}:secret > $2")
+    );
+}
@@ -48,3 +48,39 @@ fn builder_api_works() {
     let default_page = PageResult::builder(Vec::<i32>::new(), 0).build();
     assert_eq!(default_page.page_size(), DEFAULT_PAGE_SIZE);
 }
+
+#[test]
+fn navigation_helpers_cover_first_middle_and_last_pages() {
+    let first = PageResult::new(vec![1, 2, 3], 7, 0, Some(3), None);
+    assert!(!first.has_prev());
+    assert!(first.has_next());
+    assert_eq!(first.total_pages(), 3);
+
+    let middle = PageResult::new(vec![4, 5, 6], 7, 2, Some(3), Some(1));
+    assert!(middle.has_prev());
+    assert!(middle.has_next());
+
+    let last = PageResult::new(vec![7], 7, 2, Some(3), None);
+    assert!(last.has_prev());
+    assert!(!last.has_next());
+    assert_eq!(last.total(), Some(7));
+}
+
+#[test]
+fn unknown_total_infers_next_page_from_a_full_page() {
+    let full = PageResult::without_total(vec![1, 2, 3], 1, Some(3), None);
+    assert_eq!(full.total(), None);
+    assert!(full.has_prev());
+    assert!(full.has_next());
+    assert_eq!(full.total_count(), 6);
+    assert_eq!(full.total_pages(), 2);
+
+    let partial = PageResult::without_total(vec![7], 2, Some(3), None);
+    assert!(!partial.has_next());
+    assert_eq!(partial.total_count(), 7);
+
+    let empty: PageResult<u8> = PageResult::without_total(Vec::new(), 0, Some(3), None);
+    assert!(!empty.has_prev());
+    assert!(!empty.has_next());
+    assert_eq!(empty.total_pages(), 0);
+}